@@ -0,0 +1,75 @@
+use std::process::Command;
+
+/// One `powermetrics` sample on Apple Silicon. The GPU shares memory with the CPU
+/// and has no temperature sensor exposed here, so thermal state is the SoC-wide
+/// pressure level instead.
+#[derive(Debug, Clone, Default)]
+pub struct PowermetricsSample {
+    pub gpu_usage: Option<f32>,           // Active residency in percent
+    pub gpu_frequency: Option<f32>,       // Active frequency in MHz
+    pub gpu_power: Option<f32>,           // Watts
+    pub cpu_power: Option<f32>,           // Watts, all clusters
+    pub thermal_pressure: Option<String>, // Nominal, Moderate, Heavy, Trapping, Sleeping
+}
+
+/// Sampling window handed to powermetrics. Short, since the call blocks the update.
+const SAMPLE_INTERVAL_MS: &str = "200";
+
+/// Take one sample. powermetrics needs root, so this fails (and the GPU panel stays
+/// hidden) unless rmon runs under sudo.
+pub fn read_powermetrics() -> Option<PowermetricsSample> {
+    let output = Command::new("powermetrics")
+        .args([
+            "--samplers", "cpu_power,gpu_power,thermal",
+            "-i", SAMPLE_INTERVAL_MS,
+            "-n", "1",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let sample = parse_powermetrics(&String::from_utf8_lossy(&output.stdout));
+    (sample.gpu_usage.is_some() || sample.gpu_power.is_some()).then_some(sample)
+}
+
+/// Parse the plain text report:
+/// `GPU HW active frequency: 389 MHz`, `GPU HW active residency:   3.47% (...)`,
+/// `GPU Power: 12 mW`, `CPU Power: 123 mW`, `Current pressure level: Nominal`
+fn parse_powermetrics(output: &str) -> PowermetricsSample {
+    let mut sample = PowermetricsSample::default();
+    // Leading number of a value such as "3.47% (389 MHz: 3.5%)" or "12 mW"
+    let number = |value: &str| {
+        value
+            .split_whitespace()
+            .next()
+            .and_then(|v| v.trim_end_matches('%').parse::<f32>().ok())
+    };
+
+    for line in output.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        match key.trim() {
+            // Older macOS releases omit the "HW" in these two
+            "GPU HW active frequency" | "GPU active frequency" => sample.gpu_frequency = number(value),
+            "GPU HW active residency" | "GPU active residency" => sample.gpu_usage = number(value),
+            "GPU Power" => sample.gpu_power = number(value).map(|mw| mw / 1000.0),
+            "CPU Power" => sample.cpu_power = number(value).map(|mw| mw / 1000.0),
+            "Current pressure level" => sample.thermal_pressure = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    sample
+}
+
+/// Marketing name of the SoC, e.g. "Apple M2 Pro"
+pub fn chip_name() -> Option<String> {
+    let output = Command::new("sysctl")
+        .args(["-n", "machdep.cpu.brand_string"])
+        .output()
+        .ok()?;
+    let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (output.status.success() && !name.is_empty()).then_some(name)
+}
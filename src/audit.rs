@@ -0,0 +1,157 @@
+use chrono::{DateTime, Local};
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Only the tail of audit.log is read; the file can grow to many megabytes
+const TAIL_BYTES: u64 = 512 * 1024;
+/// Maximum number of events kept for display
+const MAX_EVENTS: usize = 1000;
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A single audit record, e.g. `type=SYSCALL msg=audit(1700000000.123:456): ... key="sshd"`
+pub struct AuditEvent {
+    pub time: Option<DateTime<Local>>,
+    pub serial: u64,
+    pub record_type: String,
+    pub key: Option<String>,
+    pub message: String,
+}
+
+/// State of the auditd tab: tailed events plus type/key filters
+pub struct AuditView {
+    pub path: PathBuf,
+    pub events: Vec<AuditEvent>,
+    pub error: Option<String>,
+    pub type_filter: Option<String>,
+    pub key_filter: Option<String>,
+    pub scroll: usize,
+    last_refresh: Option<Instant>,
+}
+
+impl AuditView {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            events: Vec::new(),
+            error: None,
+            type_filter: None,
+            key_filter: None,
+            scroll: 0,
+            last_refresh: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        match read_tail(&self.path) {
+            Ok(contents) => {
+                // Newest first, like the journal view
+                self.events = contents.lines().rev().filter_map(parse_line).take(MAX_EVENTS).collect();
+                self.error = None;
+            }
+            Err(err) => self.error = Some(format!("{}: {}", self.path.display(), err)),
+        }
+        self.scroll = self.scroll.min(self.filtered().len().saturating_sub(1));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Events matching the current type and key filters
+    pub fn filtered(&self) -> Vec<&AuditEvent> {
+        self.events
+            .iter()
+            .filter(|event| self.type_filter.as_ref().is_none_or(|t| event.record_type == *t))
+            .filter(|event| self.key_filter.as_ref().is_none_or(|k| event.key.as_ref() == Some(k)))
+            .collect()
+    }
+
+    /// Step the type filter through all → each seen type → all
+    pub fn cycle_type_filter(&mut self) {
+        let types = unique(self.events.iter().map(|event| event.record_type.as_str()));
+        self.type_filter = next_filter(&types, self.type_filter.as_deref());
+        self.scroll = 0;
+    }
+
+    /// Step the key filter through all → each seen key → all
+    pub fn cycle_key_filter(&mut self) {
+        let keys = unique(self.events.iter().filter_map(|event| event.key.as_deref()));
+        self.key_filter = next_filter(&keys, self.key_filter.as_deref());
+        self.scroll = 0;
+    }
+}
+
+fn unique<'a>(values: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut values: Vec<&str> = values.collect();
+    values.sort_unstable();
+    values.dedup();
+    values
+}
+
+fn next_filter(values: &[&str], current: Option<&str>) -> Option<String> {
+    let next = match current {
+        None => values.first(),
+        Some(current) => values.iter().skip_while(|v| **v != current).nth(1),
+    };
+    next.map(|v| v.to_string())
+}
+
+fn read_tail(path: &Path) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(TAIL_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)?;
+    let mut contents = String::from_utf8_lossy(&buffer).into_owned();
+
+    // Drop the partial first line when starting mid-file
+    if start > 0 {
+        if let Some(newline) = contents.find('\n') {
+            contents.drain(..=newline);
+        }
+    }
+    Ok(contents)
+}
+
+fn parse_line(line: &str) -> Option<AuditEvent> {
+    let rest = line.strip_prefix("type=")?;
+    let (record_type, rest) = rest.split_once(' ')?;
+
+    // msg=audit(1700000000.123:456): fields...
+    let rest = rest.strip_prefix("msg=audit(")?;
+    let (stamp, message) = rest.split_once("):")?;
+    let (seconds, serial) = stamp.split_once(':')?;
+    let time = seconds
+        .split('.')
+        .next()
+        .and_then(|secs| secs.parse::<i64>().ok())
+        .and_then(|secs| DateTime::from_timestamp(secs, 0))
+        .map(|utc| utc.with_timezone(&Local));
+
+    Some(AuditEvent {
+        time,
+        serial: serial.parse().unwrap_or(0),
+        record_type: record_type.to_string(),
+        key: parse_key(message),
+        message: message.trim().to_string(),
+    })
+}
+
+/// Extract `key="name"`; `key=(null)` means no key was set on the rule
+fn parse_key(message: &str) -> Option<String> {
+    let start = message.find(" key=")? + " key=".len();
+    let value = message[start..].split_whitespace().next()?;
+    let value = value.trim_matches('"');
+    if value == "(null)" || value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Reading a few files per group is cheap, but the split moves slowly
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Memory and CPU charged to one top-level cgroup and everything below it
+pub struct CgroupUsage {
+    pub name: String,      // system.slice, user.slice, docker, ...
+    pub memory: u64,       // Bytes charged, page cache included
+    pub anon: Option<u64>, // Of which anonymous memory, the part that cannot be reclaimed without swap
+    pub cpu_percent: f32,  // Of one core, like the process table
+    pub processes: usize,  // At any depth
+}
+
+/// Where the cgroup hierarchies holding the accounting are mounted
+enum Hierarchy {
+    /// The unified hierarchy: memory.current, memory.stat and cpu.stat in each group
+    V2(PathBuf),
+    /// Legacy controllers mounted separately, on older and hybrid setups
+    V1 { memory: PathBuf, cpuacct: PathBuf },
+}
+
+/// Memory and CPU by top-level cgroup (systemd slices, container runtimes), which
+/// tells who is using the RAM better than summing process RSS: page cache, tmpfs and
+/// kernel memory are charged to the group that caused them.
+pub struct CgroupView {
+    pub groups: Vec<CgroupUsage>, // Most memory first
+    pub error: Option<String>,
+    hierarchy: Hierarchy,
+    cpu_usage: HashMap<String, u64>, // CPU time of each group at the previous refresh, microseconds
+    last_refresh: Option<Instant>,
+}
+
+impl CgroupView {
+    /// None where no cgroup hierarchy accounts memory
+    pub fn new() -> Option<Self> {
+        let unified = [Path::new("/sys/fs/cgroup"), Path::new("/sys/fs/cgroup/unified")]
+            .into_iter()
+            .find(|root| fs::read_to_string(root.join("cgroup.controllers")).is_ok_and(|controllers| controllers.split_whitespace().any(|c| c == "memory")));
+        let hierarchy = match unified {
+            Some(root) => Hierarchy::V2(root.to_path_buf()),
+            None => {
+                let memory = Path::new("/sys/fs/cgroup/memory");
+                if !memory.join("memory.usage_in_bytes").exists() {
+                    return None;
+                }
+                Hierarchy::V1 { memory: memory.to_path_buf(), cpuacct: PathBuf::from("/sys/fs/cgroup/cpuacct") }
+            }
+        };
+        Some(Self {
+            groups: Vec::new(),
+            error: None,
+            hierarchy,
+            cpu_usage: HashMap::new(),
+            last_refresh: None,
+        })
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        let root = self.group_dir("");
+        let entries = match fs::read_dir(&root) {
+            Ok(entries) => entries,
+            Err(e) => {
+                self.error = Some(format!("{}: {}", root.display(), e));
+                self.last_refresh = Some(Instant::now());
+                return;
+            }
+        };
+        let names: Vec<String> = entries
+            .flatten()
+            .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+
+        let seconds = self.last_refresh.map_or(0.0, |last| last.elapsed().as_secs_f32()).max(0.001);
+        let mut cpu_usage = HashMap::with_capacity(names.len());
+        let mut groups = Vec::with_capacity(names.len());
+        for name in names {
+            let Some((memory, anon, cpu)) = self.read_group(&name) else {
+                continue;
+            };
+            // Groups that appeared since the previous refresh have no rate yet
+            let cpu_percent = match (cpu, self.cpu_usage.get(&name)) {
+                (Some(cpu), Some(&previous)) => cpu.saturating_sub(previous) as f32 / 1e6 / seconds * 100.0,
+                _ => 0.0,
+            };
+            if let Some(cpu) = cpu {
+                cpu_usage.insert(name.clone(), cpu);
+            }
+            let processes = count_processes(&self.group_dir(&name));
+            groups.push(CgroupUsage { name, memory, anon, cpu_percent, processes });
+        }
+        groups.sort_by(|a, b| b.memory.cmp(&a.memory).then_with(|| a.name.cmp(&b.name)));
+        self.groups = groups;
+        self.cpu_usage = cpu_usage;
+        self.error = None;
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// A group's directory in the hierarchy accounting memory
+    fn group_dir(&self, name: &str) -> PathBuf {
+        match &self.hierarchy {
+            Hierarchy::V2(root) => root.join(name),
+            Hierarchy::V1 { memory, .. } => memory.join(name),
+        }
+    }
+
+    /// Memory charged, anonymous memory and total CPU time in microseconds of a group
+    fn read_group(&self, name: &str) -> Option<(u64, Option<u64>, Option<u64>)> {
+        let dir = self.group_dir(name);
+        match &self.hierarchy {
+            Hierarchy::V2(_) => {
+                let memory = read_number(&dir.join("memory.current"))?;
+                let anon = read_stat(&dir.join("memory.stat"), "anon");
+                let cpu = read_stat(&dir.join("cpu.stat"), "usage_usec");
+                Some((memory, anon, cpu))
+            }
+            Hierarchy::V1 { cpuacct, .. } => {
+                let usage = read_number(&dir.join("memory.usage_in_bytes"))?;
+                let anon = read_stat(&dir.join("memory.stat"), "total_rss");
+                // Nanoseconds; the group may not exist in the cpuacct hierarchy
+                let cpu = read_number(&cpuacct.join(name).join("cpuacct.usage")).map(|ns| ns / 1000);
+                Some((usage, anon, cpu))
+            }
+        }
+    }
+}
+
+fn read_number(path: &Path) -> Option<u64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// A value of a flat keyed file like memory.stat or cpu.stat
+fn read_stat(path: &Path, key: &str) -> Option<u64> {
+    let content = fs::read_to_string(path).ok()?;
+    content.lines().find_map(|line| {
+        let (name, value) = line.split_once(' ')?;
+        (name == key).then(|| value.trim().parse().ok())?
+    })
+}
+
+/// Processes in a group and all groups below it
+fn count_processes(dir: &Path) -> usize {
+    let own = fs::read_to_string(dir.join("cgroup.procs")).map_or(0, |procs| procs.lines().count());
+    let Ok(entries) = fs::read_dir(dir) else {
+        return own;
+    };
+    own + entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_dir()))
+        .map(|entry| count_processes(&entry.path()))
+        .sum::<usize>()
+}
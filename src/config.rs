@@ -0,0 +1,511 @@
+use crate::dns;
+use crate::snmp;
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Dashboard panels / simple mode report sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Panel {
+    Cpu,
+    Gpu,
+    /// Heaviest processes by CPU and by memory
+    Top,
+    Memory,
+    Disk,
+    /// Per-disk read/write throughput
+    Io,
+    /// CPU, memory and I/O pressure stall information
+    Pressure,
+    /// Memory and CPU by top-level cgroup
+    Cgroups,
+    /// Memory and CPU per NUMA node
+    Numa,
+    Network,
+    /// Listening sockets grouped by process
+    Ports,
+    /// TCP connection counts by state
+    Tcp,
+    /// Remote hosts by TCP traffic
+    Talkers,
+    Temperature,
+    /// Chassis, CPU and PSU fan speeds
+    Fans,
+    /// Combined CPU (RAPL), GPU and battery power
+    Power,
+    /// Battery charge, state and time remaining
+    Battery,
+    /// User-defined command gauges
+    Gauges,
+    /// DNS lookup latency and failures
+    Dns,
+    /// Transfer this billing period against the monthly quota
+    Quota,
+    /// Interface rates of devices polled over SNMP
+    Snmp,
+}
+
+/// Default TUI dashboard layout (CPU, GPU and the top processes on top, everything else on bottom)
+const DEFAULT_DASHBOARD: &[Panel] = &[Panel::Cpu, Panel::Gpu, Panel::Top, Panel::Memory, Panel::Disk, Panel::Network];
+
+/// Default simple mode report order
+const DEFAULT_REPORT: &[Panel] = &[
+    Panel::Cpu,
+    Panel::Memory,
+    Panel::Disk,
+    Panel::Io,
+    Panel::Pressure,
+    Panel::Network,
+    Panel::Tcp,
+    Panel::Temperature,
+    Panel::Fans,
+    Panel::Gpu,
+    Panel::Power,
+    Panel::Battery,
+];
+
+/// User configuration loaded from `config.toml`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Panels to show, in order. Shared by the TUI dashboard and simple mode.
+    pub panels: Option<Vec<Panel>>,
+    /// Metrics update interval in seconds (`--interval` takes precedence)
+    pub interval: Option<u64>,
+    /// Process list refresh interval in seconds
+    pub process_interval: Option<u64>,
+    /// Journal log refresh interval in seconds
+    pub journal_interval: Option<u64>,
+    /// GPU polling interval in seconds, defaults to the metrics interval
+    /// (`--gpu-interval` takes precedence)
+    pub gpu_interval: Option<u64>,
+    /// Extra tabs showing command output (`[[tab]]` tables)
+    #[serde(rename = "tab")]
+    pub tabs: Vec<CustomTabConfig>,
+    /// Dashboard gauges fed by commands (`[[custom_gauge]]` tables)
+    #[serde(rename = "custom_gauge")]
+    pub gauges: Vec<CustomGaugeConfig>,
+    /// Enables the auditd tab when present
+    pub audit: Option<AuditConfig>,
+    /// Enables the firewall rule counters tab when present
+    pub firewall: Option<FirewallConfig>,
+    /// Enables the DNS probe panel when present
+    pub dns: Option<DnsConfig>,
+    /// Unit of every temperature shown (`--temperature-unit` takes precedence)
+    pub temperature_unit: Option<TemperatureUnit>,
+    /// Minutes of history kept for charts, downsampling samples older than `--history`
+    /// updates; 0 keeps only those (`--history-retention` takes precedence)
+    pub history_retention: Option<u64>,
+    /// Save the CPU, memory, network and GPU charts on exit and restore them on start
+    /// (`--persist-history` turns it on too)
+    pub persist_history: Option<bool>,
+    /// Interface shown by the network gauges and chart (`[i]` changes it), the total
+    /// when unset
+    pub network_interface: Option<String>,
+    /// Glob patterns of interfaces the network total leaves out, replacing the default
+    /// of loopback, bridges and container links (`--network-exclude` takes precedence)
+    pub network_exclude: Option<Vec<String>>,
+    /// Glob patterns of interfaces the total counts even though an exclude pattern
+    /// matches them (`--network-include` takes precedence)
+    pub network_include: Option<Vec<String>>,
+    /// Mount points whose usage the disk panel charts, the first one on its gauge;
+    /// only "/" by default (`--mounts` takes precedence)
+    pub mounts: Option<Vec<String>>,
+    /// Unit of network rates (`--rate-unit` takes precedence)
+    pub rate_unit: Option<RateUnit>,
+    /// URL answering with the public IP as plain text, checked every 5 minutes with
+    /// curl; unset by default, so rmon contacts no outside service
+    pub public_ip_url: Option<String>,
+    /// Enables monthly transfer tracking and the quota panel when present
+    pub quota: Option<QuotaConfig>,
+    /// Switches, routers and access points whose interface counters are polled over
+    /// SNMP (`[[snmp]]` tables)
+    pub snmp: Vec<SnmpConfig>,
+}
+
+/// Unit temperatures are shown in. Sensors report Celsius, so thresholds and colors
+/// stay in Celsius and only the displayed values are converted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum TemperatureUnit {
+    #[default]
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    pub fn convert(self, celsius: f32) -> f32 {
+        match self {
+            Self::Celsius => celsius,
+            Self::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Self::Celsius => "°C",
+            Self::Fahrenheit => "°F",
+        }
+    }
+
+    /// A Celsius reading in this unit with `precision` decimals, e.g. "72.5°F"
+    pub fn format(self, celsius: f32, precision: usize) -> String {
+        format!("{:.*}{}", precision, self.convert(celsius), self.symbol())
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Celsius => Self::Fahrenheit,
+            Self::Fahrenheit => Self::Celsius,
+        }
+    }
+}
+
+/// Unit network rates are shown in, scaled to the largest magnitude the rate reaches.
+/// Rates are measured in Kbps and only converted for display.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum RateUnit {
+    /// Kbps, Mbps, Gbps
+    #[default]
+    Bits,
+    /// KB/s, MB/s, GB/s
+    Bytes,
+    /// KiB/s, MiB/s, GiB/s
+    BinaryBytes,
+}
+
+impl RateUnit {
+    /// Factor from Kbps to the unit that suits `kbps`, and the unit's name
+    pub fn scale(self, kbps: f64) -> (f64, &'static str) {
+        let (mut factor, base, units) = match self {
+            Self::Bits => (1.0, 1000.0, ["Kbps", "Mbps", "Gbps"]),
+            Self::Bytes => (1.0 / 8.0, 1000.0, ["KB/s", "MB/s", "GB/s"]),
+            Self::BinaryBytes => (125.0 / 1024.0, 1024.0, ["KiB/s", "MiB/s", "GiB/s"]),
+        };
+        let mut unit = 0;
+        while kbps * factor >= base && unit < units.len() - 1 {
+            factor /= base;
+            unit += 1;
+        }
+        (factor, units[unit])
+    }
+
+    /// A rate in Kbps in this unit, e.g. "12.3 MB/s"
+    pub fn format(self, kbps: f32) -> String {
+        let (factor, unit) = self.scale(kbps as f64);
+        format!("{:.1} {}", kbps as f64 * factor, unit)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuditConfig {
+    #[serde(default = "default_audit_log")]
+    pub path: PathBuf,
+}
+
+fn default_audit_log() -> PathBuf {
+    PathBuf::from("/var/log/audit/audit.log")
+}
+
+/// Firewall rules listed with their hit counters. Listing the ruleset usually needs root.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FirewallConfig {
+    /// Where the rules come from; nftables, falling back to iptables when `nft` is not
+    /// installed, when unset
+    pub backend: Option<FirewallBackend>,
+    /// Refresh interval in seconds
+    #[serde(default = "default_firewall_interval")]
+    pub interval: u64,
+}
+
+fn default_firewall_interval() -> u64 {
+    2
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FirewallBackend {
+    /// `nft list ruleset`
+    Nft,
+    /// `iptables-save -c` and `ip6tables-save -c`
+    Iptables,
+}
+
+impl FirewallBackend {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Nft => "nftables",
+            Self::Iptables => "iptables",
+        }
+    }
+}
+
+/// Periodic lookup of `hostname`, timing how long the resolver takes to answer
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DnsConfig {
+    #[serde(default = "default_dns_hostname")]
+    pub hostname: String,
+    /// Nameserver address, optionally with a port; the first one in /etc/resolv.conf
+    /// when unset
+    pub resolver: Option<String>,
+    /// Lookup interval in seconds
+    #[serde(default = "default_dns_interval")]
+    pub interval: u64,
+}
+
+fn default_dns_hostname() -> String {
+    "example.com".to_string()
+}
+
+fn default_dns_interval() -> u64 {
+    10
+}
+
+/// Monthly transfer allowance of a metered connection. Totals are kept across sessions
+/// in `$XDG_STATE_HOME/rmon/traffic.toml`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuotaConfig {
+    /// Gigabytes per billing period, download and upload together
+    pub limit: f64,
+    /// Interface counted against the limit, the interfaces in the network total when unset
+    pub interface: Option<String>,
+    /// Day of the month the allowance resets
+    #[serde(default = "default_quota_reset_day")]
+    pub reset_day: u32,
+}
+
+fn default_quota_reset_day() -> u32 {
+    1
+}
+
+/// A device polled over SNMPv2c for the traffic counters of some of its interfaces
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SnmpConfig {
+    pub name: String,
+    /// Address or hostname, optionally with a port (161 by default)
+    pub host: String,
+    #[serde(default = "default_snmp_community")]
+    pub community: String,
+    /// ifIndex of each interface to show, as listed by `snmpwalk -v2c -c public <host> ifName`
+    #[serde(default)]
+    pub interfaces: Vec<u32>,
+    /// Byte counters outside IF-MIB, e.g. of a modem's WAN port
+    #[serde(default, rename = "counter")]
+    pub counters: Vec<SnmpCounterConfig>,
+    /// Polling interval in seconds
+    #[serde(default = "default_snmp_interval")]
+    pub interval: u64,
+}
+
+/// A pair of received and sent byte counter OIDs shown as one interface
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SnmpCounterConfig {
+    pub name: String,
+    pub rx: String,
+    pub tx: String,
+}
+
+fn default_snmp_community() -> String {
+    "public".to_string()
+}
+
+fn default_snmp_interval() -> u64 {
+    5
+}
+
+/// A tab that shows the output of a shell command, e.g. `zpool status`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomTabConfig {
+    pub title: String,
+    #[serde(alias = "cmd")]
+    pub command: String,
+    /// Refresh interval in seconds
+    #[serde(default = "default_custom_interval")]
+    pub interval: u64,
+    #[serde(default)]
+    pub format: CustomTabFormat,
+}
+
+/// How custom tab output is rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CustomTabFormat {
+    /// Plain scrollable text
+    #[default]
+    Text,
+    /// First line is the header, remaining lines are whitespace-separated rows
+    Table,
+}
+
+/// A gauge whose value is extracted from a command's output
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomGaugeConfig {
+    pub name: String,
+    #[serde(alias = "cmd")]
+    pub command: String,
+    /// Pattern locating the value; the first capture group is used if there is one.
+    /// Defaults to the first number in the output.
+    pub regex: Option<String>,
+    /// Value that fills the gauge
+    pub max: f64,
+    /// Refresh interval in seconds
+    #[serde(default = "default_custom_interval")]
+    pub interval: u64,
+}
+
+fn default_custom_interval() -> u64 {
+    5
+}
+
+impl Config {
+    /// Load the config from an explicit path, or from the default location if present.
+    /// A missing default config is not an error - built-in defaults are used instead.
+    pub fn load(path: Option<&Path>) -> Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match default_config_path() {
+                Some(path) if path.exists() => path,
+                _ => return Ok(Self::default()),
+            },
+        };
+
+        let contents = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+        let config: Self = toml::from_str(&contents)
+            .with_context(|| format!("Failed to parse config file {}", path.display()))?;
+        config.validate()
+            .with_context(|| format!("Invalid config file {}", path.display()))?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let intervals = [
+            ("interval", self.interval),
+            ("process_interval", self.process_interval),
+            ("journal_interval", self.journal_interval),
+            ("gpu_interval", self.gpu_interval),
+        ];
+        if let Some((name, _)) = intervals.iter().find(|(_, interval)| *interval == Some(0)) {
+            bail!("{} must be at least 1 second", name);
+        }
+        for gauge in &self.gauges {
+            if let Some(pattern) = &gauge.regex {
+                Regex::new(pattern)
+                    .with_context(|| format!("Invalid regex for gauge \"{}\"", gauge.name))?;
+            }
+            if gauge.max <= 0.0 {
+                bail!("Gauge \"{}\" needs a positive max", gauge.name);
+            }
+        }
+        if let Some(dns) = &self.dns {
+            if let Some(resolver) = dns.resolver.as_deref().filter(|resolver| dns::parse_resolver(resolver).is_none()) {
+                bail!("Invalid DNS resolver \"{}\", expected an IP address with an optional port", resolver);
+            }
+            let labels = dns.hostname.trim_end_matches('.').split('.');
+            if labels.into_iter().any(|label| label.is_empty() || label.len() > 63) {
+                bail!("Invalid DNS hostname \"{}\"", dns.hostname);
+            }
+        }
+        if let Some(quota) = &self.quota {
+            if quota.limit <= 0.0 {
+                bail!("Quota needs a positive limit");
+            }
+            // Every month has these days
+            if !(1..=28).contains(&quota.reset_day) {
+                bail!("Quota reset_day must be between 1 and 28");
+            }
+        }
+        if let Some(mounts) = &self.mounts {
+            if mounts.is_empty() {
+                bail!("mounts needs at least one mount point");
+            }
+            if let Some(mount) = mounts.iter().find(|mount| !mount.starts_with('/')) {
+                bail!("Invalid mount point \"{}\", expected an absolute path", mount);
+            }
+        }
+        for device in &self.snmp {
+            if device.interfaces.is_empty() && device.counters.is_empty() {
+                bail!("SNMP device \"{}\" needs interfaces or counters to poll", device.name);
+            }
+            for counter in &device.counters {
+                for oid in [&counter.rx, &counter.tx] {
+                    if snmp::parse_oid(oid).is_none() {
+                        bail!("Invalid OID \"{}\" for SNMP counter \"{}\"", oid, counter.name);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn interval(&self) -> u64 {
+        self.interval.unwrap_or(1)
+    }
+
+    pub fn process_interval(&self) -> u64 {
+        self.process_interval.unwrap_or(2)
+    }
+
+    pub fn journal_interval(&self) -> u64 {
+        self.journal_interval.unwrap_or(5)
+    }
+
+    /// Panels for the TUI dashboard. Temperatures are part of the CPU panel there.
+    pub fn dashboard_panels(&self) -> Vec<Panel> {
+        match &self.panels {
+            Some(panels) => panels.iter().copied().filter(|p| *p != Panel::Temperature).collect(),
+            None => self.with_configured_panels(DEFAULT_DASHBOARD),
+        }
+    }
+
+    /// Sections for the simple mode report
+    pub fn report_sections(&self) -> Vec<Panel> {
+        match &self.panels {
+            Some(panels) => panels.clone(),
+            None => self.with_configured_panels(DEFAULT_REPORT),
+        }
+    }
+
+    /// Default layouts get a gauges panel whenever gauges are configured, a DNS panel
+    /// with a `[dns]` table, a quota panel with a `[quota]` table and an SNMP panel with
+    /// `[[snmp]]` devices
+    fn with_configured_panels(&self, defaults: &[Panel]) -> Vec<Panel> {
+        let mut panels = defaults.to_vec();
+        if !self.gauges.is_empty() {
+            panels.push(Panel::Gauges);
+        }
+        if self.dns.is_some() {
+            panels.push(Panel::Dns);
+        }
+        if self.quota.is_some() {
+            panels.push(Panel::Quota);
+        }
+        if !self.snmp.is_empty() {
+            panels.push(Panel::Snmp);
+        }
+        panels
+    }
+}
+
+/// `$XDG_CONFIG_HOME/rmon/config.toml`, falling back to `~/.config/rmon/config.toml`
+pub fn default_config_path() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(base.join("rmon").join("config.toml"))
+}
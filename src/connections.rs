@@ -0,0 +1,478 @@
+use std::{
+    collections::HashMap,
+    fs,
+    net::{Ipv4Addr, Ipv6Addr},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::history::History;
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+/// Open ports change rarely, and finding their owners walks every process's fds
+const LISTENING_REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+const PROTOCOLS: [&str; 4] = ["tcp", "tcp6", "udp", "udp6"];
+
+/// TCP states the tcp panel counts. Steadily growing TIME_WAIT means connections are
+/// not reused, CLOSE_WAIT a peer closed and the application never did, SYN_RECV
+/// handshakes that are not completed (a full backlog or a SYN flood).
+pub const TRACKED_STATES: [&str; 4] = ["ESTABLISHED", "TIME_WAIT", "CLOSE_WAIT", "SYN_RECV"];
+
+/// A TCP or UDP socket, from a /proc/net socket table
+pub struct Connection {
+    pub protocol: &'static str, // tcp, tcp6, udp, udp6
+    pub local: String,
+    pub remote: Option<String>, // None while listening or unconnected
+    pub state: &'static str,
+    pub inode: u64,
+}
+
+impl Connection {
+    /// Listening TCP and unconnected UDP sockets
+    pub fn is_listening(&self) -> bool {
+        self.state == "LISTEN" || self.state == "UNCONN"
+    }
+
+    /// Bound to every address rather than loopback or one interface
+    pub fn is_exposed(&self) -> bool {
+        self.local.starts_with("0.0.0.0:") || self.local.starts_with("[::]:")
+    }
+
+    /// Loopback only, unreachable from other machines
+    pub fn is_loopback(&self) -> bool {
+        self.local.starts_with("127.") || self.local.starts_with("[::1]:")
+    }
+
+    fn port(&self) -> u16 {
+        self.local.rsplit_once(':').and_then(|(_, port)| port.parse().ok()).unwrap_or(0)
+    }
+}
+
+/// Listening sockets of one process, or of processes we cannot see
+pub struct ListeningProcess {
+    pub owner: Option<(u32, String)>, // PID and name
+    pub sockets: Vec<Connection>,     // By port
+}
+
+/// Listening sockets grouped by process, for the ports panel and report section
+pub struct ListeningPorts {
+    pub processes: Vec<ListeningProcess>, // By name, unknown owners last
+    last_refresh: Option<Instant>,
+}
+
+impl ListeningPorts {
+    pub fn new() -> Self {
+        Self {
+            processes: Vec::new(),
+            last_refresh: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= LISTENING_REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        let owners = socket_owners();
+        let mut processes: Vec<ListeningProcess> = Vec::new();
+        for connection in read_sockets(Path::new("/proc/net")).into_iter().filter(Connection::is_listening) {
+            let owner = owners.get(&connection.inode).cloned();
+            let pid = owner.as_ref().map(|(pid, _)| *pid);
+            match processes.iter_mut().find(|process| process.owner.as_ref().map(|(pid, _)| *pid) == pid) {
+                Some(process) => process.sockets.push(connection),
+                None => processes.push(ListeningProcess { owner, sockets: vec![connection] }),
+            }
+        }
+        for process in &mut processes {
+            process.sockets.sort_by_key(|socket| (socket.port(), socket.protocol));
+        }
+        processes.sort_by_key(|process| (process.owner.is_none(), process.owner.clone()));
+        self.processes = processes;
+        self.last_refresh = Some(Instant::now());
+    }
+}
+
+/// Number of TCP sockets in one state, IPv4 and IPv6 together
+pub struct StateCount {
+    pub state: &'static str,
+    pub count: usize,
+    history: History,
+}
+
+impl StateCount {
+    pub fn new(state: &'static str, max_history: usize) -> Self {
+        Self {
+            state,
+            count: 0,
+            history: History::new(max_history),
+        }
+    }
+
+    pub fn update(&mut self, count: usize, retention: Option<Duration>) {
+        self.count = count;
+        self.history.push(count as f32, retention);
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+}
+
+/// Retransmissions and socket buffer memory: signs of a lossy or congested network, or
+/// of applications not reading their sockets, that throughput graphs do not show
+pub struct TcpHealth {
+    pub retransmit_rate: f32,    // Segments per second
+    pub retransmit_percent: f32, // Share of the segments sent in the same interval
+    pub socket_memory: u64,      // Bytes in TCP and UDP socket buffers
+    pub orphans: u64,            // TCP sockets no longer attached to a process
+    counters: Option<(u64, u64)>, // OutSegs and RetransSegs at the previous update
+    last_update: Instant,
+    retransmit_history: History, // Segments per second
+    memory_history: History,     // KB
+}
+
+impl TcpHealth {
+    pub fn new(max_history: usize) -> Self {
+        Self {
+            retransmit_rate: 0.0,
+            retransmit_percent: 0.0,
+            socket_memory: 0,
+            orphans: 0,
+            counters: None,
+            last_update: Instant::now(),
+            retransmit_history: History::new(max_history),
+            memory_history: History::new(max_history),
+        }
+    }
+
+    pub fn update(&mut self, retention: Option<Duration>) {
+        let now = Instant::now();
+        let seconds = now.duration_since(self.last_update).as_secs_f32().max(0.001);
+        self.last_update = now;
+
+        let counters = read_tcp_segments();
+        if let (Some((prev_out, prev_retrans)), Some((out, retrans))) = (self.counters, counters) {
+            let (sent, retransmitted) = (out.saturating_sub(prev_out), retrans.saturating_sub(prev_retrans));
+            self.retransmit_rate = retransmitted as f32 / seconds;
+            self.retransmit_percent = if sent > 0 { retransmitted as f32 / sent as f32 * 100.0 } else { 0.0 };
+            self.retransmit_history.push(self.retransmit_rate, retention);
+        }
+        self.counters = counters;
+
+        if let Some((memory, orphans)) = read_socket_memory() {
+            self.socket_memory = memory;
+            self.orphans = orphans;
+            self.memory_history.push(memory as f32 / 1024.0, retention);
+        }
+    }
+
+    pub fn retransmit_history(&self) -> &History {
+        &self.retransmit_history
+    }
+
+    pub fn memory_history(&self) -> &History {
+        &self.memory_history
+    }
+}
+
+/// A socket and the process holding it, when that process is visible to us
+pub struct Socket {
+    pub connection: Connection,
+    pub owner: Option<(u32, String)>, // PID and name
+}
+
+/// Column the Connections tab is sorted by
+#[derive(Clone, Copy, PartialEq)]
+pub enum ConnectionSort {
+    State,
+    Protocol,
+    Local,
+    Remote,
+    Process,
+}
+
+impl ConnectionSort {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::State => "state",
+            Self::Protocol => "protocol",
+            Self::Local => "local",
+            Self::Remote => "remote",
+            Self::Process => "process",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::State => Self::Protocol,
+            Self::Protocol => Self::Local,
+            Self::Local => Self::Remote,
+            Self::Remote => Self::Process,
+            Self::Process => Self::State,
+        }
+    }
+}
+
+/// State of the Connections tab: every TCP/UDP socket of rmon's network namespace
+pub struct ConnectionsView {
+    pub sockets: Vec<Socket>,
+    pub sort: ConnectionSort,
+    pub protocol_filter: Option<&'static str>, // tcp or udp, IPv4 and IPv6 alike
+    pub state_filter: Option<String>,
+    pub scroll: usize,
+    last_refresh: Option<Instant>,
+}
+
+impl ConnectionsView {
+    pub fn new() -> Self {
+        Self {
+            sockets: Vec::new(),
+            sort: ConnectionSort::State,
+            protocol_filter: None,
+            state_filter: None,
+            scroll: 0,
+            last_refresh: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        let owners = socket_owners();
+        self.sockets = read_sockets(Path::new("/proc/net"))
+            .into_iter()
+            .map(|connection| {
+                let owner = owners.get(&connection.inode).cloned();
+                Socket { connection, owner }
+            })
+            .collect();
+        self.sort_sockets();
+        self.scroll = self.scroll.min(self.filtered().len().saturating_sub(1));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Sockets matching the protocol and state filters
+    pub fn filtered(&self) -> Vec<&Socket> {
+        self.sockets
+            .iter()
+            .filter(|socket| self.protocol_filter.is_none_or(|protocol| socket.connection.protocol.starts_with(protocol)))
+            .filter(|socket| self.state_filter.as_deref().is_none_or(|state| socket.connection.state == state))
+            .collect()
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort = self.sort.next();
+        self.sort_sockets();
+    }
+
+    /// Step the protocol filter through all → tcp → udp → all
+    pub fn cycle_protocol_filter(&mut self) {
+        self.protocol_filter = match self.protocol_filter {
+            None => Some("tcp"),
+            Some("tcp") => Some("udp"),
+            _ => None,
+        };
+        self.scroll = 0;
+    }
+
+    /// Step the state filter through all → each seen state → all
+    pub fn cycle_state_filter(&mut self) {
+        let mut states: Vec<&str> = self.sockets.iter().map(|socket| socket.connection.state).collect();
+        states.sort_unstable();
+        states.dedup();
+        let next = match self.state_filter.as_deref() {
+            None => states.first(),
+            Some(current) => states.iter().skip_while(|state| **state != current).nth(1),
+        };
+        self.state_filter = next.map(|state| state.to_string());
+        self.scroll = 0;
+    }
+
+    /// Listening sockets first within each key, like `ss -a`
+    fn sort_sockets(&mut self) {
+        let owner_name = |socket: &Socket| socket.owner.as_ref().map(|(_, name)| name.clone());
+        match self.sort {
+            ConnectionSort::State => self.sockets.sort_by_key(|socket| {
+                (!socket.connection.is_listening(), socket.connection.state, socket.connection.local.clone())
+            }),
+            ConnectionSort::Protocol => self.sockets.sort_by_key(|socket| {
+                (socket.connection.protocol, !socket.connection.is_listening(), socket.connection.local.clone())
+            }),
+            ConnectionSort::Local => self.sockets.sort_by_key(|socket| socket.connection.local.clone()),
+            ConnectionSort::Remote => self.sockets.sort_by_key(|socket| socket.connection.remote.clone()),
+            // Sockets without a known owner last
+            ConnectionSort::Process => self.sockets.sort_by_key(|socket| (socket.owner.is_none(), owner_name(socket))),
+        }
+    }
+}
+
+/// Every socket in the tcp, tcp6, udp and udp6 tables under `dir` (/proc/net, or
+/// /proc/<pid>/net for a process's namespace). Each line looks like
+/// `0: 0100007F:0035 00000000:0000 0A 00000000:00000000 00:00000000 00000000 0 0 12345 ...`
+/// (local, remote, state, ..., inode in the tenth field).
+pub fn read_sockets(dir: &Path) -> Vec<Connection> {
+    let mut connections = Vec::new();
+    for protocol in PROTOCOLS {
+        let Ok(table) = fs::read_to_string(dir.join(protocol)) else {
+            continue;
+        };
+        for line in table.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let (Some(local), Some(remote), Some(state), Some(inode)) = (
+                fields.get(1).and_then(|a| parse_address(a)),
+                fields.get(2).and_then(|a| parse_address(a)),
+                fields.get(3),
+                fields.get(9).and_then(|inode| inode.parse::<u64>().ok()),
+            ) else {
+                continue;
+            };
+            let state = socket_state(protocol.starts_with("tcp"), state);
+            // An all-zero peer means listening (TCP) or unconnected (UDP)
+            let remote = (!remote.ends_with(":0")).then_some(remote);
+            connections.push(Connection { protocol, local, remote, state, inode });
+        }
+    }
+    connections
+}
+
+/// TCP sockets per state in /proc/net/tcp and tcp6. Only the state column is looked
+/// at, so this stays cheap on servers with many connections.
+pub fn count_tcp_states() -> HashMap<&'static str, usize> {
+    let mut counts = HashMap::new();
+    for protocol in ["tcp", "tcp6"] {
+        let Ok(table) = fs::read_to_string(Path::new("/proc/net").join(protocol)) else {
+            continue;
+        };
+        for line in table.lines().skip(1) {
+            if let Some(state) = line.split_whitespace().nth(3) {
+                *counts.entry(socket_state(true, state)).or_insert(0) += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// OutSegs and RetransSegs from the Tcp lines of /proc/net/snmp, a header line followed
+/// by a value line; they cover IPv6 as well:
+///
+/// ```text
+/// Tcp: RtoAlgorithm RtoMin ... InSegs OutSegs RetransSegs InErrs OutRsts InCsumErrors
+/// Tcp: 1 200 ... 36370 36533 12 0 24 0
+/// ```
+fn read_tcp_segments() -> Option<(u64, u64)> {
+    let snmp = fs::read_to_string("/proc/net/snmp").ok()?;
+    let mut lines = snmp.lines().filter(|line| line.starts_with("Tcp:"));
+    let (header, values) = (lines.next()?, lines.next()?);
+    let counters: HashMap<&str, u64> = header
+        .split_whitespace()
+        .zip(values.split_whitespace())
+        .filter_map(|(name, value)| Some((name, value.parse().ok()?)))
+        .collect();
+    Some((*counters.get("OutSegs")?, *counters.get("RetransSegs")?))
+}
+
+/// Socket buffer memory in bytes and the orphaned TCP socket count from
+/// /proc/net/sockstat, where memory is counted in pages:
+///
+/// ```text
+/// TCP: inuse 8 orphan 0 tw 2 alloc 8 mem 80
+/// UDP: inuse 1 mem 0
+/// ```
+fn read_socket_memory() -> Option<(u64, u64)> {
+    let sockstat = fs::read_to_string("/proc/net/sockstat").ok()?;
+    let field = |protocol: &str, key: &str| -> Option<u64> {
+        let line = sockstat.lines().find_map(|line| line.strip_prefix(protocol))?;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        tokens.windows(2).find(|pair| pair[0] == key)?[1].parse().ok()
+    };
+    // SAFETY: sysconf has no preconditions
+    let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) }.max(0) as u64;
+    let pages = field("TCP:", "mem")? + field("UDP:", "mem").unwrap_or(0);
+    Some((pages * page_size, field("TCP:", "orphan").unwrap_or(0)))
+}
+
+/// Whether this system has socket tables to list (Linux)
+pub fn available() -> bool {
+    Path::new("/proc/net/tcp").exists()
+}
+
+/// Socket inode -> PID and name of a process holding it, from the fd links in
+/// /proc/<pid>/fd. Only our own processes are visible without root.
+fn socket_owners() -> HashMap<u64, (u32, String)> {
+    let mut owners = HashMap::new();
+    let Ok(processes) = fs::read_dir("/proc") else {
+        return owners;
+    };
+    for process in processes.flatten() {
+        let Some(pid) = process.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(process.path().join("fd")) else {
+            continue;
+        };
+        let mut name = None;
+        for fd in fds.flatten() {
+            let Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            let Some(inode) = target
+                .to_str()
+                .and_then(|target| target.strip_prefix("socket:[")?.strip_suffix(']')?.parse::<u64>().ok())
+            else {
+                continue;
+            };
+            // Read lazily: most processes hold no sockets
+            let name = name.get_or_insert_with(|| {
+                fs::read_to_string(process.path().join("comm")).map(|comm| comm.trim().to_string()).unwrap_or_default()
+            });
+            owners.entry(inode).or_insert_with(|| (pid, name.clone()));
+        }
+    }
+    owners
+}
+
+/// `0100007F:0035` -> `127.0.0.1:53`. The address is stored as 32-bit words in host
+/// (little endian) byte order, the port in big endian hex.
+fn parse_address(address: &str) -> Option<String> {
+    let (ip, port) = address.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let words = (0..ip.len() / 8)
+        .map(|i| u32::from_str_radix(&ip[i * 8..i * 8 + 8], 16).map(u32::swap_bytes))
+        .collect::<Result<Vec<u32>, _>>()
+        .ok()?;
+    match words[..] {
+        [word] => Some(format!("{}:{}", Ipv4Addr::from(word), port)),
+        [a, b, c, d] => {
+            let ip = Ipv6Addr::from(((a as u128) << 96) | ((b as u128) << 64) | ((c as u128) << 32) | d as u128);
+            // IPv4 clients of dual-stack sockets show up as ::ffff:a.b.c.d
+            match ip.to_ipv4_mapped() {
+                Some(v4) => Some(format!("{}:{}", v4, port)),
+                None => Some(format!("[{}]:{}", ip, port)),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Name of a socket state from include/net/tcp_states.h. UDP reuses the numbers:
+/// 01 for connected sockets and 07 (CLOSE) for unconnected ones.
+fn socket_state(tcp: bool, state: &str) -> &'static str {
+    match (tcp, state) {
+        (true, "01") => "ESTABLISHED",
+        (true, "02") => "SYN_SENT",
+        (true, "03") => "SYN_RECV",
+        (true, "04") => "FIN_WAIT1",
+        (true, "05") => "FIN_WAIT2",
+        (true, "06") => "TIME_WAIT",
+        (true, "07") => "CLOSE",
+        (true, "08") => "CLOSE_WAIT",
+        (true, "09") => "LAST_ACK",
+        (true, "0A") => "LISTEN",
+        (true, "0B") => "CLOSING",
+        (false, "01") => "CONNECTED",
+        (false, _) => "UNCONN",
+        _ => "UNKNOWN",
+    }
+}
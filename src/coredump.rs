@@ -0,0 +1,83 @@
+use chrono::{Duration as ChronoDuration, NaiveDateTime};
+use std::process::Command;
+
+/// A crash recorded by systemd-coredump
+#[derive(Clone, PartialEq)]
+pub struct Coredump {
+    pub time: NaiveDateTime,
+    pub pid: u32,
+    pub uid: u32,
+    pub signal: String,
+    /// Whether the core file is still stored (`present`, `missing`, `none`, ...)
+    pub corefile: String,
+    pub exe: String,
+}
+
+impl Coredump {
+    /// `journalctl` arguments selecting the log lines around this crash
+    pub fn journal_context_args(&self) -> Vec<String> {
+        let since = self.time - ChronoDuration::seconds(30);
+        let until = self.time + ChronoDuration::seconds(5);
+        vec![
+            "--since".to_string(),
+            since.format("%Y-%m-%d %H:%M:%S").to_string(),
+            "--until".to_string(),
+            until.format("%Y-%m-%d %H:%M:%S").to_string(),
+        ]
+    }
+
+    /// Program name without the directory
+    pub fn program(&self) -> &str {
+        self.exe.rsplit('/').next().unwrap_or(&self.exe)
+    }
+}
+
+/// Recent coredumps, newest first. Empty if coredumpctl is unavailable.
+pub fn list_coredumps() -> Vec<Coredump> {
+    let output = Command::new("timeout")
+        .args(["1s", "coredumpctl", "list", "--no-pager", "--no-legend", "-r", "-n", "100"])
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(parse_line)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Parse a `coredumpctl list` line:
+/// `Thu 2025-07-10 10:15:42 CEST 1234 1000 1000 SIGSEGV present /usr/bin/foo 1.2M`
+fn parse_line(line: &str) -> Option<Coredump> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 10 {
+        return None;
+    }
+
+    let time = NaiveDateTime::parse_from_str(&format!("{} {}", parts[1], parts[2]), "%Y-%m-%d %H:%M:%S").ok()?;
+    Some(Coredump {
+        time,
+        pid: parts[4].parse().ok()?,
+        uid: parts[5].parse().ok()?,
+        signal: signal_name(parts[7]),
+        corefile: parts[8].to_string(),
+        exe: parts[9].to_string(),
+    })
+}
+
+/// Older systemd versions print signal numbers instead of names
+fn signal_name(signal: &str) -> String {
+    let name = match signal.parse::<i32>() {
+        Ok(4) => "SIGILL",
+        Ok(5) => "SIGTRAP",
+        Ok(6) => "SIGABRT",
+        Ok(7) => "SIGBUS",
+        Ok(8) => "SIGFPE",
+        Ok(11) => "SIGSEGV",
+        Ok(3) => "SIGQUIT",
+        Ok(31) => "SIGSYS",
+        _ => signal,
+    };
+    name.to_string()
+}
@@ -0,0 +1,222 @@
+use crate::config::{CustomGaugeConfig, CustomTabConfig, CustomTabFormat};
+use crate::history::History;
+use crate::worker::Job;
+use regex::Regex;
+use std::{
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// Maximum time a user-defined command may run before it is killed
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Run a user-defined shell command with a timeout, returning its stdout or an error message
+pub fn run_command(command: &str) -> Result<String, String> {
+    let output = Command::new("timeout")
+        .arg(format!("{}s", COMMAND_TIMEOUT.as_secs()))
+        .arg("sh")
+        .arg("-c")
+        .arg(command)
+        .output()
+        .map_err(|e| format!("failed to run command: {}", e))?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else if output.status.code() == Some(124) {
+        Err(format!("timed out after {}s", COMMAND_TIMEOUT.as_secs()))
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let message = stderr.lines().next().unwrap_or("").trim();
+        if message.is_empty() {
+            Err(format!("exited with {}", output.status))
+        } else {
+            Err(message.to_string())
+        }
+    }
+}
+
+/// A config-defined tab showing the output of a command, refreshed on an interval. The
+/// command runs in the background, so a slow one does not hold up the UI.
+pub struct CustomTab {
+    pub config: CustomTabConfig,
+    pub lines: Vec<String>,
+    pub error: Option<String>,
+    pub scroll: usize,
+    job: Option<Job<Result<String, String>>>,
+    last_refresh: Option<Instant>,
+}
+
+impl CustomTab {
+    pub fn new(config: CustomTabConfig) -> Self {
+        Self {
+            config,
+            lines: Vec::new(),
+            error: None,
+            scroll: 0,
+            job: None,
+            last_refresh: None,
+        }
+    }
+
+    /// Due for a run, and none still going
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none()
+            && self.last_refresh.is_none_or(|last| last.elapsed() >= Duration::from_secs(self.config.interval.max(1)))
+    }
+
+    /// Start the command in the background; `poll` picks up the output
+    pub fn refresh(&mut self) {
+        let command = self.config.command.clone();
+        self.job = Some(Job::spawn(move || run_command(&command)));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Take the output of a finished run
+    pub fn poll(&mut self) {
+        let Some(result) = self.job.as_ref().and_then(Job::try_result) else {
+            return;
+        };
+        self.job = None;
+        match result.and_then(|result| result) {
+            Ok(output) => {
+                self.lines = output.lines().map(|s| s.to_string()).collect();
+                self.error = None;
+            }
+            // Keep the last good output around, just flag the failure
+            Err(err) => self.error = Some(err),
+        }
+        self.scroll = self.scroll.min(self.lines.len().saturating_sub(1));
+    }
+
+    /// Number of scrollable rows (the header line is fixed in table format)
+    pub fn row_count(&self) -> usize {
+        match self.config.format {
+            CustomTabFormat::Text => self.lines.len(),
+            CustomTabFormat::Table => self.lines.len().saturating_sub(1),
+        }
+    }
+
+    /// Split output into a header and rows of whitespace-separated columns.
+    /// The last column keeps the rest of the line, so free-form trailing text survives.
+    pub fn table(&self) -> (Vec<&str>, Vec<Vec<&str>>) {
+        let mut lines = self.lines.iter().filter(|line| !line.trim().is_empty());
+        let header: Vec<&str> = match lines.next() {
+            Some(line) => line.split_whitespace().collect(),
+            None => return (Vec::new(), Vec::new()),
+        };
+
+        let columns = header.len().max(1);
+        let rows = lines
+            .map(|line| {
+                let mut row = Vec::with_capacity(columns);
+                let mut rest = line.trim_start();
+                while row.len() + 1 < columns {
+                    match rest.split_once(char::is_whitespace) {
+                        Some((cell, tail)) => {
+                            row.push(cell);
+                            rest = tail.trim_start();
+                        }
+                        None => break,
+                    }
+                }
+                row.push(rest.trim_end());
+                row
+            })
+            .collect();
+
+        (header, rows)
+    }
+}
+
+/// A config-defined dashboard gauge, fed by a number extracted from command output. The
+/// command runs in the background, so a slow one does not hold up the UI.
+pub struct CustomGauge {
+    pub config: CustomGaugeConfig,
+    pub value: Option<f64>,
+    pub error: Option<String>,
+    history: History,
+    regex: Regex,
+    job: Option<Job<Result<f64, String>>>,
+    last_refresh: Option<Instant>,
+}
+
+impl CustomGauge {
+    pub fn new(config: CustomGaugeConfig, max_history: usize) -> Self {
+        // Patterns are validated when the config is loaded
+        let regex = config.regex
+            .as_deref()
+            .and_then(|pattern| Regex::new(pattern).ok())
+            .unwrap_or_else(|| Regex::new(r"-?\d+(?:\.\d+)?").expect("default gauge regex is valid"));
+
+        Self {
+            config,
+            value: None,
+            error: None,
+            history: History::new(max_history),
+            regex,
+            job: None,
+            last_refresh: None,
+        }
+    }
+
+    /// Due for a run, and none still going
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none()
+            && self.last_refresh.is_none_or(|last| last.elapsed() >= Duration::from_secs(self.config.interval.max(1)))
+    }
+
+    /// Start the command in the background; `poll` picks up the value
+    pub fn refresh(&mut self) {
+        let (command, regex) = (self.config.command.clone(), self.regex.clone());
+        self.job = Some(Job::spawn(move || run_command(&command).and_then(|output| extract(&regex, &output))));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Take the value of a finished run
+    pub fn poll(&mut self, retention: Option<Duration>) {
+        let Some(result) = self.job.as_ref().and_then(Job::try_result) else {
+            return;
+        };
+        self.job = None;
+        match result.and_then(|result| result) {
+            Ok(value) => {
+                self.value = Some(value);
+                self.error = None;
+                self.history.push(value as f32, retention);
+            }
+            Err(err) => {
+                self.value = None;
+                self.error = Some(err);
+            }
+        }
+    }
+
+    /// Value as a percentage of the configured max
+    pub fn percent(&self) -> f64 {
+        self.value.map_or(0.0, |value| (value / self.config.max * 100.0).clamp(0.0, 100.0))
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// The history, to carry it across restarts
+    pub fn history_mut(&mut self) -> &mut History {
+        &mut self.history
+    }
+
+    /// Key of the history in the state file
+    pub fn history_key(&self) -> String {
+        format!("gauge:{}", self.config.name)
+    }
+}
+
+/// The gauge's number: the first capture group of `regex` in the output, or the whole match
+fn extract(regex: &Regex, output: &str) -> Result<f64, String> {
+    let captures = regex.captures(output).ok_or("no match in output")?;
+    let matched = captures.get(1).or_else(|| captures.get(0)).ok_or("no match in output")?;
+    matched.as_str()
+        .trim()
+        .parse::<f64>()
+        .map_err(|_| format!("\"{}\" is not a number", matched.as_str()))
+}
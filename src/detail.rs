@@ -0,0 +1,201 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use crate::connections::{self, Connection};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+/// Most common fd targets listed in the pane
+const TOP_FD_TARGETS: usize = 50;
+
+/// Open file descriptors of a process, from /proc/<pid>/fd
+#[derive(Default)]
+pub struct FdSummary {
+    pub count: usize,
+    pub limit: Option<u64>, // Soft RLIMIT_NOFILE
+    pub files: usize,
+    pub sockets: usize,
+    pub pipes: usize,
+    pub other: usize,               // anon_inode:[eventfd], /dev/..., ...
+    pub top: Vec<(String, usize)>,  // Target -> number of fds pointing at it, most first
+}
+
+impl FdSummary {
+    pub fn percent_used(&self) -> Option<f64> {
+        self.limit.filter(|limit| *limit > 0).map(|limit| self.count as f64 / limit as f64 * 100.0)
+    }
+}
+
+/// Where the memory of a process comes from, summed over all its mappings by
+/// /proc/<pid>/smaps_rollup. Sizes in bytes.
+pub struct MemoryMap {
+    pub rss: u64,
+    pub pss: u64,       // Shared pages divided among the processes mapping them
+    pub anonymous: u64, // Heap, stacks and private mappings
+    pub file: u64,      // Binaries, libraries, mapped files and shared memory
+    pub shared: u64,    // Resident pages that other processes map too
+    pub swap: u64,
+}
+
+/// State of the process detail pane of the Processes tab
+pub struct ProcessDetail {
+    pub pid: u32,
+    pub name: String,
+    pub command: String,
+    pub fds: FdSummary,
+    pub memory: Option<MemoryMap>, // None where smaps_rollup is not readable
+    pub connections: Vec<Connection>, // Listening sockets first
+    pub error: Option<String>,
+    last_refresh: Option<Instant>,
+}
+
+impl ProcessDetail {
+    pub fn new(pid: u32, name: String) -> Self {
+        let mut detail = Self {
+            pid,
+            name,
+            command: String::new(),
+            fds: FdSummary::default(),
+            memory: None,
+            connections: Vec::new(),
+            error: None,
+            last_refresh: None,
+        };
+        detail.refresh();
+        detail
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        self.last_refresh = Some(Instant::now());
+
+        // Arguments are NUL separated
+        match fs::read(format!("/proc/{}/cmdline", self.pid)) {
+            Ok(cmdline) => {
+                self.command = String::from_utf8_lossy(&cmdline).trim_end_matches('\0').replace('\0', " ");
+            }
+            Err(_) => {
+                self.fds = FdSummary::default();
+                self.memory = None;
+                self.connections.clear();
+                self.error = Some(format!("Process {} is gone", self.pid));
+                return;
+            }
+        }
+        self.memory = read_memory_map(self.pid);
+
+        match read_fds(self.pid) {
+            Ok((fds, socket_inodes)) => {
+                self.fds = fds;
+                self.connections = read_connections(self.pid, &socket_inodes);
+                self.error = None;
+            }
+            // The fd directory of other users' processes is only readable by root
+            Err(err) => {
+                self.fds = FdSummary::default();
+                self.connections.clear();
+                self.error = Some(format!("Cannot read open files: {}", err));
+            }
+        }
+    }
+}
+
+/// Fd summary plus the inodes of the process's sockets
+fn read_fds(pid: u32) -> std::io::Result<(FdSummary, HashSet<u64>)> {
+    let mut summary = FdSummary {
+        limit: read_fd_limit(pid),
+        ..Default::default()
+    };
+    let mut socket_inodes = HashSet::new();
+    let mut targets: HashMap<String, usize> = HashMap::new();
+    for entry in fs::read_dir(format!("/proc/{}/fd", pid))?.flatten() {
+        // Closed between listing and reading the link
+        let Ok(target) = fs::read_link(entry.path()) else {
+            continue;
+        };
+        let target = target.to_string_lossy().to_string();
+        summary.count += 1;
+        if let Some(inode) = target.strip_prefix("socket:[").and_then(|rest| rest.strip_suffix(']')) {
+            summary.sockets += 1;
+            socket_inodes.extend(inode.parse::<u64>().ok());
+        } else if target.starts_with("pipe:") {
+            summary.pipes += 1;
+        } else if target.starts_with('/') && !target.starts_with("/dev/") {
+            summary.files += 1;
+        } else {
+            summary.other += 1;
+        }
+        // Sockets and pipes differ only by inode, so they are counted per kind
+        let key = match target.split_once(':') {
+            Some((kind @ ("socket" | "pipe"), _)) => format!("{} (all)", kind),
+            _ => target,
+        };
+        *targets.entry(key).or_default() += 1;
+    }
+
+    let mut top: Vec<(String, usize)> = targets.into_iter().collect();
+    top.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    top.truncate(TOP_FD_TARGETS);
+    summary.top = top;
+    Ok((summary, socket_inodes))
+}
+
+/// Sockets of the process from the socket tables of its network namespace
+fn read_connections(pid: u32, socket_inodes: &HashSet<u64>) -> Vec<Connection> {
+    if socket_inodes.is_empty() {
+        return Vec::new();
+    }
+    let mut connections: Vec<Connection> = connections::read_sockets(Path::new(&format!("/proc/{}/net", pid)))
+        .into_iter()
+        .filter(|connection| socket_inodes.contains(&connection.inode))
+        .collect();
+    connections.sort_by_key(|c| (!c.is_listening(), c.protocol, c.local.clone()));
+    connections
+}
+
+/// Memory totals from /proc/<pid>/smaps_rollup (Linux 4.14+), which like the fd
+/// directory needs root for other users' processes:
+///
+/// ```text
+/// Rss:                1408 kB
+/// Pss:                 282 kB
+/// Shared_Clean:       1264 kB
+/// Anonymous:           104 kB
+/// Swap:                  0 kB
+/// ```
+fn read_memory_map(pid: u32) -> Option<MemoryMap> {
+    let rollup = fs::read_to_string(format!("/proc/{}/smaps_rollup", pid)).ok()?;
+    let fields: HashMap<&str, u64> = rollup
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let kilobytes: u64 = value.split_whitespace().next()?.parse().ok()?;
+            Some((key, kilobytes * 1024))
+        })
+        .collect();
+    let field = |key: &str| fields.get(key).copied().unwrap_or(0);
+    let rss = *fields.get("Rss")?;
+    let anonymous = field("Anonymous");
+    Some(MemoryMap {
+        rss,
+        pss: field("Pss"),
+        anonymous,
+        // Shared memory is not anonymous here: it is backed by tmpfs files
+        file: rss.saturating_sub(anonymous),
+        shared: field("Shared_Clean") + field("Shared_Dirty"),
+        swap: field("Swap"),
+    })
+}
+
+/// Soft limit from the `Max open files` line of /proc/<pid>/limits
+fn read_fd_limit(pid: u32) -> Option<u64> {
+    let limits = fs::read_to_string(format!("/proc/{}/limits", pid)).ok()?;
+    let line = limits.lines().find(|line| line.starts_with("Max open files"))?;
+    line["Max open files".len()..].split_whitespace().next()?.parse().ok()
+}
@@ -0,0 +1,136 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::history::History;
+
+/// /proc/diskstats counts in 512-byte sectors regardless of the device's sector size
+const SECTOR_BYTES: f32 = 512.0;
+
+/// Cumulative transfer and timing counters of one block device
+#[derive(Clone)]
+pub struct DiskCounters {
+    pub name: String,
+    pub sectors_read: u64,
+    pub sectors_written: u64,
+    pub completed: u64,  // Reads and writes finished
+    pub io_time: u64,    // Milliseconds those reads and writes took, queueing included
+    pub busy_time: u64,  // Milliseconds with at least one request in flight
+    pub queue_time: u64, // Milliseconds weighted by the requests in flight
+}
+
+/// Throughput, latency and saturation of one block device, with history
+pub struct DiskIo {
+    pub name: String,
+    pub read_rate: f32,   // MB/s
+    pub write_rate: f32,  // MB/s
+    pub await_time: f32,  // Average milliseconds per request, 0 without requests
+    pub utilization: f32, // Percent of the time busy
+    pub queue_depth: f32, // Average requests in flight
+    read_history: History,
+    write_history: History,
+    await_history: History,
+    utilization_history: History,
+    queue_history: History,
+}
+
+impl DiskIo {
+    pub fn new(name: String, max_history: usize) -> Self {
+        Self {
+            name,
+            read_rate: 0.0,
+            write_rate: 0.0,
+            await_time: 0.0,
+            utilization: 0.0,
+            queue_depth: 0.0,
+            read_history: History::new(max_history),
+            write_history: History::new(max_history),
+            await_history: History::new(max_history),
+            utilization_history: History::new(max_history),
+            queue_history: History::new(max_history),
+        }
+    }
+
+    /// Rates from two counter samples `seconds` apart
+    pub fn update(&mut self, prev: &DiskCounters, current: &DiskCounters, seconds: f32, retention: Option<Duration>) {
+        let seconds = seconds.max(0.001);
+        let rate = |sectors: u64| sectors as f32 * SECTOR_BYTES / 1_000_000.0 / seconds;
+        self.read_rate = rate(current.sectors_read.saturating_sub(prev.sectors_read));
+        self.write_rate = rate(current.sectors_written.saturating_sub(prev.sectors_written));
+
+        // A disk that is merely full stays fast; a saturated one is busy all the time with
+        // requests queueing up and each of them waiting longer
+        let completed = current.completed.saturating_sub(prev.completed);
+        self.await_time = if completed > 0 {
+            current.io_time.saturating_sub(prev.io_time) as f32 / completed as f32
+        } else {
+            0.0
+        };
+        let milliseconds = seconds * 1000.0;
+        // Busy time can run slightly ahead of the wall clock between two samples
+        self.utilization = (current.busy_time.saturating_sub(prev.busy_time) as f32 / milliseconds * 100.0).min(100.0);
+        self.queue_depth = current.queue_time.saturating_sub(prev.queue_time) as f32 / milliseconds;
+
+        for (history, value) in [
+            (&mut self.read_history, self.read_rate),
+            (&mut self.write_history, self.write_rate),
+            (&mut self.await_history, self.await_time),
+            (&mut self.utilization_history, self.utilization),
+            (&mut self.queue_history, self.queue_depth),
+        ] {
+            history.push(value, retention);
+        }
+    }
+
+    pub fn read_history(&self) -> &History {
+        &self.read_history
+    }
+
+    pub fn write_history(&self) -> &History {
+        &self.write_history
+    }
+
+    pub fn await_history(&self) -> &History {
+        &self.await_history
+    }
+
+    pub fn utilization_history(&self) -> &History {
+        &self.utilization_history
+    }
+
+    pub fn queue_history(&self) -> &History {
+        &self.queue_history
+    }
+}
+
+/// Counters of every physical disk from /proc/diskstats. Partitions, loop and RAM
+/// devices and stacked devices (device mapper, md) have no `device` link in
+/// /sys/block, so the I/O of each disk is counted once.
+pub fn read_disk_counters() -> Vec<DiskCounters> {
+    let Ok(contents) = fs::read_to_string("/proc/diskstats") else {
+        return Vec::new();
+    };
+
+    // major minor name reads merged sectors_read ms_reading writes merged sectors_written
+    // ms_writing in_flight ms_doing_io weighted_ms_doing_io ...
+    contents
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let name = *fields.get(2)?;
+            if !Path::new("/sys/block").join(name).join("device").exists() {
+                return None;
+            }
+            let field = |index: usize| fields.get(index)?.parse::<u64>().ok();
+            Some(DiskCounters {
+                name: name.to_string(),
+                sectors_read: field(5)?,
+                sectors_written: field(9)?,
+                completed: field(3)? + field(7)?,
+                io_time: field(6)? + field(10)?,
+                busy_time: field(12)?,
+                queue_time: field(13)?,
+            })
+        })
+        .collect()
+}
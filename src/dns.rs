@@ -0,0 +1,224 @@
+use crate::config::DnsConfig;
+use crate::history::History;
+use crate::worker::Job;
+use chrono::{DateTime, Local};
+use std::{
+    fs,
+    net::{IpAddr, SocketAddr, UdpSocket},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// How long a lookup may take before it counts as failed
+const LOOKUP_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Periodic DNS lookup of one hostname, timed against one resolver. Lookups run in the
+/// background, so a resolver that does not answer does not hold up the UI.
+pub struct DnsProbe {
+    pub config: DnsConfig,
+    pub resolver: Option<SocketAddr>, // None without a configured or system nameserver
+    pub latency: Option<f32>,         // Milliseconds; None while failing
+    pub error: Option<String>,
+    pub lookups: u32,
+    pub failures: u32,
+    pub last_failure: Option<(DateTime<Local>, String)>,
+    latency_history: History, // Milliseconds, successful lookups only
+    failure_history: History, // 1 for a failed lookup, 0 otherwise
+    job: Option<Job<Result<f32, String>>>,
+    last_refresh: Option<Instant>,
+}
+
+impl DnsProbe {
+    pub fn new(config: DnsConfig, max_history: usize) -> Self {
+        // Resolvers are validated when the config is loaded
+        let resolver = match &config.resolver {
+            Some(resolver) => parse_resolver(resolver),
+            None => system_resolver(),
+        };
+        Self {
+            config,
+            resolver,
+            latency: None,
+            error: None,
+            lookups: 0,
+            failures: 0,
+            last_failure: None,
+            latency_history: History::new(max_history),
+            failure_history: History::new(max_history),
+            job: None,
+            last_refresh: None,
+        }
+    }
+
+    /// Due for a lookup, and none still running
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none()
+            && self.last_refresh.is_none_or(|last| last.elapsed() >= Duration::from_secs(self.config.interval.max(1)))
+    }
+
+    /// Start a lookup in the background; `poll` picks up its result
+    pub fn refresh(&mut self) {
+        let resolver = self.resolver;
+        let hostname = self.config.hostname.clone();
+        self.job = Some(Job::spawn(move || match resolver {
+            Some(resolver) => lookup(resolver, &hostname),
+            None => Err("no nameserver in /etc/resolv.conf".to_string()),
+        }));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Count in the result of a finished lookup
+    pub fn poll(&mut self, retention: Option<Duration>) {
+        let Some(result) = self.job.as_ref().and_then(Job::try_result) else {
+            return;
+        };
+        self.job = None;
+        let result = result.and_then(|result| result);
+        self.lookups += 1;
+        match result {
+            Ok(latency) => {
+                self.latency = Some(latency);
+                self.error = None;
+                self.latency_history.push(latency, retention);
+                self.failure_history.push(0.0, retention);
+            }
+            Err(err) => {
+                self.latency = None;
+                self.failures += 1;
+                self.last_failure = Some((Local::now(), err.clone()));
+                self.error = Some(err);
+                self.failure_history.push(1.0, retention);
+            }
+        }
+    }
+
+    /// Resolver address for display, e.g. "1.1.1.1", "127.0.0.1:5353" or "127.0.0.53 (system)"
+    pub fn resolver_label(&self) -> String {
+        let Some(resolver) = self.resolver else {
+            return "no resolver".to_string();
+        };
+        let address = if resolver.port() == 53 { resolver.ip().to_string() } else { resolver.to_string() };
+        match self.config.resolver {
+            Some(_) => address,
+            None => format!("{} (system)", address),
+        }
+    }
+
+    pub fn latency_history(&self) -> &History {
+        &self.latency_history
+    }
+
+    pub fn failure_history(&self) -> &History {
+        &self.failure_history
+    }
+}
+
+/// `1.1.1.1`, `1.1.1.1:5353`, `2606:4700::1111` or `[2606:4700::1111]:53`
+pub fn parse_resolver(resolver: &str) -> Option<SocketAddr> {
+    resolver
+        .parse::<SocketAddr>()
+        .ok()
+        .or_else(|| resolver.parse::<IpAddr>().ok().map(|ip| SocketAddr::new(ip, 53)))
+}
+
+/// First usable `nameserver` line of /etc/resolv.conf
+fn system_resolver() -> Option<SocketAddr> {
+    let contents = fs::read_to_string("/etc/resolv.conf").ok()?;
+    contents
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .find_map(|address| parse_resolver(address.trim()))
+}
+
+/// Send one A query for `hostname` to `resolver` over UDP and return the round trip in
+/// milliseconds. Answers without records (NXDOMAIN, SERVFAIL, empty) count as failures.
+fn lookup(resolver: SocketAddr, hostname: &str) -> Result<f32, String> {
+    let bind: SocketAddr = if resolver.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" }.parse().expect("valid bind address");
+    let socket = UdpSocket::bind(bind).map_err(|e| format!("socket: {}", e))?;
+    socket.connect(resolver).map_err(|e| format!("connect: {}", e))?;
+
+    // Not security relevant, just enough to tell our answer from stray packets
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.subsec_nanos() as u16);
+    let query = build_query(id, hostname);
+    let start = Instant::now();
+    socket.send(&query).map_err(|e| format!("send: {}", e))?;
+
+    let mut response = [0u8; 512];
+    // Stray packets do not extend the wait
+    let deadline = start + LOOKUP_TIMEOUT;
+    loop {
+        let timeout = || format!("timed out after {}s", LOOKUP_TIMEOUT.as_secs());
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(timeout());
+        }
+        socket.set_read_timeout(Some(remaining)).map_err(|e| format!("socket: {}", e))?;
+        let length = socket.recv(&mut response).map_err(|e| match e.kind() {
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => timeout(),
+            _ => format!("receive: {}", e),
+        })?;
+        if length < 12 || u16::from_be_bytes([response[0], response[1]]) != id {
+            continue;
+        }
+        let latency = start.elapsed().as_secs_f32() * 1000.0;
+        let answers = u16::from_be_bytes([response[6], response[7]]);
+        return match response[3] & 0x0F {
+            0 if answers > 0 => Ok(latency),
+            0 => Err("no A records".to_string()),
+            2 => Err("SERVFAIL".to_string()),
+            3 => Err("NXDOMAIN".to_string()),
+            5 => Err("REFUSED".to_string()),
+            code => Err(format!("response code {}", code)),
+        };
+    }
+}
+
+/// Header with recursion desired and one question: `hostname` IN A
+fn build_query(id: u16, hostname: &str) -> Vec<u8> {
+    let mut query = Vec::with_capacity(hostname.len() + 18);
+    query.extend_from_slice(&id.to_be_bytes());
+    query.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]); // RD; 1 question
+    for label in hostname.trim_end_matches('.').split('.') {
+        query.push(label.len() as u8);
+        query.extend_from_slice(label.as_bytes());
+    }
+    query.push(0);
+    query.extend_from_slice(&[0, 1, 0, 1]); // Type A, class IN
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_a_query_for_one_a_record() {
+        let query = build_query(0xBEEF, "example.com");
+        let expected: &[u8] = &[
+            0xBE, 0xEF, // ID
+            0x01, 0x00, // Recursion desired
+            0, 1, 0, 0, 0, 0, 0, 0, // One question, no other records
+            7, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 3, b'c', b'o', b'm', 0,
+            0, 1, 0, 1, // Type A, class IN
+        ];
+        assert_eq!(query, expected);
+    }
+
+    #[test]
+    fn ignores_a_trailing_dot() {
+        assert_eq!(build_query(1, "example.com."), build_query(1, "example.com"));
+    }
+
+    #[test]
+    fn encodes_single_label_names() {
+        assert_eq!(build_query(1, "localhost")[12..], [9, b'l', b'o', b'c', b'a', b'l', b'h', b'o', b's', b't', 0, 0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn parses_resolvers() {
+        assert_eq!(parse_resolver("1.1.1.1"), Some("1.1.1.1:53".parse().unwrap()));
+        assert_eq!(parse_resolver("127.0.0.1:5353"), Some("127.0.0.1:5353".parse().unwrap()));
+        assert_eq!(parse_resolver("2606:4700::1111"), Some("[2606:4700::1111]:53".parse().unwrap()));
+        assert_eq!(parse_resolver("[2606:4700::1111]:5353"), Some("[2606:4700::1111]:5353".parse().unwrap()));
+        assert_eq!(parse_resolver("dns.example"), None);
+    }
+}
@@ -0,0 +1,71 @@
+use std::cmp::Reverse;
+use std::fs;
+use std::path::Path;
+
+const EDAC_MC_PATH: &str = "/sys/devices/system/edac/mc";
+
+/// Error counts of one memory module that has logged errors
+#[derive(Clone)]
+pub struct DimmErrors {
+    pub label: String,
+    pub corrected: u64,
+    pub uncorrected: u64,
+}
+
+/// ECC error counters summed over all memory controllers, since boot
+#[derive(Clone, Default)]
+pub struct EccErrors {
+    pub controllers: usize,
+    pub corrected: u64,
+    pub uncorrected: u64,
+    pub dimms: Vec<DimmErrors>, // Only modules with errors
+}
+
+/// Counters of every EDAC memory controller. None without an EDAC driver, which is
+/// the case on most desktops and VMs.
+pub fn read_ecc_errors() -> Option<EccErrors> {
+    let mut errors = EccErrors::default();
+    for entry in fs::read_dir(EDAC_MC_PATH).ok()?.flatten() {
+        if !entry.file_name().to_string_lossy().starts_with("mc") {
+            continue;
+        }
+        let path = entry.path();
+        errors.controllers += 1;
+        // Totals include errors that could not be attributed to a module
+        errors.corrected += read_count(&path.join("ce_count"));
+        errors.uncorrected += read_count(&path.join("ue_count"));
+
+        // dimm* on current kernels, rank* on older ones for some drivers
+        let Ok(modules) = fs::read_dir(&path) else {
+            continue;
+        };
+        for module in modules.flatten() {
+            let name = module.file_name().to_string_lossy().to_string();
+            let prefix = if name.starts_with("dimm") { "dimm" } else if name.starts_with("rank") { "rank" } else { continue };
+            let module_path = module.path();
+            let corrected = read_count(&module_path.join(format!("{}_ce_count", prefix)));
+            let uncorrected = read_count(&module_path.join(format!("{}_ue_count", prefix)));
+            if corrected == 0 && uncorrected == 0 {
+                continue;
+            }
+            let label = fs::read_to_string(module_path.join(format!("{}_label", prefix)))
+                .map(|label| label.trim().to_string())
+                .ok()
+                .filter(|label| !label.is_empty())
+                .unwrap_or_else(|| format!("{}/{}", entry.file_name().to_string_lossy(), name));
+            errors.dimms.push(DimmErrors { label, corrected, uncorrected });
+        }
+    }
+    if errors.controllers == 0 {
+        return None;
+    }
+    errors.dimms.sort_by_key(|dimm| Reverse((dimm.uncorrected, dimm.corrected)));
+    Some(errors)
+}
+
+fn read_count(path: &Path) -> u64 {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|count| count.trim().parse().ok())
+        .unwrap_or(0)
+}
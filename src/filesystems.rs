@@ -0,0 +1,215 @@
+use std::ffi::CString;
+use std::fs;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::time::Duration;
+use sysinfo::Disks;
+
+use crate::history::History;
+use crate::nfs;
+
+/// Inode usage from which a filesystem is flagged as running out of inodes
+pub const INODE_WARNING_PERCENT: f32 = 90.0;
+
+/// tmpfs usage from which a mount is flagged as nearly full
+pub const TMPFS_WARNING_PERCENT: f32 = 90.0;
+
+/// One mounted filesystem with its space usage
+pub struct Filesystem {
+    pub mount_point: String,
+    pub device: String,
+    pub fs_type: String,
+    pub total: u64,     // Bytes
+    pub available: u64, // Bytes available to unprivileged users
+    pub inodes: Option<(u64, u64)>, // Total and free; None where inodes are allocated dynamically
+}
+
+impl Filesystem {
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.available)
+    }
+
+    pub fn usage_percent(&self) -> f32 {
+        if self.total == 0 {
+            0.0
+        } else {
+            self.used() as f32 / self.total as f32 * 100.0
+        }
+    }
+
+    pub fn inodes_used(&self) -> Option<u64> {
+        self.inodes.map(|(total, free)| total.saturating_sub(free))
+    }
+
+    pub fn inode_percent(&self) -> Option<f32> {
+        self.inodes.map(|(total, free)| total.saturating_sub(free) as f32 / total as f32 * 100.0)
+    }
+
+    /// Backed by a block device, rather than tmpfs, overlay or a network share
+    pub fn is_partition(&self) -> bool {
+        self.device.starts_with("/dev/")
+    }
+
+    /// Close to "No space left on device" with bytes to spare
+    pub fn inodes_low(&self) -> bool {
+        self.inode_percent().is_some_and(|percent| percent >= INODE_WARNING_PERCENT)
+    }
+}
+
+/// A mount point whose usage is charted over time, "/" unless `mounts` says otherwise
+pub struct TrackedMount {
+    pub mount_point: String,
+    history: History, // Usage percent, while mounted
+}
+
+impl TrackedMount {
+    pub fn new(mount_point: String, max_history: usize) -> Self {
+        Self {
+            mount_point,
+            history: History::new(max_history),
+        }
+    }
+
+    pub fn push(&mut self, usage: f32, retention: Option<Duration>) {
+        self.history.push(usage, retention);
+    }
+
+    /// Latest usage percent, 0 before the first sample
+    pub fn usage(&self) -> f32 {
+        self.history.back().copied().unwrap_or(0.0)
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+}
+
+/// Pseudo filesystems, which have no space of their own, as sysinfo leaves them out.
+/// tmpfs is listed with memory instead, see `list_tmpfs`.
+const IGNORED_FILESYSTEMS: [&str; 12] = [
+    "rootfs", "sysfs", "proc", "devtmpfs", "cgroup", "cgroup2", "pstore", "squashfs", "rpc_pipefs", "iso9660", "autofs", "tmpfs",
+];
+
+/// Every mounted filesystem with a size, sorted by mount point. Bind mounts and
+/// overmounts of the same mount point are listed once. NFS and SMB mounts are left
+/// out: asking for their size hangs along with the server, which would freeze the
+/// display, and the NFS panel shows how they are doing.
+pub fn list_filesystems() -> Vec<Filesystem> {
+    let mut filesystems = match read_mounts() {
+        Some(mounts) => stat_local_mounts(mounts),
+        None => list_disks(),
+    };
+    filesystems.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    filesystems.dedup_by(|a, b| a.mount_point == b.mount_point);
+    filesystems
+}
+
+/// Sizes of the mounts worth listing, in /proc/mounts order
+#[allow(clippy::unnecessary_cast)] // fsblkcnt_t is 32 bits on some platforms
+fn stat_local_mounts(mounts: Vec<(String, String, String)>) -> Vec<Filesystem> {
+    mounts
+        .into_iter()
+        .filter(|(device, mount_point, fs_type)| {
+            !IGNORED_FILESYSTEMS.contains(&fs_type.as_str())
+                && !nfs::is_network_filesystem(fs_type)
+                && !device.starts_with("sunrpc")
+                && !mount_point.starts_with("/sys")
+                && !mount_point.starts_with("/proc")
+                && (!mount_point.starts_with("/run") || mount_point.starts_with("/run/media"))
+        })
+        .filter_map(|(device, mount_point, fs_type)| {
+            let stat = statvfs(Path::new(&mount_point))?;
+            let block_size = stat.f_frsize as u64;
+            Some(Filesystem {
+                total: stat.f_blocks as u64 * block_size,
+                available: stat.f_bavail as u64 * block_size,
+                inodes: inode_counts(&stat),
+                mount_point,
+                device,
+                fs_type,
+            })
+        })
+        .filter(|fs| fs.total > 0)
+        .collect()
+}
+
+/// The filesystems sysinfo finds, on systems without /proc/mounts (macOS)
+fn list_disks() -> Vec<Filesystem> {
+    let disks = Disks::new_with_refreshed_list();
+    disks
+        .iter()
+        .filter(|disk| disk.total_space() > 0)
+        .map(|disk| Filesystem {
+            mount_point: disk.mount_point().to_string_lossy().to_string(),
+            device: disk.name().to_string_lossy().to_string(),
+            fs_type: disk.file_system().to_string_lossy().to_string(),
+            total: disk.total_space(),
+            available: disk.available_space(),
+            // sysinfo does not pass on the inode counts of its own statvfs
+            inodes: statvfs(disk.mount_point()).and_then(|stat| inode_counts(&stat)),
+        })
+        .collect()
+}
+
+/// Mounted tmpfs filesystems with a size limit, fullest first. Their pages live in RAM
+/// and count as shared memory.
+#[allow(clippy::unnecessary_cast)] // fsblkcnt_t is 32 bits on some platforms
+pub fn list_tmpfs() -> Vec<Filesystem> {
+    let Some(mounts) = read_mounts() else {
+        return Vec::new();
+    };
+    let mut filesystems: Vec<Filesystem> = mounts
+        .into_iter()
+        .filter(|(_, _, fs_type)| fs_type == "tmpfs")
+        .filter_map(|(device, mount_point, fs_type)| {
+            let stat = statvfs(Path::new(&mount_point))?;
+            let block_size = stat.f_frsize as u64;
+            Some(Filesystem {
+                device,
+                fs_type,
+                total: stat.f_blocks as u64 * block_size,
+                available: stat.f_bavail as u64 * block_size,
+                inodes: inode_counts(&stat),
+                mount_point,
+            })
+        })
+        // size=0 means no limit, reported as zero blocks
+        .filter(|fs| fs.total > 0)
+        .collect();
+    filesystems.sort_by(|a, b| a.mount_point.cmp(&b.mount_point));
+    filesystems.dedup_by(|a, b| a.mount_point == b.mount_point);
+    filesystems.sort_by(|a, b| b.usage_percent().total_cmp(&a.usage_percent()).then_with(|| a.mount_point.cmp(&b.mount_point)));
+    filesystems
+}
+
+/// Device, mount point and type of every mount in /proc/mounts; None without it
+fn read_mounts() -> Option<Vec<(String, String, String)>> {
+    let mounts = fs::read_to_string("/proc/mounts").ok()?;
+    let mounts = mounts
+        .lines()
+        .filter_map(|line| {
+            // "tmpfs /dev/shm tmpfs rw,nosuid,nodev,size=8167456k 0 0", spaces escaped as \040
+            let mut fields = line.split_whitespace();
+            let (device, mount_point, fs_type) = (fields.next()?, fields.next()?, fields.next()?);
+            Some((device.to_string(), mount_point.replace("\\040", " "), fs_type.to_string()))
+        })
+        .collect();
+    Some(mounts)
+}
+
+/// Total and free inodes from statvfs. Filesystems such as btrfs report zero since
+/// they have no fixed inode table.
+#[allow(clippy::unnecessary_cast)] // fsfilcnt_t is 32 bits on some platforms
+fn inode_counts(stat: &libc::statvfs) -> Option<(u64, u64)> {
+    (stat.f_files > 0).then_some((stat.f_files as u64, stat.f_ffree as u64))
+}
+
+fn statvfs(mount_point: &Path) -> Option<libc::statvfs> {
+    let path = CString::new(mount_point.as_os_str().as_bytes()).ok()?;
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    // SAFETY: `path` is NUL-terminated and `stat` is a valid statvfs to fill in
+    if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+        return None;
+    }
+    Some(stat)
+}
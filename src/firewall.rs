@@ -0,0 +1,243 @@
+use crate::config::{FirewallBackend, FirewallConfig};
+use regex::Regex;
+use std::{
+    collections::HashMap,
+    io::ErrorKind,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// Time given to a listing command before it is killed
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// One firewall rule with its hit counter
+pub struct FirewallRule {
+    pub table: String, // Family and table, e.g. "inet filter"
+    pub chain: String,
+    pub rule: String,                 // As listed, without the counter
+    pub counter: Option<(u64, u64)>, // Packets and bytes; None for nft rules without `counter`
+    pub rate: f32,                    // Packets per second since the previous refresh
+}
+
+/// State of the Firewall tab: rule counters from nftables or iptables
+pub struct FirewallView {
+    pub config: FirewallConfig,
+    pub rules: Vec<FirewallRule>,
+    pub backend: Option<FirewallBackend>, // The one that last answered
+    pub error: Option<String>,
+    pub matching_only: bool, // Only rules whose counter moved since the previous refresh
+    pub scroll: usize,
+    previous: HashMap<(String, String, String), u64>, // Packet counts of the previous refresh
+    last_refresh: Option<Instant>,
+}
+
+impl FirewallView {
+    pub fn new(config: FirewallConfig) -> Self {
+        Self {
+            config,
+            rules: Vec::new(),
+            backend: None,
+            error: None,
+            matching_only: false,
+            scroll: 0,
+            previous: HashMap::new(),
+            last_refresh: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh
+            .is_none_or(|last| last.elapsed() >= Duration::from_secs(self.config.interval.max(1)))
+    }
+
+    pub fn refresh(&mut self) {
+        let result = match self.config.backend {
+            Some(FirewallBackend::Nft) => read_nft().map(|rules| (FirewallBackend::Nft, rules)),
+            Some(FirewallBackend::Iptables) => read_iptables().map(|rules| (FirewallBackend::Iptables, rules)),
+            // iptables only when nft is not installed at all
+            None => match read_nft() {
+                Err(err) if err.kind == ErrorKind::NotFound => read_iptables().map(|rules| (FirewallBackend::Iptables, rules)),
+                result => result.map(|rules| (FirewallBackend::Nft, rules)),
+            },
+        };
+        let seconds = self.last_refresh.map_or(0.0, |last| last.elapsed().as_secs_f32());
+        match result {
+            Ok((backend, mut rules)) => {
+                for rule in &mut rules {
+                    let key = (rule.table.clone(), rule.chain.clone(), rule.rule.clone());
+                    if let (Some((packets, _)), Some(previous)) = (rule.counter, self.previous.get(&key)) {
+                        rule.rate = packets.saturating_sub(*previous) as f32 / seconds.max(0.001);
+                    }
+                }
+                self.previous = rules
+                    .iter()
+                    .filter_map(|rule| Some(((rule.table.clone(), rule.chain.clone(), rule.rule.clone()), rule.counter?.0)))
+                    .collect();
+                self.rules = rules;
+                self.backend = Some(backend);
+                self.error = None;
+            }
+            Err(err) => self.error = Some(err.message),
+        }
+        self.scroll = self.scroll.min(self.filtered().len().saturating_sub(1));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Rules to show, in ruleset order
+    pub fn filtered(&self) -> Vec<&FirewallRule> {
+        self.rules.iter().filter(|rule| !self.matching_only || rule.rate > 0.0).collect()
+    }
+
+    pub fn toggle_matching_only(&mut self) {
+        self.matching_only = !self.matching_only;
+        self.scroll = 0;
+    }
+}
+
+/// A failed listing; NotFound when the tool is not installed
+struct ListError {
+    kind: ErrorKind,
+    message: String,
+}
+
+/// Run a listing command under a timeout, since nft and the iptables tools wait for a
+/// lock another one may hold, mapping a failure to the tool's own message (usually
+/// "Operation not permitted" without root)
+fn run(program: &str, args: &[&str]) -> Result<String, ListError> {
+    let output = Command::new("timeout")
+        .arg(format!("{}s", COMMAND_TIMEOUT.as_secs()))
+        .arg(program)
+        .args(args)
+        .output()
+        .map_err(|e| ListError {
+            kind: e.kind(),
+            message: format!("failed to run {}: {}", program, e),
+        })?;
+    // timeout exits with 127 when it cannot find the command
+    if output.status.code() == Some(127) {
+        return Err(ListError {
+            kind: ErrorKind::NotFound,
+            message: format!("failed to run {}: not found", program),
+        });
+    }
+    if output.status.code() == Some(124) {
+        return Err(ListError {
+            kind: ErrorKind::TimedOut,
+            message: format!("{}: timed out after {}s", program, COMMAND_TIMEOUT.as_secs()),
+        });
+    }
+    if !output.status.success() {
+        return Err(ListError {
+            kind: ErrorKind::Other,
+            message: format!("{}: {}", program, String::from_utf8_lossy(&output.stderr).trim()),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Rules of every chain in `nft list ruleset`:
+///
+/// ```text
+/// table inet filter {
+///     chain input {
+///         type filter hook input priority filter; policy drop;
+///         tcp dport 22 counter packets 5 bytes 300 accept
+///     }
+/// }
+/// ```
+///
+/// Sets, maps and flowtables are skipped. Only rules with a `counter` statement count.
+fn read_nft() -> Result<Vec<FirewallRule>, ListError> {
+    let output = run("nft", &["list", "ruleset"])?;
+    let counter = Regex::new(r"\s*counter packets (\d+) bytes (\d+)").expect("valid counter regex");
+    let mut rules = Vec::new();
+    let (mut depth, mut table, mut chain) = (0usize, String::new(), None::<String>);
+    for line in output.lines().map(str::trim) {
+        let (opens, closes) = (line.matches('{').count(), line.matches('}').count());
+        if depth == 0 && line.starts_with("table ") {
+            table = line.trim_start_matches("table ").trim_end_matches('{').trim().to_string();
+        } else if depth == 1 && line.starts_with("chain ") {
+            chain = Some(line.trim_start_matches("chain ").trim_end_matches('{').trim().to_string());
+        } else if let Some(chain) = chain.as_ref().filter(|_| depth == 2 && opens == closes) {
+            // The chain's own "type ... hook ...; policy ...;" line is not a rule
+            if !line.is_empty() && !line.starts_with("type ") && !line.starts_with("policy ") {
+                let captures = counter.captures(line);
+                rules.push(FirewallRule {
+                    table: table.clone(),
+                    chain: chain.clone(),
+                    rule: counter.replace(line, "").trim().to_string(),
+                    counter: captures.and_then(|captures| Some((captures[1].parse().ok()?, captures[2].parse().ok()?))),
+                    rate: 0.0,
+                });
+            }
+        }
+        depth = (depth + opens).saturating_sub(closes);
+        if depth < 2 {
+            chain = None;
+        }
+    }
+    Ok(rules)
+}
+
+/// Rules from `iptables-save -c` and `ip6tables-save -c`, plus the policy of each
+/// built-in chain, which has a counter of its own:
+///
+/// ```text
+/// *filter
+/// :INPUT DROP [120:9600]
+/// [5:300] -A INPUT -p tcp -m tcp --dport 22 -j ACCEPT
+/// COMMIT
+/// ```
+fn read_iptables() -> Result<Vec<FirewallRule>, ListError> {
+    let mut rules = parse_iptables_save(&run("iptables-save", &["-c"])?, "ip");
+    // IPv6 rules are a bonus; many systems have no ip6tables
+    if let Ok(output) = run("ip6tables-save", &["-c"]) {
+        rules.extend(parse_iptables_save(&output, "ip6"));
+    }
+    Ok(rules)
+}
+
+fn parse_iptables_save(output: &str, family: &str) -> Vec<FirewallRule> {
+    // "[packets:bytes]"
+    let counter = |value: &str| {
+        let (packets, bytes) = value.trim_start_matches('[').trim_end_matches(']').split_once(':')?;
+        Some((packets.parse().ok()?, bytes.parse().ok()?))
+    };
+    let mut rules = Vec::new();
+    let mut table = String::new();
+    for line in output.lines() {
+        if let Some(name) = line.strip_prefix('*') {
+            table = format!("{} {}", family, name);
+        } else if let Some(chain) = line.strip_prefix(':') {
+            // User-defined chains have no policy ("-")
+            if let [chain, policy, value] = chain.split_whitespace().collect::<Vec<_>>()[..] {
+                if policy != "-" {
+                    rules.push(FirewallRule {
+                        table: table.clone(),
+                        chain: chain.to_string(),
+                        rule: format!("policy {}", policy),
+                        counter: counter(value),
+                        rate: 0.0,
+                    });
+                }
+            }
+        } else if line.starts_with('[') {
+            let Some((value, rule)) = line.split_once(' ') else {
+                continue;
+            };
+            let Some((chain, rule)) = rule.strip_prefix("-A ").and_then(|rule| rule.split_once(' ')) else {
+                continue;
+            };
+            rules.push(FirewallRule {
+                table: table.clone(),
+                chain: chain.to_string(),
+                rule: rule.to_string(),
+                counter: counter(value),
+                rate: 0.0,
+            });
+        }
+    }
+    // Policies are listed before the rules but apply after them
+    rules.sort_by_key(|rule| (rule.table.clone(), rule.chain.clone(), rule.rule.starts_with("policy ")));
+    rules
+}
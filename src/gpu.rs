@@ -0,0 +1,763 @@
+use std::{collections::{HashMap, VecDeque}, fs, path::Path, process::Command, time::{Duration, Instant}};
+
+use crate::history::History;
+use sysinfo::{Pid, System, Uid};
+
+/// A process using the GPU, merged from the compute, pmon and graphics queries
+#[derive(Debug, Clone)]
+pub struct GpuProcess {
+    pub pid: u32,
+    pub name: String,
+    pub memory_mb: u32,
+    pub gpu_util: Option<u32>,     // GPU utilization percentage
+    pub mem_util: Option<u32>,     // Memory utilization percentage
+    pub user: Option<String>,      // Owner, from the host process table
+    pub cpu_usage: Option<f32>,    // Host CPU usage percentage
+    pub gpu_index: Option<usize>,  // GPU the process runs on (from pmon)
+}
+
+/// Refreshes of VRAM usage kept per process
+const VRAM_HISTORY_SAMPLES: usize = 30;
+
+/// Change in VRAM over the kept samples below which a process counts as steady
+const VRAM_TREND_THRESHOLD_MB: u32 = 16;
+
+/// Direction of a process's VRAM usage over the kept samples
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VramTrend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+/// VRAM usage of each GPU process across refreshes, keyed by PID, to catch leaks in
+/// long-running jobs
+#[derive(Default)]
+pub struct VramHistory {
+    samples: HashMap<u32, VecDeque<u32>>,
+}
+
+impl VramHistory {
+    /// Append the current usage of every process and forget processes that are gone
+    pub fn record(&mut self, processes: &[GpuProcess]) {
+        self.samples.retain(|pid, _| processes.iter().any(|p| p.pid == *pid));
+        for process in processes {
+            let samples = self.samples
+                .entry(process.pid)
+                .or_insert_with(|| VecDeque::with_capacity(VRAM_HISTORY_SAMPLES));
+            if samples.len() >= VRAM_HISTORY_SAMPLES {
+                samples.pop_front();
+            }
+            samples.push_back(process.memory_mb);
+        }
+    }
+
+    pub fn samples(&self, pid: u32) -> Option<&VecDeque<u32>> {
+        self.samples.get(&pid)
+    }
+
+    pub fn trend(&self, pid: u32) -> VramTrend {
+        let Some(samples) = self.samples.get(&pid) else {
+            return VramTrend::Steady;
+        };
+        let (Some(&first), Some(&last)) = (samples.front(), samples.back()) else {
+            return VramTrend::Steady;
+        };
+        if last > first.saturating_add(VRAM_TREND_THRESHOLD_MB) {
+            VramTrend::Rising
+        } else if first > last.saturating_add(VRAM_TREND_THRESHOLD_MB) {
+            VramTrend::Falling
+        } else {
+            VramTrend::Steady
+        }
+    }
+}
+
+/// Readings and history for one GPU, as shown in the GPU tab
+pub struct GpuDevice {
+    pub index: usize,
+    pub name: String,
+    pub usage: Option<f32>,
+    pub temperature: Option<f32>,
+    pub power_draw: Option<f32>,
+    pub memory_used: Option<f32>,
+    pub memory_total: Option<f32>,
+    pub core_clock: Option<f32>,
+    pub memory_clock: Option<f32>,
+    usage_history: History,
+    memory_percent_history: History,
+    temperature_history: History,
+    core_clock_history: History,
+    memory_clock_history: History,
+}
+
+impl GpuDevice {
+    pub fn new(index: usize, max_history: usize) -> Self {
+        Self {
+            index,
+            name: String::new(),
+            usage: None,
+            temperature: None,
+            power_draw: None,
+            memory_used: None,
+            memory_total: None,
+            core_clock: None,
+            memory_clock: None,
+            usage_history: History::new(max_history),
+            memory_percent_history: History::new(max_history),
+            temperature_history: History::new(max_history),
+            core_clock_history: History::new(max_history),
+            memory_clock_history: History::new(max_history),
+        }
+    }
+
+    /// Take readings from one `--query-gpu` line (name, utilization, temperature, fan,
+    /// power, memory used/total, graphics/memory clock, ...) and record them in history
+    pub fn update(&mut self, parts: &[&str], retention: Option<Duration>) {
+        let value = |i: usize| parts.get(i).and_then(|v| v.parse::<f32>().ok());
+        self.name = parts.first().map(|name| name.to_string()).unwrap_or_default();
+        self.usage = value(1);
+        self.temperature = value(2);
+        self.power_draw = value(4);
+        self.memory_used = value(5);
+        self.memory_total = value(6);
+        self.core_clock = value(7);
+        self.memory_clock = value(8);
+
+        let memory_percent = self.memory_percent();
+        for (history, sample) in [
+            (&mut self.usage_history, self.usage),
+            (&mut self.memory_percent_history, memory_percent),
+            (&mut self.temperature_history, self.temperature),
+            (&mut self.core_clock_history, self.core_clock),
+            (&mut self.memory_clock_history, self.memory_clock),
+        ] {
+            history.push(sample.unwrap_or(0.0), retention);
+        }
+    }
+
+    pub fn memory_percent(&self) -> Option<f32> {
+        match (self.memory_used, self.memory_total) {
+            (Some(used), Some(total)) if total > 0.0 => Some(used / total * 100.0),
+            _ => None,
+        }
+    }
+
+    pub fn usage_history(&self) -> &History {
+        &self.usage_history
+    }
+
+    pub fn memory_percent_history(&self) -> &History {
+        &self.memory_percent_history
+    }
+
+    pub fn temperature_history(&self) -> &History {
+        &self.temperature_history
+    }
+
+    pub fn core_clock_history(&self) -> &History {
+        &self.core_clock_history
+    }
+
+    pub fn memory_clock_history(&self) -> &History {
+        &self.memory_clock_history
+    }
+}
+
+/// Per-process GPU usage from DRM fdinfo, the kernel's drm-usage-stats
+/// (`/proc/<pid>/fdinfo/<fd>` of open `/dev/dri` files). Drivers such as amdgpu and
+/// i915 report cumulative busy time per engine, so utilization needs two samples.
+#[derive(Default)]
+pub struct DrmUsage {
+    engine_ns: HashMap<(u32, String, String), u64>, // (pid, client, engine) -> busy ns
+    last_sample: Option<Instant>,
+}
+
+impl DrmUsage {
+    /// Sample all readable processes with DRM clients. GPU usage is the busiest engine
+    /// since the previous sample, VRAM the sum over the process's clients.
+    fn sample(&mut self) -> Vec<GpuProcess> {
+        let now = Instant::now();
+        let elapsed_ns = self.last_sample.map(|last| now.duration_since(last).as_nanos() as f64);
+        self.last_sample = Some(now);
+
+        let mut engine_ns = HashMap::new();
+        let mut processes = Vec::new();
+        let Ok(entries) = fs::read_dir("/proc") else {
+            return processes;
+        };
+        for entry in entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|name| name.parse::<u32>().ok()) else {
+                continue;
+            };
+            let clients = read_drm_clients(pid);
+            if clients.is_empty() {
+                continue;
+            }
+
+            let mut busiest: Option<f64> = None;
+            let mut vram_kib = 0;
+            for client in &clients {
+                vram_kib += client.vram_kib;
+                for (engine, &ns) in &client.engine_ns {
+                    let key = (pid, client.id.clone(), engine.clone());
+                    if let (Some(elapsed_ns), Some(&prev)) = (elapsed_ns, self.engine_ns.get(&key)) {
+                        let percent = ns.saturating_sub(prev) as f64 / elapsed_ns * 100.0;
+                        busiest = Some(busiest.map_or(percent, |b| b.max(percent)));
+                    }
+                    engine_ns.insert(key, ns);
+                }
+            }
+
+            let name = fs::read_to_string(format!("/proc/{}/comm", pid)).unwrap_or_default();
+            processes.push(GpuProcess {
+                pid,
+                name: name.trim().to_string(),
+                memory_mb: (vram_kib / 1024) as u32,
+                gpu_util: busiest.map(|percent| percent.clamp(0.0, 100.0).round() as u32),
+                mem_util: None,
+                user: None,
+                cpu_usage: None,
+                gpu_index: None,
+            });
+        }
+
+        // Only keep clients that still exist
+        self.engine_ns = engine_ns;
+        processes
+    }
+
+    /// Fill in utilization (and VRAM where nvidia-smi had none) from fdinfo, adding
+    /// processes nvidia-smi did not list
+    fn merge_into(&mut self, processes: &mut Vec<GpuProcess>) {
+        for sampled in self.sample() {
+            match processes.iter_mut().find(|p| p.pid == sampled.pid) {
+                Some(process) => {
+                    process.gpu_util = process.gpu_util.or(sampled.gpu_util);
+                    if process.memory_mb == 0 {
+                        process.memory_mb = sampled.memory_mb;
+                    }
+                }
+                None => processes.push(sampled),
+            }
+        }
+    }
+}
+
+/// One DRM client (an open render/card node) as reported in fdinfo
+struct DrmClient {
+    id: String,
+    engine_ns: HashMap<String, u64>,
+    vram_kib: u64,
+}
+
+/// Read the DRM clients of a process. A client can be open on several fds (dup,
+/// fork), so clients are deduplicated by device and client id.
+fn read_drm_clients(pid: u32) -> Vec<DrmClient> {
+    let mut clients: Vec<DrmClient> = Vec::new();
+    let Ok(fds) = fs::read_dir(format!("/proc/{}/fd", pid)) else {
+        return clients;
+    };
+
+    for fd in fds.flatten() {
+        let is_drm = fs::read_link(fd.path()).is_ok_and(|target| target.starts_with("/dev/dri/"));
+        if !is_drm {
+            continue;
+        }
+        let Ok(fdinfo) = fs::read_to_string(format!("/proc/{}/fdinfo/{}", pid, fd.file_name().to_string_lossy())) else {
+            continue;
+        };
+
+        // drm-engine-gfx: 123456 ns, drm-memory-vram: 2048 KiB (drm-total-vram on newer kernels)
+        let mut device = String::new();
+        let mut client_id = None;
+        let mut engine_ns = HashMap::new();
+        let mut vram_kib = 0;
+        for line in fdinfo.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            let number = || value.split_whitespace().next().and_then(|v| v.parse::<u64>().ok());
+            match key {
+                "drm-pdev" => device = value.to_string(),
+                "drm-client-id" => client_id = Some(value.to_string()),
+                "drm-memory-vram" | "drm-total-vram" => vram_kib = number().unwrap_or(0),
+                _ => {
+                    if let (Some(engine), Some(ns)) = (key.strip_prefix("drm-engine-"), number()) {
+                        // drm-engine-capacity-* is an engine count, not busy time
+                        if !engine.starts_with("capacity-") {
+                            engine_ns.insert(engine.to_string(), ns);
+                        }
+                    }
+                }
+            }
+        }
+
+        let Some(client_id) = client_id else {
+            continue;
+        };
+        let id = format!("{}/{}", device, client_id);
+        if !clients.iter().any(|client| client.id == id) {
+            clients.push(DrmClient { id, engine_ns, vram_kib });
+        }
+    }
+    clients
+}
+
+/// GPU processes from nvidia-smi, with utilization from DRM fdinfo when pmon is
+/// unsupported (or nvidia-smi is missing altogether, as on AMD and Intel)
+pub fn get_gpu_processes(drm: &mut DrmUsage) -> Vec<GpuProcess> {
+    let mut processes = Vec::new();
+    
+    // Try to get all GPU processes using the comprehensive query method
+    let comprehensive_output = Command::new("nvidia-smi")
+        .args([
+            "--query-compute-apps=pid,name,used_memory",
+            "--format=csv,noheader,nounits",
+        ])
+        .output();
+
+    if let Ok(output) = comprehensive_output {
+        if output.status.success() {
+            if let Ok(out_str) = String::from_utf8(output.stdout) {
+                for line in out_str.lines() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+                    if parts.len() >= 3 {
+                        if let (Ok(pid), Ok(memory)) = (parts[0].parse::<u32>(), parts[2].parse::<u32>()) {
+                            let name = parts[1].to_string();
+                            processes.push(GpuProcess {
+                                pid,
+                                name,
+                                memory_mb: memory,
+                                gpu_util: None,
+                                mem_util: None,
+                                user: None,
+                                cpu_usage: None,
+                                gpu_index: None,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    // Get per-process GPU utilization using pmon
+    let pmon_output = Command::new("nvidia-smi")
+        .args(["pmon", "-c", "1", "-s", "u"])
+        .output();
+    let mut pmon_supported = false;
+
+    if let Ok(output) = pmon_output {
+        if output.status.success() {
+            if let Ok(out_str) = String::from_utf8(output.stdout) {
+                // Some GPUs (and vGPU/WSL setups) answer but cannot monitor processes
+                pmon_supported = !out_str.contains("not supported");
+                for line in out_str.lines() {
+                    // Skip header and separator lines
+                    if line.starts_with('#') || line.trim().is_empty() || line.contains("gpu") {
+                        continue;
+                    }
+                    
+                    let parts: Vec<&str> = line.split_whitespace().collect();
+                    // Expected format: gpu pid type sm mem enc dec command
+                    if parts.len() >= 7 {
+                        if let Ok(pid) = parts[1].parse::<u32>() {
+                            // Parse utilization percentages - handle both % and - cases
+                            let gpu_util = if parts[3] == "-" { 
+                                None 
+                            } else { 
+                                parts[3].replace("%", "").parse::<u32>().ok() 
+                            };
+                            let mem_util = if parts[4] == "-" { 
+                                None 
+                            } else { 
+                                parts[4].replace("%", "").parse::<u32>().ok() 
+                            };
+                            
+                            let gpu_index = parts[0].parse::<usize>().ok();
+                            
+                            // Check if we already have this process from compute query
+                            if let Some(process) = processes.iter_mut().find(|p| p.pid == pid) {
+                                // Update existing process with utilization info
+                                process.gpu_util = gpu_util;
+                                process.mem_util = mem_util;
+                                process.gpu_index = gpu_index;
+                            } else {
+                                // Add new process found in pmon but not in compute apps
+                                let name = parts[6..].join(" ");
+                                processes.push(GpuProcess {
+                                    pid,
+                                    name,
+                                    memory_mb: 0, // Will be updated from graphics query
+                                    gpu_util,
+                                    mem_util,
+                                    user: None,
+                                    cpu_usage: None,
+                                    gpu_index,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    
+    // Get additional graphics processes if available
+    let graphics_output = Command::new("nvidia-smi")
+        .args([
+            "--query-apps=pid,name,used_memory",
+            "--format=csv,noheader,nounits",
+        ])
+        .output();
+
+    if let Ok(output) = graphics_output {
+        if output.status.success() {
+            if let Ok(out_str) = String::from_utf8(output.stdout) {
+                for line in out_str.lines() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+                    if parts.len() >= 3 {
+                        if let (Ok(pid), Ok(memory)) = (parts[0].parse::<u32>(), parts[2].parse::<u32>()) {
+                            let name = parts[1].to_string();
+                            
+                            // Check if we already have this process
+                            if let Some(process) = processes.iter_mut().find(|p| p.pid == pid) {
+                                // Update memory if it's higher (more accurate)
+                                if memory > process.memory_mb {
+                                    process.memory_mb = memory;
+                                }
+                            } else {
+                                // Add new graphics process
+                                processes.push(GpuProcess {
+                                    pid,
+                                    name,
+                                    memory_mb: memory,
+                                    gpu_util: None,
+                                    mem_util: None,
+                                    user: None,
+                                    cpu_usage: None,
+                                    gpu_index: None,
+                                });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if !pmon_supported {
+        drm.merge_into(&mut processes);
+    }
+
+    // Sort by memory usage (highest first)
+    processes.sort_by_key(|p| std::cmp::Reverse(p.memory_mb));
+    processes
+}
+
+/// Fill in name, user and CPU usage from the host process table. nvidia-smi reports
+/// names as (often truncated) paths or the 15 character comm; the executable is more useful.
+/// `system` must have been refreshed for these PIDs.
+pub fn annotate_processes(processes: &mut [GpuProcess], system: &System, user_names: &HashMap<Uid, String>) {
+    for gpu_process in processes {
+        let Some(process) = system.process(Pid::from_u32(gpu_process.pid)) else {
+            continue;
+        };
+
+        let exe_name = process.exe()
+            .and_then(|exe| exe.file_name())
+            .map(|name| name.to_string_lossy().into_owned());
+        gpu_process.name = exe_name.unwrap_or_else(|| process.name().to_string_lossy().into_owned());
+        gpu_process.user = process.user_id()
+            .map(|uid| user_names.get(uid).cloned().unwrap_or_else(|| uid.to_string()));
+        gpu_process.cpu_usage = Some(process.cpu_usage());
+    }
+}
+
+/// Driver and firmware versions, which only change across reboots or driver updates
+#[derive(Debug, Clone)]
+pub struct GpuDriverInfo {
+    pub driver_version: String,
+    pub cuda_version: Option<String>,
+    pub vbios_version: Option<String>,
+}
+
+/// Query driver, CUDA and VBIOS versions. Meant to run once at startup.
+pub fn query_driver_info() -> Option<GpuDriverInfo> {
+    let output = Command::new("nvidia-smi")
+        .args(["--query-gpu=driver_version,vbios_version", "--format=csv,noheader"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let out_str = String::from_utf8_lossy(&output.stdout);
+    let line = out_str.lines().next()?;
+    let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+    let driver_version = parts.first().filter(|v| !v.is_empty())?.to_string();
+    let vbios_version = parts.get(1)
+        .filter(|v| !v.is_empty() && !v.starts_with('['))
+        .map(|v| v.to_string());
+
+    Some(GpuDriverInfo {
+        driver_version,
+        cuda_version: query_cuda_version(),
+        vbios_version,
+    })
+}
+
+/// The CUDA version is not a --query-gpu field, only part of the full report:
+/// `CUDA Version                              : 12.2`
+fn query_cuda_version() -> Option<String> {
+    let output = Command::new("nvidia-smi").arg("-q").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == "CUDA Version")
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// One GPU fan. Cards differ in what they report, so both readings are optional.
+#[derive(Debug, Clone)]
+pub struct GpuFan {
+    pub index: u32,
+    pub rpm: Option<u32>,
+    pub percent: Option<f32>,
+}
+
+/// Enumerate GPU fans. DRM hwmon (amdgpu, nouveau) is read directly; the NVIDIA driver
+/// only reports individual fans through `nvidia-settings`, which is tried if `query_nvidia`.
+pub fn read_fans(query_nvidia: bool) -> Vec<GpuFan> {
+    let fans = read_hwmon_fans();
+    if !fans.is_empty() || !query_nvidia {
+        return fans;
+    }
+    read_nvidia_settings_fans()
+}
+
+fn read_hwmon_fans() -> Vec<GpuFan> {
+    let mut fans = Vec::new();
+    let Ok(cards) = fs::read_dir("/sys/class/drm") else {
+        return fans;
+    };
+
+    for card in cards.flatten() {
+        let name = card.file_name().to_string_lossy().into_owned();
+        // card0, card1, ... but not connectors like card0-DP-1
+        if !name.starts_with("card") || name.contains('-') {
+            continue;
+        }
+        let Ok(hwmons) = fs::read_dir(card.path().join("device/hwmon")) else {
+            continue;
+        };
+        for hwmon in hwmons.flatten() {
+            let path = hwmon.path();
+            for n in 1..=8 {
+                let rpm = read_u32(&path.join(format!("fan{}_input", n)));
+                // pwmN is the duty cycle on a 0-255 scale
+                let percent = read_u32(&path.join(format!("pwm{}", n))).map(|pwm| pwm as f32 / 255.0 * 100.0);
+                if rpm.is_none() && percent.is_none() {
+                    continue;
+                }
+                fans.push(GpuFan {
+                    index: fans.len() as u32,
+                    rpm,
+                    percent,
+                });
+            }
+        }
+    }
+    fans
+}
+
+fn read_nvidia_settings_fans() -> Vec<GpuFan> {
+    let rpm = query_nvidia_fan_attribute("GPUCurrentFanSpeedRPM");
+    let percent = query_nvidia_fan_attribute("GPUCurrentFanSpeed");
+
+    let mut indices: Vec<u32> = rpm.iter().chain(percent.iter()).map(|(index, _)| *index).collect();
+    indices.sort_unstable();
+    indices.dedup();
+
+    let lookup = |values: &[(u32, u32)], index: u32| values.iter().find(|(i, _)| *i == index).map(|(_, v)| *v);
+    indices
+        .into_iter()
+        .map(|index| GpuFan {
+            index,
+            rpm: lookup(&rpm, index),
+            percent: lookup(&percent, index).map(|p| p as f32),
+        })
+        .collect()
+}
+
+/// Parse `  Attribute 'GPUCurrentFanSpeedRPM' (host:0[fan:1]): 1510.` lines
+fn query_nvidia_fan_attribute(attribute: &str) -> Vec<(u32, u32)> {
+    let output = Command::new("timeout")
+        .args(["1s", "nvidia-settings", "-q", attribute])
+        .output();
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let rest = &line[line.find("[fan:")? + "[fan:".len()..];
+            let (index, rest) = rest.split_once(']')?;
+            let value = rest.rsplit(':').next()?.trim().trim_end_matches('.');
+            Some((index.parse().ok()?, value.parse().ok()?))
+        })
+        .collect()
+}
+
+fn read_u32(path: &Path) -> Option<u32> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// One active NVLink link of one GPU
+pub struct NvLink {
+    pub gpu: usize,
+    pub link: u32,
+    pub speed_gbs: f32,            // Per-direction link speed in GB/s
+    pub tx_rate: Option<f32>,      // KB/s, from the data throughput counters
+    pub rx_rate: Option<f32>,      // KB/s
+    pub replay_errors: u64,
+    pub recovery_errors: u64,
+    pub crc_errors: u64,
+    tx_total_kib: Option<u64>,     // Cumulative counters the rates are derived from
+    rx_total_kib: Option<u64>,
+    history: History,        // Combined TX + RX in KB/s
+}
+
+impl NvLink {
+    /// How busy the busier direction is, relative to the link speed
+    pub fn utilization(&self) -> Option<f32> {
+        let busiest = self.tx_rate?.max(self.rx_rate?);
+        Some((busiest / (self.speed_gbs * 1_000_000.0) * 100.0).clamp(0.0, 100.0))
+    }
+
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+}
+
+/// Refresh NVLink state of all GPUs. Links are matched by (GPU, link) to keep their
+/// counters and history; `seconds` is the time since the last refresh.
+pub fn update_nvlinks(links: &mut Vec<NvLink>, seconds: f32, max_history: usize, retention: Option<Duration>) {
+    // `Link 0: 25 GB/s`, or `Link 0: <inactive>`
+    let active: Vec<(usize, u32, f32)> = query_nvlink(&["-s"])
+        .into_iter()
+        .filter_map(|(gpu, link, value)| {
+            let speed = value.strip_suffix("GB/s")?.trim().parse().ok()?;
+            Some((gpu, link, speed))
+        })
+        .collect();
+
+    links.retain(|l| active.iter().any(|&(gpu, link, _)| gpu == l.gpu && link == l.link));
+    if active.is_empty() {
+        return;
+    }
+
+    // `Link 0: Data Tx: 123456 KiB`; cumulative since the driver loaded
+    let throughput = query_nvlink(&["-gt", "d"]);
+    // `Link 0: Replay Errors: 0`
+    let errors = query_nvlink(&["-e"]);
+    let counter = |lines: &[(usize, u32, String)], gpu: usize, link: u32, name: &str| {
+        lines
+            .iter()
+            .filter(|(g, l, _)| *g == gpu && *l == link)
+            .filter_map(|(_, _, value)| value.split_once(':'))
+            .find(|(key, _)| key.trim() == name)
+            .and_then(|(_, value)| value.split_whitespace().next()?.parse::<u64>().ok())
+    };
+
+    for (gpu, link, speed_gbs) in active {
+        let index = match links.iter().position(|l| l.gpu == gpu && l.link == link) {
+            Some(index) => index,
+            None => {
+                links.push(NvLink {
+                    gpu,
+                    link,
+                    speed_gbs,
+                    tx_rate: None,
+                    rx_rate: None,
+                    replay_errors: 0,
+                    recovery_errors: 0,
+                    crc_errors: 0,
+                    tx_total_kib: None,
+                    rx_total_kib: None,
+                    history: History::new(max_history),
+                });
+                links.len() - 1
+            }
+        };
+        let state = &mut links[index];
+        state.speed_gbs = speed_gbs;
+
+        let tx_total = counter(&throughput, gpu, link, "Data Tx");
+        let rx_total = counter(&throughput, gpu, link, "Data Rx");
+        // KiB to KB per second; a counter that went backwards was reset
+        let rate = |prev: Option<u64>, current: Option<u64>| match (prev, current) {
+            (Some(prev), Some(current)) if current >= prev && seconds > 0.0 => {
+                Some((current - prev) as f32 * 1.024 / seconds)
+            }
+            _ => None,
+        };
+        state.tx_rate = rate(state.tx_total_kib, tx_total);
+        state.rx_rate = rate(state.rx_total_kib, rx_total);
+        state.tx_total_kib = tx_total;
+        state.rx_total_kib = rx_total;
+
+        state.replay_errors = counter(&errors, gpu, link, "Replay Errors").unwrap_or(0);
+        state.recovery_errors = counter(&errors, gpu, link, "Recovery Errors").unwrap_or(0);
+        state.crc_errors = counter(&errors, gpu, link, "CRC Errors").unwrap_or(0);
+
+        state.history.push(state.tx_rate.unwrap_or(0.0) + state.rx_rate.unwrap_or(0.0), retention);
+    }
+    links.sort_by_key(|l| (l.gpu, l.link));
+}
+
+/// Run `nvidia-smi nvlink <args>` and split its output into (GPU, link, rest of line).
+/// GPUs are introduced by `GPU 0: <name> (UUID: ...)` headers, links by `Link N: ...`.
+fn query_nvlink(args: &[&str]) -> Vec<(usize, u32, String)> {
+    let output = Command::new("timeout").args(["1s", "nvidia-smi", "nvlink"]).args(args).output();
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let mut lines = Vec::new();
+    let mut gpu = None;
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("GPU ") {
+            gpu = rest.split(':').next().and_then(|index| index.trim().parse().ok());
+        } else if let (Some(gpu), Some(rest)) = (gpu, line.strip_prefix("Link ")) {
+            let Some((link, value)) = rest.split_once(':') else {
+                continue;
+            };
+            if let Ok(link) = link.trim().parse() {
+                lines.push((gpu, link, value.trim().to_string()));
+            }
+        }
+    }
+    lines
+}
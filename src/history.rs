@@ -0,0 +1,147 @@
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Points kept for samples older than the raw buffer, spread evenly over the retention
+/// period: about one per braille dot of a wide chart
+const DOWNSAMPLED_POINTS: u32 = 240;
+
+/// A history as written to the state file, with the age of each point in seconds at
+/// the time of saving rather than an Instant, which means nothing to another process
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SavedHistory {
+    downsampled: Vec<(f64, f32, u32)>, // Age, mean and sample count
+    samples: Vec<(f64, f32)>,
+}
+
+/// Fixed-size ring buffer of timestamped samples, oldest first. Charts place samples by
+/// their age rather than their index, so they stay true to time when the update
+/// interval varies (slow nvidia-smi polls, a suspended laptop, a changed `interval`).
+///
+/// With a retention period, samples pushed out of the ring are downsampled into
+/// averaged points until they are older than that instead of being dropped, so
+/// multi-hour histories take a few hundred points rather than one per update. The
+/// period comes with each push, so changing it at runtime reaches every history.
+#[derive(Clone, Debug)]
+pub struct History {
+    downsampled: VecDeque<(Instant, f32, u32)>, // Bucket start, mean and sample count
+    samples: VecDeque<(Instant, f32)>,
+    capacity: usize,
+}
+
+impl History {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            downsampled: VecDeque::new(),
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Record a sample taken now. When full, the oldest sample is folded into the
+    /// downsampled points, or dropped without a retention period.
+    pub fn push(&mut self, value: f32, retention: Option<Duration>) {
+        let now = Instant::now();
+        if self.samples.len() >= self.capacity {
+            if let Some((time, value)) = self.samples.pop_front() {
+                if let Some(retention) = retention {
+                    self.downsample(time, value, retention / DOWNSAMPLED_POINTS);
+                }
+            }
+        }
+        self.samples.push_back((now, value));
+
+        match retention {
+            Some(retention) => {
+                let expired = |time: Instant| now.saturating_duration_since(time) > retention;
+                while self.downsampled.front().is_some_and(|&(time, _, _)| expired(time)) {
+                    self.downsampled.pop_front();
+                }
+                // The newest sample is kept even if the retention is shorter than an update
+                while self.samples.len() > 1 && self.samples.front().is_some_and(|&(time, _)| expired(time)) {
+                    self.samples.pop_front();
+                }
+            }
+            None => self.downsampled.clear(),
+        }
+    }
+
+    /// Average a sample into the newest downsampled point while it is less than `bucket`
+    /// old, otherwise start a new point
+    fn downsample(&mut self, time: Instant, value: f32, bucket: Duration) {
+        match self.downsampled.back_mut() {
+            Some((start, mean, count)) if time.saturating_duration_since(*start) < bucket => {
+                *count += 1;
+                *mean += (value - *mean) / *count as f32;
+            }
+            _ => self.downsampled.push_back((time, value, 1)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.downsampled.len() + self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Values, oldest first: downsampled points, then the raw samples
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &f32> {
+        self.downsampled
+            .iter()
+            .map(|(_, mean, _)| mean)
+            .chain(self.samples.iter().map(|(_, value)| value))
+    }
+
+    /// Most recent value
+    pub fn back(&self) -> Option<&f32> {
+        self.samples.back().map(|(_, value)| value)
+    }
+
+    /// The same samples with `f` applied to every value, e.g. for a unit conversion
+    pub fn map(&self, f: impl Fn(f32) -> f32) -> Self {
+        Self {
+            downsampled: self.downsampled.iter().map(|&(time, mean, count)| (time, f(mean), count)).collect(),
+            samples: self.samples.iter().map(|&(time, value)| (time, f(value))).collect(),
+            capacity: self.capacity,
+        }
+    }
+
+    /// Chart points as (seconds before `now`, value): the newest sample sits near 0
+    /// and older ones at negative x
+    pub fn points(&self, now: Instant) -> Vec<(f64, f64)> {
+        let age = |time: Instant| -now.saturating_duration_since(time).as_secs_f64();
+        self.downsampled
+            .iter()
+            .map(|&(time, mean, _)| (age(time), mean as f64))
+            .chain(self.samples.iter().map(|&(time, value)| (age(time), value as f64)))
+            .collect()
+    }
+
+    /// The points with their ages at `now`, for the state file
+    pub fn save(&self, now: Instant) -> SavedHistory {
+        let age = |time: Instant| now.saturating_duration_since(time).as_secs_f64();
+        SavedHistory {
+            downsampled: self.downsampled.iter().map(|&(time, mean, count)| (age(time), mean, count)).collect(),
+            samples: self.samples.iter().map(|&(time, value)| (age(time), value)).collect(),
+        }
+    }
+
+    /// Put back points saved `elapsed` ago, before any new sample is pushed. Samples
+    /// beyond the capacity and points too old to place are left out; the next push
+    /// expires the ones past the retention period.
+    pub fn restore(&mut self, saved: &SavedHistory, elapsed: Duration) {
+        let now = Instant::now();
+        let time = |age: f64| Duration::try_from_secs_f64(age).ok().and_then(|age| now.checked_sub(age + elapsed));
+        self.downsampled = saved.downsampled.iter().filter_map(|&(age, mean, count)| Some((time(age)?, mean, count))).collect();
+        let skip = saved.samples.len().saturating_sub(self.capacity);
+        self.samples = saved.samples.iter().skip(skip).filter_map(|&(age, value)| Some((time(age)?, value))).collect();
+    }
+
+    /// Seconds from the oldest sample to `now`
+    pub fn span(&self, now: Instant) -> f64 {
+        let oldest = self.downsampled.front().map(|&(time, _, _)| time).or(self.samples.front().map(|&(time, _)| time));
+        oldest.map_or(0.0, |time| now.saturating_duration_since(time).as_secs_f64())
+    }
+}
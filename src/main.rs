@@ -10,54 +10,338 @@ use ratatui::{
     Terminal,
 };
 use std::{
+    collections::{HashMap, HashSet, VecDeque},
     io,
+    path::PathBuf,
     process::Command,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     thread,
     time::{Duration, Instant},
 };
-use sysinfo::{Disks, System};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, System, Uid, UpdateKind, Users};
 
+mod apple;
+mod audit;
+mod cgroups;
+mod config;
+mod connections;
+mod coredump;
+mod custom;
+mod detail;
+mod diskio;
+mod dns;
+mod edac;
+mod filesystems;
+mod firewall;
+mod gpu;
+mod history;
+mod meminfo;
 mod metrics;
+mod network;
+mod numa;
+mod nfs;
+mod oom;
+mod power;
+mod pressure;
+mod quota;
+mod routes;
+mod sensors;
+mod smart;
+mod snmp;
+mod sockets;
+mod space;
+mod state;
+mod storage;
+mod talkers;
+mod threads;
 mod ui;
+mod worker;
 
+use audit::AuditView;
+use cgroups::CgroupView;
+use config::{Config, Panel, RateUnit, TemperatureUnit};
+use connections::{ConnectionsView, ListeningPorts};
+use dns::DnsProbe;
+use firewall::FirewallView;
+use quota::TrafficQuota;
+use routes::RoutesView;
+use snmp::SnmpDevice;
+use talkers::TopTalkers;
+use coredump::Coredump;
+use custom::{CustomGauge, CustomTab};
+use detail::ProcessDetail;
+use gpu::{DrmUsage, GpuProcess, VramHistory};
+use sensors::SensorsView;
+use smart::SsdWear;
+use sockets::{SocketSampler, SocketTraffic};
+use space::SpaceExplorer;
+use state::HistoryStore;
+use storage::StorageHealth;
 use metrics::SystemMetrics;
+use network::{InterfaceFilter, Link, NetworkInterface, PublicIp};
+use numa::NumaView;
+use oom::OomWatch;
+use power::BatteryStatus;
+use threads::ThreadView;
+
+
+/// How long status messages stay visible in the title bar
+const STATUS_MESSAGE_DURATION: Duration = Duration::from_secs(5);
+
+/// How often coredumps are checked while the Crashes tab is not visible
+const COREDUMP_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// How often coredumps are refreshed while the Crashes tab is visible
+const COREDUMP_TAB_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Processes younger than this are highlighted in the process table
+const NEW_PROCESS_AGE: Duration = Duration::from_secs(10);
+/// How long exited processes stay in the process table, struck through
+const EXITED_PROCESS_LINGER: Duration = Duration::from_secs(5);
+/// CPU samples kept per process for the sparkline column of the process table
+const PROCESS_CPU_SAMPLES: usize = 20;
+/// Processes listed by CPU and by memory in the top consumers panel
+const TOP_CONSUMERS: usize = 5;
+/// History retention steps in minutes for [ and ]; 0 keeps only the last --history samples
+const HISTORY_RETENTION_STEPS: [u64; 7] = [0, 5, 15, 60, 240, 720, 1440];
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Args {
-    #[arg(short, long, default_value_t = 1)]
-    interval: u64,
+    /// Metrics update interval in seconds [default: 1]
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    interval: Option<u64>,
     
     #[arg(short, long)]
     simple: bool,
     
     #[arg(long, default_value_t = 60)]
     history: usize,
+    
+    /// Show network rates in bits, bytes or binary bytes per second [default: bits]
+    #[arg(long, value_enum)]
+    rate_unit: Option<RateUnit>,
+    
+    /// Minutes of chart history; samples older than --history updates are downsampled,
+    /// 0 keeps only those ([ and ] change it) [default: 0]
+    #[arg(long)]
+    history_retention: Option<u64>,
+    
+    /// Save the CPU, memory, network and GPU charts on exit and restore them on start,
+    /// in $XDG_STATE_HOME/rmon/history.toml
+    #[arg(long)]
+    persist_history: bool,
+    
+    /// Highlight values that changed significantly between simple mode reports (like `watch -d`)
+    #[arg(short, long)]
+    differences: bool,
+    
+    /// GPU polling interval in seconds; nvidia-smi is expensive [default: same as --interval]
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    gpu_interval: Option<u64>,
+    
+    /// Only list processes of this user in the Processes tab ([U] toggles the filter)
+    #[arg(short, long)]
+    user: Option<String>,
+    
+    /// Path to config file (defaults to ~/.config/rmon/config.toml)
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+    
+    /// Show temperatures in this unit ([Shift+F] toggles it) [default: celsius]
+    #[arg(long, value_enum)]
+    temperature_unit: Option<TemperatureUnit>,
+    
+    /// Leave interfaces matching these glob patterns out of the network total
+    /// [default: lo,virbr*,docker*,veth*]
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    network_exclude: Option<Vec<String>>,
+    
+    /// Count interfaces matching these glob patterns in the network total even when
+    /// excluded, e.g. docker0
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    network_include: Option<Vec<String>>,
+    
+    /// Mount points whose usage the disk panel charts, e.g. /,/home,/var [default: /]
+    #[arg(long, value_delimiter = ',', num_args = 1..)]
+    mounts: Option<Vec<String>>,
 }
 
 struct App {
     system: System,
+    user_names: HashMap<Uid, String>, // From the passwd database at startup
+    config: Config,
+    config_path: Option<PathBuf>,
+    cli_interval: Option<u64>,
+    cli_gpu_interval: Option<u64>,
+    cli_temperature_unit: Option<TemperatureUnit>,
+    cli_history_retention: Option<u64>,
+    cli_persist_history: bool,
+    cli_rate_unit: Option<RateUnit>,
+    cli_network_exclude: Option<Vec<String>>,
+    cli_network_include: Option<Vec<String>>,
+    cli_mounts: Option<Vec<String>>,
+    reload_requested: Arc<AtomicBool>,
+    terminate_requested: Arc<AtomicBool>, // SIGINT or SIGTERM, so state is saved on the way out
+    status_message: Option<(String, Instant)>,
     metrics: SystemMetrics,
+    history_store: Option<HistoryStore>, // With persist_history
     should_quit: bool,
     last_update: Instant,
     update_interval: Duration,
-    simple_mode: bool,
+    temperature_unit: TemperatureUnit,
+    rate_unit: RateUnit,
+    tabs: Vec<Tab>,
     current_tab: usize,
+    custom_tabs: Vec<CustomTab>,
+    custom_gauges: Vec<CustomGauge>,
+    dns_probe: Option<DnsProbe>,
+    snmp_devices: Vec<SnmpDevice>,
+    public_ip: Option<PublicIp>,
+    traffic_quota: Option<TrafficQuota>,
+    history_size: usize,
     journal_logs: Vec<String>,
     journal_scroll: usize,
+    journal_context: Option<JournalContext>,
+    coredumps: Vec<Coredump>,
+    coredump_scroll: usize,
+    last_coredump_refresh: Instant,
+    oom_watch: Option<OomWatch>, // Where the kernel counts OOM kills
+    filesystem_scroll: usize,
+    audit: Option<AuditView>,
+    firewall: Option<FirewallView>,
+    sensors: Option<SensorsView>,
+    connections: Option<ConnectionsView>,
+    routes: Option<RoutesView>,
+    listening_ports: Option<ListeningPorts>,
+    storage_health: StorageHealth, // ZFS pools and btrfs filesystems, shown with the disk panel
+    ssd_wear: SsdWear,             // Also with the disk panel
+    top_talkers: Option<TopTalkers>,
+    cgroups: Option<CgroupView>,
+    numa: Option<NumaView>, // Only on machines with several nodes
     processes: Vec<ProcessInfo>,
+    exited_processes: Vec<(ProcessInfo, Instant)>, // Gone since a recent refresh, with exit time
+    process_cpu_history: HashMap<u32, VecDeque<f32>>,
     process_scroll: usize,
+    followed_pid: Option<u32>,  // Stays selected across refreshes, even after scrolling away
     last_process_refresh: Instant,
     last_journal_refresh: Instant,
     process_refresh_interval: Duration,
     journal_refresh_interval: Duration,
     process_sort_mode: ProcessSortMode,
+    process_sort_reversed: bool,
+    show_memory_columns: bool,  // Swap and shared memory, read from /proc/<pid>/status
+    process_user: String,       // User the [U] filter restricts to
+    filter_by_user: bool,
+    socket_sampler: SocketSampler,
+    socket_traffic: SocketTraffic,
+    gpu_processes: Vec<GpuProcess>,
+    gpu_process_scroll: usize,
+    gpu_process_sort_mode: GpuProcessSortMode,
+    gpu_vram_history: VramHistory,
+    gpu_drm_usage: DrmUsage,
+    last_gpu_process_refresh: Instant,
+    selected_gpu: usize,
+    network_interface: Option<String>, // Shown by the network gauges and chart, None for all
+    kill_dialog: Option<KillDialog>,
+    space_explorer: Option<SpaceExplorer>, // Opened from the disk panel, over everything else
+    thread_view: Option<ThreadView>, // Threads of one process, below the process table
+    process_detail: Option<ProcessDetail>, // Takes the thread view's place when open
+}
+
+/// A top-level TUI tab
+#[derive(Clone, Copy, PartialEq)]
+enum Tab {
+    System,
+    Processes,
+    Journal,
+    /// Full-screen GPU details, only when a supported GPU was found
+    Gpu,
+    Coredumps,
+    /// Usage of every mounted filesystem
+    Filesystems,
+    /// TCP/UDP sockets and their processes, only where /proc/net exists
+    Connections,
+    /// Routing table and neighbor cache, on Linux
+    Routes,
+    /// nftables/iptables rule counters, only when enabled in the config
+    Firewall,
+    /// All hwmon sensors, only on machines that have any
+    Sensors,
+    /// auditd events, only when enabled in the config
+    Audit,
+    /// Config-defined tab, indexing into `App::custom_tabs`
+    Custom(usize),
+}
+
+/// Journal filter applied instead of the plain "latest entries" view
+struct JournalContext {
+    title: String,
+    args: Vec<String>,
 }
 
+/// Column the process list is sorted by. Usage columns sort highest first,
+/// identifiers (PID, name, user) A-Z, before `process_sort_reversed` applies.
 #[derive(Clone, Copy, PartialEq)]
 enum ProcessSortMode {
     Cpu,
     Memory,
+    Network,
+    Pid,
+    Name,
+    User,
+    Runtime,
+    Swap,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GpuProcessSortMode {
+    Vram,
+    Gpu,
+}
+
+/// Signals rmon can send to a process
+#[derive(Clone, Copy, PartialEq)]
+enum Signal {
+    Term,
+    Kill,
+    Hup,
+    Int,
+    Stop,
+    Cont,
+}
+
+impl Signal {
+    /// Offered by the kill dialog, gentlest first
+    const KILL_CHOICES: [Signal; 5] = [Signal::Term, Signal::Kill, Signal::Hup, Signal::Int, Signal::Stop];
+
+    /// Name as understood by `kill -s`
+    fn name(self) -> &'static str {
+        match self {
+            Signal::Term => "TERM",
+            Signal::Kill => "KILL",
+            Signal::Hup => "HUP",
+            Signal::Int => "INT",
+            Signal::Stop => "STOP",
+            Signal::Cont => "CONT",
+        }
+    }
+}
+
+/// Confirmation overlay shown before signalling a process
+struct KillDialog {
+    pid: u32,
+    name: String,
+    /// Index into `Signal::KILL_CHOICES`
+    choice: usize,
+}
+
+impl KillDialog {
+    fn signal(&self) -> Signal {
+        Signal::KILL_CHOICES[self.choice]
+    }
 }
 
 #[derive(Clone)]
@@ -66,35 +350,423 @@ struct ProcessInfo {
     name: String,
     cpu_usage: f32,
     memory_usage: u64,
+    swap_usage: u64,    // Bytes, only read while the swap/shared columns are shown
+    shared_memory: u64, // Resident file-backed and shmem pages, like top's SHR
     user: String,
+    net_rx: f32,  // Download rate in KB/s
+    net_tx: f32,  // Upload rate in KB/s
+    priority: Option<(i32, i32)>, // Kernel priority and nice value
+    run_time: u64, // Seconds since the process started
+    state: char,   // ps-style state: R, S, D, Z, T, ...
+    exited: bool,  // Gone since a recent refresh, kept around with its last readings
+    thread: bool,  // A thread listed next to its process, sharing its memory
+}
+
+impl ProcessInfo {
+    fn net_total(&self) -> f32 {
+        self.net_rx + self.net_tx
+    }
+}
+
+/// The heaviest running processes by CPU and by memory, whatever the process table is sorted by
+fn top_consumers(processes: &[ProcessInfo]) -> (Vec<&ProcessInfo>, Vec<&ProcessInfo>) {
+    let mut by_cpu: Vec<&ProcessInfo> = processes.iter().filter(|process| !process.exited && !process.thread).collect();
+    let mut by_memory = by_cpu.clone();
+    by_cpu.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage).then_with(|| b.memory_usage.cmp(&a.memory_usage)));
+    by_cpu.truncate(TOP_CONSUMERS);
+    by_memory.sort_by(|a, b| b.memory_usage.cmp(&a.memory_usage).then_with(|| b.cpu_usage.total_cmp(&a.cpu_usage)));
+    by_memory.truncate(TOP_CONSUMERS);
+    (by_cpu, by_memory)
+}
+
+impl Drop for App {
+    /// Keep the charts since the last periodic save, whether quitting or interrupted
+    fn drop(&mut self) {
+        self.save_history();
+    }
 }
 
 impl App {
-    fn new(interval: u64, history_size: usize, simple_mode: bool) -> Self {
+    fn new(args: &Args, config: Config) -> Self {
+        let history_size = args.history;
         let mut system = System::new_all();
         system.refresh_all();
+        let user_names: HashMap<Uid, String> = Users::new_with_refreshed_list()
+            .iter()
+            .map(|user| (user.id().clone(), user.name().to_string()))
+            .collect();
         
-        Self {
+        // Without --user, [U] shows the processes of whoever runs rmon
+        let filter_by_user = args.user.is_some();
+        let process_user = args.user.clone().unwrap_or_else(|| {
+            sysinfo::get_current_pid()
+                .ok()
+                .and_then(|pid| system.process(pid)?.user_id().cloned())
+                .and_then(|uid| user_names.get(&uid).cloned())
+                .unwrap_or_else(|| std::env::var("USER").unwrap_or_default())
+        });
+        
+        let metrics = SystemMetrics::new(history_size);
+        let oom_watch = metrics.oom_kill_count().map(OomWatch::new);
+        let mut app = Self {
             system,
-            metrics: SystemMetrics::new(history_size),
+            user_names,
+            config,
+            config_path: args.config.clone(),
+            cli_interval: args.interval,
+            cli_gpu_interval: args.gpu_interval,
+            cli_temperature_unit: args.temperature_unit,
+            cli_history_retention: args.history_retention,
+            cli_persist_history: args.persist_history,
+            cli_rate_unit: args.rate_unit,
+            cli_network_exclude: args.network_exclude.clone(),
+            cli_network_include: args.network_include.clone(),
+            cli_mounts: args.mounts.clone(),
+            reload_requested: Arc::new(AtomicBool::new(false)),
+            terminate_requested: Arc::new(AtomicBool::new(false)),
+            status_message: None,
+            metrics,
+            history_store: None,
             should_quit: false,
             last_update: Instant::now(),
-            update_interval: Duration::from_secs(interval),
-            simple_mode,
+            update_interval: Duration::from_secs(1),
+            temperature_unit: TemperatureUnit::default(),
+            rate_unit: RateUnit::default(),
+            tabs: Vec::new(),
             current_tab: 0,
+            custom_tabs: Vec::new(),
+            custom_gauges: Vec::new(),
+            dns_probe: None,
+            snmp_devices: Vec::new(),
+            public_ip: None,
+            traffic_quota: None,
+            history_size,
             journal_logs: Vec::new(),
             journal_scroll: 0,
+            journal_context: None,
+            // Existing crashes are loaded up front so only new ones raise a notice
+            coredumps: coredump::list_coredumps(),
+            coredump_scroll: 0,
+            last_coredump_refresh: Instant::now(),
+            oom_watch,
+            filesystem_scroll: 0,
+            audit: None,
+            firewall: None,
+            sensors: sensors::hwmon_available().then(SensorsView::new),
+            connections: connections::available().then(ConnectionsView::new),
+            routes: routes::available().then(RoutesView::new),
+            listening_ports: connections::available().then(ListeningPorts::new),
+            storage_health: StorageHealth::new(),
+            ssd_wear: SsdWear::new(),
+            top_talkers: connections::available().then(TopTalkers::new),
+            cgroups: CgroupView::new(),
+            numa: NumaView::new(),
             processes: Vec::new(),
             process_scroll: 0,
+            followed_pid: None,
             last_process_refresh: Instant::now(),
             last_journal_refresh: Instant::now(),
             process_refresh_interval: Duration::from_secs(2), // Refresh processes every 2 seconds
             journal_refresh_interval: Duration::from_secs(5), // Refresh logs every 5 seconds
+            exited_processes: Vec::new(),
+            process_cpu_history: HashMap::new(),
             process_sort_mode: ProcessSortMode::Cpu, // Default to CPU sorting
+            process_sort_reversed: false,
+            show_memory_columns: false,
+            process_user,
+            filter_by_user,
+            socket_sampler: SocketSampler::default(),
+            socket_traffic: SocketTraffic::default(),
+            gpu_processes: Vec::new(),
+            gpu_process_scroll: 0,
+            gpu_process_sort_mode: GpuProcessSortMode::Vram,
+            gpu_vram_history: VramHistory::default(),
+            gpu_drm_usage: DrmUsage::default(),
+            last_gpu_process_refresh: Instant::now(),
+            selected_gpu: 0,
+            network_interface: None,
+            kill_dialog: None,
+            space_explorer: None,
+            thread_view: None,
+            process_detail: None,
+        };
+        app.apply_config();
+        // After apply_config set the retention, and before the first sample
+        if let Some(store) = &mut app.history_store {
+            if let Some((histories, elapsed)) = store.load() {
+                app.metrics.restore_histories(&histories, elapsed);
+                for gauge in &mut app.custom_gauges {
+                    if let Some(saved) = histories.get(&gauge.history_key()) {
+                        gauge.history_mut().restore(saved, elapsed);
+                    }
+                }
+            }
+            if let Some(err) = store.error.clone() {
+                app.set_status(format!("History not restored: {}", err));
+            }
+        }
+        app.refresh_gpu_processes();
+        app
+    }
+
+    /// Write the charts to the state file when persist_history is on
+    fn save_history(&mut self) {
+        let Some(store) = &mut self.history_store else {
+            return;
+        };
+        let mut histories = self.metrics.save_histories();
+        let now = Instant::now();
+        for gauge in &self.custom_gauges {
+            histories.insert(gauge.history_key(), gauge.history().save(now));
+        }
+        store.save(histories);
+        if let Some(err) = store.error.clone() {
+            self.set_status(format!("History not saved: {}", err));
+        }
+    }
+
+    /// Apply settings derived from the config. Called on startup and after every reload.
+    fn apply_config(&mut self) {
+        // Command line interval wins over the config file
+        let interval = self.cli_interval.unwrap_or_else(|| self.config.interval());
+        self.update_interval = Duration::from_secs(interval.max(1));
+        let gpu_interval = self.cli_gpu_interval.or(self.config.gpu_interval).unwrap_or(interval);
+        self.metrics.set_gpu_interval(Duration::from_secs(gpu_interval));
+        self.process_refresh_interval = Duration::from_secs(self.config.process_interval().max(1));
+        self.journal_refresh_interval = Duration::from_secs(self.config.journal_interval().max(1));
+        self.temperature_unit = self.cli_temperature_unit.or(self.config.temperature_unit).unwrap_or_default();
+        self.rate_unit = self.cli_rate_unit.or(self.config.rate_unit).unwrap_or_default();
+        self.network_interface = self.config.network_interface.clone();
+        let network_include = self.cli_network_include.clone().or_else(|| self.config.network_include.clone());
+        let network_exclude = self.cli_network_exclude.clone().or_else(|| self.config.network_exclude.clone());
+        self.metrics.set_interface_filter(InterfaceFilter::new(network_include.unwrap_or_default(), network_exclude));
+        let mounts = self.cli_mounts.clone().or_else(|| self.config.mounts.clone()).unwrap_or_else(|| vec!["/".to_string()]);
+        self.metrics.set_tracked_mounts(&mounts);
+        let retention = self.cli_history_retention.or(self.config.history_retention).unwrap_or(0);
+        self.metrics.set_history_retention((retention > 0).then(|| Duration::from_secs(retention * 60)));
+        // History is only restored at startup, but saving follows reloads
+        let persist_history = self.cli_persist_history || self.config.persist_history.unwrap_or(false);
+        if persist_history != self.history_store.is_some() {
+            self.history_store = persist_history.then(HistoryStore::new);
+        }
+        
+        // Rebuild custom tabs, keeping state for the ones whose definition didn't change
+        let mut old_tabs = std::mem::take(&mut self.custom_tabs);
+        self.custom_tabs = self.config.tabs
+            .iter()
+            .map(|tab_config| match old_tabs.iter().position(|old| old.config == *tab_config) {
+                Some(i) => old_tabs.swap_remove(i),
+                None => CustomTab::new(tab_config.clone()),
+            })
+            .collect();
+        
+        // Same for gauges, so their history survives a reload
+        let mut old_gauges = std::mem::take(&mut self.custom_gauges);
+        self.custom_gauges = self.config.gauges
+            .iter()
+            .map(|gauge_config| match old_gauges.iter().position(|old| old.config == *gauge_config) {
+                Some(i) => old_gauges.swap_remove(i),
+                None => CustomGauge::new(gauge_config.clone(), self.history_size),
+            })
+            .collect();
+        
+        if self.dns_probe.as_ref().map(|probe| &probe.config) != self.config.dns.as_ref() {
+            self.dns_probe = self.config.dns.clone().map(|config| DnsProbe::new(config, self.history_size));
+        }
+        
+        // Same for SNMP devices, whose session totals start from the first poll
+        let mut old_devices = std::mem::take(&mut self.snmp_devices);
+        self.snmp_devices = self.config.snmp
+            .iter()
+            .map(|device_config| match old_devices.iter().position(|old| old.config == *device_config) {
+                Some(i) => old_devices.swap_remove(i),
+                None => SnmpDevice::new(device_config.clone(), self.history_size),
+            })
+            .collect();
+        
+        if self.public_ip.as_ref().map(|public_ip| &public_ip.url) != self.config.public_ip_url.as_ref() {
+            self.public_ip = self.config.public_ip_url.clone().map(PublicIp::new);
+        }
+        
+        if self.traffic_quota.as_ref().map(|quota| &quota.config) != self.config.quota.as_ref() {
+            // Count the old tracker's last transfer and drop it first, so its totals are
+            // saved before the new one loads them
+            if let Some(mut quota) = self.traffic_quota.take() {
+                quota.update(self.metrics.network_interfaces(), self.metrics.interface_filter());
+            }
+            let interfaces = self.metrics.network_interfaces();
+            let filter = self.metrics.interface_filter();
+            self.traffic_quota = self.config.quota.clone().map(|config| TrafficQuota::new(config, interfaces, filter));
+        }
+        
+        let audit_path = self.config.audit.as_ref().map(|audit| &audit.path);
+        if self.audit.as_ref().map(|view| &view.path) != audit_path {
+            self.audit = audit_path.map(|path| AuditView::new(path.clone()));
+        }
+        
+        if self.firewall.as_ref().map(|view| &view.config) != self.config.firewall.as_ref() {
+            self.firewall = self.config.firewall.clone().map(FirewallView::new);
+        }
+        
+        self.tabs = vec![Tab::System, Tab::Processes, Tab::Journal];
+        if self.metrics.gpu_available() {
+            self.tabs.push(Tab::Gpu);
+        }
+        self.tabs.push(Tab::Coredumps);
+        self.tabs.push(Tab::Filesystems);
+        if self.connections.is_some() {
+            self.tabs.push(Tab::Connections);
+        }
+        if self.routes.is_some() {
+            self.tabs.push(Tab::Routes);
+        }
+        if self.firewall.is_some() {
+            self.tabs.push(Tab::Firewall);
+        }
+        if self.sensors.is_some() {
+            self.tabs.push(Tab::Sensors);
+        }
+        if self.audit.is_some() {
+            self.tabs.push(Tab::Audit);
+        }
+        self.tabs.extend((0..self.custom_tabs.len()).map(Tab::Custom));
+        self.current_tab = self.current_tab.min(self.tabs.len() - 1);
+    }
+
+    fn tab(&self) -> Tab {
+        self.tabs[self.current_tab]
+    }
+
+    /// Configured dashboard panels, minus the GPU panel when there is no supported GPU
+    /// and the battery panel when there is no battery
+    fn dashboard_panels(&self) -> Vec<Panel> {
+        self.available_panels(self.config.dashboard_panels())
+    }
+
+    /// Configured simple mode sections, minus GPU and battery where there is none
+    fn report_sections(&self) -> Vec<Panel> {
+        self.available_panels(self.config.report_sections())
+    }
+
+    /// The interface the network gauges and chart follow, or the total when none is
+    /// selected or it went away
+    fn selected_network(&self) -> &NetworkInterface {
+        self.metrics.network_interface(self.network_interface.as_deref())
+    }
+
+    /// Select the next interface, going back to the total after the last one
+    fn cycle_network_interface(&mut self) {
+        let names: Vec<&str> = self.metrics.network_interfaces().iter().map(|interface| interface.name.as_str()).collect();
+        let next = match self.network_interface.as_deref().and_then(|name| names.iter().position(|&other| other == name)) {
+            Some(i) => names.get(i + 1),
+            None => names.first(),
+        };
+        self.network_interface = next.map(|name| name.to_string());
+        self.set_status(format!("Network: {}", self.selected_network().name));
+    }
+
+    /// Whether the current tab shows the GPU process table
+    fn shows_gpu_processes(&self) -> bool {
+        match self.tab() {
+            Tab::System => self.dashboard_panels().contains(&Panel::Gpu),
+            Tab::Gpu => true,
+            _ => false,
+        }
+    }
+
+    /// The GPU process table runs nvidia-smi too, so it follows the slower of the
+    /// process and GPU intervals
+    fn gpu_process_refresh_interval(&self) -> Duration {
+        self.process_refresh_interval.max(self.metrics.gpu_interval())
+    }
+
+    fn available_panels(&self, mut panels: Vec<Panel>) -> Vec<Panel> {
+        if !self.metrics.gpu_available() {
+            panels.retain(|panel| *panel != Panel::Gpu);
+        }
+        if self.metrics.batteries().is_empty() {
+            panels.retain(|panel| *panel != Panel::Battery);
+        }
+        if self.listening_ports.is_none() {
+            panels.retain(|panel| *panel != Panel::Ports);
+        }
+        if self.top_talkers.is_none() {
+            panels.retain(|panel| *panel != Panel::Talkers);
+        }
+        if self.cgroups.is_none() {
+            panels.retain(|panel| *panel != Panel::Cgroups);
         }
+        if self.numa.is_none() {
+            panels.retain(|panel| *panel != Panel::Numa);
+        }
+        if self.dns_probe.is_none() {
+            panels.retain(|panel| *panel != Panel::Dns);
+        }
+        if self.traffic_quota.is_none() {
+            panels.retain(|panel| *panel != Panel::Quota);
+        }
+        if self.snmp_devices.is_empty() {
+            panels.retain(|panel| *panel != Panel::Snmp);
+        }
+        panels
+    }
+
+    /// Re-read the config file, keeping the current config if the new one is invalid.
+    /// Metric history is left untouched.
+    fn reload_config(&mut self) {
+        match Config::load(self.config_path.as_deref()) {
+            Ok(config) => {
+                self.config = config;
+                self.apply_config();
+                self.set_status("Config reloaded".to_string());
+            }
+            Err(err) => {
+                // Parse errors span several lines; keep the status on one line
+                let err = format!("{:#}", err).split_whitespace().collect::<Vec<_>>().join(" ");
+                self.set_status(format!("Config reload failed: {}", err));
+            }
+        }
+    }
+
+    /// Move chart history retention to the next longer or shorter step
+    fn step_history_retention(&mut self, longer: bool) {
+        let current = self.metrics.history_retention().map_or(0, |retention| retention.as_secs() / 60);
+        let next = if longer {
+            HISTORY_RETENTION_STEPS.iter().copied().find(|&step| step > current)
+        } else {
+            HISTORY_RETENTION_STEPS.iter().rev().copied().find(|&step| step < current)
+        };
+        let Some(minutes) = next else {
+            return;
+        };
+        self.metrics.set_history_retention((minutes > 0).then(|| Duration::from_secs(minutes * 60)));
+        match minutes {
+            0 => self.set_status(format!("History: last {} updates", self.history_size)),
+            _ => self.set_status(format!("History: {}", format_uptime(minutes * 60))),
+        }
+    }
+
+    fn set_status(&mut self, message: String) {
+        self.status_message = Some((message, Instant::now()));
+    }
+
+    /// Status message to show, if one was set recently
+    fn status_message(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, set_at)| set_at.elapsed() < STATUS_MESSAGE_DURATION)
+            .map(|(message, _)| message.as_str())
     }
 
     fn update(&mut self) {
+        // Reload config if SIGHUP was received
+        if self.reload_requested.swap(false, Ordering::Relaxed) {
+            self.reload_config();
+        }
+        if self.terminate_requested.load(Ordering::Relaxed) {
+            self.should_quit = true;
+        }
+        
         if self.last_update.elapsed() >= self.update_interval {
             // Only refresh essential system metrics for main display
             self.system.refresh_cpu_specifics(sysinfo::CpuRefreshKind::everything());
@@ -102,134 +774,659 @@ impl App {
             // Skip disk and network refresh here - they're handled separately by metrics
             
             self.metrics.update(&self.system);
+            // OOM kills are announced like crashes, since they leave no coredump behind
+            if let (Some(watch), Some(count)) = (&mut self.oom_watch, self.metrics.oom_kill_count()) {
+                if watch.update(count) > 0 {
+                    if let Some(kill) = watch.kills.first() {
+                        let message = format!("💀 OOM killed {} at {}", kill.summary(), kill.time.format("%H:%M:%S"));
+                        self.set_status(message);
+                    }
+                }
+            }
+            if let Some(quota) = &mut self.traffic_quota {
+                quota.update(self.metrics.network_interfaces(), self.metrics.interface_filter());
+            }
+            if self.history_store.as_ref().is_some_and(HistoryStore::is_due) {
+                self.save_history();
+            }
             self.last_update = Instant::now();
         }
         
-        // Update processes and logs based on their own intervals and current tab
-        if self.current_tab == 1 && self.last_process_refresh.elapsed() >= self.process_refresh_interval {
-            self.refresh_processes_cached();
+        // Custom gauges are on the dashboard, so they refresh regardless of tab
+        for gauge in &mut self.custom_gauges {
+            gauge.poll(self.metrics.history_retention());
+            if gauge.is_stale() {
+                gauge.refresh();
+            }
+        }
+        
+        if let Some(probe) = &mut self.dns_probe {
+            probe.poll(self.metrics.history_retention());
+            if probe.is_stale() {
+                probe.refresh();
+            }
+        }
+        
+        for device in &mut self.snmp_devices {
+            device.poll(self.metrics.history_retention());
+            if device.is_stale() {
+                device.refresh();
+            }
+        }
+        
+        if let Some(public_ip) = &mut self.public_ip {
+            public_ip.poll();
+            if public_ip.is_stale() {
+                public_ip.refresh();
+            }
+        }
+        
+        // Open ports, talkers, cgroups, NUMA nodes and pools are only looked up while a panel or report section shows them
+        if self.dashboard_panels().contains(&Panel::Ports) || self.report_sections().contains(&Panel::Ports) {
+            if let Some(ports) = self.listening_ports.as_mut().filter(|ports| ports.is_stale()) {
+                ports.refresh();
+            }
+        }
+        // One socket sample feeds both the talkers and the per-process network rates
+        if let Some(sample) = self.socket_sampler.poll() {
+            if let Ok(sample) = &sample {
+                self.socket_traffic.update(sample);
+            }
+            if let Some(talkers) = &mut self.top_talkers {
+                talkers.update(sample.as_ref().map_err(String::clone));
+            }
+        }
+        let talkers_shown = self.top_talkers.is_some()
+            && (self.dashboard_panels().contains(&Panel::Talkers) || self.report_sections().contains(&Panel::Talkers));
+        if (talkers_shown || self.tab() == Tab::Processes) && self.socket_sampler.is_stale() {
+            self.socket_sampler.refresh();
+        }
+        if self.dashboard_panels().contains(&Panel::Cgroups) || self.report_sections().contains(&Panel::Cgroups) {
+            if let Some(cgroups) = self.cgroups.as_mut().filter(|cgroups| cgroups.is_stale()) {
+                cgroups.refresh();
+            }
+        }
+        if self.dashboard_panels().contains(&Panel::Numa) || self.report_sections().contains(&Panel::Numa) {
+            if let Some(numa) = self.numa.as_mut().filter(|numa| numa.is_stale()) {
+                numa.refresh();
+            }
+        }
+        if self.dashboard_panels().contains(&Panel::Disk) || self.report_sections().contains(&Panel::Disk) {
+            if self.storage_health.is_stale() {
+                self.storage_health.refresh();
+            }
+            self.ssd_wear.poll();
+            if self.ssd_wear.is_stale() {
+                self.ssd_wear.refresh();
+            }
+        }
+        if let Some(explorer) = &mut self.space_explorer {
+            explorer.poll();
+        }
+        
+        // Crashes are polled in the background too, so new ones can be announced
+        let coredump_interval = if self.tab() == Tab::Coredumps {
+            COREDUMP_TAB_INTERVAL
+        } else {
+            COREDUMP_POLL_INTERVAL
+        };
+        if self.last_coredump_refresh.elapsed() >= coredump_interval {
+            self.refresh_coredumps();
         }
         
-        if self.current_tab == 2 && self.last_journal_refresh.elapsed() >= self.journal_refresh_interval {
-            self.refresh_journal_logs_cached();
+        // Update processes, logs and custom tabs based on their own intervals and current tab
+        for tab in &mut self.custom_tabs {
+            tab.poll();
+        }
+        match self.tab() {
+            Tab::Processes if self.last_process_refresh.elapsed() >= self.process_refresh_interval => {
+                self.refresh_processes_cached();
+            }
+            Tab::Journal if self.last_journal_refresh.elapsed() >= self.journal_refresh_interval => {
+                self.refresh_journal_logs_cached();
+            }
+            Tab::Custom(i) if self.custom_tabs[i].is_stale() => {
+                self.custom_tabs[i].refresh();
+            }
+            Tab::Audit => {
+                if let Some(audit) = self.audit.as_mut().filter(|audit| audit.is_stale()) {
+                    audit.refresh();
+                }
+            }
+            Tab::Sensors => {
+                if let Some(sensors) = self.sensors.as_mut().filter(|sensors| sensors.is_stale()) {
+                    sensors.refresh();
+                }
+            }
+            Tab::Connections => {
+                if let Some(connections) = self.connections.as_mut().filter(|connections| connections.is_stale()) {
+                    connections.refresh();
+                }
+            }
+            Tab::Routes => {
+                if let Some(routes) = self.routes.as_mut().filter(|routes| routes.is_stale()) {
+                    routes.refresh();
+                }
+            }
+            Tab::Firewall => {
+                if let Some(firewall) = self.firewall.as_mut().filter(|firewall| firewall.is_stale()) {
+                    firewall.refresh();
+                }
+            }
+            Tab::System | Tab::Gpu if self.last_gpu_process_refresh.elapsed() >= self.gpu_process_refresh_interval() => {
+                self.refresh_gpu_processes();
+            }
+            _ => {}
+        }
+        // The top consumers panel reads the process list, which otherwise only refreshes on its tab
+        let top_shown = (self.tab() == Tab::System && self.dashboard_panels().contains(&Panel::Top)) || self.report_sections().contains(&Panel::Top);
+        let process_list_stale = self.processes.is_empty() || self.last_process_refresh.elapsed() >= self.process_refresh_interval;
+        if top_shown && self.tab() != Tab::Processes && process_list_stale {
+            self.refresh_processes_cached();
+        }
+        if self.tab() == Tab::Processes {
+            if let Some(view) = self.thread_view.as_mut().filter(|view| view.is_stale()) {
+                view.refresh();
+            }
+            if let Some(detail) = self.process_detail.as_mut().filter(|detail| detail.is_stale()) {
+                detail.refresh();
+            }
         }
     }
 
     fn handle_input(&mut self) -> Result<()> {
         if event::poll(Duration::from_millis(50))? {
             if let Event::Key(key) = event::read()? {
+                // The kill dialog is modal and takes every key while open
+                if self.kill_dialog.is_some() {
+                    self.handle_kill_dialog_input(key.code);
+                    return Ok(());
+                }
+                if self.space_explorer.is_some() {
+                    self.handle_space_explorer_input(key.code);
+                    return Ok(());
+                }
+                
                 match key.code {
                     KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
                     KeyCode::Char('c') if key.modifiers.contains(event::KeyModifiers::CONTROL) => self.should_quit = true,
-                    KeyCode::Tab => {
-                        self.current_tab = (self.current_tab + 1) % 3;
-                        // Trigger immediate refresh for new tab if data is stale
-                        match self.current_tab {
-                            1 => {
-                                if self.processes.is_empty() || self.last_process_refresh.elapsed() >= self.process_refresh_interval {
-                                    self.refresh_processes_cached();
+                    KeyCode::Char('r') if key.modifiers.contains(event::KeyModifiers::CONTROL) => self.reload_config(),
+                    KeyCode::Char('F') => {
+                        self.temperature_unit = self.temperature_unit.toggled();
+                        self.set_status(format!("Temperatures in {}", self.temperature_unit.symbol()));
+                    }
+                    KeyCode::Char('[') => self.step_history_retention(false),
+                    KeyCode::Char(']') => self.step_history_retention(true),
+                    KeyCode::Tab => self.switch_tab((self.current_tab + 1) % self.tabs.len()),
+                    KeyCode::BackTab => self.switch_tab((self.current_tab + self.tabs.len() - 1) % self.tabs.len()),
+                    KeyCode::Char(c @ '1'..='9') => {
+                        let tab = c as usize - '1' as usize;
+                        if tab < self.tabs.len() {
+                            self.switch_tab(tab);
+                        }
+                    }
+                    KeyCode::Up => {
+                        match self.tab() {
+                            Tab::Processes if !self.processes.is_empty() && self.process_scroll > 0 => {
+                                self.process_scroll -= 1;
+                            }
+                            Tab::Journal if !self.journal_logs.is_empty() && self.journal_scroll > 0 => {
+                                self.journal_scroll -= 1;
+                            }
+                            Tab::Coredumps => {
+                                self.coredump_scroll = self.coredump_scroll.saturating_sub(1);
+                            }
+                            Tab::Filesystems => {
+                                self.filesystem_scroll = self.filesystem_scroll.saturating_sub(1);
+                            }
+                            Tab::Audit => {
+                                if let Some(audit) = self.audit.as_mut() {
+                                    audit.scroll = audit.scroll.saturating_sub(1);
                                 }
                             }
-                            2 => {
-                                if self.journal_logs.is_empty() || self.last_journal_refresh.elapsed() >= self.journal_refresh_interval {
-                                    self.refresh_journal_logs_cached();
+                            Tab::Sensors => {
+                                if let Some(sensors) = self.sensors.as_mut() {
+                                    sensors.scroll = sensors.scroll.saturating_sub(1);
                                 }
                             }
-                            _ => {}
-                        }
-                    }
-                    KeyCode::Up => {
-                        match self.current_tab {
-                            1 => {
-                                if !self.processes.is_empty() && self.process_scroll > 0 {
-                                    self.process_scroll -= 1;
+                            Tab::Connections => {
+                                if let Some(connections) = self.connections.as_mut() {
+                                    connections.scroll = connections.scroll.saturating_sub(1);
+                                }
+                            }
+                            Tab::Routes => {
+                                if let Some(routes) = self.routes.as_mut() {
+                                    routes.scroll = routes.scroll.saturating_sub(1);
                                 }
                             }
-                            2 => {
-                                if !self.journal_logs.is_empty() && self.journal_scroll > 0 {
-                                    self.journal_scroll -= 1;
+                            Tab::Firewall => {
+                                if let Some(firewall) = self.firewall.as_mut() {
+                                    firewall.scroll = firewall.scroll.saturating_sub(1);
                                 }
                             }
+                            Tab::Custom(i) => {
+                                self.custom_tabs[i].scroll = self.custom_tabs[i].scroll.saturating_sub(1);
+                            }
+                            Tab::System | Tab::Gpu => {
+                                self.gpu_process_scroll = self.gpu_process_scroll.saturating_sub(1);
+                            }
                             _ => {}
                         }
                     }
                     KeyCode::Down => {
-                        match self.current_tab {
-                            1 => {
-                                if !self.processes.is_empty() && self.process_scroll < self.processes.len().saturating_sub(1) {
-                                    self.process_scroll += 1;
+                        match self.tab() {
+                            Tab::Processes if !self.processes.is_empty() && self.process_scroll < self.processes.len().saturating_sub(1) => {
+                                self.process_scroll += 1;
+                            }
+                            Tab::Journal if !self.journal_logs.is_empty() && self.journal_scroll < self.journal_logs.len().saturating_sub(1) => {
+                                self.journal_scroll += 1;
+                            }
+                            Tab::Coredumps => {
+                                self.coredump_scroll = (self.coredump_scroll + 1).min(self.coredumps.len().saturating_sub(1));
+                            }
+                            Tab::Filesystems => {
+                                self.filesystem_scroll = (self.filesystem_scroll + 1).min(self.metrics.filesystems().len().saturating_sub(1));
+                            }
+                            Tab::Audit => {
+                                if let Some(audit) = self.audit.as_mut() {
+                                    audit.scroll = (audit.scroll + 1).min(audit.filtered().len().saturating_sub(1));
+                                }
+                            }
+                            Tab::Sensors => {
+                                if let Some(sensors) = self.sensors.as_mut() {
+                                    sensors.scroll = (sensors.scroll + 1).min(sensors.sensors.len().saturating_sub(1));
+                                }
+                            }
+                            Tab::Connections => {
+                                if let Some(connections) = self.connections.as_mut() {
+                                    connections.scroll = (connections.scroll + 1).min(connections.filtered().len().saturating_sub(1));
+                                }
+                            }
+                            Tab::Routes => {
+                                if let Some(routes) = self.routes.as_mut() {
+                                    routes.scroll = (routes.scroll + 1).min(routes.neighbors.len().saturating_sub(1));
                                 }
                             }
-                            2 => {
-                                if !self.journal_logs.is_empty() && self.journal_scroll < self.journal_logs.len().saturating_sub(1) {
-                                    self.journal_scroll += 1;
+                            Tab::Firewall => {
+                                if let Some(firewall) = self.firewall.as_mut() {
+                                    firewall.scroll = (firewall.scroll + 1).min(firewall.filtered().len().saturating_sub(1));
                                 }
                             }
+                            Tab::Custom(i) => {
+                                let tab = &mut self.custom_tabs[i];
+                                tab.scroll = (tab.scroll + 1).min(tab.row_count().saturating_sub(1));
+                            }
+                            Tab::System | Tab::Gpu => {
+                                self.gpu_process_scroll = (self.gpu_process_scroll + 1).min(self.gpu_processes.len().saturating_sub(1));
+                            }
                             _ => {}
                         }
                     }
                     KeyCode::PageUp => {
-                        match self.current_tab {
-                            1 => {
-                                if !self.processes.is_empty() {
-                                    self.process_scroll = self.process_scroll.saturating_sub(10);
+                        match self.tab() {
+                            Tab::Processes if !self.processes.is_empty() => {
+                                self.process_scroll = self.process_scroll.saturating_sub(10);
+                            }
+                            Tab::Journal if !self.journal_logs.is_empty() => {
+                                self.journal_scroll = self.journal_scroll.saturating_sub(10);
+                            }
+                            Tab::Coredumps => {
+                                self.coredump_scroll = self.coredump_scroll.saturating_sub(10);
+                            }
+                            Tab::Filesystems => {
+                                self.filesystem_scroll = self.filesystem_scroll.saturating_sub(10);
+                            }
+                            Tab::Audit => {
+                                if let Some(audit) = self.audit.as_mut() {
+                                    audit.scroll = audit.scroll.saturating_sub(10);
+                                }
+                            }
+                            Tab::Sensors => {
+                                if let Some(sensors) = self.sensors.as_mut() {
+                                    sensors.scroll = sensors.scroll.saturating_sub(10);
+                                }
+                            }
+                            Tab::Connections => {
+                                if let Some(connections) = self.connections.as_mut() {
+                                    connections.scroll = connections.scroll.saturating_sub(10);
+                                }
+                            }
+                            Tab::Routes => {
+                                if let Some(routes) = self.routes.as_mut() {
+                                    routes.scroll = routes.scroll.saturating_sub(10);
                                 }
                             }
-                            2 => {
-                                if !self.journal_logs.is_empty() {
-                                    self.journal_scroll = self.journal_scroll.saturating_sub(10);
+                            Tab::Firewall => {
+                                if let Some(firewall) = self.firewall.as_mut() {
+                                    firewall.scroll = firewall.scroll.saturating_sub(10);
                                 }
                             }
+                            Tab::Custom(i) => {
+                                self.custom_tabs[i].scroll = self.custom_tabs[i].scroll.saturating_sub(10);
+                            }
+                            Tab::System | Tab::Gpu => {
+                                self.gpu_process_scroll = self.gpu_process_scroll.saturating_sub(10);
+                            }
                             _ => {}
                         }
                     }
                     KeyCode::PageDown => {
-                        match self.current_tab {
-                            1 => {
-                                if !self.processes.is_empty() {
-                                    self.process_scroll = (self.process_scroll + 10).min(self.processes.len().saturating_sub(1));
+                        match self.tab() {
+                            Tab::Processes if !self.processes.is_empty() => {
+                                self.process_scroll = (self.process_scroll + 10).min(self.processes.len().saturating_sub(1));
+                            }
+                            Tab::Journal if !self.journal_logs.is_empty() => {
+                                self.journal_scroll = (self.journal_scroll + 10).min(self.journal_logs.len().saturating_sub(1));
+                            }
+                            Tab::Coredumps => {
+                                self.coredump_scroll = (self.coredump_scroll + 10).min(self.coredumps.len().saturating_sub(1));
+                            }
+                            Tab::Filesystems => {
+                                self.filesystem_scroll = (self.filesystem_scroll + 10).min(self.metrics.filesystems().len().saturating_sub(1));
+                            }
+                            Tab::Audit => {
+                                if let Some(audit) = self.audit.as_mut() {
+                                    audit.scroll = (audit.scroll + 10).min(audit.filtered().len().saturating_sub(1));
+                                }
+                            }
+                            Tab::Sensors => {
+                                if let Some(sensors) = self.sensors.as_mut() {
+                                    sensors.scroll = (sensors.scroll + 10).min(sensors.sensors.len().saturating_sub(1));
+                                }
+                            }
+                            Tab::Connections => {
+                                if let Some(connections) = self.connections.as_mut() {
+                                    connections.scroll = (connections.scroll + 10).min(connections.filtered().len().saturating_sub(1));
+                                }
+                            }
+                            Tab::Routes => {
+                                if let Some(routes) = self.routes.as_mut() {
+                                    routes.scroll = (routes.scroll + 10).min(routes.neighbors.len().saturating_sub(1));
                                 }
                             }
-                            2 => {
-                                if !self.journal_logs.is_empty() {
-                                    self.journal_scroll = (self.journal_scroll + 10).min(self.journal_logs.len().saturating_sub(1));
+                            Tab::Firewall => {
+                                if let Some(firewall) = self.firewall.as_mut() {
+                                    firewall.scroll = (firewall.scroll + 10).min(firewall.filtered().len().saturating_sub(1));
                                 }
                             }
+                            Tab::Custom(i) => {
+                                let tab = &mut self.custom_tabs[i];
+                                tab.scroll = (tab.scroll + 10).min(tab.row_count().saturating_sub(1));
+                            }
+                            Tab::System | Tab::Gpu => {
+                                self.gpu_process_scroll = (self.gpu_process_scroll + 10).min(self.gpu_processes.len().saturating_sub(1));
+                            }
                             _ => {}
                         }
                     }
-                    KeyCode::Char('c') => {
-                        if self.current_tab == 1 {
-                            self.process_sort_mode = ProcessSortMode::Cpu;
-                            self.refresh_processes_cached();
+                    KeyCode::Enter if self.tab() == Tab::Coredumps && !self.coredumps.is_empty() => {
+                        self.open_coredump_journal();
+                    }
+                    KeyCode::Enter if self.tab() == Tab::Connections => {
+                        self.open_connection_process();
+                    }
+                    KeyCode::Backspace if self.tab() == Tab::Journal && self.journal_context.is_some() => {
+                        self.journal_context = None;
+                        self.journal_logs.clear();
+                        self.journal_scroll = 0;
+                        self.refresh_journal_logs_cached();
+                    }
+                    KeyCode::Char('r') if self.tab() == Tab::Sensors => {
+                        if let Some(sensors) = self.sensors.as_mut() {
+                            sensors.reset_min_max();
                         }
+                        self.set_status("Sensor min/max reset".to_string());
                     }
-                    KeyCode::Char('m') => {
-                        if self.current_tab == 1 {
-                            self.process_sort_mode = ProcessSortMode::Memory;
-                            self.refresh_processes_cached();
+                    KeyCode::Char(c @ ('s' | 'p' | 't')) if self.tab() == Tab::Connections => {
+                        if let Some(connections) = self.connections.as_mut() {
+                            match c {
+                                's' => connections.cycle_sort(),
+                                'p' => connections.cycle_protocol_filter(),
+                                _ => connections.cycle_state_filter(),
+                            }
                         }
                     }
-                    KeyCode::Char('k') => {
-                        if self.current_tab == 1 && !self.processes.is_empty() {
-                            let selected_process = &self.processes[self.process_scroll];
-                            self.kill_process(selected_process.pid);
+                    KeyCode::Char('m') if self.tab() == Tab::Firewall => {
+                        if let Some(firewall) = self.firewall.as_mut() {
+                            firewall.toggle_matching_only();
                         }
                     }
-                    _ => {}
-                }
-            }
-        }
-        Ok(())
-    }
-
-    fn refresh_journal_logs_cached(&mut self) {
-        // Non-blocking journal refresh with timeout
-        if let Ok(output) = Command::new("timeout")
+                    KeyCode::Char('t') if self.tab() == Tab::Audit => {
+                        if let Some(audit) = self.audit.as_mut() {
+                            audit.cycle_type_filter();
+                        }
+                    }
+                    KeyCode::Char('k') if self.tab() == Tab::Audit => {
+                        if let Some(audit) = self.audit.as_mut() {
+                            audit.cycle_key_filter();
+                        }
+                    }
+                    KeyCode::Char(c @ ('c' | 'm' | 'b' | 'p' | 'n' | 'o' | 't')) if self.tab() == Tab::Processes => {
+                        self.process_sort_mode = match c {
+                            'c' => ProcessSortMode::Cpu,
+                            'm' => ProcessSortMode::Memory,
+                            'b' => ProcessSortMode::Network,
+                            'p' => ProcessSortMode::Pid,
+                            'n' => ProcessSortMode::Name,
+                            'o' => ProcessSortMode::User,
+                            _ => ProcessSortMode::Runtime,
+                        };
+                        self.refresh_processes_cached();
+                    }
+                    // Sorting by swap needs the swap column
+                    KeyCode::Char('s') if self.tab() == Tab::Processes => {
+                        self.process_sort_mode = ProcessSortMode::Swap;
+                        self.show_memory_columns = true;
+                        self.refresh_processes_cached();
+                    }
+                    KeyCode::Char('w') if self.tab() == Tab::Processes => {
+                        self.show_memory_columns = !self.show_memory_columns;
+                        if !self.show_memory_columns && self.process_sort_mode == ProcessSortMode::Swap {
+                            self.process_sort_mode = ProcessSortMode::Memory;
+                        }
+                        self.refresh_processes_cached();
+                    }
+                    KeyCode::Char('r') if self.tab() == Tab::Processes => {
+                        self.process_sort_reversed = !self.process_sort_reversed;
+                        self.refresh_processes_cached();
+                    }
+                    KeyCode::Char('u') if self.tab() == Tab::Processes => {
+                        self.filter_by_user = !self.filter_by_user;
+                        self.refresh_processes_cached();
+                    }
+                    KeyCode::Char('f') if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let pid = self.processes[self.process_scroll].pid;
+                        self.followed_pid = if self.followed_pid == Some(pid) { None } else { Some(pid) };
+                    }
+                    // Opens the threads of the selected process, or closes them when already shown
+                    KeyCode::Char('T') if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let selected_process = &self.processes[self.process_scroll];
+                        if self.thread_view.as_ref().is_some_and(|view| view.pid == selected_process.pid) {
+                            self.thread_view = None;
+                        } else {
+                            self.thread_view = Some(ThreadView::new(selected_process.pid, selected_process.name.clone()));
+                            self.process_detail = None;
+                        }
+                    }
+                    // Same for the detail pane (command line and open files)
+                    KeyCode::Enter if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let selected_process = &self.processes[self.process_scroll];
+                        if self.process_detail.as_ref().is_some_and(|detail| detail.pid == selected_process.pid) {
+                            self.process_detail = None;
+                        } else {
+                            self.process_detail = Some(ProcessDetail::new(selected_process.pid, selected_process.name.clone()));
+                            self.thread_view = None;
+                        }
+                    }
+                    // Exited rows linger struck through; their PID may already belong to another process
+                    KeyCode::Char(c @ ('k' | 'z' | 'h' | 'g' | '+' | '-')) if self.tab() == Tab::Processes && self.selected_process_exited() => {
+                        let process = &self.processes[self.process_scroll];
+                        let action = if matches!(c, '+' | '-') { "renice" } else { "signal" };
+                        self.set_status(format!("Cannot {} {} ({}): process has exited", action, process.pid, process.name));
+                    }
+                    KeyCode::Char('k') if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let selected_process = &self.processes[self.process_scroll];
+                        self.open_kill_dialog(selected_process.pid, selected_process.name.clone(), Signal::Term);
+                    }
+                    // Stopping the wrong process (a shell, sshd) can lock the user out and
+                    // SIGHUP terminates most programs, so both are confirmed like a kill
+                    KeyCode::Char(c @ ('z' | 'h')) if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let signal = if c == 'z' { Signal::Stop } else { Signal::Hup };
+                        let selected_process = &self.processes[self.process_scroll];
+                        self.open_kill_dialog(selected_process.pid, selected_process.name.clone(), signal);
+                    }
+                    // Resuming is harmless enough to skip the dialog
+                    KeyCode::Char('g') if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let selected_process = &self.processes[self.process_scroll];
+                        let (pid, name) = (selected_process.pid, selected_process.name.clone());
+                        self.send_signal(pid, &name, Signal::Cont);
+                    }
+                    // + lowers the priority (higher nice), - raises it
+                    KeyCode::Char(c @ ('+' | '-')) if self.tab() == Tab::Processes && !self.processes.is_empty() => {
+                        let step = if c == '+' { 1 } else { -1 };
+                        self.renice_selected(step);
+                    }
+                    KeyCode::Left if self.tab() == Tab::Gpu => {
+                        self.selected_gpu = self.selected_gpu.saturating_sub(1);
+                    }
+                    KeyCode::Right if self.tab() == Tab::Gpu => {
+                        self.selected_gpu = (self.selected_gpu + 1).min(self.metrics.gpu_devices().len().saturating_sub(1));
+                    }
+                    KeyCode::Char('i') if self.tab() == Tab::System && self.dashboard_panels().contains(&Panel::Network) => {
+                        self.cycle_network_interface();
+                    }
+                    KeyCode::Char('u') if self.tab() == Tab::System && self.dashboard_panels().contains(&Panel::Disk) => {
+                        self.open_space_explorer();
+                    }
+                    KeyCode::Char('v') if self.shows_gpu_processes() => {
+                        self.gpu_process_sort_mode = GpuProcessSortMode::Vram;
+                        self.sort_gpu_processes();
+                    }
+                    KeyCode::Char('g') if self.shows_gpu_processes() => {
+                        self.gpu_process_sort_mode = GpuProcessSortMode::Gpu;
+                        self.sort_gpu_processes();
+                    }
+                    KeyCode::Char('k') if self.shows_gpu_processes() && !self.gpu_processes.is_empty() => {
+                        let selected_process = &self.gpu_processes[self.gpu_process_scroll];
+                        self.open_kill_dialog(selected_process.pid, selected_process.name.clone(), Signal::Term);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn switch_tab(&mut self, tab: usize) {
+        self.current_tab = tab;
+        // Trigger immediate refresh for new tab if data is stale
+        match self.tab() {
+            Tab::Processes if self.processes.is_empty() || self.last_process_refresh.elapsed() >= self.process_refresh_interval => {
+                self.refresh_processes_cached();
+            }
+            Tab::Journal if self.journal_logs.is_empty() || self.last_journal_refresh.elapsed() >= self.journal_refresh_interval => {
+                self.refresh_journal_logs_cached();
+            }
+            Tab::Custom(i) if self.custom_tabs[i].is_stale() => {
+                self.custom_tabs[i].refresh();
+            }
+            Tab::Audit => {
+                if let Some(audit) = self.audit.as_mut().filter(|audit| audit.is_stale()) {
+                    audit.refresh();
+                }
+            }
+            Tab::Sensors => {
+                if let Some(sensors) = self.sensors.as_mut().filter(|sensors| sensors.is_stale()) {
+                    sensors.refresh();
+                }
+            }
+            Tab::Connections => {
+                if let Some(connections) = self.connections.as_mut().filter(|connections| connections.is_stale()) {
+                    connections.refresh();
+                }
+            }
+            Tab::Routes => {
+                if let Some(routes) = self.routes.as_mut().filter(|routes| routes.is_stale()) {
+                    routes.refresh();
+                }
+            }
+            Tab::Firewall => {
+                if let Some(firewall) = self.firewall.as_mut().filter(|firewall| firewall.is_stale()) {
+                    firewall.refresh();
+                }
+            }
+            Tab::System | Tab::Gpu if self.last_gpu_process_refresh.elapsed() >= self.gpu_process_refresh_interval() => {
+                self.refresh_gpu_processes();
+            }
+            _ => {}
+        }
+    }
+
+    fn refresh_coredumps(&mut self) {
+        let coredumps = coredump::list_coredumps();
+        
+        // Announce crashes newer than anything seen before
+        let newest_known = self.coredumps.first().map(|dump| dump.time);
+        if let Some(newest) = coredumps.first() {
+            if newest_known.is_none_or(|known| newest.time > known) {
+                let message = format!("💥 {} crashed ({}) - see Crashes tab", newest.program(), newest.signal);
+                self.set_status(message);
+            }
+        }
+        
+        self.coredumps = coredumps;
+        self.coredump_scroll = self.coredump_scroll.min(self.coredumps.len().saturating_sub(1));
+        self.last_coredump_refresh = Instant::now();
+    }
+
+    /// Show the journal around the selected crash in the Journal tab
+    fn open_coredump_journal(&mut self) {
+        let dump = &self.coredumps[self.coredump_scroll];
+        self.journal_context = Some(JournalContext {
+            title: format!("{} (PID {}) crash at {}", dump.program(), dump.pid, dump.time.format("%Y-%m-%d %H:%M:%S")),
+            args: dump.journal_context_args(),
+        });
+        self.journal_logs.clear();
+        self.journal_scroll = 0;
+        if let Some(index) = self.tabs.iter().position(|tab| *tab == Tab::Journal) {
+            self.current_tab = index;
+        }
+        self.refresh_journal_logs_cached();
+    }
+
+    /// Select the process holding the selected socket in the Processes tab
+    fn open_connection_process(&mut self) {
+        let owner = self.connections.as_ref().and_then(|connections| {
+            connections.filtered().get(connections.scroll)?.owner.clone()
+        });
+        let Some((pid, name)) = owner else {
+            self.set_status("No visible process holds this socket".to_string());
+            return;
+        };
+        if let Some(index) = self.tabs.iter().position(|tab| *tab == Tab::Processes) {
+            self.current_tab = index;
+        }
+        // Following another process would pull the selection back on the next refresh
+        self.followed_pid = self.followed_pid.filter(|&followed| followed == pid);
+        self.refresh_processes_cached();
+        match self.processes.iter().position(|process| process.pid == pid) {
+            Some(index) => self.process_scroll = index,
+            None => self.set_status(format!("{} ({}) is not in the process list (filtered out or exited)", name, pid)),
+        }
+    }
+
+    fn refresh_journal_logs_cached(&mut self) {
+        // Either a filtered context (e.g. around a crash) or the latest entries
+        let filter_args = match &self.journal_context {
+            Some(context) => context.args.clone(),
+            None => vec!["-n".to_string(), "100".to_string()],
+        };
+        
+        // Non-blocking journal refresh with timeout
+        if let Ok(output) = Command::new("timeout")
             .arg("1s") // 1 second timeout
             .arg("journalctl")
-            .arg("-n")
-            .arg("100")
+            .args(&filter_args)
             .arg("--no-pager")
             .arg("-o")
             .arg("short")
@@ -253,23 +1450,82 @@ impl App {
     }
 
     fn refresh_processes_cached(&mut self) {
+        // The selection belongs to a process, not a row, so it survives re-sorting
+        let selected_pid = self.followed_pid
+            .or_else(|| self.processes.get(self.process_scroll).map(|process| process.pid));
+        
         // Optimized process refresh - only refresh processes, not all system info
-        self.system.refresh_processes(sysinfo::ProcessesToUpdate::All, false); // false = don't refresh everything
+        self.system.refresh_processes_specifics(ProcessesToUpdate::All, true, process_refresh_kind()); // true = drop exited processes
+        let net_rates = &self.socket_traffic.rates;
         
+        let user_names = &self.user_names;
+        let read_memory_details = self.show_memory_columns;
+        let user_filter = self.filter_by_user.then_some(self.process_user.as_str());
         let mut processes: Vec<ProcessInfo> = self.system.processes()
             .values()
             .filter(|process| {
-                // More efficient filtering
-                !process.name().is_empty() && process.memory() > 1024 // > 1KB to filter out tiny processes
+                // More efficient filtering; zombies have no memory left but are worth seeing
+                !process.name().is_empty()
+                    && (process.memory() > 1024 || process.status() == ProcessStatus::Zombie) // > 1KB to filter out tiny processes
+            })
+            .filter(|process| {
+                user_filter.is_none_or(|name| {
+                    process.user_id()
+                        .and_then(|uid| user_names.get(uid))
+                        .is_some_and(|user| user == name)
+                })
             })
-            .map(|process| ProcessInfo {
-                pid: process.pid().as_u32(),
-                name: process.name().to_string_lossy().to_string(),
-                cpu_usage: process.cpu_usage(),
-                memory_usage: process.memory(),
-                user: process.user_id().map(|uid| uid.to_string()).unwrap_or_else(|| "unknown".to_string()),
+            .map(|process| {
+                let (swap_usage, shared_memory) = if read_memory_details {
+                    read_swap_and_shared(process.pid().as_u32()).unwrap_or_default()
+                } else {
+                    (0, 0)
+                };
+                ProcessInfo {
+                    pid: process.pid().as_u32(),
+                    name: process.name().to_string_lossy().to_string(),
+                    cpu_usage: process.cpu_usage(),
+                    memory_usage: process.memory(),
+                    swap_usage,
+                    shared_memory,
+                    user: process.user_id().map_or("unknown".to_string(), |uid| user_name(user_names, uid)),
+                    net_rx: net_rates.get(&process.pid().as_u32()).map_or(0.0, |rate| rate.0),
+                    net_tx: net_rates.get(&process.pid().as_u32()).map_or(0.0, |rate| rate.1),
+                    priority: read_priority(process.pid().as_u32()),
+                    run_time: process.run_time(),
+                    state: state_letter(process.status()),
+                    exited: false,
+                    thread: process.thread_kind().is_some(),
+                }
             })
             .collect();
+
+        // PID churn: processes that disappeared linger for a moment so crash loops stand out
+        let now = Instant::now();
+        for process in self.processes.iter().filter(|p| !p.exited) {
+            if self.system.process(Pid::from_u32(process.pid)).is_none() {
+                self.exited_processes.push((ProcessInfo { exited: true, ..process.clone() }, now));
+            }
+        }
+        let system = &self.system;
+        self.exited_processes.retain(|(process, exited_at)| {
+            // A reused PID belongs to the new process
+            now.duration_since(*exited_at) < EXITED_PROCESS_LINGER
+                && system.process(Pid::from_u32(process.pid)).is_none()
+        });
+        processes.extend(self.exited_processes.iter().map(|(process, _)| process.clone()));
+
+        let listed: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+        self.process_cpu_history.retain(|pid, _| listed.contains(pid));
+        for process in processes.iter().filter(|p| !p.exited) {
+            let samples = self.process_cpu_history
+                .entry(process.pid)
+                .or_insert_with(|| VecDeque::with_capacity(PROCESS_CPU_SAMPLES));
+            if samples.len() >= PROCESS_CPU_SAMPLES {
+                samples.pop_front();
+            }
+            samples.push_back(process.cpu_usage);
+        }
         
         // Sort based on current sort mode
         match self.process_sort_mode {
@@ -287,6 +1543,32 @@ impl App {
                             .unwrap_or(std::cmp::Ordering::Equal))
                 });
             }
+            ProcessSortMode::Network => {
+                processes.sort_by(|a, b| {
+                    b.net_total().partial_cmp(&a.net_total())
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then_with(|| b.cpu_usage.partial_cmp(&a.cpu_usage)
+                            .unwrap_or(std::cmp::Ordering::Equal))
+                });
+            }
+            ProcessSortMode::Pid => processes.sort_by_key(|p| p.pid),
+            ProcessSortMode::Name => {
+                processes.sort_by(|a, b| {
+                    a.name.to_lowercase().cmp(&b.name.to_lowercase()).then_with(|| a.pid.cmp(&b.pid))
+                });
+            }
+            ProcessSortMode::User => {
+                processes.sort_by(|a, b| a.user.cmp(&b.user).then_with(|| a.pid.cmp(&b.pid)));
+            }
+            ProcessSortMode::Runtime => {
+                processes.sort_by(|a, b| b.run_time.cmp(&a.run_time).then_with(|| a.pid.cmp(&b.pid)));
+            }
+            ProcessSortMode::Swap => {
+                processes.sort_by(|a, b| b.swap_usage.cmp(&a.swap_usage).then_with(|| b.memory_usage.cmp(&a.memory_usage)));
+            }
+        }
+        if self.process_sort_reversed {
+            processes.reverse();
         }
         
         // Limit to top 500 processes for performance
@@ -295,37 +1577,251 @@ impl App {
         self.processes = processes;
         self.last_process_refresh = Instant::now();
         
+        if let Some(index) = selected_pid.and_then(|pid| self.processes.iter().position(|p| p.pid == pid)) {
+            self.process_scroll = index;
+        }
+        if let Some(pid) = self.followed_pid {
+            if self.system.process(Pid::from_u32(pid)).is_none() {
+                self.followed_pid = None;
+                self.set_status(format!("Followed process {} exited", pid));
+            }
+        }
+        
         // Ensure scroll position is within bounds
         if self.process_scroll >= self.processes.len() {
             self.process_scroll = self.processes.len().saturating_sub(1);
         }
     }
 
-    fn kill_process(&mut self, pid: u32) {
-        // Use kill command to send SIGKILL to the process
+    fn refresh_gpu_processes(&mut self) {
+        // Only worth the nvidia-smi calls when the process table is on screen (and a GPU exists)
+        if self.shows_gpu_processes() {
+            self.gpu_processes = gpu::get_gpu_processes(&mut self.gpu_drm_usage);
+            let pids: Vec<Pid> = self.gpu_processes.iter().map(|p| Pid::from_u32(p.pid)).collect();
+            self.system.refresh_processes_specifics(ProcessesToUpdate::Some(&pids), true, process_refresh_kind());
+            gpu::annotate_processes(&mut self.gpu_processes, &self.system, &self.user_names);
+            self.gpu_vram_history.record(&self.gpu_processes);
+            self.sort_gpu_processes();
+            self.gpu_process_scroll = self.gpu_process_scroll.min(self.gpu_processes.len().saturating_sub(1));
+        }
+        self.last_gpu_process_refresh = Instant::now();
+    }
+
+    fn sort_gpu_processes(&mut self) {
+        match self.gpu_process_sort_mode {
+            GpuProcessSortMode::Vram => {
+                self.gpu_processes.sort_by(|a, b| {
+                    b.memory_mb.cmp(&a.memory_mb).then_with(|| b.gpu_util.cmp(&a.gpu_util))
+                });
+            }
+            GpuProcessSortMode::Gpu => {
+                self.gpu_processes.sort_by(|a, b| {
+                    b.gpu_util.cmp(&a.gpu_util).then_with(|| b.memory_mb.cmp(&a.memory_mb))
+                });
+            }
+        }
+    }
+
+    /// The selected row of the process table is a process that has exited
+    fn selected_process_exited(&self) -> bool {
+        self.processes.get(self.process_scroll).is_some_and(|process| process.exited)
+    }
+
+    /// Confirm before signalling, with `signal` preselected
+    fn open_kill_dialog(&mut self, pid: u32, name: String, signal: Signal) {
+        let choice = Signal::KILL_CHOICES.iter().position(|&choice| choice == signal).unwrap_or(0);
+        self.kill_dialog = Some(KillDialog { pid, name, choice });
+    }
+
+    fn handle_kill_dialog_input(&mut self, code: KeyCode) {
+        let Some(dialog) = self.kill_dialog.as_mut() else {
+            return;
+        };
+        let choices = Signal::KILL_CHOICES.len();
+        match code {
+            KeyCode::Left | KeyCode::Up | KeyCode::BackTab => dialog.choice = (dialog.choice + choices - 1) % choices,
+            KeyCode::Right | KeyCode::Down | KeyCode::Tab => dialog.choice = (dialog.choice + 1) % choices,
+            KeyCode::Char(c @ '1'..='5') => dialog.choice = c as usize - '1' as usize,
+            KeyCode::Enter | KeyCode::Char('y') => {
+                let (pid, name, signal) = (dialog.pid, dialog.name.clone(), dialog.signal());
+                self.kill_dialog = None;
+                self.send_signal(pid, &name, signal);
+            }
+            KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('q') => self.kill_dialog = None,
+            _ => {}
+        }
+    }
+
+    /// Offer the tracked mounts first, in the disk panel's order, then other partitions
+    fn open_space_explorer(&mut self) {
+        let mut mounts: Vec<String> = self
+            .metrics
+            .tracked_mounts()
+            .iter()
+            .filter(|mount| self.metrics.filesystem(&mount.mount_point).is_some())
+            .map(|mount| mount.mount_point.clone())
+            .collect();
+        for fs in self.metrics.partitions() {
+            if !mounts.contains(&fs.mount_point) {
+                mounts.push(fs.mount_point.clone());
+            }
+        }
+        if !mounts.is_empty() {
+            self.space_explorer = Some(SpaceExplorer::new(mounts));
+        }
+    }
+
+    fn handle_space_explorer_input(&mut self, code: KeyCode) {
+        let Some(explorer) = self.space_explorer.as_mut() else {
+            return;
+        };
+        match code {
+            KeyCode::Up => explorer.select(-1),
+            KeyCode::Down => explorer.select(1),
+            KeyCode::PageUp => explorer.select(-10),
+            KeyCode::PageDown => explorer.select(10),
+            KeyCode::Enter | KeyCode::Right => explorer.enter(),
+            KeyCode::Backspace | KeyCode::Left => explorer.back(),
+            KeyCode::Char('r') if explorer.mount_point.is_some() && !explorer.is_scanning() => explorer.start(),
+            // Closing cancels a running scan
+            KeyCode::Esc | KeyCode::Char('q') => self.space_explorer = None,
+            _ => {}
+        }
+    }
+
+    fn renice_selected(&mut self, step: i32) {
+        let process = &self.processes[self.process_scroll];
+        let (pid, name) = (process.pid, process.name.clone());
+        let Some((_, nice)) = process.priority else {
+            self.set_status(format!("Cannot read the nice value of {} ({})", pid, name));
+            return;
+        };
+        let nice = (nice + step).clamp(-20, 19);
+        
+        let result = Command::new("renice")
+            .args(["-n", &nice.to_string(), "-p", &pid.to_string()])
+            .output();
+        match result {
+            Ok(output) if output.status.success() => {
+                self.set_status(format!("Set nice of {} ({}) to {}", pid, name, nice));
+            }
+            Ok(output) => {
+                // Raising priority (negative steps) needs root
+                let err = String::from_utf8_lossy(&output.stderr);
+                self.set_status(format!("Failed to renice {} ({}): {}", pid, name, err.trim()));
+            }
+            Err(err) => {
+                self.set_status(format!("Failed to run renice: {}", err));
+            }
+        }
+        self.refresh_processes_cached();
+    }
+
+    fn send_signal(&mut self, pid: u32, name: &str, signal: Signal) {
         let result = Command::new("kill")
-            .arg("-9") // SIGKILL
+            .args(["-s", signal.name()])
             .arg(pid.to_string())
             .output();
         
         match result {
+            Ok(output) if output.status.success() => {
+                self.set_status(format!("Sent SIG{} to {} ({})", signal.name(), pid, name));
+            }
             Ok(output) => {
-                if output.status.success() {
-                    // Process killed successfully, refresh the process list
-                    self.refresh_processes_cached();
-                }
-                // Note: We don't show success/error messages to keep the UI clean
-                // System administrators expect immediate feedback through the process list update
+                // e.g. insufficient permissions or the process already exited
+                let err = String::from_utf8_lossy(&output.stderr);
+                self.set_status(format!("Failed to send SIG{} to {} ({}): {}", signal.name(), pid, name, err.trim()));
             }
-            Err(_) => {
-                // Kill command failed (e.g., insufficient permissions, process doesn't exist)
-                // Refresh the list anyway to show current state
-                self.refresh_processes_cached();
+            Err(err) => {
+                self.set_status(format!("Failed to run kill: {}", err));
             }
         }
+        
+        // Refresh the lists anyway to show the current state
+        self.refresh_processes_cached();
+        self.refresh_gpu_processes();
+    }
+}
+
+/// What sysinfo refreshes per process: its defaults plus the owner, which it otherwise
+/// leaves unset for processes started after launch
+fn process_refresh_kind() -> ProcessRefreshKind {
+    ProcessRefreshKind::new()
+        .with_memory()
+        .with_cpu()
+        .with_disk_usage()
+        .with_exe(UpdateKind::OnlyIfNotSet)
+        .with_user(UpdateKind::OnlyIfNotSet)
+}
+
+/// Username of a UID, or the UID itself for accounts missing from the passwd database
+/// (e.g. users of a container's namespace)
+fn user_name(user_names: &HashMap<Uid, String>, uid: &Uid) -> String {
+    user_names.get(uid).cloned().unwrap_or_else(|| uid.to_string())
+}
+
+/// System uptime such as `3d 4h 12m`; minutes are dropped after a month
+fn format_uptime(seconds: u64) -> String {
+    let (days, hours, minutes) = (seconds / 86400, seconds % 86400 / 3600, seconds % 3600 / 60);
+    match days {
+        0 if hours == 0 => format!("{}m", minutes),
+        0 => format!("{}h {}m", hours, minutes),
+        1..=30 => format!("{}d {}h {}m", days, hours, minutes),
+        _ => format!("{}d {}h", days, hours),
+    }
+}
+
+/// Local boot time, from the boot timestamp sysinfo reads once
+fn boot_time() -> Option<chrono::DateTime<chrono::Local>> {
+    chrono::DateTime::from_timestamp(System::boot_time() as i64, 0).map(|time| time.with_timezone(&chrono::Local))
+}
+
+/// VmSwap and RssFile + RssShmem from /proc/<pid>/status, in bytes
+fn read_swap_and_shared(pid: u32) -> Option<(u64, u64)> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let (mut swap, mut shared) = (0, 0);
+    for line in status.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        // Values look like "   1234 kB"
+        let kb = || value.split_whitespace().next()?.parse::<u64>().ok();
+        match key {
+            "VmSwap" => swap = kb()? * 1024,
+            "RssFile" | "RssShmem" => shared += kb()? * 1024,
+            _ => {}
+        }
+    }
+    Some((swap, shared))
+}
+
+/// Single-letter process state as shown by ps and top
+fn state_letter(status: ProcessStatus) -> char {
+    match status {
+        ProcessStatus::Run => 'R',
+        ProcessStatus::Sleep => 'S',
+        ProcessStatus::Idle => 'I',
+        ProcessStatus::UninterruptibleDiskSleep => 'D',
+        ProcessStatus::Zombie => 'Z',
+        ProcessStatus::Stop => 'T',
+        ProcessStatus::Tracing => 't',
+        ProcessStatus::Dead => 'X',
+        ProcessStatus::Wakekill => 'K',
+        ProcessStatus::Waking => 'W',
+        ProcessStatus::Parked => 'P',
+        ProcessStatus::LockBlocked => 'L',
+        ProcessStatus::Unknown(_) => '?',
     }
 }
 
+/// Kernel priority and nice value from /proc/<pid>/stat (fields 18 and 19)
+fn read_priority(pid: u32) -> Option<(i32, i32)> {
+    let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+    // The command name may contain spaces and parentheses; fields resume after the last ')'
+    let fields: Vec<&str> = stat[stat.rfind(')')? + 1..].split_whitespace().collect();
+    Some((fields.get(15)?.parse().ok()?, fields.get(16)?.parse().ok()?))
+}
+
 fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
     loop {
         app.update();
@@ -344,9 +1840,14 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, mut app: App) -> Result<()> {
     Ok(())
 }
 
-fn run_simple_mode(mut app: App) -> Result<()> {
+fn run_simple_mode(mut app: App, differences: bool) -> Result<()> {
+    let mut hl = ChangeHighlighter::new(differences);
+    
     loop {
         app.update();
+        if app.should_quit {
+            break;
+        }
         
         // Clear screen and move cursor to top
         print!("\x1B[2J\x1B[H");
@@ -358,182 +1859,954 @@ fn run_simple_mode(mut app: App) -> Result<()> {
         let padding = (header_width - clock_text.len()) / 2;
         println!("{:padding$}{}", "", clock_text, padding = padding);
         println!("==============================");
+        if let Some(message) = app.status_message() {
+            println!("{}", message);
+        }
         
-        // CPU info
-        println!("\nCPU:");
-        println!("  Overall Usage: {:.1}%", app.metrics.cpu_usage());
-        if let Some(cpu_info) = app.system.cpus().first() {
-            println!("  Brand: {}", cpu_info.brand());
-            println!("  Frequency: {:.0} MHz", cpu_info.frequency());
-            println!("  Cores: {}", app.system.cpus().len());
-        }
-        
-        // Per-core CPU usage
-        let per_core = app.metrics.per_core_usage();
-        if !per_core.is_empty() {
-            println!("  Per-core Usage:");
-            let cores_per_row = 4;
-            for (i, &usage) in per_core.iter().enumerate() {
-                if i % cores_per_row == 0 {
-                    print!("    ");
-                }
-                print!("C{:02}:{:5.1}%", i, usage);
-                if i % cores_per_row == cores_per_row - 1 || i == per_core.len() - 1 {
-                    println!();
-                } else {
-                    print!("  ");
+        // Sections in the order given by the panel config
+        for section in app.report_sections() {
+            match section {
+                Panel::Cpu => print_cpu_section(&app, &mut hl),
+                Panel::Memory => print_memory_section(&app, &mut hl),
+                Panel::Disk => print_disk_section(&app, &mut hl),
+                Panel::Io => print_io_section(&app, &mut hl),
+                Panel::Pressure => print_pressure_section(&app, &mut hl),
+                Panel::Cgroups => print_cgroups_section(&app, &mut hl),
+                Panel::Numa => print_numa_section(&app, &mut hl),
+                Panel::Network => print_network_section(&app, &mut hl),
+                Panel::Ports => print_ports_section(&app),
+                Panel::Dns => print_dns_section(&app, &mut hl),
+                Panel::Quota => print_quota_section(&app, &mut hl),
+                Panel::Snmp => print_snmp_section(&app, &mut hl),
+                Panel::Tcp => print_tcp_section(&app, &mut hl),
+                Panel::Talkers => print_talkers_section(&app, &mut hl),
+                Panel::Temperature => print_temperature_section(&app, &mut hl),
+                Panel::Fans => print_fans_section(&app, &mut hl),
+                Panel::Gpu => print_gpu_section(&app, &mut hl),
+                Panel::Top => print_top_section(&app, &mut hl),
+                Panel::Power => print_power_section(&app, &mut hl),
+                Panel::Battery => print_battery_section(&app, &mut hl),
+                Panel::Gauges => print_gauges_section(&app, &mut hl),
+            }
+        }
+        
+        // Handle Ctrl+C
+        if let Ok(true) = event::poll(Duration::from_millis(100)) {
+            if let Ok(Event::Key(key)) = event::read() {
+                if key.code == KeyCode::Char('c') && key.modifiers.contains(event::KeyModifiers::CONTROL) {
+                    break;
                 }
             }
         }
         
-        // Memory info
-        println!("\nMemory:");
-        let total_mem = app.system.total_memory() as f64 / 1024.0 / 1024.0;
-        let used_mem = app.system.used_memory() as f64 / 1024.0 / 1024.0;
-        let usage_percent = (used_mem / total_mem) * 100.0;
-        println!("  Usage: {:.1}%", usage_percent);
-        println!("  Used: {:.1} MB", used_mem);
-        println!("  Total: {:.1} MB", total_mem);
-        
-        // Disk info
-        println!("\nDisk:");
-        let disks = Disks::new_with_refreshed_list();
-        for disk in &disks {
-            if disk.mount_point().to_str() == Some("/") {
-                let total = disk.total_space() as f64 / 1024.0 / 1024.0 / 1024.0;
-                let available = disk.available_space() as f64 / 1024.0 / 1024.0 / 1024.0;
-                let used = total - available;
-                let usage_percent = (used / total) * 100.0;
-                println!("  Usage: {:.1}%", usage_percent);
-                println!("  Used: {:.1} GB", used);
-                println!("  Total: {:.1} GB", total);
-                break;
-            }
-        }
-        
-        
-        // Network info
-        println!("\nNetwork:");
-        let download_rate = app.metrics.network_download_rate();
-        let upload_rate = app.metrics.network_upload_rate();
-        let (total_rx, total_tx) = app.metrics.total_network_bytes();
-        println!("  Download: {:.1} Kbps", download_rate);
-        println!("  Upload: {:.1} Kbps", upload_rate);
-        println!("  Total Down: {:.1} MB", total_rx as f64 / 1024.0 / 1024.0);
-        println!("  Total Up: {:.1} MB", total_tx as f64 / 1024.0 / 1024.0);
-        
-        // Temperature info
-        println!("\nTemperature:");
-        if let Some(temp) = app.metrics.cpu_temperature() {
-            println!("  CPU Package: {:.1}°C", temp);
-        } else {
-            println!("  CPU Package: N/A");
+        thread::sleep(app.update_interval);
+    }
+    
+    Ok(())
+}
+
+/// Highlights values that moved significantly since the previous report, like `watch -d`
+struct ChangeHighlighter {
+    enabled: bool,
+    previous: HashMap<String, f32>,
+}
+
+impl ChangeHighlighter {
+    fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            previous: HashMap::new(),
+        }
+    }
+
+    /// Return `text` in reverse video if `value` moved by at least `min_delta` since last report
+    fn mark(&mut self, key: &str, value: f32, min_delta: f32, text: String) -> String {
+        if !self.enabled {
+            return text;
         }
         
-        // Per-core temperatures
-        let per_core_temps = app.metrics.per_core_temperatures();
-        if !per_core_temps.is_empty() {
-            let logical_cores = app.metrics.per_core_usage().len();
-            let temp_cores = per_core_temps.len();
-            
-            if temp_cores == logical_cores {
-                println!("  Per-core Temps:");
-            } else if temp_cores < logical_cores {
-                println!("  Per-core Temps (physical cores mapped to logical):");
-            } else {
-                println!("  Core Temps:");
+        let changed = self.previous
+            .insert(key.to_string(), value)
+            .is_some_and(|prev| (value - prev).abs() >= min_delta);
+        
+        if changed {
+            format!("\x1B[7m{}\x1B[0m", text)
+        } else {
+            text
+        }
+    }
+}
+
+fn print_cpu_section(app: &App, hl: &mut ChangeHighlighter) {
+    // CPU info
+    println!("\nCPU:");
+    let cpu_usage = app.metrics.cpu_usage();
+    println!("  Overall Usage: {}", hl.mark("cpu", cpu_usage, 5.0, format!("{:.1}%", cpu_usage)));
+    if let Some(cpu_info) = app.system.cpus().first() {
+        println!("  Brand: {}", cpu_info.brand());
+        let freq = app.metrics.frequency_history().back().copied().unwrap_or(cpu_info.frequency() as f32);
+        println!("  Frequency: {}", hl.mark("cpu_freq", freq, 100.0, format!("{:.0} MHz", freq)));
+        let current: Vec<f32> = app.metrics.per_core_frequency_history().iter().filter_map(|history| history.back().copied()).collect();
+        if current.len() > 1 {
+            let min = current.iter().copied().fold(f32::MAX, f32::min);
+            let max = current.iter().copied().fold(0.0f32, f32::max);
+            println!("  Core Frequencies: {:.0}-{:.0} MHz", min, max);
+        }
+        println!("  Cores: {}", app.system.cpus().len());
+    }
+    match boot_time() {
+        Some(boot) => println!("  Uptime: {} (booted {})", format_uptime(System::uptime()), boot.format("%Y-%m-%d %H:%M")),
+        None => println!("  Uptime: {}", format_uptime(System::uptime())),
+    }
+    let load = app.metrics.load_average();
+    println!(
+        "  Load Average: {}, {:.2}, {:.2}",
+        hl.mark("load1", load.one as f32, 0.5, format!("{:.2}", load.one)),
+        load.five,
+        load.fifteen
+    );
+    if let Some((running, blocked)) = app.metrics.run_queue() {
+        println!("  Tasks: {} running, {} blocked", running, blocked);
+    }
+    if let (Some(switches), Some(interrupts)) = (app.metrics.context_switch_rate(), app.metrics.interrupt_rate()) {
+        println!(
+            "  Context Switches: {}, Interrupts: {}",
+            hl.mark("ctxt", switches, 1000.0, format!("{:.0}/s", switches)),
+            hl.mark("intr", interrupts, 1000.0, format!("{:.0}/s", interrupts)),
+        );
+    }
+    
+    // Per-core CPU usage
+    let per_core = app.metrics.per_core_usage();
+    if !per_core.is_empty() {
+        println!("  Per-core Usage:");
+        let cores_per_row = 4;
+        for (i, &usage) in per_core.iter().enumerate() {
+            if i % cores_per_row == 0 {
+                print!("    ");
             }
-            
-            let cores_per_row = 4;
-            for (i, &temp) in per_core_temps.iter().enumerate() {
-                if i % cores_per_row == 0 {
-                    print!("    ");
-                }
-                print!("C{:02}:{:5.1}°C", i, temp);
-                if i % cores_per_row == cores_per_row - 1 || i == per_core_temps.len() - 1 {
-                    println!();
-                } else {
-                    print!("  ");
-                }
+            print!("C{:02}:{}", i, hl.mark(&format!("core{}", i), usage, 10.0, format!("{:5.1}%", usage)));
+            if i % cores_per_row == cores_per_row - 1 || i == per_core.len() - 1 {
+                println!();
+            } else {
+                print!("  ");
             }
         }
+    }
+}
 
-        // Enhanced GPU info
-        println!("\nGPU:");
-        
-        // GPU name if available
-        if let Some(name) = app.metrics.gpu_name() {
-            println!("  Model: {}", name);
+fn print_memory_section(app: &App, hl: &mut ChangeHighlighter) {
+    // Memory info
+    println!("\nMemory:");
+    let total_mem = app.system.total_memory() as f64 / 1024.0 / 1024.0;
+    let used_mem = app.system.used_memory() as f64 / 1024.0 / 1024.0;
+    let usage_percent = (used_mem / total_mem) * 100.0;
+    println!("  Usage: {}", hl.mark("mem", usage_percent as f32, 2.0, format!("{:.1}%", usage_percent)));
+    println!("  Used: {}", hl.mark("mem_used", used_mem as f32, 100.0, format!("{:.1} MB", used_mem)));
+    println!("  Total: {:.1} MB", total_mem);
+    if let Some(memory) = app.metrics.memory_breakdown() {
+        let megabytes = |bytes: u64| bytes as f64 / 1024.0 / 1024.0;
+        println!("  Available: {}", hl.mark("mem_available", megabytes(memory.available) as f32, 100.0, format!("{:.1} MB", megabytes(memory.available))));
+        println!("  Cache/Buffers: {:.1} MB", megabytes(memory.cache()));
+        println!("  Free: {:.1} MB", megabytes(memory.free));
+        println!("  Shared: {:.1} MB, Slab: {:.1} MB", megabytes(memory.shared), megabytes(memory.slab));
+        let warning = if memory.dirty_percent().is_some_and(|percent| percent >= meminfo::DIRTY_WARNING_PERCENT) { "⚠️  " } else { "" };
+        let limit = memory.dirty_percent().map(|percent| format!(" ({:.0}% of limit)", percent)).unwrap_or_default();
+        println!(
+            "  {}Dirty: {}, Writeback: {}{}",
+            warning,
+            hl.mark("dirty", megabytes(memory.dirty) as f32, 50.0, format_bytes(memory.dirty)),
+            format_bytes(memory.writeback),
+            limit,
+        );
+        for line in memory.special_memory_lines() {
+            println!("  {}", line);
         }
-        
-        // Core usage
-        if let Some(usage) = app.metrics.gpu_usage() {
-            println!("  Usage: {:.1}%", usage);
-        } else {
-            println!("  Usage: N/A");
+    }
+    if let (Some(faults), Some(major)) = (app.metrics.page_fault_rate(), app.metrics.major_fault_rate()) {
+        let warning = if major >= meminfo::MAJOR_FAULT_WARNING_RATE { "⚠️  " } else { "" };
+        println!(
+            "  {}Page Faults: {:.0}/s, Major: {}",
+            warning,
+            faults,
+            hl.mark("major_faults", major, 50.0, format!("{:.0}/s", major)),
+        );
+    }
+    for fs in app.metrics.tmpfs_mounts() {
+        let warning = if fs.usage_percent() >= filesystems::TMPFS_WARNING_PERCENT { "⚠️  " } else { "" };
+        println!(
+            "  {}tmpfs {}: {} of {} ({})",
+            warning,
+            fs.mount_point,
+            format_bytes(fs.used()),
+            format_bytes(fs.total),
+            hl.mark(&format!("tmpfs_{}", fs.mount_point), fs.usage_percent(), 2.0, format!("{:.1}%", fs.usage_percent())),
+        );
+    }
+    if let Some(watch) = app.oom_watch.as_ref().filter(|watch| watch.count > 0) {
+        println!("  OOM Kills: {} this boot", hl.mark("oom_kills", watch.count as f32, 1.0, watch.count.to_string()));
+        for kill in &watch.kills {
+            println!(
+                "    ⚠️  {} killed {}, {} resident",
+                kill.time.format("%Y-%m-%d %H:%M:%S"),
+                kill.summary(),
+                format_bytes(kill.rss),
+            );
         }
-        
-        // Temperature with status
-        if let Some(temp) = app.metrics.gpu_temperature() {
-            let status = if temp > 80.0 { "🚨" } else if temp > 70.0 { "🔥" } else { "🌡️" };
-            println!("  Temp: {:.1}°C {}", temp, status);
-        } else {
-            println!("  Temp: N/A");
+    }
+    if let Some(errors) = app.metrics.ecc_errors() {
+        let (new_corrected, new_uncorrected) = app.metrics.new_ecc_errors();
+        let warning = if errors.uncorrected > 0 { "⚠️  " } else { "" };
+        println!(
+            "  {}ECC Errors: {} corrected, {} uncorrected (+{}/+{} since start)",
+            warning,
+            hl.mark("ecc_ce", errors.corrected as f32, 1.0, errors.corrected.to_string()),
+            hl.mark("ecc_ue", errors.uncorrected as f32, 1.0, errors.uncorrected.to_string()),
+            new_corrected,
+            new_uncorrected,
+        );
+        for dimm in &errors.dimms {
+            println!("    {}: {} corrected, {} uncorrected", dimm.label, dimm.corrected, dimm.uncorrected);
         }
-        
-        // Fan speed
-        if let Some(fan) = app.metrics.gpu_fan_speed() {
-            let fan_status = if fan > 70.0 { "🚁" } else if fan > 30.0 { "🌪️" } else { "💨" };
-            println!("  Fan: {:.0}% {}", fan, fan_status);
+    }
+}
+
+fn print_disk_section(app: &App, hl: &mut ChangeHighlighter) {
+    // Disk info
+    println!("\nDisk:");
+    // Tracked mounts in full, labelled unless only "/" is tracked
+    let tracked = app.metrics.tracked_mounts();
+    let labelled = tracked.len() > 1 || tracked.first().is_some_and(|mount| mount.mount_point != "/");
+    for mount in tracked {
+        let indent = if labelled {
+            println!("  {}:", mount.mount_point);
+            "    "
         } else {
-            println!("  Fan: N/A");
+            "  "
+        };
+        let Some(fs) = app.metrics.filesystem(&mount.mount_point) else {
+            println!("{}Not mounted", indent);
+            continue;
+        };
+        // The root filesystem keeps its original keys
+        let key = |name: &str| if mount.mount_point == "/" { name.to_string() } else { format!("{}_{}", name, mount.mount_point) };
+        let total = fs.total as f64 / 1024.0 / 1024.0 / 1024.0;
+        let used = fs.used() as f64 / 1024.0 / 1024.0 / 1024.0;
+        let usage_percent = fs.usage_percent();
+        println!("{}Usage: {}", indent, hl.mark(&key("disk"), usage_percent, 1.0, format!("{:.1}%", usage_percent)));
+        println!("{}Used: {}", indent, hl.mark(&key("disk_used"), used as f32, 1.0, format!("{:.1} GB", used)));
+        println!("{}Total: {:.1} GB", indent, total);
+        if let Some(percent) = fs.inode_percent() {
+            println!("{}Inodes: {}", indent, hl.mark(&key("disk_inodes"), percent, 1.0, format!("{:.1}%", percent)));
         }
-        
-        // Power draw
-        if let Some(power) = app.metrics.gpu_power_draw() {
-            let power_status = if power > 250.0 { "🔋" } else if power > 150.0 { "🔌" } else { "⚡" };
-            println!("  Power: {:.1}W {}", power, power_status);
-        } else {
-            println!("  Power: N/A");
+    }
+    
+    // Every other mount, so separate /home or /var filesystems are covered too
+    let others: Vec<_> = app
+        .metrics
+        .filesystems()
+        .iter()
+        .filter(|fs| !tracked.iter().any(|mount| mount.mount_point == fs.mount_point))
+        .collect();
+    if !others.is_empty() {
+        println!("  Other Filesystems:");
+        for fs in others {
+            let inodes = fs.inode_percent().map_or(String::new(), |percent| format!(", inodes {:.1}%", percent));
+            println!(
+                "    {} ({}, {}): {} of {:.1} GB{}",
+                fs.mount_point,
+                fs.device,
+                fs.fs_type,
+                hl.mark(&format!("fs_{}", fs.mount_point), fs.usage_percent(), 1.0, format!("{:.1}%", fs.usage_percent())),
+                fs.total as f64 / 1024.0 / 1024.0 / 1024.0,
+                inodes,
+            );
+        }
+    }
+    if !app.ssd_wear.drives.is_empty() {
+        println!("  SSD Wear:");
+    }
+    for wear in &app.ssd_wear.drives {
+        let written = wear.written.map_or(String::new(), |bytes| format!(", {} written", format_bytes(bytes)));
+        let hours = wear.power_on_hours.map_or(String::new(), |hours| format!(", {} h powered on", hours));
+        let spare = wear.spare.map_or(String::new(), |spare| format!(", spare {:.0}%", spare));
+        println!(
+            "    {}: {} of endurance used{}{}{}{}",
+            wear.drive,
+            hl.mark(&format!("wear_{}", wear.drive), wear.percent_used, 1.0, format!("{:.0}%", wear.percent_used)),
+            written,
+            hours,
+            spare,
+            if wear.is_worn() { " ⚠️  aging" } else { "" },
+        );
+    }
+    if let Some(err) = &app.ssd_wear.error {
+        println!("  SSD Wear: N/A ({})", err);
+    }
+    if !app.storage_health.pools.is_empty() {
+        println!("  Storage Pools:");
+    }
+    for pool in &app.storage_health.pools {
+        let mut details = vec![match pool.used {
+            Some(used) => format!("{} of {}", format_bytes(used), format_bytes(pool.size)),
+            None => format_bytes(pool.size),
+        }];
+        if let Some((used, total)) = pool.metadata {
+            details.push(format!("metadata {} of {}", format_bytes(used), format_bytes(total)));
+        }
+        if pool.errors > 0 {
+            details.push(format!("{} device errors", pool.errors));
+        }
+        details.extend(pool.problems.iter().cloned());
+        if let Some(scrub) = &pool.scrub {
+            details.push(scrub.clone());
+        }
+        let health = pool.health.clone().unwrap_or_else(|| if pool.is_degraded() { "ERRORS" } else { "OK" }.to_string());
+        println!(
+            "    {} ({}): {} · {}{}",
+            pool.name,
+            pool.kind.label(),
+            hl.mark(&format!("pool_{}", pool.name), pool.is_degraded() as u8 as f32, 1.0, health),
+            details.join(" · "),
+            if pool.is_degraded() { " ⚠️" } else { "" },
+        );
+    }
+    for group in &app.storage_health.volume_groups {
+        println!(
+            "  LVM {}: {} free of {}",
+            group.name,
+            hl.mark(&format!("vg_free_{}", group.name), group.free as f32 / 1024.0 / 1024.0 / 1024.0, 1.0, format_bytes(group.free)),
+            format_bytes(group.size),
+        );
+        for pool in &group.thin_pools {
+            println!(
+                "    {} (thin pool, {}): data {} · metadata {:.1}%{}",
+                pool.name,
+                format_bytes(pool.size),
+                hl.mark(&format!("thin_{}_{}", group.name, pool.name), pool.data_percent, 1.0, format!("{:.1}%", pool.data_percent)),
+                pool.metadata_percent,
+                if pool.is_filling() { " ⚠️  filling up" } else { "" },
+            );
+        }
+    }
+    for err in &app.storage_health.errors {
+        println!("  Storage: N/A ({})", err);
+    }
+    let drives = app.metrics.drive_temperatures();
+    if !drives.is_empty() {
+        println!("  Drive Temperatures:");
+        for drive in drives {
+            let warning = if drive.is_hot() { " ⚠️  above warning threshold" } else { "" };
+            println!(
+                "    {}: {}{}",
+                drive.drive,
+                hl.mark(&format!("drive_temp_{}", drive.drive), drive.celsius, 3.0, app.temperature_unit.format(drive.celsius, 1)),
+                warning,
+            );
+        }
+    }
+    for fs in app.metrics.filesystems().iter().filter(|fs| fs.inodes_low()) {
+        println!("  ⚠️  {} is running out of inodes ({:.1}% used)", fs.mount_point, fs.inode_percent().unwrap_or(0.0));
+    }
+    
+    // Network filesystems
+    let nfs_mounts = app.metrics.nfs_mounts();
+    if !nfs_mounts.is_empty() {
+        println!("  Network Mounts:");
+        for mount in nfs_mounts {
+            println!("    {} ({}, {}):", mount.mount_point, mount.device, mount.fstype);
+            if mount.is_not_responding() {
+                println!("      ⚠️  not responding for {:.0}s", mount.unanswered_secs);
+                continue;
+            }
+            // The cifs module only counts requests
+            if mount.is_smb() {
+                println!(
+                    "      {} op/s",
+                    hl.mark(&format!("nfs_ops_{}", mount.mount_point), mount.ops_per_sec, 50.0, format!("{:.1}", mount.ops_per_sec)),
+                );
+                continue;
+            }
+            let rtt = mount.avg_rtt_ms.map(|rtt| format!("{:.1} ms", rtt)).unwrap_or_else(|| "-".to_string());
+            let exec = mount.avg_exec_ms.map(|exec| format!("{:.1} ms", exec)).unwrap_or_else(|| "-".to_string());
+            println!(
+                "      {} op/s, RTT {}, exec {}, retrans {:.1}/s, timeouts {}",
+                hl.mark(&format!("nfs_ops_{}", mount.mount_point), mount.ops_per_sec, 50.0, format!("{:.1}", mount.ops_per_sec)),
+                rtt,
+                exec,
+                mount.retrans_per_sec,
+                mount.timeouts,
+            );
+        }
+    }
+}
+
+fn print_io_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nDisk I/O:");
+    for disk in app.metrics.disk_io() {
+        println!(
+            "  {}: read {}, write {}",
+            disk.name,
+            hl.mark(&format!("io_read_{}", disk.name), disk.read_rate, 5.0, format!("{:.1} MB/s", disk.read_rate)),
+            hl.mark(&format!("io_write_{}", disk.name), disk.write_rate, 5.0, format!("{:.1} MB/s", disk.write_rate)),
+        );
+        println!(
+            "    await {}, utilization {}, queue depth {:.2}",
+            hl.mark(&format!("io_await_{}", disk.name), disk.await_time, 5.0, format!("{:.1} ms", disk.await_time)),
+            hl.mark(&format!("io_util_{}", disk.name), disk.utilization, 10.0, format!("{:.0}%", disk.utilization)),
+            disk.queue_depth,
+        );
+    }
+    println!("  Total: read {:.1} MB/s, write {:.1} MB/s", app.metrics.disk_read_rate(), app.metrics.disk_write_rate());
+}
+
+fn print_top_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nTop Processes:");
+    let (by_cpu, by_memory) = top_consumers(&app.processes);
+    println!("  By CPU:");
+    for process in by_cpu {
+        println!(
+            "    {} (PID {}): {}",
+            process.name,
+            process.pid,
+            hl.mark(&format!("top_cpu_{}", process.pid), process.cpu_usage, 5.0, format!("{:.1}%", process.cpu_usage)),
+        );
+    }
+    println!("  By Memory:");
+    for process in by_memory {
+        println!("    {} (PID {}): {}", process.name, process.pid, format_bytes(process.memory_usage));
+    }
+}
+
+fn print_pressure_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nPressure (avg10):");
+    if app.metrics.pressure().is_empty() {
+        println!("  N/A (kernel without PSI support)");
+    }
+    for pressure in app.metrics.pressure() {
+        let full = pressure.full.map_or(String::new(), |full| format!(", full {:.2}%", full));
+        println!(
+            "  {}: some {}{}",
+            pressure.resource,
+            hl.mark(&format!("psi_{}", pressure.resource), pressure.some, 5.0, format!("{:.2}%", pressure.some)),
+            full,
+        );
+    }
+}
+
+fn print_network_section(app: &App, hl: &mut ChangeHighlighter) {
+    // Network info
+    let selected = app.selected_network();
+    println!("\nNetwork ({}):", selected.name);
+    let (total_rx, total_tx) = selected.session_bytes();
+    println!("  Download: {}", hl.mark("net_rx", selected.rx_rate, 100.0, app.rate_unit.format(selected.rx_rate)));
+    println!("  Upload: {}", hl.mark("net_tx", selected.tx_rate, 100.0, app.rate_unit.format(selected.tx_rate)));
+    println!("  Total Down: {:.1} MB", total_rx as f64 / 1024.0 / 1024.0);
+    println!("  Total Up: {:.1} MB", total_tx as f64 / 1024.0 / 1024.0);
+    if let Some(vpn) = app.metrics.vpn_total() {
+        let percent = |share: Option<f32>| share.map_or("N/A".to_string(), |share| format!("{:.0}%", share));
+        let (now, session) = vpn.share_of(app.metrics.network_total());
+        println!(
+            "  VPN ({}): ↓ {} ↑ {}, {} of traffic tunneled ({} this session)",
+            app.metrics.vpn_interfaces().join(", "),
+            app.rate_unit.format(vpn.rx_rate),
+            app.rate_unit.format(vpn.tx_rate),
+            hl.mark("net_vpn_share", now.unwrap_or(0.0), 10.0, percent(now)),
+            percent(session),
+        );
+    }
+    for gateway in app.metrics.gateways() {
+        println!("  Gateway: {} ({}, metric {})", gateway.address, gateway.interface, gateway.metric);
+    }
+    if let Some(public_ip) = &app.public_ip {
+        match (&public_ip.address, &public_ip.error) {
+            (Some(address), None) => println!("  Public IP: {}", address),
+            (Some(address), Some(err)) => println!("  Public IP: {} (last check failed: {})", address, err),
+            (None, Some(err)) => println!("  Public IP: N/A ({})", err),
+            (None, None) => println!("  Public IP: N/A"),
+        }
+    }
+    println!("  Interfaces:");
+    for interface in app.metrics.network_interfaces() {
+        let (rx, tx) = interface.session_bytes();
+        println!(
+            "    {}: {} ↓ {} ↑ {} (session {:.1} / {:.1} MB)",
+            interface.name,
+            interface.link.as_ref().map_or("unknown".to_string(), Link::summary),
+            hl.mark(&format!("net_rx_{}", interface.name), interface.rx_rate, 100.0, app.rate_unit.format(interface.rx_rate)),
+            hl.mark(&format!("net_tx_{}", interface.name), interface.tx_rate, 100.0, app.rate_unit.format(interface.tx_rate)),
+            rx as f64 / 1024.0 / 1024.0,
+            tx as f64 / 1024.0 / 1024.0,
+        );
+        if let Some(details) = interface.link.as_ref().map(Link::details).filter(|details| !details.is_empty()) {
+            println!("      {}", details);
+        }
+        if let Some(aggregate) = interface.link.as_ref().and_then(|link| link.aggregate.as_ref()) {
+            println!(
+                "      {} ({} down)",
+                aggregate.summary(),
+                hl.mark(
+                    &format!("net_members_down_{}", interface.name),
+                    aggregate.down_members() as f32,
+                    1.0,
+                    format!("{} of {} members", aggregate.down_members(), aggregate.members.len()),
+                ),
+            );
         }
+        if let Some(wireless) = &interface.wireless {
+            println!("      {}", wireless.summary());
+        }
+        if let Some(errors) = interface.session_errors() {
+            println!(
+                "      {} ({})",
+                errors.summary(),
+                hl.mark(&format!("net_errors_{}", interface.name), interface.error_rate, 1.0, format!("{:.1}/s", interface.error_rate)),
+            );
+        }
+    }
+}
+
+fn print_tcp_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nTCP States:");
+    if app.metrics.tcp_states().is_empty() {
+        println!("  N/A (no /proc/net/tcp)");
+    }
+    for state in app.metrics.tcp_states() {
+        println!(
+            "  {}: {}",
+            state.state,
+            hl.mark(&format!("tcp_{}", state.state), state.count as f32, 10.0, state.count.to_string()),
+        );
+    }
+    if let Some(health) = app.metrics.tcp_health() {
+        println!(
+            "  Retransmits: {} ({:.1}% of segments sent)",
+            hl.mark("tcp_retransmits", health.retransmit_rate, 10.0, format!("{:.0}/s", health.retransmit_rate)),
+            health.retransmit_percent,
+        );
+        println!(
+            "  Socket memory: {} ({} orphaned socket{})",
+            hl.mark("tcp_socket_memory", health.socket_memory as f32 / 1024.0, 1024.0, format_bytes(health.socket_memory)),
+            health.orphans,
+            if health.orphans == 1 { "" } else { "s" },
+        );
+    }
+}
+
+fn print_talkers_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nTop Talkers:");
+    let Some(talkers) = &app.top_talkers else {
+        return;
+    };
+    if let Some(err) = &talkers.error {
+        println!("  N/A ({})", err);
+        return;
+    }
+    if talkers.talkers.is_empty() {
+        println!("  No remote TCP connections");
+    }
+    for talker in talkers.talkers.iter().take(talkers::TOP_TALKERS) {
+        let rate = talker.rx_rate + talker.tx_rate;
+        println!(
+            "  {}: ↓ {} ↑ {} ({} connection{}, {} / {} this session)",
+            talker.address,
+            hl.mark(&format!("talker_{}", talker.address), rate, 100.0, app.rate_unit.format(talker.rx_rate)),
+            app.rate_unit.format(talker.tx_rate),
+            talker.connections,
+            if talker.connections == 1 { "" } else { "s" },
+            format_bytes(talker.rx_bytes),
+            format_bytes(talker.tx_bytes),
+        );
+    }
+}
+
+fn print_cgroups_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nCgroups:");
+    let Some(cgroups) = &app.cgroups else {
+        return;
+    };
+    if let Some(err) = &cgroups.error {
+        println!("  N/A ({})", err);
+        return;
+    }
+    for group in &cgroups.groups {
+        let anon = group.anon.map(|anon| format!(", {} anonymous", format_bytes(anon))).unwrap_or_default();
+        println!(
+            "  {}: {}{}, CPU {}, {} process{}",
+            group.name,
+            format_bytes(group.memory),
+            anon,
+            hl.mark(&format!("cgroup_cpu_{}", group.name), group.cpu_percent, 5.0, format!("{:.1}%", group.cpu_percent)),
+            group.processes,
+            if group.processes == 1 { "" } else { "es" },
+        );
+    }
+}
+
+fn print_numa_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nNUMA Nodes:");
+    let Some(numa) = &app.numa else {
+        return;
+    };
+    if let Some(err) = &numa.error {
+        println!("  N/A ({})", err);
+        return;
+    }
+    for node in &numa.nodes {
+        let cpus = if node.cpus.is_empty() { "no CPUs".to_string() } else { format!("CPUs {}", node.cpus) };
+        let foreign = if node.foreign_rate > 0.0 { format!(", {:.0} pages/s allocated elsewhere", node.foreign_rate) } else { String::new() };
+        println!(
+            "  Node {} ({}): CPU {}, Memory {} of {} ({}){}",
+            node.id,
+            cpus,
+            hl.mark(&format!("numa_cpu_{}", node.id), node.cpu_percent, 5.0, format!("{:.1}%", node.cpu_percent)),
+            format_bytes(node.memory_used()),
+            format_bytes(node.memory_total),
+            hl.mark(&format!("numa_mem_{}", node.id), node.memory_percent(), 2.0, format!("{:.1}%", node.memory_percent())),
+            foreign,
+        );
+    }
+}
+
+fn print_ports_section(app: &App) {
+    println!("\nListening Ports:");
+    let Some(ports) = &app.listening_ports else {
+        return;
+    };
+    if ports.processes.is_empty() {
+        println!("  None");
+    }
+    for process in &ports.processes {
+        let owner = match &process.owner {
+            Some((pid, name)) => format!("{} ({})", name, pid),
+            None => "unknown process".to_string(),
+        };
+        let sockets: Vec<String> = process.sockets.iter().map(|socket| format!("{}/{}", socket.local, socket.protocol)).collect();
+        println!("  {}: {}", owner, sockets.join(", "));
+    }
+}
+
+fn print_fans_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nFans:");
+    if app.metrics.fans().is_empty() {
+        println!("  N/A (no hwmon fan sensors)");
+    }
+    for fan in app.metrics.fans() {
+        let percent = fan.percent.map_or(String::new(), |percent| format!(" ({:.0}%)", percent));
+        println!(
+            "  {}: {}{}",
+            fan.name,
+            hl.mark(&format!("fan_{}", fan.name), fan.rpm, 100.0, format!("{:.0} RPM", fan.rpm)),
+            percent,
+        );
+    }
+}
+
+fn print_temperature_section(app: &App, hl: &mut ChangeHighlighter) {
+    // Temperature info
+    println!("\nTemperature:");
+    if let Some(temp) = app.metrics.cpu_temperature() {
+        println!("  CPU Package: {}", hl.mark("cpu_temp", temp, 3.0, app.temperature_unit.format(temp, 1)));
+    } else {
+        println!("  CPU Package: N/A");
+    }
+    
+    // Per-core temperatures
+    let per_core_temps = app.metrics.per_core_temperatures();
+    if !per_core_temps.is_empty() {
+        let logical_cores = app.metrics.per_core_usage().len();
+        let temp_cores = per_core_temps.len();
         
-        // VRAM usage
-        if let (Some(used), Some(total)) = (app.metrics.gpu_memory_used(), app.metrics.gpu_memory_total()) {
-            let percent = (used / total) * 100.0;
-            let free = total - used;
-            println!("  VRAM: {:.0}MB / {:.0}MB ({:.1}%)", used, total, percent);
-            println!("  VRAM Free: {:.0}MB", free);
+        if temp_cores == logical_cores {
+            println!("  Per-core Temps:");
+        } else if temp_cores < logical_cores {
+            println!("  Per-core Temps (physical cores mapped to logical):");
         } else {
-            println!("  VRAM: N/A");
+            println!("  Core Temps:");
         }
         
-        // Performance status
-        if let Some(usage) = app.metrics.gpu_usage() {
-            let status = if usage > 80.0 {
-                "🔴 High Load"
-            } else if usage > 50.0 {
-                "🟡 Medium Load"
-            } else if usage > 10.0 {
-                "🟢 Light Load"
+        let cores_per_row = 4;
+        for (i, &temp) in per_core_temps.iter().enumerate() {
+            if i % cores_per_row == 0 {
+                print!("    ");
+            }
+            print!("C{:02}:{}", i, hl.mark(&format!("core_temp{}", i), temp, 3.0, format!("{:5.1}{}", app.temperature_unit.convert(temp), app.temperature_unit.symbol())));
+            if i % cores_per_row == cores_per_row - 1 || i == per_core_temps.len() - 1 {
+                println!();
             } else {
-                "💤 Idle"
-            };
-            println!("  Status: {}", status);
+                print!("  ");
+            }
         }
-        
-        // Handle Ctrl+C
-        if let Ok(true) = event::poll(Duration::from_millis(100)) {
-            if let Ok(Event::Key(key)) = event::read() {
-                if key.code == KeyCode::Char('c') && key.modifiers.contains(event::KeyModifiers::CONTROL) {
-                    break;
-                }
+    }
+
+    // Motherboard, VRM and chipset sensors (Super I/O chips, ACPI zones)
+    let board = sensors::read_board_temperatures();
+    if !board.is_empty() {
+        println!("  Board:");
+        for sensor in board {
+            let key = format!("board_temp_{}_{}", sensor.chip, sensor.label);
+            let temp = sensor.value as f32;
+            println!("    {} {}: {}", sensor.chip, sensor.board_label(), hl.mark(&key, temp, 3.0, app.temperature_unit.format(temp, 1)));
+        }
+    }
+}
+
+fn print_gpu_section(app: &App, hl: &mut ChangeHighlighter) {
+    // Enhanced GPU info
+    match app.metrics.stale_gpu_data_age() {
+        Some(age) => println!("\nGPU (sampled {}s ago):", age.as_secs()),
+        None => println!("\nGPU:"),
+    }
+    
+    // GPU name if available
+    if let Some(name) = app.metrics.gpu_name() {
+        println!("  Model: {}", name);
+    }
+    
+    // Driver, CUDA and VBIOS versions
+    if let Some(driver) = app.metrics.gpu_driver_info() {
+        let cuda = driver.cuda_version.as_deref().unwrap_or("N/A");
+        let vbios = driver.vbios_version.as_deref().unwrap_or("N/A");
+        println!("  Driver: {} (CUDA {}, VBIOS {})", driver.driver_version, cuda, vbios);
+    }
+    
+    // Core usage
+    if let Some(usage) = app.metrics.gpu_usage() {
+        println!("  Usage: {}", hl.mark("gpu", usage, 5.0, format!("{:.1}%", usage)));
+    } else {
+        println!("  Usage: N/A");
+    }
+    
+    // Temperature with status
+    if let Some(temp) = app.metrics.gpu_temperature() {
+        let status = if temp > 80.0 { "🚨" } else if temp > 70.0 { "🔥" } else { "🌡️" };
+        println!("  Temp: {} {}", hl.mark("gpu_temp", temp, 3.0, app.temperature_unit.format(temp, 1)), status);
+    } else if let Some(pressure) = app.metrics.gpu_thermal_pressure() {
+        println!("  Thermal pressure: {}", pressure);
+    } else {
+        println!("  Temp: N/A");
+    }
+    
+    // Fan speed, per fan where the card reports them
+    let fans = app.metrics.gpu_fans();
+    if !fans.is_empty() {
+        for fan in fans {
+            let rpm = fan.rpm.map(|rpm| rpm as f32);
+            let rpm_text = rpm
+                .map(|rpm| hl.mark(&format!("gpu_fan_rpm{}", fan.index), rpm, 200.0, format!("{:.0} RPM", rpm)))
+                .unwrap_or_else(|| "N/A".to_string());
+            match fan.percent {
+                Some(percent) => println!("  Fan {}: {} ({:.0}%)", fan.index, rpm_text, percent),
+                None => println!("  Fan {}: {}", fan.index, rpm_text),
             }
         }
-        
-        thread::sleep(app.update_interval);
+    } else if let Some(fan) = app.metrics.gpu_fan_speed() {
+        let fan_status = if fan > 70.0 { "🚁" } else if fan > 30.0 { "🌪️" } else { "💨" };
+        println!("  Fan: {} {}", hl.mark("gpu_fan", fan, 5.0, format!("{:.0}%", fan)), fan_status);
+    } else {
+        println!("  Fan: N/A");
     }
     
-    Ok(())
+    // Power draw
+    if let Some(power) = app.metrics.gpu_power_draw() {
+        let power_status = if power > 250.0 { "🔋" } else if power > 150.0 { "🔌" } else { "⚡" };
+        println!("  Power: {} {}", hl.mark("gpu_power", power, 10.0, format!("{:.1}W", power)), power_status);
+        println!("  Energy: {:.2} Wh this session", app.metrics.gpu_energy_wh());
+    } else {
+        println!("  Power: N/A");
+    }
+    
+    // Clocks
+    match (app.metrics.gpu_core_clock(), app.metrics.gpu_memory_clock()) {
+        (Some(core), Some(mem)) => println!(
+            "  Clocks: core {}, mem {:.0} MHz",
+            hl.mark("gpu_clock", core, 100.0, format!("{:.0} MHz", core)),
+            mem
+        ),
+        (Some(core), None) => println!("  Clocks: core {}", hl.mark("gpu_clock", core, 100.0, format!("{:.0} MHz", core))),
+        _ => println!("  Clocks: N/A"),
+    }
+    
+    // PCIe link and throughput
+    if let Some(link) = app.metrics.gpu_pcie_link() {
+        print!("  PCIe: Gen{} x{}", link.generation, link.width);
+        if link.generation < link.max_generation || link.width < link.max_width {
+            print!(" (max Gen{} x{})", link.max_generation, link.max_width);
+        }
+        match app.metrics.gpu_pcie_throughput() {
+            Some((rx, tx)) => println!(
+                ", RX {}, TX {}",
+                hl.mark("gpu_pcie_rx", rx, 10000.0, format!("{:.1} MB/s", rx / 1000.0)),
+                hl.mark("gpu_pcie_tx", tx, 10000.0, format!("{:.1} MB/s", tx / 1000.0))
+            ),
+            None => println!(),
+        }
+    } else {
+        println!("  PCIe: N/A");
+    }
+    
+    // NVLink, one line per active link
+    for link in app.metrics.nvlinks() {
+        let rate = |kbs: Option<f32>| kbs.map(|kbs| format!("{:.1} MB/s", kbs / 1000.0)).unwrap_or_else(|| "-".to_string());
+        println!(
+            "  NVLink GPU{} link {}: {:.0} GB/s, TX {}, RX {}, errors replay {} / recovery {} / CRC {}",
+            link.gpu, link.link, link.speed_gbs, rate(link.tx_rate), rate(link.rx_rate),
+            link.replay_errors, link.recovery_errors, link.crc_errors
+        );
+    }
+    
+    // VRAM usage
+    if let (Some(used), Some(total)) = (app.metrics.gpu_memory_used(), app.metrics.gpu_memory_total()) {
+        let percent = (used / total) * 100.0;
+        let free = total - used;
+        println!("  VRAM: {} / {:.0}MB ({:.1}%)", hl.mark("gpu_vram", used, 100.0, format!("{:.0}MB", used)), total, percent);
+        println!("  VRAM Free: {:.0}MB", free);
+    } else {
+        println!("  VRAM: N/A");
+    }
+    
+    // Performance status
+    if let Some(usage) = app.metrics.gpu_usage() {
+        let status = if usage > 80.0 {
+            "🔴 High Load"
+        } else if usage > 50.0 {
+            "🟡 Medium Load"
+        } else if usage > 10.0 {
+            "🟢 Light Load"
+        } else {
+            "💤 Idle"
+        };
+        println!("  Status: {}", status);
+    }
+}
+
+fn print_power_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nPower:");
+    let watts = |value: Option<f32>| value.map(|w| format!("{:.1}W", w)).unwrap_or_else(|| "N/A".to_string());
+    println!("  CPU (RAPL): {}", watts(app.metrics.cpu_power()));
+    println!("  GPU: {}", watts(app.metrics.gpu_power_draw()));
+    match app.metrics.battery_power() {
+        Some(battery) if app.metrics.on_battery() => println!("  Battery: discharging {:.1}W", battery),
+        Some(_) => println!("  Battery: not discharging"),
+        None => println!("  Battery: N/A"),
+    }
+    
+    if let Some(total) = app.metrics.system_power() {
+        let source = if app.metrics.on_battery() { "battery" } else { "CPU+GPU" };
+        println!("  Total: {} ({})", hl.mark("power", total, 5.0, format!("{:.1}W", total)), source);
+    } else {
+        println!("  Total: N/A");
+    }
+    println!("  Session Energy: {:.2} Wh", app.metrics.session_energy_wh());
+}
+
+fn print_battery_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nBattery:");
+    for battery in app.metrics.batteries() {
+        let mut details = vec![battery.status.label().to_string()];
+        if let Some(watts) = battery.watts.filter(|watts| *watts > 0.0) {
+            details.push(format!("{:.1}W", watts));
+        }
+        if let Some(remaining) = battery.time_remaining {
+            let until = if battery.status == BatteryStatus::Charging { "until full" } else { "left" };
+            details.push(format!("{} {}", format_uptime(remaining.as_secs()), until));
+        }
+        let key = format!("battery_{}", battery.name);
+        println!(
+            "  {}: {} ({})",
+            battery.name,
+            hl.mark(&key, battery.percent, 1.0, format!("{:.0}%", battery.percent)),
+            details.join(", "),
+        );
+        if let (Some(now), Some(full)) = (battery.energy_now, battery.energy_full) {
+            println!("    Energy: {:.1} / {:.1} Wh", now, full);
+        }
+    }
+    // Dual-battery laptops drain one battery after the other
+    if let Some(charge) = app.metrics.battery_charge().filter(|_| app.metrics.batteries().len() > 1) {
+        println!("  Combined: {:.0}%", charge);
+    }
+}
+
+fn print_gauges_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nCustom:");
+    for gauge in &app.custom_gauges {
+        match (gauge.value, &gauge.error) {
+            (Some(value), _) => {
+                let key = format!("gauge_{}", gauge.config.name);
+                let text = format!("{} / {} ({:.1}%)", value, gauge.config.max, gauge.percent());
+                println!("  {}: {}", gauge.config.name, hl.mark(&key, value as f32, (gauge.config.max * 0.05) as f32, text));
+            }
+            (None, Some(err)) => println!("  {}: N/A ({})", gauge.config.name, err),
+            (None, None) => println!("  {}: N/A", gauge.config.name),
+        }
+    }
+}
+
+fn print_dns_section(app: &App, hl: &mut ChangeHighlighter) {
+    let Some(probe) = &app.dns_probe else {
+        return;
+    };
+    println!("\nDNS ({} via {}):", probe.config.hostname, probe.resolver_label());
+    match (probe.latency, &probe.error) {
+        (Some(latency), _) => println!("  Latency: {}", hl.mark("dns_latency", latency, 50.0, format!("{:.1} ms", latency))),
+        (None, Some(err)) => println!("  Latency: failed ({})", err),
+        (None, None) => println!("  Latency: N/A"),
+    }
+    println!("  Failed: {} of {} lookups", probe.failures, probe.lookups);
+    if let Some((time, err)) = &probe.last_failure {
+        println!("  Last failure: {} ({})", time.format("%H:%M:%S"), err);
+    }
+}
+
+fn print_quota_section(app: &App, hl: &mut ChangeHighlighter) {
+    let Some(quota) = &app.traffic_quota else {
+        return;
+    };
+    println!("\nQuota ({}, resets {}):", quota.scope_label(), quota.next_reset().format("%b %-d"));
+    let percent = quota.percent();
+    println!(
+        "  Used: {} of {} ({})",
+        quota::format_gigabytes(quota.used()),
+        quota::format_gigabytes(quota.limit()),
+        hl.mark("quota_used", percent as f32, 1.0, format!("{:.1}%", percent)),
+    );
+    if let Some(projected) = quota.projected() {
+        println!("  Projected: {}", quota::format_gigabytes(projected));
+    }
+    for (name, transfer) in quota.interfaces() {
+        println!("  {}: ↓ {} ↑ {}", name, quota::format_gigabytes(transfer.rx), quota::format_gigabytes(transfer.tx));
+    }
+    if let Some(err) = &quota.error {
+        println!("  Error: {}", err);
+    }
+}
+
+fn print_snmp_section(app: &App, hl: &mut ChangeHighlighter) {
+    println!("\nSNMP:");
+    for device in &app.snmp_devices {
+        match device.uptime {
+            Some(uptime) => println!("  {} ({}, up {}):", device.label(), device.config.host, format_uptime(uptime)),
+            None => println!("  {} ({}):", device.label(), device.config.host),
+        }
+        if let Some(err) = &device.error {
+            println!("    Error: {}", err);
+        }
+        for interface in device.interfaces() {
+            let (rx, tx) = interface.session_bytes();
+            let key = format!("snmp_{}_{}", device.config.name, interface.name);
+            // Configured counters have no link state
+            println!(
+                "    {}: {}↓ {} ↑ {} (session {} / {})",
+                interface.name,
+                interface.link.as_ref().map_or(String::new(), |link| format!("{} ", link.summary())),
+                hl.mark(&format!("{}_rx", key), interface.rx_rate, 100.0, app.rate_unit.format(interface.rx_rate)),
+                hl.mark(&format!("{}_tx", key), interface.tx_rate, 100.0, app.rate_unit.format(interface.tx_rate)),
+                format_bytes(rx),
+                format_bytes(tx),
+            );
+        }
+    }
 }
 
 fn format_bytes(bytes: u64) -> String {
@@ -553,10 +2826,18 @@ fn format_bytes(bytes: u64) -> String {
 async fn main() -> Result<()> {
     let args = Args::parse();
     
-    let app = App::new(args.interval, args.history, args.simple);
+    let config = Config::load(args.config.as_deref())?;
+    let app = App::new(&args, config);
+    
+    // SIGHUP reloads the config file
+    signal_hook::flag::register(signal_hook::consts::SIGHUP, Arc::clone(&app.reload_requested))?;
+    // SIGINT (Ctrl+C in simple mode) and SIGTERM end the loop, so the app is dropped
+    // normally instead of killed
+    signal_hook::flag::register(signal_hook::consts::SIGINT, Arc::clone(&app.terminate_requested))?;
+    signal_hook::flag::register(signal_hook::consts::SIGTERM, Arc::clone(&app.terminate_requested))?;
     
     if args.simple {
-        run_simple_mode(app)?;
+        run_simple_mode(app, args.differences)?;
     } else {
         // Check if we're in a proper terminal environment
         if std::env::var("TERM").is_err() {
@@ -0,0 +1,211 @@
+use std::{collections::HashMap, fs};
+
+/// Major faults per second from which the memory panel flags paging; sustained rates
+/// like this mean the working set no longer fits in RAM
+pub const MAJOR_FAULT_WARNING_RATE: f32 = 100.0;
+
+/// Share of the dirty limit from which the memory panel flags dirty pages; at the limit
+/// the kernel makes writers wait for the disk
+pub const DIRTY_WARNING_PERCENT: f32 = 50.0;
+
+/// A zram compressed block device, usually used as swap
+#[derive(Clone)]
+pub struct Zram {
+    pub name: String,
+    pub original: u64,    // Bytes stored, before compression
+    pub compressed: u64,  // Bytes after compression
+    pub memory_used: u64, // RAM taken, including allocator overhead
+}
+
+/// Where the memory goes, from /proc/meminfo. Sizes in bytes.
+#[derive(Clone, Default)]
+pub struct MemoryBreakdown {
+    pub total: u64,
+    pub free: u64,        // Not used for anything, not even cache
+    pub available: u64,   // Kernel estimate of what can be allocated without swapping
+    pub buffers: u64,
+    pub cached: u64,      // Page cache, including shared memory
+    pub shared: u64,      // tmpfs and shared anonymous mappings
+    pub slab: u64,
+    pub slab_reclaimable: u64,
+    pub dirty: u64,               // Written to the page cache but not yet to disk
+    pub writeback: u64,           // Being written to disk right now
+    pub dirty_limit: Option<u64>, // Dirty bytes at which writers are throttled
+    pub huge_pages_total: u64, // Preallocated hugetlbfs pages (count)
+    pub huge_pages_free: u64,  // Count
+    pub huge_page_size: u64,
+    pub transparent_huge_pages: u64, // Anonymous memory backed by THP
+    pub zswap: Option<(u64, u64)>,   // Compressed size and original size, Linux 5.19+
+    pub zram: Vec<Zram>,
+}
+
+impl MemoryBreakdown {
+    /// Memory that cannot be given back without swapping, i.e. what `free` calls used
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.available)
+    }
+
+    /// Caches the kernel drops under pressure. Shared memory lives in the page cache
+    /// but cannot be dropped, so it is left out.
+    pub fn cache(&self) -> u64 {
+        (self.buffers + self.cached + self.slab_reclaimable).saturating_sub(self.shared)
+    }
+
+    /// Dirty and writeback pages as a percentage of the dirty limit
+    pub fn dirty_percent(&self) -> Option<f32> {
+        let limit = self.dirty_limit.filter(|&limit| limit > 0)?;
+        Some((self.dirty + self.writeback) as f32 / limit as f32 * 100.0)
+    }
+
+    /// Hugepage and compressed memory lines for the memory widget and simple mode; empty
+    /// on machines that use neither
+    pub fn special_memory_lines(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        if self.huge_pages_total > 0 {
+            lines.push(format!(
+                "Hugepages: {}/{} used × {} ({} reserved)",
+                self.huge_pages_total.saturating_sub(self.huge_pages_free),
+                self.huge_pages_total,
+                format_size(self.huge_page_size),
+                format_size(self.huge_pages_total * self.huge_page_size),
+            ));
+        }
+        if self.transparent_huge_pages > 0 {
+            lines.push(format!("Transparent hugepages: {}", format_size(self.transparent_huge_pages)));
+        }
+        // Compressed pages are counted as used at their compressed size
+        if let Some((compressed, original)) = self.zswap.filter(|&(_, original)| original > 0) {
+            lines.push(format!(
+                "zswap: {} → {} ({:.1}x)",
+                format_size(original),
+                format_size(compressed),
+                original as f64 / compressed.max(1) as f64,
+            ));
+        }
+        for zram in &self.zram {
+            // Zero-filled pages are stored without compressed data
+            let ratio = match zram.compressed {
+                0 => String::new(),
+                compressed => format!(" ({:.1}x)", zram.original as f64 / compressed as f64),
+            };
+            lines.push(format!(
+                "{}: {} → {}{}, {} RAM",
+                zram.name,
+                format_size(zram.original),
+                format_size(zram.compressed),
+                ratio,
+                format_size(zram.memory_used),
+            ));
+        }
+        lines
+    }
+}
+
+/// Parse /proc/meminfo. None where it does not exist (macOS, Windows).
+pub fn read_meminfo() -> Option<MemoryBreakdown> {
+    let contents = fs::read_to_string("/proc/meminfo").ok()?;
+
+    // "MemAvailable:    5529604 kB", or a page count without unit ("HugePages_Total: 16")
+    let fields: HashMap<&str, u64> = contents
+        .lines()
+        .filter_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            let mut parts = value.split_whitespace();
+            let number: u64 = parts.next()?.parse().ok()?;
+            let bytes = if parts.next() == Some("kB") { number * 1024 } else { number };
+            Some((key, bytes))
+        })
+        .collect();
+    let field = |key: &str| fields.get(key).copied().unwrap_or(0);
+
+    let total = *fields.get("MemTotal")?;
+    let free = field("MemFree");
+    // Kernels before 3.14 have no MemAvailable
+    let available = fields.get("MemAvailable").copied().unwrap_or(free + field("Buffers") + field("Cached"));
+    Some(MemoryBreakdown {
+        total,
+        free,
+        available,
+        buffers: field("Buffers"),
+        cached: field("Cached"),
+        shared: field("Shmem"),
+        slab: field("Slab"),
+        slab_reclaimable: field("SReclaimable"),
+        dirty: field("Dirty"),
+        writeback: field("Writeback"),
+        dirty_limit: read_dirty_limit(available),
+        huge_pages_total: field("HugePages_Total"),
+        huge_pages_free: field("HugePages_Free"),
+        huge_page_size: field("Hugepagesize"),
+        transparent_huge_pages: field("AnonHugePages"),
+        zswap: fields.get("Zswap").copied().zip(fields.get("Zswapped").copied()),
+        zram: read_zram(),
+    })
+}
+
+/// Counters from /proc/vmstat, cumulative since boot
+#[derive(Clone, Copy)]
+pub struct VmStat {
+    pub page_faults: u64,
+    pub major_faults: u64,      // Those that had to read from disk
+    pub oom_kills: Option<u64>, // Counted since Linux 4.13
+}
+
+pub fn read_vmstat() -> Option<VmStat> {
+    let vmstat = fs::read_to_string("/proc/vmstat").ok()?;
+    let count = |key: &str| vmstat.lines().find_map(|line| line.strip_prefix(key)?.trim().parse::<u64>().ok());
+    Some(VmStat {
+        page_faults: count("pgfault ")?,
+        major_faults: count("pgmajfault ")?,
+        oom_kills: count("oom_kill "),
+    })
+}
+
+/// The dirty limit from vm.dirty_bytes, or else vm.dirty_ratio. The kernel takes the
+/// ratio of free and reclaimable page cache memory, which available memory approximates.
+fn read_dirty_limit(available: u64) -> Option<u64> {
+    let read = |name: &str| fs::read_to_string(format!("/proc/sys/vm/{}", name)).ok()?.trim().parse::<u64>().ok();
+    match read("dirty_bytes") {
+        Some(bytes) if bytes > 0 => Some(bytes),
+        _ => read("dirty_ratio").map(|ratio| available / 100 * ratio),
+    }
+}
+
+/// Initialized zram devices, from the first columns of /sys/block/zram*/mm_stat
+/// (orig_data_size compr_data_size mem_used_total ...)
+fn read_zram() -> Vec<Zram> {
+    let Ok(entries) = fs::read_dir("/sys/block") else {
+        return Vec::new();
+    };
+    let mut devices: Vec<Zram> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if !name.starts_with("zram") {
+                return None;
+            }
+            // A device without a disk size has not been set up
+            let disk_size: u64 = fs::read_to_string(entry.path().join("disksize")).ok()?.trim().parse().ok()?;
+            if disk_size == 0 {
+                return None;
+            }
+            let mm_stat = fs::read_to_string(entry.path().join("mm_stat")).ok()?;
+            let columns: Vec<u64> = mm_stat.split_whitespace().take(3).filter_map(|column| column.parse().ok()).collect();
+            let [original, compressed, memory_used] = columns[..] else {
+                return None;
+            };
+            Some(Zram { name, original, compressed, memory_used })
+        })
+        .collect();
+    devices.sort_by(|a, b| a.name.cmp(&b.name));
+    devices
+}
+
+fn format_size(bytes: u64) -> String {
+    let mb = bytes as f64 / 1024.0 / 1024.0;
+    if mb >= 1024.0 {
+        format!("{:.1} GB", mb / 1024.0)
+    } else {
+        format!("{:.1} MB", mb)
+    }
+}
@@ -1,40 +1,173 @@
-use std::collections::VecDeque;
-use sysinfo::{Disks, System, Networks};
-use std::time::Instant;
+use crate::apple::{self, PowermetricsSample};
+use crate::connections::{self, StateCount, TcpHealth};
+use crate::diskio::{self, DiskCounters, DiskIo};
+use crate::edac::{self, EccErrors};
+use crate::filesystems::{self, Filesystem, TrackedMount};
+use crate::meminfo::{self, MemoryBreakdown, VmStat};
+use crate::gpu::{self, GpuDevice, GpuDriverInfo, GpuFan, NvLink};
+use crate::history::{History, SavedHistory};
+use crate::network::{self, Gateway, InterfaceFilter, NetworkInterface};
+use crate::nfs::{self, NfsCounters, NfsMountStats};
+use crate::power::{self, Battery, RaplCounter};
+use crate::pressure::{self, Pressure};
+use crate::sensors::{self, DriveTemperature, Fan};
+use sysinfo::{LoadAvg, System, Networks};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// First retry delay after nvidia-smi fails; doubles with every further failure
+const GPU_PROBE_BASE_DELAY: Duration = Duration::from_secs(5);
+/// Upper bound for the retry delay, so a GPU that shows up later is still noticed
+const GPU_PROBE_MAX_DELAY: Duration = Duration::from_secs(600);
+
+/// Retry delay after a probe failure, doubling with each further one up to the maximum
+fn probe_backoff(failures: u32) -> Duration {
+    (GPU_PROBE_BASE_DELAY * 2u32.pow(failures.min(7))).min(GPU_PROBE_MAX_DELAY)
+}
+
+/// GPU readings older than this show their age
+const GPU_STALE_AGE: Duration = Duration::from_secs(2);
+
+/// PCIe throughput needs a report of its own from nvidia-smi, too slow to run on every
+/// GPU poll; the values in between are the last ones read
+const PCIE_THROUGHPUT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Current and maximum PCIe link of the GPU
+#[derive(Clone, Copy)]
+pub struct PcieLink {
+    pub generation: u32,
+    pub width: u32,
+    pub max_generation: u32,
+    pub max_width: u32,
+}
+
+impl PcieLink {
+    /// Theoretical per-direction bandwidth of the current link in KB/s
+    pub fn bandwidth_kbs(&self) -> f32 {
+        // Usable MB/s per lane after encoding overhead
+        let per_lane_mbs = match self.generation {
+            1 => 250.0,
+            2 => 500.0,
+            3 => 985.0,
+            4 => 1969.0,
+            5 => 3938.0,
+            _ => 7877.0,
+        };
+        per_lane_mbs * self.width as f32 * 1000.0
+    }
+}
 
 pub struct SystemMetrics {
-    cpu_history: VecDeque<f32>,
-    memory_history: VecDeque<f32>,
-    disk_history: VecDeque<f32>,
+    cpu_history: History,
+    memory_history: History,
+    memory_breakdown: Option<MemoryBreakdown>, // Linux only
+    ecc_errors: Option<EccErrors>,             // None without an EDAC driver
+    ecc_baseline: (u64, u64),                  // Corrected and uncorrected counts at startup
+    vmstat: Option<VmStat>,                    // Linux only
+    page_fault_history: History,               // Per second
+    major_fault_history: History,              // Per second
+    last_fault_update: Instant,
+    dirty_history: History,                    // Dirty and writeback pages, MB
+    tracked_mounts: Vec<TrackedMount>, // Usage history per mount, "/" by default
+    filesystems: Vec<Filesystem>,
+    tmpfs: Vec<Filesystem>,            // RAM-backed, so shown with the memory
+    drive_temperatures: Vec<DriveTemperature>,
+    
+    // Block device throughput
+    disk_counters: Vec<DiskCounters>,
+    disk_io: Vec<DiskIo>,
+    disk_read_history: History,   // All disks, MB/s
+    disk_write_history: History,  // All disks, MB/s
+    last_disk_io_update: Instant,
     
     // Network monitoring data
-    network_rx_history: VecDeque<f32>,  // Download rate in Kbps
-    network_tx_history: VecDeque<f32>,  // Upload rate in Kbps
-    prev_rx_bytes: u64,
-    prev_tx_bytes: u64,
-    initial_rx_bytes: u64,  // Baseline for session totals
-    initial_tx_bytes: u64,  // Baseline for session totals
+    network_total: NetworkInterface, // Interfaces not excluded by the filter
+    interface_filter: InterfaceFilter,
+    vpn_total: NetworkInterface,     // Tunnel interfaces, also counted encrypted in the total
+    vpn_interfaces: Vec<String>,
+    network_interfaces: Vec<NetworkInterface>,
     networks: Networks,
     last_network_update: Instant,
+    gateways: Vec<Gateway>, // Default routes, lowest metric first
+    tcp_states: Vec<StateCount>, // Tracked states in order; empty without /proc/net/tcp
+    tcp_health: Option<TcpHealth>, // None without /proc/net
+    
+    // Network filesystem (NFS) data
+    nfs_counters: Vec<NfsCounters>,
+    nfs_mounts: Vec<NfsMountStats>,
+    last_nfs_update: Instant,
     
     // Per-core CPU data
     per_core_usage: Vec<f32>,
     per_core_temperatures: Vec<f32>,
+    cpu_temperature_history: History,
+    fans: Vec<Fan>,
+    per_core_frequency_history: Vec<History>, // MHz
+    frequency_history: History,               // Average of all cores, MHz
+
+    // Pressure stall information; empty without kernel support
+    pressure: Vec<Pressure>,
+
+    // Scheduler load
+    load_average: LoadAvg,
+    load_history: History,           // 1-minute load average
+    run_queue: Option<(u64, u64)>,         // Runnable and blocked (D state) tasks, Linux only
+    scheduler_counters: Option<(u64, u64)>, // Cumulative context switches and interrupts, Linux only
+    context_switch_history: History,  // Per second
+    interrupt_history: History,       // Per second
+    last_scheduler_update: Instant,
 
     // GPU data (NVIDIA via nvidia-smi)
     gpu_usage: Option<f32>,
     gpu_temperature: Option<f32>,
     gpu_fan_speed: Option<f32>,       // Fan speed in percentage
+    gpu_fans: Vec<GpuFan>,            // Individual fans, where the card reports them
     gpu_power_draw: Option<f32>,      // Power usage in watts
     gpu_memory_used: Option<f32>,     // VRAM used in MB
     gpu_memory_total: Option<f32>,    // Total VRAM in MB
     gpu_name: Option<String>,         // GPU name for display
+    gpu_driver_info: Option<GpuDriverInfo>, // Queried once at startup
+    gpu_available: bool,              // Whether the last nvidia-smi query succeeded
+    gpu_devices: Vec<GpuDevice>,      // Every GPU; the fields above describe the first one
+    gpu_probe_failures: u32,
+    next_gpu_probe: Instant,          // Earliest retry while unavailable
+    fan_probe_failures: u32,          // nvidia-settings launches in a row that found no fans
+    next_fan_probe: Instant,          // Earliest retry of nvidia-settings meanwhile
+    gpu_interval: Duration,           // Polling interval, independent of the CPU/memory cadence
+    next_gpu_poll: Instant,
+    last_gpu_sample: Option<Instant>, // Last successful query
+    gpu_core_clock: Option<f32>,      // Graphics clock in MHz
+    gpu_memory_clock: Option<f32>,    // Memory clock in MHz
+    gpu_pcie_link: Option<PcieLink>,
+    gpu_pcie_rx: Option<f32>,         // PCIe receive throughput in KB/s
+    gpu_pcie_tx: Option<f32>,         // PCIe transmit throughput in KB/s
+    last_pcie_throughput_update: Option<Instant>,
+    soc_sample: Option<PowermetricsSample>, // Apple Silicon only, from powermetrics
+    nvlinks: Vec<NvLink>,             // Active NVLink links of all GPUs
+    last_nvlink_update: Instant,
     
     // GPU history for charts
-    gpu_usage_history: VecDeque<f32>,
-    gpu_memory_percent_history: VecDeque<f32>,
+    gpu_usage_history: History,
+    gpu_memory_percent_history: History,
+    gpu_core_clock_history: History,
+    gpu_memory_clock_history: History,
+    gpu_pcie_history: History,  // Combined RX + TX in KB/s
+    gpu_power_history: History, // Power draw in watts
+    gpu_energy_wh: f64,               // Energy used by the GPU since startup
+    last_gpu_energy_update: Instant,
+    
+    // System power (RAPL + GPU + battery)
+    rapl_counters: Vec<RaplCounter>,
+    cpu_power: Option<f32>,           // CPU package power in watts
+    battery_power: Option<f32>,       // Battery discharge rate in watts
+    batteries: Vec<Battery>,
+    battery_history: History,   // Combined charge in percent
+    power_history: History,     // Estimated total draw in watts
+    session_energy_wh: f64,
+    last_power_update: Instant,
     
     max_history: usize,
+    history_retention: Option<Duration>, // None keeps only the last max_history samples
 }
 
 impl SystemMetrics {
@@ -42,51 +175,159 @@ impl SystemMetrics {
         let mut networks = Networks::new();
         networks.refresh_list();
         
-        // Get initial network byte counts to use as baseline (reset point)
-        let mut initial_rx_bytes = 0;
-        let mut initial_tx_bytes = 0;
+        // Initial network byte counts are the baseline for session totals
+        let interface_filter = InterfaceFilter::default();
+        let (initial_rx_bytes, initial_tx_bytes) = counted_network_bytes(&networks, &interface_filter);
+        let vpn_interfaces = vpn_interfaces(&networks);
+        let (vpn_rx_bytes, vpn_tx_bytes) = network_bytes(&networks, &vpn_interfaces);
+        let mut wireless = network::read_wireless();
+        let mut network_interfaces: Vec<NetworkInterface> = networks
+            .iter()
+            .map(|(name, network)| {
+                let mut interface = NetworkInterface::new(name.clone(), network.total_received(), network.total_transmitted(), max_history);
+                interface.refresh_link(network);
+                interface.update_wireless(wireless.remove(name), None);
+                interface.update_errors(0.0, None);
+                interface
+            })
+            .collect();
+        network::sort_interfaces(&mut network_interfaces, &interface_filter);
         
-        for (interface_name, network) in &networks {
-            if interface_name != "lo" && !interface_name.starts_with("virbr") && !interface_name.starts_with("docker") && !interface_name.starts_with("veth") {
-                initial_rx_bytes += network.total_received();
-                initial_tx_bytes += network.total_transmitted();
-            }
-        }
+        // The startup driver query doubles as the first GPU probe
+        let gpu_driver_info = gpu::query_driver_info();
+        let proc_stat = read_proc_stat();
+        let ecc_errors = edac::read_ecc_errors();
+        let batteries = power::read_batteries();
+        let has_gpu = gpu_driver_info.is_some()
+            || (cfg!(target_os = "macos") && apple::read_powermetrics().is_some());
         
         Self {
-            cpu_history: VecDeque::with_capacity(max_history),
-            memory_history: VecDeque::with_capacity(max_history),
-            disk_history: VecDeque::with_capacity(max_history),
-            network_rx_history: VecDeque::with_capacity(max_history),
-            network_tx_history: VecDeque::with_capacity(max_history),
-            prev_rx_bytes: initial_rx_bytes,
-            prev_tx_bytes: initial_tx_bytes,
-            initial_rx_bytes,
-            initial_tx_bytes,
+            cpu_history: History::new(max_history),
+            memory_history: History::new(max_history),
+            vmstat: meminfo::read_vmstat(),
+            page_fault_history: History::new(max_history),
+            major_fault_history: History::new(max_history),
+            last_fault_update: Instant::now(),
+            dirty_history: History::new(max_history),
+            memory_breakdown: meminfo::read_meminfo(),
+            ecc_baseline: ecc_errors.as_ref().map_or((0, 0), |errors| (errors.corrected, errors.uncorrected)),
+            ecc_errors,
+            tracked_mounts: vec![TrackedMount::new("/".to_string(), max_history)],
+            filesystems: filesystems::list_filesystems(),
+            tmpfs: filesystems::list_tmpfs(),
+            drive_temperatures: sensors::read_drive_temperatures(),
+            disk_counters: diskio::read_disk_counters(),
+            disk_io: Vec::new(),
+            disk_read_history: History::new(max_history),
+            disk_write_history: History::new(max_history),
+            last_disk_io_update: Instant::now(),
+            network_total: NetworkInterface::new("All".to_string(), initial_rx_bytes, initial_tx_bytes, max_history),
+            interface_filter,
+            vpn_total: NetworkInterface::new("VPN".to_string(), vpn_rx_bytes, vpn_tx_bytes, max_history),
+            vpn_interfaces,
+            network_interfaces,
             networks,
             last_network_update: Instant::now(),
+            gateways: network::default_gateways(),
+            tcp_states: if connections::available() {
+                let counts = connections::count_tcp_states();
+                connections::TRACKED_STATES
+                    .into_iter()
+                    .map(|state| {
+                        let mut count = StateCount::new(state, max_history);
+                        count.update(counts.get(state).copied().unwrap_or(0), None);
+                        count
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            },
+            tcp_health: connections::available().then(|| {
+                let mut health = TcpHealth::new(max_history);
+                health.update(None);
+                health
+            }),
+            nfs_counters: nfs::read_nfs_counters(),
+            nfs_mounts: Vec::new(),
+            last_nfs_update: Instant::now(),
             per_core_usage: Vec::new(),
             per_core_temperatures: Vec::new(),
+            cpu_temperature_history: History::new(max_history),
+            fans: {
+                let mut fans = Vec::new();
+                sensors::update_fans(&mut fans, max_history, None);
+                fans
+            },
+            per_core_frequency_history: Vec::new(),
+            frequency_history: History::new(max_history),
+            pressure: pressure::RESOURCES
+                .into_iter()
+                .filter_map(|resource| {
+                    let (some, full) = pressure::read_pressure(resource)?;
+                    let mut pressure = Pressure::new(resource, max_history);
+                    pressure.update(some, full, None);
+                    Some(pressure)
+                })
+                .collect(),
+            load_average: System::load_average(),
+            load_history: History::new(max_history),
+            run_queue: proc_stat.as_ref().map(|stat| (stat.running, stat.blocked)),
+            scheduler_counters: proc_stat.map(|stat| (stat.context_switches, stat.interrupts)),
+            context_switch_history: History::new(max_history),
+            interrupt_history: History::new(max_history),
+            last_scheduler_update: Instant::now(),
             gpu_usage: None,
             gpu_temperature: None,
             gpu_fan_speed: None,
+            gpu_fans: Vec::new(),
             gpu_power_draw: None,
             gpu_memory_used: None,
             gpu_memory_total: None,
             gpu_name: None,
-            gpu_usage_history: VecDeque::with_capacity(max_history),
-            gpu_memory_percent_history: VecDeque::with_capacity(max_history),
+            gpu_driver_info,
+            gpu_available: has_gpu,
+            gpu_devices: Vec::new(),
+            gpu_probe_failures: if has_gpu { 0 } else { 1 },
+            next_gpu_probe: Instant::now() + GPU_PROBE_BASE_DELAY,
+            fan_probe_failures: 0,
+            next_fan_probe: Instant::now(),
+            gpu_interval: Duration::ZERO,
+            next_gpu_poll: Instant::now(),
+            last_gpu_sample: None,
+            gpu_core_clock: None,
+            gpu_memory_clock: None,
+            gpu_pcie_link: None,
+            gpu_pcie_rx: None,
+            gpu_pcie_tx: None,
+            last_pcie_throughput_update: None,
+            soc_sample: None,
+            nvlinks: Vec::new(),
+            last_nvlink_update: Instant::now(),
+            gpu_usage_history: History::new(max_history),
+            gpu_memory_percent_history: History::new(max_history),
+            gpu_core_clock_history: History::new(max_history),
+            gpu_memory_clock_history: History::new(max_history),
+            gpu_pcie_history: History::new(max_history),
+            gpu_power_history: History::new(max_history),
+            gpu_energy_wh: 0.0,
+            last_gpu_energy_update: Instant::now(),
+            rapl_counters: power::read_rapl_counters(),
+            cpu_power: None,
+            battery_power: power::battery_discharge(&batteries),
+            battery_history: History::new(max_history),
+            batteries,
+            power_history: History::new(max_history),
+            session_energy_wh: 0.0,
+            last_power_update: Instant::now(),
             max_history,
+            history_retention: None,
         }
     }
 
     pub fn update(&mut self, system: &System) {
         // Update CPU usage
         let cpu_usage = system.global_cpu_usage();
-        if self.cpu_history.len() >= self.max_history {
-            self.cpu_history.pop_front();
-        }
-        self.cpu_history.push_back(cpu_usage);
+        self.cpu_history.push(cpu_usage, self.history_retention);
 
         // Update per-core CPU usage
         self.per_core_usage.clear();
@@ -94,40 +335,92 @@ impl SystemMetrics {
             self.per_core_usage.push(cpu.cpu_usage());
         }
 
+        // Update per-core frequencies, which move with boost and throttling
+        self.per_core_frequency_history.resize_with(system.cpus().len(), || History::new(self.max_history));
+        for (history, cpu) in self.per_core_frequency_history.iter_mut().zip(system.cpus()) {
+            history.push(cpu.frequency() as f32, self.history_retention);
+        }
+        let average_frequency = system.cpus().iter().map(|cpu| cpu.frequency() as f32).sum::<f32>()
+            / system.cpus().len().max(1) as f32;
+        self.frequency_history.push(average_frequency, self.history_retention);
+
+        // Update load average and run queue
+        self.load_average = System::load_average();
+        self.load_history.push(self.load_average.one as f32, self.history_retention);
+        self.update_scheduler_stats();
+
         // Update per-core temperatures
         self.update_per_core_temperatures();
 
+        // Update fans, with the CPU temperature alongside to correlate them
+        sensors::update_fans(&mut self.fans, self.max_history, self.history_retention);
+        if let Some(temperature) = self.cpu_temperature() {
+            self.cpu_temperature_history.push(temperature, self.history_retention);
+        }
+
         // Update memory usage
         let memory_usage = (system.used_memory() as f32 / system.total_memory() as f32) * 100.0;
-        if self.memory_history.len() >= self.max_history {
-            self.memory_history.pop_front();
-        }
-        self.memory_history.push_back(memory_usage);
-
-        // Update disk usage (root filesystem)
-        let mut disk_usage = 0.0;
-        let disks = Disks::new_with_refreshed_list();
-        for disk in &disks {
-            if disk.mount_point().to_str() == Some("/") {
-                let total = disk.total_space() as f32;
-                let available = disk.available_space() as f32;
-                disk_usage = ((total - available) / total) * 100.0;
-                break;
-            }
+        self.memory_breakdown = meminfo::read_meminfo();
+        if let Some(memory) = &self.memory_breakdown {
+            self.dirty_history.push((memory.dirty + memory.writeback) as f32 / 1024.0 / 1024.0, self.history_retention);
         }
-        if self.disk_history.len() >= self.max_history {
-            self.disk_history.pop_front();
+        self.ecc_errors = edac::read_ecc_errors();
+        self.tmpfs = filesystems::list_tmpfs();
+        self.memory_history.push(memory_usage, self.history_retention);
+        self.update_page_fault_stats();
+
+        // Update all mounts; histories are kept for the tracked ones
+        self.filesystems = filesystems::list_filesystems();
+        for mount in &mut self.tracked_mounts {
+            // Nothing is recorded while unmounted, rather than a misleading 0%
+            if let Some(fs) = self.filesystems.iter().find(|fs| fs.mount_point == mount.mount_point) {
+                mount.push(fs.usage_percent(), self.history_retention);
+            }
         }
-        self.disk_history.push_back(disk_usage);
+        self.drive_temperatures = sensors::read_drive_temperatures();
+
+        // Update per-disk read/write throughput
+        self.update_disk_io_stats();
 
         // Update network usage
         self.update_network_stats();
 
-        // Update GPU usage/temperature if available
-        self.update_gpu_stats();
+        // Update TCP connection counts by state
+        if !self.tcp_states.is_empty() {
+            let counts = connections::count_tcp_states();
+            for state in &mut self.tcp_states {
+                state.update(counts.get(state.state).copied().unwrap_or(0), self.history_retention);
+            }
+        }
+        if let Some(health) = &mut self.tcp_health {
+            health.update(self.history_retention);
+        }
+
+        // Update CPU, memory and I/O pressure
+        for pressure in &mut self.pressure {
+            if let Some((some, full)) = pressure::read_pressure(pressure.resource) {
+                pressure.update(some, full, self.history_retention);
+            }
+        }
+        
+        // Update NFS per-mount statistics
+        self.update_nfs_stats();
+
+        // Update GPU stats and history on their own cadence, nvidia-smi is comparatively expensive
+        let now = Instant::now();
+        if now >= self.next_gpu_poll {
+            // Advance by the schedule rather than from now, so an interval equal to the
+            // metrics interval does not skip every other update
+            self.next_gpu_poll = (self.next_gpu_poll + self.gpu_interval).max(now);
+            
+            self.update_gpu_stats();
+            self.update_gpu_fans();
+            self.update_nvlink_stats();
+            self.update_gpu_history();
+        }
         
-        // Update GPU history
-        self.update_gpu_history();
+        // Combine CPU, GPU and battery power (needs the GPU power draw from above)
+        self.update_power_stats();
     }
 
 
@@ -139,47 +432,255 @@ impl SystemMetrics {
         self.memory_history.back().copied().unwrap_or(0.0)
     }
 
-    pub fn disk_usage(&self) -> f32 {
-        self.disk_history.back().copied().unwrap_or(0.0)
+    pub fn memory_breakdown(&self) -> Option<&MemoryBreakdown> {
+        self.memory_breakdown.as_ref()
+    }
+
+    pub fn ecc_errors(&self) -> Option<&EccErrors> {
+        self.ecc_errors.as_ref()
+    }
+
+    /// Corrected and uncorrected ECC errors logged since rmon started
+    pub fn new_ecc_errors(&self) -> (u64, u64) {
+        self.ecc_errors.as_ref().map_or((0, 0), |errors| {
+            (errors.corrected.saturating_sub(self.ecc_baseline.0), errors.uncorrected.saturating_sub(self.ecc_baseline.1))
+        })
+    }
+
+    pub fn filesystems(&self) -> &[Filesystem] {
+        &self.filesystems
+    }
+
+    /// tmpfs mounts with a size limit, fullest first
+    pub fn tmpfs_mounts(&self) -> &[Filesystem] {
+        &self.tmpfs
+    }
+
+    pub fn filesystem(&self, mount_point: &str) -> Option<&Filesystem> {
+        self.filesystems.iter().find(|fs| fs.mount_point == mount_point)
+    }
+
+    pub fn tracked_mounts(&self) -> &[TrackedMount] {
+        &self.tracked_mounts
+    }
+
+    /// How far back histories reach. Samples that fall out of the last `max_history` are
+    /// averaged into coarser points until they are older than this; None drops them.
+    pub fn set_history_retention(&mut self, retention: Option<Duration>) {
+        self.history_retention = retention;
+    }
+
+    pub fn history_retention(&self) -> Option<Duration> {
+        self.history_retention
     }
 
-    pub fn cpu_history(&self) -> &VecDeque<f32> {
-        &self.cpu_history
+    /// Track these mount points, in this order; unchanged ones keep their history
+    pub fn set_tracked_mounts(&mut self, mount_points: &[String]) {
+        let mut old = std::mem::take(&mut self.tracked_mounts);
+        self.tracked_mounts = mount_points
+            .iter()
+            .map(|mount_point| match old.iter().position(|mount| mount.mount_point == *mount_point) {
+                Some(i) => old.swap_remove(i),
+                None => TrackedMount::new(mount_point.clone(), self.max_history),
+            })
+            .collect();
     }
 
-    pub fn memory_history(&self) -> &VecDeque<f32> {
+    /// Filesystems on block devices, each device once under its first mount point
+    /// (btrfs subvolumes share their device's space)
+    pub fn partitions(&self) -> Vec<&Filesystem> {
+        let mut partitions: Vec<&Filesystem> = Vec::new();
+        for fs in self.filesystems.iter().filter(|fs| fs.is_partition()) {
+            if !partitions.iter().any(|partition| partition.device == fs.device) {
+                partitions.push(fs);
+            }
+        }
+        partitions
+    }
+
+    pub fn drive_temperatures(&self) -> &[DriveTemperature] {
+        &self.drive_temperatures
+    }
+
+    pub fn memory_history(&self) -> &History {
         &self.memory_history
     }
 
-    pub fn disk_history(&self) -> &VecDeque<f32> {
-        &self.disk_history
+    /// Page faults per second; None where /proc/vmstat is missing
+    pub fn page_fault_rate(&self) -> Option<f32> {
+        self.page_fault_history.back().copied()
+    }
+
+    /// Major faults per second, the ones that waited for the disk
+    pub fn major_fault_rate(&self) -> Option<f32> {
+        self.major_fault_history.back().copied()
+    }
+
+    pub fn major_fault_history(&self) -> &History {
+        &self.major_fault_history
+    }
+
+    /// OOM kills this boot as of the last update; None where the kernel does not count them
+    pub fn oom_kill_count(&self) -> Option<u64> {
+        self.vmstat.and_then(|vmstat| vmstat.oom_kills)
+    }
+
+    /// Dirty and writeback pages in MB, which a sync or fsync would have to flush
+    pub fn dirty_history(&self) -> &History {
+        &self.dirty_history
+    }
+
+    pub fn disk_io(&self) -> &[DiskIo] {
+        &self.disk_io
+    }
+
+    pub fn disk_read_rate(&self) -> f32 {
+        self.disk_read_history.back().copied().unwrap_or(0.0)
     }
 
-    pub fn network_download_rate(&self) -> f32 {
-        self.network_rx_history.back().copied().unwrap_or(0.0)
+    pub fn disk_write_rate(&self) -> f32 {
+        self.disk_write_history.back().copied().unwrap_or(0.0)
     }
 
-    pub fn network_upload_rate(&self) -> f32 {
-        self.network_tx_history.back().copied().unwrap_or(0.0)
+    pub fn disk_read_history(&self) -> &History {
+        &self.disk_read_history
     }
 
-    pub fn network_rx_history(&self) -> &VecDeque<f32> {
-        &self.network_rx_history
+    pub fn disk_write_history(&self) -> &History {
+        &self.disk_write_history
     }
 
-    pub fn network_tx_history(&self) -> &VecDeque<f32> {
-        &self.network_tx_history
+    /// Combined throughput of the counted interfaces, physical ones by default
+    pub fn network_total(&self) -> &NetworkInterface {
+        &self.network_total
     }
 
+    /// Combined throughput of the VPN interfaces, None without any
+    pub fn vpn_total(&self) -> Option<&NetworkInterface> {
+        (!self.vpn_interfaces.is_empty()).then_some(&self.vpn_total)
+    }
+
+    pub fn vpn_interfaces(&self) -> &[String] {
+        &self.vpn_interfaces
+    }
+
+    /// Every interface, counted ones first
+    pub fn network_interfaces(&self) -> &[NetworkInterface] {
+        &self.network_interfaces
+    }
+
+    pub fn gateways(&self) -> &[Gateway] {
+        &self.gateways
+    }
+
+    /// The named interface, or the total when it is None or gone
+    pub fn network_interface(&self, name: Option<&str>) -> &NetworkInterface {
+        name.and_then(|name| self.network_interfaces.iter().find(|interface| interface.name == name))
+            .unwrap_or(&self.network_total)
+    }
+
+    pub fn tcp_states(&self) -> &[StateCount] {
+        &self.tcp_states
+    }
+
+    pub fn tcp_health(&self) -> Option<&TcpHealth> {
+        self.tcp_health.as_ref()
+    }
 
     pub fn per_core_usage(&self) -> &[f32] {
         &self.per_core_usage
     }
 
+    pub fn load_average(&self) -> &LoadAvg {
+        &self.load_average
+    }
+
+    pub fn load_history(&self) -> &History {
+        &self.load_history
+    }
+
+    pub fn run_queue(&self) -> Option<(u64, u64)> {
+        self.run_queue
+    }
+
+    /// Context switches per second; None where /proc/stat is missing
+    pub fn context_switch_rate(&self) -> Option<f32> {
+        self.context_switch_history.back().copied()
+    }
+
+    /// Interrupts per second; None where /proc/stat is missing
+    pub fn interrupt_rate(&self) -> Option<f32> {
+        self.interrupt_history.back().copied()
+    }
+
+    pub fn context_switch_history(&self) -> &History {
+        &self.context_switch_history
+    }
+
+    pub fn interrupt_history(&self) -> &History {
+        &self.interrupt_history
+    }
+
+    pub fn per_core_frequency_history(&self) -> &[History] {
+        &self.per_core_frequency_history
+    }
+
+    pub fn frequency_history(&self) -> &History {
+        &self.frequency_history
+    }
+
+    pub fn fans(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    pub fn cpu_temperature_history(&self) -> &History {
+        &self.cpu_temperature_history
+    }
+
     pub fn per_core_temperatures(&self) -> &[f32] {
         &self.per_core_temperatures
     }
 
+    /// Whether a supported GPU answered the last query
+    pub fn gpu_available(&self) -> bool {
+        self.gpu_available
+    }
+
+    pub fn set_gpu_interval(&mut self, interval: Duration) {
+        self.gpu_interval = interval;
+        self.next_gpu_poll = self.next_gpu_poll.min(Instant::now() + interval);
+    }
+
+    /// Change which interfaces the network total sums, keeping the total continuous
+    pub fn set_interface_filter(&mut self, filter: InterfaceFilter) {
+        if filter == self.interface_filter {
+            return;
+        }
+        self.interface_filter = filter;
+        let (rx_bytes, tx_bytes) = counted_network_bytes(&self.networks, &self.interface_filter);
+        self.network_total.rebase(rx_bytes, tx_bytes);
+        network::sort_interfaces(&mut self.network_interfaces, &self.interface_filter);
+    }
+
+    pub fn interface_filter(&self) -> &InterfaceFilter {
+        &self.interface_filter
+    }
+
+    pub fn gpu_interval(&self) -> Duration {
+        self.gpu_interval
+    }
+
+    /// Time since the GPU was last sampled successfully, if that is long enough to mention
+    pub fn stale_gpu_data_age(&self) -> Option<Duration> {
+        self.last_gpu_sample
+            .map(|sample| sample.elapsed())
+            .filter(|age| *age >= GPU_STALE_AGE)
+    }
+
+    pub fn gpu_devices(&self) -> &[GpuDevice] {
+        &self.gpu_devices
+    }
+
     pub fn gpu_usage(&self) -> Option<f32> {
         self.gpu_usage
     }
@@ -192,6 +693,10 @@ impl SystemMetrics {
         self.gpu_fan_speed
     }
 
+    pub fn gpu_fans(&self) -> &[GpuFan] {
+        &self.gpu_fans
+    }
+
     pub fn gpu_power_draw(&self) -> Option<f32> {
         self.gpu_power_draw
     }
@@ -220,72 +725,226 @@ impl SystemMetrics {
         self.gpu_name.as_ref()
     }
 
-    pub fn gpu_usage_history(&self) -> &VecDeque<f32> {
+    pub fn gpu_driver_info(&self) -> Option<&GpuDriverInfo> {
+        self.gpu_driver_info.as_ref()
+    }
+
+    pub fn gpu_usage_history(&self) -> &History {
         &self.gpu_usage_history
     }
 
-    pub fn gpu_memory_percent_history(&self) -> &VecDeque<f32> {
-        &self.gpu_memory_percent_history
+    /// The charts worth keeping across restarts (CPU, memory, network and GPU) by
+    /// state file key
+    fn persisted_histories(&mut self) -> Vec<(String, &mut History)> {
+        let mut histories = vec![
+            ("cpu".to_string(), &mut self.cpu_history),
+            ("memory".to_string(), &mut self.memory_history),
+            ("gpu_usage".to_string(), &mut self.gpu_usage_history),
+            ("gpu_memory".to_string(), &mut self.gpu_memory_percent_history),
+        ];
+        let [rx, tx] = self.network_total.rate_histories_mut();
+        histories.push(("network_rx".to_string(), rx));
+        histories.push(("network_tx".to_string(), tx));
+        for interface in &mut self.network_interfaces {
+            let name = interface.name.clone();
+            let [rx, tx] = interface.rate_histories_mut();
+            histories.push((format!("network_rx:{}", name), rx));
+            histories.push((format!("network_tx:{}", name), tx));
+        }
+        histories
     }
 
-    fn update_network_stats(&mut self) {
-        // Refresh network data
-        self.networks.refresh();
-        
-        let mut total_rx_bytes = 0;
-        let mut total_tx_bytes = 0;
-        
-        // Sum up bytes from all network interfaces (excluding loopback)
-        for (interface_name, network) in &self.networks {
-            if interface_name != "lo" && !interface_name.starts_with("virbr") && !interface_name.starts_with("docker") && !interface_name.starts_with("veth") {
-                total_rx_bytes += network.total_received();
-                total_tx_bytes += network.total_transmitted();
-                // Debug: uncomment to see which interfaces are being monitored
-                // eprintln!("Interface: {}, RX: {}, TX: {}", interface_name, network.total_received(), network.total_transmitted());
+    pub fn save_histories(&mut self) -> BTreeMap<String, SavedHistory> {
+        let now = Instant::now();
+        self.persisted_histories().into_iter().map(|(key, history)| (key, history.save(now))).collect()
+    }
+
+    /// Put back histories saved `elapsed` ago; interfaces that are gone are skipped
+    pub fn restore_histories(&mut self, saved: &BTreeMap<String, SavedHistory>, elapsed: Duration) {
+        for (key, history) in self.persisted_histories() {
+            if let Some(saved) = saved.get(&key) {
+                history.restore(saved, elapsed);
             }
         }
+    }
+
+    pub fn gpu_memory_percent_history(&self) -> &History {
+        &self.gpu_memory_percent_history
+    }
+
+    pub fn gpu_core_clock(&self) -> Option<f32> {
+        self.gpu_core_clock
+    }
+
+    pub fn gpu_memory_clock(&self) -> Option<f32> {
+        self.gpu_memory_clock
+    }
+
+    pub fn gpu_core_clock_history(&self) -> &History {
+        &self.gpu_core_clock_history
+    }
+
+    pub fn gpu_memory_clock_history(&self) -> &History {
+        &self.gpu_memory_clock_history
+    }
+
+    pub fn gpu_pcie_link(&self) -> Option<PcieLink> {
+        self.gpu_pcie_link
+    }
+
+    /// PCIe (RX, TX) throughput in KB/s
+    pub fn gpu_pcie_throughput(&self) -> Option<(f32, f32)> {
+        Some((self.gpu_pcie_rx?, self.gpu_pcie_tx?))
+    }
+
+    pub fn gpu_pcie_history(&self) -> &History {
+        &self.gpu_pcie_history
+    }
+
+    pub fn gpu_power_history(&self) -> &History {
+        &self.gpu_power_history
+    }
+
+    /// GPU energy used since startup in watt-hours, integrated from the power draw
+    pub fn gpu_energy_wh(&self) -> f64 {
+        self.gpu_energy_wh
+    }
+
+    fn update_network_stats(&mut self) {
+        // Refresh network data, picking up interfaces that appeared since the last update
+        self.networks.refresh_list();
         
         // Calculate time elapsed since last update
         let now = Instant::now();
         let time_diff = now.duration_since(self.last_network_update).as_secs_f32();
         self.last_network_update = now;
         
-        // Calculate rates (bytes per second, converted to Kbps)
-        let rx_rate = if self.prev_rx_bytes > 0 && time_diff > 0.0 {
-            let bytes_diff = total_rx_bytes.saturating_sub(self.prev_rx_bytes);
-            (bytes_diff as f32) / time_diff * 8.0 / 1000.0 // Convert to Kbps (bits per second / 1000)
-        } else {
-            0.0
-        };
+        let (total_rx_bytes, total_tx_bytes) = counted_network_bytes(&self.networks, &self.interface_filter);
+        self.network_total.update(total_rx_bytes, total_tx_bytes, time_diff, self.history_retention);
         
-        let tx_rate = if self.prev_tx_bytes > 0 && time_diff > 0.0 {
-            let bytes_diff = total_tx_bytes.saturating_sub(self.prev_tx_bytes);
-            (bytes_diff as f32) / time_diff * 8.0 / 1000.0 // Convert to Kbps (bits per second / 1000)
-        } else {
-            0.0
-        };
+        // Tunnels come and go with the VPN connection, which must not show up as a burst
+        let vpn_interfaces = vpn_interfaces(&self.networks);
+        let (vpn_rx_bytes, vpn_tx_bytes) = network_bytes(&self.networks, &vpn_interfaces);
+        if vpn_interfaces != self.vpn_interfaces {
+            self.vpn_total.rebase(vpn_rx_bytes, vpn_tx_bytes);
+            self.vpn_interfaces = vpn_interfaces;
+        }
+        self.vpn_total.update(vpn_rx_bytes, vpn_tx_bytes, time_diff, self.history_retention);
         
-        // Update history
-        if self.network_rx_history.len() >= self.max_history {
-            self.network_rx_history.pop_front();
+        self.network_interfaces.retain(|interface| self.networks.list().contains_key(&interface.name));
+        let mut wireless = network::read_wireless();
+        for (name, network) in &self.networks {
+            let (rx_bytes, tx_bytes) = (network.total_received(), network.total_transmitted());
+            let index = match self.network_interfaces.iter().position(|interface| interface.name == *name) {
+                Some(index) => index,
+                None => {
+                    self.network_interfaces.push(NetworkInterface::new(name.clone(), rx_bytes, tx_bytes, self.max_history));
+                    self.network_interfaces.len() - 1
+                }
+            };
+            let interface = &mut self.network_interfaces[index];
+            interface.update(rx_bytes, tx_bytes, time_diff, self.history_retention);
+            interface.update_errors(time_diff, self.history_retention);
+            interface.refresh_link(network);
+            interface.update_wireless(wireless.remove(name), self.history_retention);
         }
-        self.network_rx_history.push_back(rx_rate);
+        network::sort_interfaces(&mut self.network_interfaces, &self.interface_filter);
+        // Routes change when a VPN connects or an uplink fails over
+        self.gateways = network::default_gateways();
+    }
+
+    fn update_scheduler_stats(&mut self) {
+        let stat = read_proc_stat();
+        self.run_queue = stat.as_ref().map(|stat| (stat.running, stat.blocked));
+
+        let now = Instant::now();
+        let seconds = now.duration_since(self.last_scheduler_update).as_secs_f32().max(0.001);
+        self.last_scheduler_update = now;
+
+        let counters = stat.map(|stat| (stat.context_switches, stat.interrupts));
+        if let (Some((prev_switches, prev_interrupts)), Some((switches, interrupts))) = (self.scheduler_counters, counters) {
+            for (history, rate) in [
+                (&mut self.context_switch_history, switches.saturating_sub(prev_switches) as f32 / seconds),
+                (&mut self.interrupt_history, interrupts.saturating_sub(prev_interrupts) as f32 / seconds),
+            ] {
+                history.push(rate, self.history_retention);
+            }
+        }
+        self.scheduler_counters = counters;
+    }
+
+    fn update_page_fault_stats(&mut self) {
+        let now = Instant::now();
+        let seconds = now.duration_since(self.last_fault_update).as_secs_f32().max(0.001);
+        self.last_fault_update = now;
+
+        let vmstat = meminfo::read_vmstat();
+        if let (Some(prev), Some(current)) = (self.vmstat, vmstat) {
+            self.page_fault_history.push(current.page_faults.saturating_sub(prev.page_faults) as f32 / seconds, self.history_retention);
+            self.major_fault_history.push(current.major_faults.saturating_sub(prev.major_faults) as f32 / seconds, self.history_retention);
+        }
+        self.vmstat = vmstat;
+    }
+
+    fn update_disk_io_stats(&mut self) {
+        let counters = diskio::read_disk_counters();
+        
+        let now = Instant::now();
+        let time_diff = now.duration_since(self.last_disk_io_update).as_secs_f32();
+        self.last_disk_io_update = now;
         
-        if self.network_tx_history.len() >= self.max_history {
-            self.network_tx_history.pop_front();
+        // Hot-plugged disks come and go with their counters
+        self.disk_io.retain(|io| counters.iter().any(|current| current.name == io.name));
+        for current in &counters {
+            let prev = self.disk_counters.iter().find(|prev| prev.name == current.name).unwrap_or(current);
+            let index = match self.disk_io.iter().position(|io| io.name == current.name) {
+                Some(index) => index,
+                None => {
+                    self.disk_io.push(DiskIo::new(current.name.clone(), self.max_history));
+                    self.disk_io.len() - 1
+                }
+            };
+            self.disk_io[index].update(prev, current, time_diff, self.history_retention);
         }
-        self.network_tx_history.push_back(tx_rate);
         
-        // Store current values for next calculation
-        self.prev_rx_bytes = total_rx_bytes;
-        self.prev_tx_bytes = total_tx_bytes;
+        let read_rate = self.disk_io.iter().map(|io| io.read_rate).sum();
+        let write_rate = self.disk_io.iter().map(|io| io.write_rate).sum();
+        self.disk_read_history.push(read_rate, self.history_retention);
+        self.disk_write_history.push(write_rate, self.history_retention);
+        
+        self.disk_counters = counters;
+    }
+
+    pub fn pressure(&self) -> &[Pressure] {
+        &self.pressure
     }
 
-    pub fn total_network_bytes(&self) -> (u64, u64) {
-        // Return session-relative totals (current - initial)
-        let session_rx = self.prev_rx_bytes.saturating_sub(self.initial_rx_bytes);
-        let session_tx = self.prev_tx_bytes.saturating_sub(self.initial_tx_bytes);
-        (session_rx, session_tx)
+    pub fn nfs_mounts(&self) -> &[NfsMountStats] {
+        &self.nfs_mounts
+    }
+
+    fn update_nfs_stats(&mut self) {
+        let counters = nfs::read_nfs_counters();
+        
+        let now = Instant::now();
+        let time_diff = now.duration_since(self.last_nfs_update).as_secs_f32();
+        self.last_nfs_update = now;
+        
+        // Rates are deltas against the previous sample of the same mount
+        self.nfs_mounts = counters
+            .iter()
+            .map(|current| {
+                let prev = self.nfs_counters.iter().find(|prev| prev.mount_point == current.mount_point);
+                let unanswered_secs = self
+                    .nfs_mounts
+                    .iter()
+                    .find(|stats| stats.mount_point == current.mount_point)
+                    .map_or(0.0, |stats| stats.unanswered_secs);
+                NfsMountStats::from_samples(prev.or(Some(current)), current, time_diff, unanswered_secs)
+            })
+            .collect();
+        
+        self.nfs_counters = counters;
     }
 
     pub fn cpu_temperature(&self) -> Option<f32> {
@@ -540,12 +1199,51 @@ impl SystemMetrics {
     }
 
     fn update_gpu_stats(&mut self) {
+        // Without a working GPU, back off instead of launching nvidia-smi every interval
+        if !self.gpu_available && Instant::now() < self.next_gpu_probe {
+            return;
+        }
+        
+        self.gpu_available = if cfg!(target_os = "macos") {
+            self.query_powermetrics()
+        } else {
+            self.query_gpu_stats()
+        };
+        if self.gpu_available {
+            self.gpu_probe_failures = 0;
+            self.last_gpu_sample = Some(Instant::now());
+        } else {
+            self.next_gpu_probe = Instant::now() + probe_backoff(self.gpu_probe_failures);
+            self.gpu_probe_failures += 1;
+        }
+    }
+
+    /// Fans from DRM hwmon, or from nvidia-settings on NVIDIA cards. nvidia-settings
+    /// fails on headless and Wayland hosts, so it backs off like the GPU probe instead
+    /// of being launched on every poll.
+    fn update_gpu_fans(&mut self) {
+        let query_nvidia = self.gpu_name.is_some() && !cfg!(target_os = "macos") && Instant::now() >= self.next_fan_probe;
+        self.gpu_fans = gpu::read_fans(query_nvidia);
+        if !query_nvidia {
+            return;
+        }
+        // Empty with the query allowed means hwmon had none and nvidia-settings failed
+        if self.gpu_fans.is_empty() {
+            self.next_fan_probe = Instant::now() + probe_backoff(self.fan_probe_failures);
+            self.fan_probe_failures += 1;
+        } else {
+            self.fan_probe_failures = 0;
+        }
+    }
+
+    /// Query nvidia-smi, returning whether it answered
+    fn query_gpu_stats(&mut self) -> bool {
         use std::process::Command;
 
         // Enhanced nvidia-smi query for comprehensive GPU information
         let output = Command::new("nvidia-smi")
             .args([
-                "--query-gpu=name,utilization.gpu,temperature.gpu,fan.speed,power.draw,memory.used,memory.total",
+                "--query-gpu=name,utilization.gpu,temperature.gpu,fan.speed,power.draw,memory.used,memory.total,clocks.gr,clocks.mem,pcie.link.gen.current,pcie.link.width.current,pcie.link.gen.max,pcie.link.width.max",
                 "--format=csv,noheader,nounits",
             ])
             .output();
@@ -553,9 +1251,12 @@ impl SystemMetrics {
         if let Ok(output) = output {
             if output.status.success() {
                 if let Ok(out_str) = String::from_utf8(output.stdout) {
+                    // One line per GPU; the dashboard fields below follow the first
+                    self.update_gpu_devices(&out_str);
+                    
                     if let Some(line) = out_str.lines().next() {
                         let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-                        if parts.len() >= 7 {
+                        if parts.len() >= 13 {
                             // Parse all GPU metrics
                             self.gpu_name = if !parts[0].is_empty() && parts[0] != "[Not Supported]" {
                                 Some(parts[0].to_string())
@@ -593,7 +1294,33 @@ impl SystemMetrics {
                                 None
                             };
                             
-                            return;
+                            // Graphics and memory clocks (MHz)
+                            self.gpu_core_clock = if parts[7] != "[Not Supported]" {
+                                parts[7].parse::<f32>().ok()
+                            } else {
+                                None
+                            };
+                            
+                            self.gpu_memory_clock = if parts[8] != "[Not Supported]" {
+                                parts[8].parse::<f32>().ok()
+                            } else {
+                                None
+                            };
+                            
+                            // PCIe link (generation drops at idle to save power)
+                            self.gpu_pcie_link = match (parts[9].parse(), parts[10].parse()) {
+                                (Ok(generation), Ok(width)) => Some(PcieLink {
+                                    generation,
+                                    width,
+                                    max_generation: parts[11].parse().unwrap_or(generation),
+                                    max_width: parts[12].parse().unwrap_or(width),
+                                }),
+                                _ => None,
+                            };
+                            
+                            self.update_gpu_pcie_throughput();
+                            
+                            return true;
                         }
                     }
                 }
@@ -623,7 +1350,14 @@ impl SystemMetrics {
                             self.gpu_memory_used = None;
                             self.gpu_memory_total = None;
                             self.gpu_name = None;
-                            return;
+                            self.gpu_core_clock = None;
+                            self.gpu_memory_clock = None;
+                            self.gpu_pcie_link = None;
+                            self.gpu_pcie_rx = None;
+                            self.gpu_pcie_tx = None;
+                            self.last_pcie_throughput_update = None;
+                            self.gpu_devices.clear();
+                            return true;
                         }
                     }
                 }
@@ -631,6 +1365,58 @@ impl SystemMetrics {
         }
 
         // Clear all GPU data if nvidia-smi is not available or failed
+        self.clear_gpu_stats();
+        false
+    }
+
+    /// Apple Silicon: fill the GPU fields from powermetrics, returning whether it answered
+    fn query_powermetrics(&mut self) -> bool {
+        let Some(sample) = apple::read_powermetrics() else {
+            self.clear_gpu_stats();
+            return false;
+        };
+
+        if self.gpu_name.is_none() {
+            self.gpu_name = apple::chip_name().map(|chip| format!("{} GPU", chip));
+        }
+        self.gpu_usage = sample.gpu_usage;
+        self.gpu_power_draw = sample.gpu_power;
+        self.gpu_core_clock = sample.gpu_frequency;
+
+        // Feed the GPU tab a line shaped like the nvidia-smi query
+        // (name, utilization, temperature, fan, power, memory used/total, clocks)
+        let field = |value: Option<f32>| value.map(|v| v.to_string()).unwrap_or_default();
+        let line = format!(
+            "{}, {}, , , {}, , , {}",
+            self.gpu_name.as_deref().unwrap_or("Apple GPU"),
+            field(sample.gpu_usage),
+            field(sample.gpu_power),
+            field(sample.gpu_frequency),
+        );
+        self.update_gpu_devices(&line);
+        
+        self.soc_sample = Some(sample);
+        true
+    }
+
+    fn update_nvlink_stats(&mut self) {
+        let now = Instant::now();
+        let time_diff = now.duration_since(self.last_nvlink_update).as_secs_f32();
+        self.last_nvlink_update = now;
+        
+        // NVLink is NVIDIA only; the driver query tells whether nvidia-smi is there at all
+        if self.gpu_available && self.gpu_driver_info.is_some() {
+            gpu::update_nvlinks(&mut self.nvlinks, time_diff, self.max_history, self.history_retention);
+        } else {
+            self.nvlinks.clear();
+        }
+    }
+
+    pub fn nvlinks(&self) -> &[NvLink] {
+        &self.nvlinks
+    }
+
+    fn clear_gpu_stats(&mut self) {
         self.gpu_usage = None;
         self.gpu_temperature = None;
         self.gpu_fan_speed = None;
@@ -638,21 +1424,212 @@ impl SystemMetrics {
         self.gpu_memory_used = None;
         self.gpu_memory_total = None;
         self.gpu_name = None;
+        self.gpu_core_clock = None;
+        self.gpu_memory_clock = None;
+        self.gpu_pcie_link = None;
+        self.gpu_pcie_rx = None;
+        self.gpu_pcie_tx = None;
+        self.last_pcie_throughput_update = None;
+        self.gpu_devices.clear();
+        self.soc_sample = None;
+    }
+
+    /// SoC thermal pressure level on Apple Silicon, where no GPU temperature is available
+    pub fn gpu_thermal_pressure(&self) -> Option<&str> {
+        self.soc_sample.as_ref()?.thermal_pressure.as_deref()
+    }
+
+    fn update_gpu_devices(&mut self, output: &str) {
+        let lines: Vec<&str> = output.lines().filter(|line| !line.trim().is_empty()).collect();
+        
+        // Keep existing devices (and their history) as long as the GPU count is stable
+        self.gpu_devices.truncate(lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            if index >= self.gpu_devices.len() {
+                self.gpu_devices.push(GpuDevice::new(index, self.max_history));
+            }
+            let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
+            self.gpu_devices[index].update(&parts, self.history_retention);
+        }
+    }
+
+    fn update_gpu_pcie_throughput(&mut self) {
+        use std::process::Command;
+
+        if self.last_pcie_throughput_update.is_some_and(|last| last.elapsed() < PCIE_THROUGHPUT_INTERVAL) {
+            return;
+        }
+        self.last_pcie_throughput_update = Some(Instant::now());
+
+        // Throughput is not a --query-gpu field, only part of the PCIe section of the
+        // full report:
+        //         Tx Throughput                     : 1250 KB/s
+        //         Rx Throughput                     : 500 KB/s
+        self.gpu_pcie_rx = None;
+        self.gpu_pcie_tx = None;
+
+        let Ok(output) = Command::new("timeout").args(["1s", "nvidia-smi", "-q", "-d", "PCIE"]).output() else {
+            return;
+        };
+        if !output.status.success() {
+            return;
+        }
+
+        let out_str = String::from_utf8_lossy(&output.stdout);
+        for line in out_str.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let throughput = value
+                .split_whitespace()
+                .next()
+                .and_then(|v| v.parse::<f32>().ok());
+            match key.trim() {
+                "Rx Throughput" if self.gpu_pcie_rx.is_none() => self.gpu_pcie_rx = throughput,
+                "Tx Throughput" if self.gpu_pcie_tx.is_none() => self.gpu_pcie_tx = throughput,
+                _ => {}
+            }
+        }
+    }
+
+    pub fn cpu_power(&self) -> Option<f32> {
+        self.cpu_power
+    }
+
+    pub fn battery_power(&self) -> Option<f32> {
+        self.battery_power
+    }
+
+    pub fn batteries(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    /// Charge of all batteries together in percent; None without a battery
+    pub fn battery_charge(&self) -> Option<f32> {
+        power::battery_charge(&self.batteries)
+    }
+
+    pub fn battery_history(&self) -> &History {
+        &self.battery_history
+    }
+
+    /// Estimated whole-system power draw in watts
+    pub fn system_power(&self) -> Option<f32> {
+        power::total_power(self.cpu_power, self.gpu_power_draw, self.battery_power)
+    }
+
+    /// Whether the system power figure comes from the battery rather than CPU + GPU
+    pub fn on_battery(&self) -> bool {
+        self.battery_power.is_some_and(|watts| watts > 0.0)
+    }
+
+    pub fn power_history(&self) -> &History {
+        &self.power_history
+    }
+
+    /// Energy used since startup in watt-hours, integrated from the system power
+    pub fn session_energy_wh(&self) -> f64 {
+        self.session_energy_wh
+    }
+
+    fn update_power_stats(&mut self) {
+        let counters = power::read_rapl_counters();
+        
+        let now = Instant::now();
+        let time_diff = now.duration_since(self.last_power_update).as_secs_f32();
+        self.last_power_update = now;
+        
+        // No RAPL on Apple Silicon; powermetrics reports CPU power there
+        self.cpu_power = power::rapl_power(&self.rapl_counters, &counters, time_diff)
+            .or_else(|| self.soc_sample.as_ref()?.cpu_power);
+        self.rapl_counters = counters;
+        self.batteries = power::read_batteries();
+        self.battery_power = power::battery_discharge(&self.batteries);
+        if let Some(charge) = power::battery_charge(&self.batteries) {
+            self.battery_history.push(charge, self.history_retention);
+        }
+        
+        let total = self.system_power();
+        if let Some(watts) = total {
+            self.session_energy_wh += watts as f64 * time_diff as f64 / 3600.0;
+        }
+        
+        self.power_history.push(total.unwrap_or(0.0), self.history_retention);
     }
 
     fn update_gpu_history(&mut self) {
         // Update GPU usage history
         let gpu_usage = self.gpu_usage.unwrap_or(0.0);
-        if self.gpu_usage_history.len() >= self.max_history {
-            self.gpu_usage_history.pop_front();
-        }
-        self.gpu_usage_history.push_back(gpu_usage);
+        self.gpu_usage_history.push(gpu_usage, self.history_retention);
 
         // Update GPU memory percentage history
         let gpu_memory_percent = self.gpu_memory_usage_percent().unwrap_or(0.0);
-        if self.gpu_memory_percent_history.len() >= self.max_history {
-            self.gpu_memory_percent_history.pop_front();
+        self.gpu_memory_percent_history.push(gpu_memory_percent, self.history_retention);
+
+        // Update GPU clock histories
+        self.gpu_core_clock_history.push(self.gpu_core_clock.unwrap_or(0.0), self.history_retention);
+
+        self.gpu_memory_clock_history.push(self.gpu_memory_clock.unwrap_or(0.0), self.history_retention);
+
+        // Update PCIe throughput history
+        let pcie_total = self.gpu_pcie_throughput().map_or(0.0, |(rx, tx)| rx + tx);
+        self.gpu_pcie_history.push(pcie_total, self.history_retention);
+
+        // Update GPU power history and integrate energy
+        let now = Instant::now();
+        let time_diff = now.duration_since(self.last_gpu_energy_update).as_secs_f64();
+        self.last_gpu_energy_update = now;
+        if let Some(watts) = self.gpu_power_draw {
+            self.gpu_energy_wh += watts as f64 * time_diff / 3600.0;
         }
-        self.gpu_memory_percent_history.push_back(gpu_memory_percent);
+        self.gpu_power_history.push(self.gpu_power_draw.unwrap_or(0.0), self.history_retention);
     }
-}
\ No newline at end of file
+}
+
+/// Byte counters summed over the interfaces the total counts
+fn counted_network_bytes(networks: &Networks, filter: &InterfaceFilter) -> (u64, u64) {
+    networks
+        .iter()
+        .filter(|(name, _)| !filter.is_excluded(name))
+        .fold((0, 0), |(rx, tx), (_, network)| (rx + network.total_received(), tx + network.total_transmitted()))
+}
+
+/// Names of the VPN interfaces, sorted
+fn vpn_interfaces(networks: &Networks) -> Vec<String> {
+    let mut names: Vec<String> = networks.list().keys().filter(|name| network::is_vpn(name)).cloned().collect();
+    names.sort();
+    names
+}
+
+/// Byte counters summed over the named interfaces
+fn network_bytes(networks: &Networks, names: &[String]) -> (u64, u64) {
+    networks
+        .iter()
+        .filter(|(name, _)| names.contains(name))
+        .fold((0, 0), |(rx, tx), (_, network)| (rx + network.total_received(), tx + network.total_transmitted()))
+}
+
+/// Scheduler counters from /proc/stat
+struct ProcStat {
+    running: u64,
+    blocked: u64,
+    context_switches: u64, // Since boot
+    interrupts: u64,       // Since boot, all sources
+}
+
+/// The procs_running, procs_blocked, ctxt and intr lines of /proc/stat. intr is
+/// followed by per-IRQ counts; the first number is the total.
+fn read_proc_stat() -> Option<ProcStat> {
+    let stat = std::fs::read_to_string("/proc/stat").ok()?;
+    let count = |key: &str| {
+        stat.lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|value| value.split_whitespace().next()?.parse::<u64>().ok())
+    };
+    Some(ProcStat {
+        running: count("procs_running ")?,
+        blocked: count("procs_blocked ")?,
+        context_switches: count("ctxt ")?,
+        interrupts: count("intr ")?,
+    })
+}
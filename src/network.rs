@@ -0,0 +1,699 @@
+use std::{
+    collections::HashMap,
+    fs,
+    net::IpAddr,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, Instant},
+};
+
+use sysinfo::NetworkData;
+
+use crate::history::History;
+use crate::routes::{self, Route};
+use crate::worker::Job;
+
+/// Loopback, bridges and container links, left out of the total unless configured
+/// otherwise, so traffic is not counted once per hop
+const DEFAULT_EXCLUDED_INTERFACES: [&str; 4] = ["lo", "virbr*", "docker*", "veth*"];
+
+/// Name prefixes of tunnel interfaces, for systems without sysfs (macOS utun)
+const VPN_PREFIXES: [&str; 3] = ["tun", "wg", "utun"];
+
+/// IFF_TUN in /sys/class/net/<name>/tun_flags: a layer 3 tunnel rather than a tap
+/// device, which is more often a VM's link than a VPN
+const IFF_TUN: u32 = 0x0001;
+
+/// Interfaces left out of the total, as glob patterns of their names (`*` and `?`)
+#[derive(Clone, Default, PartialEq)]
+pub struct InterfaceFilter {
+    include: Vec<String>,         // Counted even when an exclude pattern matches
+    exclude: Option<Vec<String>>, // None for DEFAULT_EXCLUDED_INTERFACES
+}
+
+impl InterfaceFilter {
+    /// Exclude patterns (None for loopback, bridges and container links) and the
+    /// patterns of interfaces counted even though an exclude pattern matches
+    pub fn new(include: Vec<String>, exclude: Option<Vec<String>>) -> Self {
+        Self { include, exclude }
+    }
+
+    /// Whether the total leaves this interface out. VPN interfaces are always left out
+    /// unless included, as tunneled traffic also crosses the uplink, encrypted.
+    pub fn is_excluded(&self, name: &str) -> bool {
+        let excluded = match &self.exclude {
+            Some(patterns) => patterns.iter().any(|pattern| glob_match(pattern, name)),
+            None => DEFAULT_EXCLUDED_INTERFACES.iter().any(|pattern| glob_match(pattern, name)),
+        };
+        (excluded || is_vpn(name)) && !self.include.iter().any(|pattern| glob_match(pattern, name))
+    }
+}
+
+/// Smallest gauge scale in Kbps for interfaces without a known link speed, so an idle
+/// link does not fill its gauge with a few packets
+const MIN_GAUGE_SCALE: f32 = 1000.0;
+
+/// The public address changes rarely, and every check is a request to an outside service
+const PUBLIC_IP_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Link quality most drivers report as their maximum in /proc/net/wireless (`iwconfig`
+/// shows it as "Link Quality=54/70")
+const MAX_LINK_QUALITY: f32 = 70.0;
+
+/// Link details of one interface, from /sys/class/net and its assigned addresses
+#[derive(Clone, Default)]
+pub struct Link {
+    pub state: String,          // operstate: up, down, dormant...
+    pub speed: Option<u32>,     // Negotiated speed in Mbps, when the driver reports one
+    pub duplex: Option<String>, // full or half
+    pub mtu: Option<u32>,
+    pub mac: Option<String>,
+    pub addresses: Vec<String>, // CIDR notation, IPv4 first
+    pub master: Option<String>, // Bond or bridge this interface is a member of
+    pub aggregate: Option<Aggregate>, // Members, when this interface is a bond or bridge
+}
+
+impl Link {
+    /// State, speed and duplex, e.g. "up 1000 Mbps full"
+    pub fn summary(&self) -> String {
+        let mut summary = self.state.clone();
+        if let Some(speed) = self.speed {
+            summary.push_str(&format!(" {} Mbps", speed));
+        }
+        if let Some(duplex) = &self.duplex {
+            summary.push_str(&format!(" {}", duplex));
+        }
+        summary
+    }
+
+    /// MAC, MTU, addresses and the bond or bridge this is part of, e.g.
+    /// "MAC 3c:7c:3f:1e:22:a1 · MTU 1500 · 192.168.1.20/24 · in bond0"
+    pub fn details(&self) -> String {
+        let mut details = Vec::new();
+        if let Some(mac) = &self.mac {
+            details.push(format!("MAC {}", mac));
+        }
+        if let Some(mtu) = self.mtu {
+            details.push(format!("MTU {}", mtu));
+        }
+        details.extend(self.addresses.iter().cloned());
+        if let Some(master) = &self.master {
+            details.push(format!("in {}", master));
+        }
+        details.join(" · ")
+    }
+}
+
+/// Members of a bond or bridge, from /sys/class/net/<name>/bonding and /brif
+#[derive(Clone)]
+pub struct Aggregate {
+    pub kind: AggregateKind,
+    pub members: Vec<Member>, // In the order the kernel lists them
+}
+
+#[derive(Clone)]
+pub enum AggregateKind {
+    Bond {
+        mode: String,           // active-backup, balance-rr, 802.3ad...
+        active: Option<String>, // Member carrying the traffic; only active-backup and similar modes have one
+    },
+    Bridge,
+}
+
+/// One bond slave or bridge port
+#[derive(Clone)]
+pub struct Member {
+    pub name: String,
+    pub up: bool,                   // Bond: MII status; bridge: operstate, and the port not disabled
+    pub state: String,              // Bond: active or backup; bridge: STP port state
+    pub link_failures: Option<u64>, // Bond slaves only, since the bond was set up
+}
+
+impl Aggregate {
+    /// Members whose link is down: a failed bond leg or a dead bridge port
+    pub fn down_members(&self) -> usize {
+        self.members.iter().filter(|member| !member.up).count()
+    }
+
+    /// Kind, mode and each member, e.g.
+    /// "bond active-backup, active eth0 · eth0 up active · eth1 down backup, 3 link failures"
+    pub fn summary(&self) -> String {
+        let mut summary = vec![match &self.kind {
+            AggregateKind::Bond { mode, active: Some(active) } => format!("bond {}, active {}", mode, active),
+            AggregateKind::Bond { mode, active: None } => format!("bond {}", mode),
+            AggregateKind::Bridge => "bridge".to_string(),
+        }];
+        if self.members.is_empty() {
+            summary.push("no members".to_string());
+        }
+        for member in &self.members {
+            let mut text = format!("{} {} {}", member.name, if member.up { "up" } else { "down" }, member.state);
+            if let Some(failures) = member.link_failures.filter(|&failures| failures > 0) {
+                text.push_str(&format!(", {} link failure{}", failures, if failures == 1 { "" } else { "s" }));
+            }
+            summary.push(text);
+        }
+        summary.join(" · ")
+    }
+}
+
+/// Wireless link of one interface: quality and signal from /proc/net/wireless, SSID and
+/// bitrate from `iw`, which asks the driver over nl80211 in the background
+#[derive(Clone, Default)]
+pub struct Wireless {
+    pub ssid: Option<String>, // None while not associated, or without iw
+    pub signal: Option<f32>,  // dBm; None for drivers reporting a relative level
+    pub quality: f32,         // Percent
+    pub bitrate: Option<f32>, // Transmit bitrate, Mbit/s
+}
+
+impl Wireless {
+    /// SSID, signal, quality and bitrate, e.g. "HomeNet · -56 dBm · quality 77% · 866.7 Mbit/s"
+    pub fn summary(&self) -> String {
+        let mut summary = vec![self.ssid.clone().unwrap_or_else(|| "not associated".to_string())];
+        if let Some(signal) = self.signal {
+            summary.push(format!("{:.0} dBm", signal));
+        }
+        summary.push(format!("quality {:.0}%", self.quality));
+        if let Some(bitrate) = self.bitrate {
+            summary.push(format!("{:.1} Mbit/s", bitrate));
+        }
+        summary.join(" · ")
+    }
+
+}
+
+/// What `iw dev <name> link` tells beyond /proc/net/wireless
+#[derive(Clone, Default)]
+struct IwLink {
+    ssid: Option<String>,
+    signal: Option<f32>, // dBm, for drivers whose /proc/net/wireless level is relative
+    bitrate: Option<f32>,
+}
+
+impl IwLink {
+    /// Parse `iw dev <name> link`, given a second at most:
+    ///
+    /// ```text
+    /// Connected to 3c:7c:3f:1e:22:a1 (on wlp2s0)
+    ///         SSID: HomeNet
+    ///         signal: -56 dBm
+    ///         tx bitrate: 866.7 MBit/s VHT-MCS 9 80MHz short GI VHT-NSS 2
+    /// ```
+    fn read(name: &str) -> Self {
+        let mut link = Self::default();
+        let Ok(output) = Command::new("timeout").args(["1s", "iw", "dev", name, "link"]).output() else {
+            return link;
+        };
+        for line in String::from_utf8_lossy(&output.stdout).lines().map(str::trim) {
+            let number = |value: &str| value.split_whitespace().next()?.parse::<f32>().ok();
+            if let Some(ssid) = line.strip_prefix("SSID: ") {
+                link.ssid = Some(ssid.to_string());
+            } else if let Some(signal) = line.strip_prefix("signal: ") {
+                link.signal = number(signal);
+            } else if let Some(bitrate) = line.strip_prefix("tx bitrate: ") {
+                link.bitrate = number(bitrate);
+            }
+        }
+        link
+    }
+}
+
+/// A default route: where traffic without a more specific route leaves the machine
+pub struct Gateway {
+    pub address: IpAddr,
+    pub interface: String,
+    pub metric: u32, // Lowest wins when there are several uplinks
+}
+
+/// Public address as seen by an outside service, opt-in via `public_ip_url`. Checks
+/// run in the background, so a slow service does not hold up the UI.
+pub struct PublicIp {
+    pub url: String,
+    pub address: Option<String>,
+    pub error: Option<String>,
+    job: Option<Job<Result<String, String>>>,
+    last_refresh: Option<Instant>,
+}
+
+impl PublicIp {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            address: None,
+            error: None,
+            job: None,
+            last_refresh: None,
+        }
+    }
+
+    /// Due for a check, and none still running
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none() && self.last_refresh.is_none_or(|last| last.elapsed() >= PUBLIC_IP_REFRESH_INTERVAL)
+    }
+
+    /// Start fetching the address with curl; `poll` picks up the answer
+    pub fn refresh(&mut self) {
+        let url = self.url.clone();
+        self.job = Some(Job::spawn(move || fetch_public_ip(&url)));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Take the answer of a finished check. The last known address is kept while a check
+    /// fails, so a flaky uplink does not blank it.
+    pub fn poll(&mut self) {
+        let Some(result) = self.job.as_ref().and_then(Job::try_result) else {
+            return;
+        };
+        self.job = None;
+        match result.and_then(|result| result) {
+            Ok(address) => {
+                self.address = Some(address);
+                self.error = None;
+            }
+            Err(err) => self.error = Some(err),
+        }
+    }
+}
+
+/// The address `url` answers with, e.g. https://ifconfig.me
+fn fetch_public_ip(url: &str) -> Result<String, String> {
+    Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--max-time", "2", url])
+        .output()
+        .map_err(|e| format!("failed to run curl: {}", e))
+        .and_then(|output| {
+            let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+            match stdout.parse::<IpAddr>() {
+                Ok(address) if output.status.success() => Ok(address.to_string()),
+                _ if output.status.success() => Err("response is not an IP address".to_string()),
+                _ => Err(String::from_utf8_lossy(&output.stderr).trim().trim_start_matches("curl: ").to_string()),
+            }
+        })
+}
+
+/// Error, drop and collision counters of one interface, from
+/// /sys/class/net/<name>/statistics
+#[derive(Clone, Copy, Default)]
+pub struct PacketErrors {
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub collisions: u64, // Only meaningful on half-duplex links
+}
+
+impl PacketErrors {
+    fn read(name: &str) -> Option<Self> {
+        let read = |counter: &str| -> Option<u64> {
+            fs::read_to_string(format!("/sys/class/net/{}/statistics/{}", name, counter)).ok()?.trim().parse().ok()
+        };
+        Some(Self {
+            rx_errors: read("rx_errors")?,
+            tx_errors: read("tx_errors")?,
+            rx_dropped: read("rx_dropped")?,
+            tx_dropped: read("tx_dropped")?,
+            collisions: read("collisions").unwrap_or(0),
+        })
+    }
+
+    /// Every failed or discarded packet
+    pub fn total(&self) -> u64 {
+        self.rx_errors + self.tx_errors + self.rx_dropped + self.tx_dropped + self.collisions
+    }
+
+    /// Receive/transmit counts, e.g. "rx/tx errors 0/0 · drops 12/0 · collisions 0"
+    pub fn summary(&self) -> String {
+        format!(
+            "rx/tx errors {}/{} · drops {}/{} · collisions {}",
+            self.rx_errors, self.tx_errors, self.rx_dropped, self.tx_dropped, self.collisions,
+        )
+    }
+
+    /// Counts since `baseline`; counters reset when a driver is reloaded
+    fn since(&self, baseline: &PacketErrors) -> Self {
+        Self {
+            rx_errors: self.rx_errors.saturating_sub(baseline.rx_errors),
+            tx_errors: self.tx_errors.saturating_sub(baseline.tx_errors),
+            rx_dropped: self.rx_dropped.saturating_sub(baseline.rx_dropped),
+            tx_dropped: self.tx_dropped.saturating_sub(baseline.tx_dropped),
+            collisions: self.collisions.saturating_sub(baseline.collisions),
+        }
+    }
+}
+
+/// Throughput and session totals of one network interface, or of all of them
+pub struct NetworkInterface {
+    pub name: String,
+    pub link: Option<Link>, // None for the total
+    pub wireless: Option<Wireless>, // None for wired interfaces and the total
+    pub rx_rate: f32,       // Kbps
+    pub tx_rate: f32,       // Kbps
+    pub error_rate: f32,    // Errors, drops and collisions per second
+    pub peak_rate: f32,     // Highest download or upload rate this session, Kbps
+    initial_bytes: (u64, u64), // Baseline for session totals
+    prev_bytes: (u64, u64),
+    initial_errors: Option<PacketErrors>, // None for the total and without sysfs
+    prev_errors: Option<PacketErrors>,
+    rx_history: History,
+    tx_history: History,
+    error_history: History,
+    signal_history: History,  // dBm
+    iw_link: Option<IwLink>,   // Last answer of `iw`, for wireless interfaces
+    iw_job: Option<Job<IwLink>>,
+}
+
+impl NetworkInterface {
+    /// Start tracking from the interface's current byte counters
+    pub fn new(name: String, rx_bytes: u64, tx_bytes: u64, max_history: usize) -> Self {
+        Self {
+            name,
+            link: None,
+            wireless: None,
+            rx_rate: 0.0,
+            tx_rate: 0.0,
+            error_rate: 0.0,
+            peak_rate: 0.0,
+            initial_bytes: (rx_bytes, tx_bytes),
+            prev_bytes: (rx_bytes, tx_bytes),
+            initial_errors: None,
+            prev_errors: None,
+            rx_history: History::new(max_history),
+            tx_history: History::new(max_history),
+            error_history: History::new(max_history),
+            signal_history: History::new(max_history),
+            iw_link: None,
+            iw_job: None,
+        }
+    }
+
+    /// Continue from different cumulative counters without a jump in the rates or the
+    /// session totals, e.g. after the interfaces making up a total changed
+    pub fn rebase(&mut self, rx_bytes: u64, tx_bytes: u64) {
+        let (session_rx, session_tx) = self.session_bytes();
+        self.initial_bytes = (rx_bytes.saturating_sub(session_rx), tx_bytes.saturating_sub(session_tx));
+        self.prev_bytes = (rx_bytes, tx_bytes);
+    }
+
+    /// Rates from the cumulative byte counters, `seconds` after the previous update
+    pub fn update(&mut self, rx_bytes: u64, tx_bytes: u64, seconds: f32, retention: Option<Duration>) {
+        let seconds = seconds.max(0.001);
+        // Bytes per second, converted to Kbps (bits per second / 1000)
+        let rate = |current: u64, prev: u64| current.saturating_sub(prev) as f32 / seconds * 8.0 / 1000.0;
+        self.rx_rate = rate(rx_bytes, self.prev_bytes.0);
+        self.tx_rate = rate(tx_bytes, self.prev_bytes.1);
+        self.prev_bytes = (rx_bytes, tx_bytes);
+        self.peak_rate = self.peak_rate.max(self.rx_rate).max(self.tx_rate);
+        self.rx_history.push(self.rx_rate, retention);
+        self.tx_history.push(self.tx_rate, retention);
+    }
+
+    /// Error and drop rate from the interface's counters, `seconds` after the previous
+    /// update. The first call sets the baseline for the session counts.
+    pub fn update_errors(&mut self, seconds: f32, retention: Option<Duration>) {
+        let Some(current) = PacketErrors::read(&self.name) else {
+            return;
+        };
+        if let Some(prev) = self.prev_errors {
+            self.error_rate = current.since(&prev).total() as f32 / seconds.max(0.001);
+            self.error_history.push(self.error_rate, retention);
+        }
+        self.initial_errors.get_or_insert(current);
+        self.prev_errors = Some(current);
+    }
+
+    /// Read the link details again; they change when cables are plugged or addresses
+    /// are assigned
+    pub fn refresh_link(&mut self, network: &NetworkData) {
+        self.link = Some(read_link(&self.name, network));
+    }
+
+    /// Wireless link state from this update's /proc/net/wireless entry, if the
+    /// interface has one, completed by the latest answer of `iw`, which is asked
+    /// again in the background
+    pub fn update_wireless(&mut self, wireless: Option<Wireless>, retention: Option<Duration>) {
+        if wireless.is_none() {
+            self.iw_link = None;
+            self.iw_job = None;
+        }
+        if let Some(result) = self.iw_job.as_ref().and_then(Job::try_result) {
+            self.iw_link = result.ok();
+            self.iw_job = None;
+        }
+        if wireless.is_some() && self.iw_job.is_none() {
+            let name = self.name.clone();
+            self.iw_job = Some(Job::spawn(move || IwLink::read(&name)));
+        }
+        self.wireless = wireless.map(|mut wireless| {
+            if let Some(iw_link) = &self.iw_link {
+                wireless.ssid = iw_link.ssid.clone();
+                wireless.signal = wireless.signal.or(iw_link.signal);
+                wireless.bitrate = iw_link.bitrate;
+            }
+            if let Some(signal) = wireless.signal {
+                self.signal_history.push(signal, retention);
+            }
+            wireless
+        });
+    }
+
+    /// Full scale of the rate gauges in Kbps and where it comes from: the negotiated
+    /// link speed, or the session peak rounded up to 1, 2 or 5 × 10ⁿ
+    pub fn gauge_scale(&self) -> (f32, &'static str) {
+        if let Some(speed) = self.link.as_ref().and_then(|link| link.speed) {
+            return (speed as f32 * 1000.0, "link speed");
+        }
+        let peak = self.peak_rate.max(MIN_GAUGE_SCALE);
+        let magnitude = 10f32.powf(peak.log10().floor());
+        let scale = [1.0, 2.0, 5.0, 10.0]
+            .into_iter()
+            .map(|step| step * magnitude)
+            .find(|&scale| scale >= peak)
+            .unwrap_or(10.0 * magnitude);
+        (scale, "session peak")
+    }
+
+    /// Bytes received and sent since rmon started
+    pub fn session_bytes(&self) -> (u64, u64) {
+        (
+            self.prev_bytes.0.saturating_sub(self.initial_bytes.0),
+            self.prev_bytes.1.saturating_sub(self.initial_bytes.1),
+        )
+    }
+
+    /// Percent of `total`'s current throughput and of its session transfer this one
+    /// makes up, e.g. the tunneled share of the uplink; None while `total` is idle
+    pub fn share_of(&self, total: &NetworkInterface) -> (Option<f32>, Option<f32>) {
+        let percent = |part: f64, whole: f64| (whole > 0.0).then(|| (part / whole * 100.0).min(100.0) as f32);
+        let ((rx, tx), (total_rx, total_tx)) = (self.session_bytes(), total.session_bytes());
+        (
+            percent((self.rx_rate + self.tx_rate) as f64, (total.rx_rate + total.tx_rate) as f64),
+            percent((rx + tx) as f64, (total_rx + total_tx) as f64),
+        )
+    }
+
+    /// Errors, drops and collisions since rmon started
+    pub fn session_errors(&self) -> Option<PacketErrors> {
+        Some(self.prev_errors?.since(&self.initial_errors?))
+    }
+
+    pub fn rx_history(&self) -> &History {
+        &self.rx_history
+    }
+
+    pub fn tx_history(&self) -> &History {
+        &self.tx_history
+    }
+
+    /// Download and upload histories, to carry them across restarts
+    pub fn rate_histories_mut(&mut self) -> [&mut History; 2] {
+        [&mut self.rx_history, &mut self.tx_history]
+    }
+
+    pub fn error_history(&self) -> &History {
+        &self.error_history
+    }
+
+    pub fn signal_history(&self) -> &History {
+        &self.signal_history
+    }
+}
+
+/// WireGuard and tun interfaces (OpenVPN, Tailscale, ...)
+pub fn is_vpn(name: &str) -> bool {
+    let sysfs = Path::new("/sys/class/net").join(name);
+    if let Ok(flags) = fs::read_to_string(sysfs.join("tun_flags")) {
+        return u32::from_str_radix(flags.trim().trim_start_matches("0x"), 16).is_ok_and(|flags| flags & IFF_TUN != 0);
+    }
+    fs::read_to_string(sysfs.join("uevent")).is_ok_and(|uevent| uevent.lines().any(|line| line == "DEVTYPE=wireguard"))
+        || (!sysfs.exists() && VPN_PREFIXES.iter().any(|prefix| name.starts_with(prefix)))
+}
+
+/// Counted interfaces first, then by name
+pub fn sort_interfaces(interfaces: &mut [NetworkInterface], filter: &InterfaceFilter) {
+    interfaces.sort_by_cached_key(|interface| (filter.is_excluded(&interface.name), interface.name.clone()));
+}
+
+/// Shell-style match of a whole name, where `*` matches any run of characters and `?`
+/// any single one
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let (pattern, name): (Vec<char>, Vec<char>) = (pattern.chars().collect(), name.chars().collect());
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` and the name position it was tried at, to backtrack to
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    // Let the `*` swallow one more character
+                    star = Some((star_p, star_n + 1));
+                    p = star_p + 1;
+                    n = star_n + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Default routes with a gateway, IPv4 first, lowest metric first
+pub fn default_gateways() -> Vec<Gateway> {
+    routes::read_routes()
+        .into_iter()
+        .filter(Route::is_default)
+        .filter_map(|route| {
+            Some(Gateway {
+                address: route.gateway?,
+                interface: route.interface,
+                metric: route.metric,
+            })
+        })
+        .collect()
+}
+
+/// Quality and signal level of every wireless interface, from /proc/net/wireless:
+///
+/// ```text
+/// Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE
+///  face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+///  wlp2s0: 0000   54.  -56.  -256        0      0      0      0      0        0
+/// ```
+///
+/// Values end in a dot when updated since the last read. A positive level is a
+/// driver-specific relative value rather than dBm.
+pub fn read_wireless() -> HashMap<String, Wireless> {
+    let Ok(contents) = fs::read_to_string("/proc/net/wireless") else {
+        return HashMap::new();
+    };
+    contents
+        .lines()
+        .skip(2)
+        .filter_map(|line| {
+            let (name, values) = line.split_once(':')?;
+            let mut values = values.split_whitespace().skip(1).map(|value| value.trim_end_matches('.').parse::<f32>().ok());
+            let (quality, level) = (values.next()??, values.next()??);
+            let wireless = Wireless {
+                quality: (quality / MAX_LINK_QUALITY * 100.0).min(100.0),
+                signal: (level < 0.0).then_some(level),
+                ..Default::default()
+            };
+            Some((name.trim().to_string(), wireless))
+        })
+        .collect()
+}
+
+/// Link details from /sys/class/net. Speed is -1 or unreadable and duplex "unknown"
+/// for interfaces without a negotiated link (down, wireless, virtual).
+fn read_link(name: &str, network: &NetworkData) -> Link {
+    let read = |file: &str| {
+        fs::read_to_string(format!("/sys/class/net/{}/{}", name, file))
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+    let mut addresses: Vec<_> = network.ip_networks().to_vec();
+    addresses.sort_by_key(|address| (address.addr.is_ipv6(), address.addr));
+    let mac = network.mac_address();
+    Link {
+        state: read("operstate").unwrap_or_else(|| "unknown".to_string()),
+        speed: read("speed")
+            .and_then(|speed| speed.parse::<i64>().ok())
+            .filter(|&speed| speed > 0)
+            .map(|speed| speed as u32),
+        duplex: read("duplex").filter(|duplex| duplex == "full" || duplex == "half"),
+        mtu: read("mtu").and_then(|mtu| mtu.parse().ok()),
+        mac: (!mac.is_unspecified()).then(|| mac.to_string()),
+        addresses: addresses.iter().map(|address| address.to_string()).collect(),
+        master: fs::read_link(format!("/sys/class/net/{}/master", name))
+            .ok()
+            .and_then(|master| Some(master.file_name()?.to_string_lossy().into_owned())),
+        aggregate: read_bond(name).or_else(|| read_bridge(name)),
+    }
+}
+
+/// Slaves of a bond and their state, from /sys/class/net/<bond>/bonding, e.g. mode
+/// "active-backup 1" and slaves "eth0 eth1", and each slave's bonding_slave directory
+fn read_bond(name: &str) -> Option<Aggregate> {
+    let bonding = Path::new("/sys/class/net").join(name).join("bonding");
+    let read = |path: PathBuf| fs::read_to_string(path).ok().map(|value| value.trim().to_string());
+    let mode = read(bonding.join("mode"))?;
+    let members = read(bonding.join("slaves"))
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|slave| {
+            let slave_dir = Path::new("/sys/class/net").join(slave).join("bonding_slave");
+            Member {
+                name: slave.to_string(),
+                up: read(slave_dir.join("mii_status")).is_some_and(|status| status == "up"),
+                state: read(slave_dir.join("state")).unwrap_or_else(|| "unknown".to_string()),
+                link_failures: read(slave_dir.join("link_failure_count")).and_then(|count| count.parse().ok()),
+            }
+        })
+        .collect();
+    Some(Aggregate {
+        kind: AggregateKind::Bond {
+            mode: mode.split_whitespace().next().unwrap_or_default().to_string(),
+            active: read(bonding.join("active_slave")).filter(|slave| !slave.is_empty()),
+        },
+        members,
+    })
+}
+
+/// Ports of a bridge from /sys/class/net/<bridge>/brif, with their STP state
+fn read_bridge(name: &str) -> Option<Aggregate> {
+    let ports = fs::read_dir(Path::new("/sys/class/net").join(name).join("brif")).ok()?;
+    let mut members: Vec<Member> = ports
+        .flatten()
+        .map(|port| {
+            let port_name = port.file_name().to_string_lossy().into_owned();
+            let read = |path: PathBuf| fs::read_to_string(path).ok().map(|value| value.trim().to_string());
+            // BR_STATE_* from include/uapi/linux/if_bridge.h
+            let state = match read(port.path().join("state")).as_deref() {
+                Some("0") => "disabled",
+                Some("1") => "listening",
+                Some("2") => "learning",
+                Some("3") => "forwarding",
+                Some("4") => "blocking",
+                _ => "unknown",
+            };
+            let operstate = read(Path::new("/sys/class/net").join(&port_name).join("operstate"));
+            Member {
+                // Blocking is STP keeping a redundant port in reserve, not a failure
+                up: operstate.as_deref() != Some("down") && state != "disabled",
+                name: port_name,
+                state: state.to_string(),
+                link_failures: None,
+            }
+        })
+        .collect();
+    members.sort_by(|a, b| a.name.cmp(&b.name));
+    Some(Aggregate { kind: AggregateKind::Bridge, members })
+}
@@ -0,0 +1,329 @@
+use std::fs;
+
+/// How long requests may go unanswered before an NFS mount is flagged as not
+/// responding. Hard mounts block every process touching them meanwhile.
+pub const NOT_RESPONDING_SECS: f32 = 10.0;
+
+/// Cumulative RPC counters for one NFS mount, summed over all operations. SMB mounts
+/// only have an operation count, from the cifs module's statistics.
+#[derive(Clone, Default)]
+pub struct NfsCounters {
+    pub device: String,
+    pub mount_point: String,
+    pub fstype: String,
+    pub ops: u64,
+    pub transmissions: u64,
+    pub timeouts: u64,
+    pub rtt_ms: u64,
+    pub exec_ms: u64,
+    pub sends: u64,   // RPC requests put on the wire, retransmissions included, over all transports
+    pub replies: u64, // Replies received to them
+}
+
+/// Per-interval NFS statistics derived from two counter samples
+#[derive(Clone)]
+pub struct NfsMountStats {
+    pub device: String,
+    pub mount_point: String,
+    pub fstype: String,
+    pub ops_per_sec: f32,
+    /// Retransmissions (transmissions beyond the first per op) per second
+    pub retrans_per_sec: f32,
+    pub timeouts: u64,
+    /// Average round trip time per op in this interval
+    pub avg_rtt_ms: Option<f32>,
+    /// Average total execution time per op (queue + RTT) in this interval
+    pub avg_exec_ms: Option<f32>,
+    /// Seconds requests have been waiting without any reply coming back
+    pub unanswered_secs: f32,
+}
+
+impl NfsMountStats {
+    /// `unanswered_secs` carries over from the mount's previous stats
+    pub fn from_samples(prev: Option<&NfsCounters>, current: &NfsCounters, seconds: f32, unanswered_secs: f32) -> Self {
+        let zero = NfsCounters::default();
+        let prev = prev.unwrap_or(&zero);
+        let ops = current.ops.saturating_sub(prev.ops);
+        let transmissions = current.transmissions.saturating_sub(prev.transmissions);
+        let retrans = transmissions.saturating_sub(ops);
+        let per_op = |total: u64| (ops > 0).then(|| total as f32 / ops as f32);
+        let seconds = seconds.max(0.001);
+        // Requests sent in this interval with no reply coming back. The lifetime totals
+        // cannot tell: sends include retransmissions, so they stay ahead of replies for
+        // good after one retransmit. An idle interval ends the wait.
+        let waiting = current.sends > prev.sends && current.replies == prev.replies;
+
+        Self {
+            device: current.device.clone(),
+            mount_point: current.mount_point.clone(),
+            fstype: current.fstype.clone(),
+            ops_per_sec: ops as f32 / seconds,
+            retrans_per_sec: retrans as f32 / seconds,
+            timeouts: current.timeouts,
+            avg_rtt_ms: per_op(current.rtt_ms.saturating_sub(prev.rtt_ms)),
+            avg_exec_ms: per_op(current.exec_ms.saturating_sub(prev.exec_ms)),
+            unanswered_secs: if waiting { unanswered_secs + seconds } else { 0.0 },
+        }
+    }
+
+    /// A cifs or smb3 mount, which has an op rate but no RPC timings
+    pub fn is_smb(&self) -> bool {
+        !self.fstype.starts_with("nfs")
+    }
+
+    /// Requests unanswered for long enough that the server is likely gone or the mount
+    /// hung, the "server not responding" of the kernel log
+    pub fn is_not_responding(&self) -> bool {
+        self.unanswered_secs >= NOT_RESPONDING_SECS
+    }
+}
+
+/// NFS and SMB mounts, the ones tracked here. statvfs and other calls on them block
+/// while the server is unreachable on hard mounts, the default.
+pub fn is_network_filesystem(fstype: &str) -> bool {
+    fstype.starts_with("nfs") || fstype == "cifs" || fstype == "smb3"
+}
+
+/// Read counters for every NFS and SMB mount from /proc/self/mountstats, with SMB
+/// operation counts from /proc/fs/cifs/Stats where the cifs module keeps them
+pub fn read_nfs_counters() -> Vec<NfsCounters> {
+    let mut mounts = match fs::read_to_string("/proc/self/mountstats") {
+        Ok(contents) => parse_mountstats(&contents),
+        Err(_) => Vec::new(),
+    };
+    if mounts.iter().any(|mount| !mount.fstype.starts_with("nfs")) {
+        if let Ok(contents) = fs::read_to_string("/proc/fs/cifs/Stats") {
+            let shares = parse_cifs_stats(&contents);
+            for mount in mounts.iter_mut().filter(|mount| !mount.fstype.starts_with("nfs")) {
+                // Shares are listed as \\server\share, mounts as //server/share
+                if let Some((_, ops)) = shares.iter().find(|(share, _)| share.eq_ignore_ascii_case(&mount.device)) {
+                    mount.ops = *ops;
+                }
+            }
+        }
+    }
+    mounts
+}
+
+/// Request count of each share in /proc/fs/cifs/Stats, keyed by its UNC path with
+/// forward slashes:
+///
+/// ```text
+/// 1) \\fileserver\projects
+/// SMBs: 2042
+/// ```
+fn parse_cifs_stats(contents: &str) -> Vec<(String, u64)> {
+    let mut shares = Vec::new();
+    let mut share: Option<String> = None;
+    for line in contents.lines().map(str::trim) {
+        if let Some((number, path)) = line.split_once(") ") {
+            if number.parse::<u32>().is_ok() && path.starts_with("\\\\") {
+                share = Some(path.split_whitespace().next().unwrap_or(path).replace('\\', "/"));
+            }
+        } else if let Some(count) = line.strip_prefix("SMBs:") {
+            if let (Some(share), Ok(count)) = (share.take(), count.split_whitespace().next().unwrap_or_default().parse()) {
+                shares.push((share, count));
+            }
+        }
+    }
+    shares
+}
+
+fn parse_mountstats(contents: &str) -> Vec<NfsCounters> {
+    let mut mounts = Vec::new();
+    let mut current: Option<NfsCounters> = None;
+    let mut in_per_op = false;
+
+    for line in contents.lines() {
+        // device server:/export mounted on /mnt/data with fstype nfs4 statvers=1.1
+        if let Some(rest) = line.strip_prefix("device ") {
+            mounts.extend(current.take());
+            in_per_op = false;
+
+            let parts: Vec<&str> = rest.split_whitespace().collect();
+            if parts.len() >= 6 && parts[1] == "mounted" && parts[2] == "on" && parts[4] == "with" {
+                let fstype = parts.get(6).copied().unwrap_or("");
+                if is_network_filesystem(fstype) {
+                    current = Some(NfsCounters {
+                        device: parts[0].to_string(),
+                        mount_point: unescape(parts[3]),
+                        fstype: fstype.to_string(),
+                        ..Default::default()
+                    });
+                }
+            }
+            continue;
+        }
+
+        let Some(mount) = current.as_mut() else { continue };
+        let line = line.trim();
+        if line == "per-op statistics" {
+            in_per_op = true;
+            continue;
+        }
+
+        // xprt: tcp port bind_count connect_count connect_time idle_time sends recvs ...
+        // xprt: udp port bind_count sends recvs ...
+        // One line per connection with nconnect
+        if let Some(xprt) = line.strip_prefix("xprt:") {
+            let fields: Vec<&str> = xprt.split_whitespace().collect();
+            let first = if fields.first() == Some(&"udp") { 3 } else { 6 };
+            if let (Some(Ok(sends)), Some(Ok(replies))) =
+                (fields.get(first).map(|v| v.parse::<u64>()), fields.get(first + 1).map(|v| v.parse::<u64>()))
+            {
+                mount.sends += sends;
+                mount.replies += replies;
+            }
+            continue;
+        }
+
+        // READ: ops transmissions timeouts bytes_sent bytes_recv queue_ms rtt_ms exec_ms [errors]
+        if in_per_op {
+            if let Some((_, counters)) = line.split_once(':') {
+                let values: Vec<u64> = counters.split_whitespace().filter_map(|v| v.parse().ok()).collect();
+                if values.len() >= 8 {
+                    mount.ops += values[0];
+                    mount.transmissions += values[1];
+                    mount.timeouts += values[2];
+                    mount.rtt_ms += values[6];
+                    mount.exec_ms += values[7];
+                }
+            }
+        }
+    }
+
+    mounts.extend(current);
+    mounts
+}
+
+/// Mount points escape spaces and other specials as octal (`\040`)
+fn unescape(path: &str) -> String {
+    let mut out = String::with_capacity(path.len());
+    let mut chars = path.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let digits: String = chars.clone().take(3).collect();
+            if digits.len() == 3 {
+                if let Ok(code) = u8::from_str_radix(&digits, 8) {
+                    out.push(code as char);
+                    chars.nth(2);
+                    continue;
+                }
+            }
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOUNTSTATS: &str = "\
+device proc mounted on /proc with fstype proc
+device /dev/sda1 mounted on / with fstype ext4
+device server:/export mounted on /mnt/my\\040data with fstype nfs4 statvers=1.1
+	opts:	rw,vers=4.2,rsize=1048576,wsize=1048576,hard,proto=tcp,nconnect=2
+	age:	3600
+	events:	10 20 0 0 5 3 30 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+	bytes:	409600 204800 0 0 409600 204800 100 50
+	RPC iostats version: 1.1  p/v: 100003/4 (nfs)
+	xprt:	tcp 0 1 2 0 0 1000 990 0 5000 0 2 0 0
+	xprt:	tcp 0 1 1 0 0 500 500 0 2000 0 1 0 0
+	per-op statistics
+	        NULL: 1 1 0 44 24 0 0 0 0
+	        READ: 100 105 5 15000 409600 10 2000 2100 0
+	       WRITE: 50 50 0 204800 7200 5 1000 1100 0
+device old:/srv mounted on /mnt/old with fstype nfs statvers=1.1
+	RPC iostats version: 1.1  p/v: 100003/3 (nfs)
+	xprt:	udp 0 1 40 38 0 40 0 0 0
+	per-op statistics
+	     GETATTR: 20 22 2 2400 2000 0 60 70
+device //fileserver/projects mounted on /mnt/projects with fstype cifs
+";
+
+    #[test]
+    fn parses_nfs_and_smb_mounts() {
+        let mounts = parse_mountstats(MOUNTSTATS);
+        let names: Vec<(&str, &str, &str)> =
+            mounts.iter().map(|mount| (mount.device.as_str(), mount.mount_point.as_str(), mount.fstype.as_str())).collect();
+        assert_eq!(
+            names,
+            [
+                ("server:/export", "/mnt/my data", "nfs4"),
+                ("old:/srv", "/mnt/old", "nfs"),
+                ("//fileserver/projects", "/mnt/projects", "cifs"),
+            ]
+        );
+    }
+
+    #[test]
+    fn sums_per_op_counters() {
+        let mount = &parse_mountstats(MOUNTSTATS)[0];
+        assert_eq!((mount.ops, mount.transmissions, mount.timeouts), (151, 156, 5));
+        assert_eq!((mount.rtt_ms, mount.exec_ms), (3000, 3200));
+    }
+
+    #[test]
+    fn sums_sends_and_replies_over_connections() {
+        let mounts = parse_mountstats(MOUNTSTATS);
+        // Two TCP connections with nconnect
+        assert_eq!((mounts[0].sends, mounts[0].replies), (1500, 1490));
+        // UDP lists fewer fields before the counts
+        assert_eq!((mounts[1].sends, mounts[1].replies), (40, 38));
+        assert_eq!((mounts[2].ops, mounts[2].sends), (0, 0));
+    }
+
+    #[test]
+    fn ignores_truncated_lines() {
+        let mounts = parse_mountstats("device server:/export mounted on /mnt with fstype nfs4\n\txprt:\ttcp 0 1\n\tper-op statistics\n\tREAD: 1 2\n");
+        assert_eq!(mounts.len(), 1);
+        assert_eq!((mounts[0].ops, mounts[0].sends), (0, 0));
+        assert!(parse_mountstats("device server:/export mounted on\n").is_empty());
+    }
+
+    #[test]
+    fn unescapes_mount_points() {
+        assert_eq!(unescape("/mnt/my\\040data"), "/mnt/my data");
+        assert_eq!(unescape("/mnt/tab\\011"), "/mnt/tab\t");
+        assert_eq!(unescape("/mnt/back\\slash"), "/mnt/back\\slash");
+    }
+
+    #[test]
+    fn parses_cifs_stats() {
+        let stats = "\
+Resources in use
+CIFS Session: 1
+Share (unique mount targets): 2
+SMB Request/Response Buffer: 1 Pool size: 5
+Operations (MIDs): 0
+
+0 session 0 share reconnects
+Total vfs operations: 2100 maximum at one time: 3
+
+Max requests in flight: 4
+1) \\\\fileserver\\projects
+SMBs: 2042
+Bytes read: 409600  Bytes written: 204800
+2) \\\\nas\\media Mounts: 2
+SMBs: 17 since 2026-10-16 08:00:00 UTC
+";
+        assert_eq!(parse_cifs_stats(stats), [("//fileserver/projects".to_string(), 2042), ("//nas/media".to_string(), 17)]);
+    }
+
+    #[test]
+    fn waits_only_while_replies_stall() {
+        let sample = |sends, replies| NfsCounters { sends, replies, ..Default::default() };
+        // Retransmissions left sends ahead of replies for good; replies still come back
+        let stats = NfsMountStats::from_samples(Some(&sample(110, 100)), &sample(120, 110), 1.0, 5.0);
+        assert_eq!(stats.unanswered_secs, 0.0);
+        // Nothing sent, nothing to wait for
+        let stats = NfsMountStats::from_samples(Some(&sample(110, 100)), &sample(110, 100), 1.0, 5.0);
+        assert_eq!(stats.unanswered_secs, 0.0);
+        // Sent without any reply
+        let stats = NfsMountStats::from_samples(Some(&sample(110, 100)), &sample(115, 100), 2.0, 9.0);
+        assert_eq!(stats.unanswered_secs, 11.0);
+        assert!(stats.is_not_responding());
+    }
+}
@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Same pace as the CPU panel's own sampling
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Memory and CPU of one NUMA node, from /sys/devices/system/node/node<N>
+pub struct NumaNode {
+    pub id: u32,
+    pub cpus: String,      // As the kernel lists them, e.g. 0-15,32-47
+    pub memory_total: u64, // Bytes
+    pub memory_free: u64,  // Bytes
+    pub cpu_percent: f32,  // Average over the node's CPUs
+    pub foreign_rate: f32, // Pages per second meant for this node but allocated on another
+    cpu_ids: Vec<usize>,
+}
+
+impl NumaNode {
+    pub fn memory_used(&self) -> u64 {
+        self.memory_total.saturating_sub(self.memory_free)
+    }
+
+    pub fn memory_percent(&self) -> f32 {
+        if self.memory_total == 0 {
+            return 0.0;
+        }
+        self.memory_used() as f32 / self.memory_total as f32 * 100.0
+    }
+}
+
+/// Per-node memory usage and CPU utilization on multi-socket machines, where the
+/// global numbers hide one node filling up while another sits idle
+pub struct NumaView {
+    pub nodes: Vec<NumaNode>, // By node id
+    pub error: Option<String>,
+    cpu_times: HashMap<usize, (u64, u64)>, // Busy and total jiffies of each CPU at the previous refresh
+    foreign: HashMap<u32, u64>,            // numa_foreign of each node at the previous refresh
+    last_refresh: Option<Instant>,
+}
+
+impl NumaView {
+    /// None on machines with a single node (or none exposed), where the panel would
+    /// only repeat the CPU and memory panels
+    pub fn new() -> Option<Self> {
+        let nodes = list_nodes();
+        if nodes.len() < 2 {
+            return None;
+        }
+        Some(Self {
+            nodes,
+            error: None,
+            cpu_times: HashMap::new(),
+            foreign: HashMap::new(),
+            last_refresh: None,
+        })
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        let seconds = self.last_refresh.map_or(0.0, |last| last.elapsed().as_secs_f32()).max(0.001);
+        self.last_refresh = Some(Instant::now());
+        let cpu_times = match read_cpu_times() {
+            Ok(cpu_times) => cpu_times,
+            Err(e) => {
+                self.error = Some(format!("/proc/stat: {}", e));
+                return;
+            }
+        };
+
+        for node in &mut self.nodes {
+            let dir = Path::new("/sys/devices/system/node").join(format!("node{}", node.id));
+            // "Node 0 MemTotal:       65842312 kB"
+            if let Ok(meminfo) = fs::read_to_string(dir.join("meminfo")) {
+                let field = |key: &str| {
+                    meminfo.lines().find_map(|line| {
+                        let (_, rest) = line.split_once(key)?;
+                        rest.split_whitespace().next()?.parse::<u64>().ok().map(|kilobytes| kilobytes * 1024)
+                    })
+                };
+                node.memory_total = field("MemTotal:").unwrap_or(0);
+                node.memory_free = field("MemFree:").unwrap_or(0);
+            }
+
+            // Busy share over all of the node's CPUs since the previous refresh
+            let (mut busy, mut total) = (0, 0);
+            for cpu in &node.cpu_ids {
+                if let (Some(&(now_busy, now_total)), Some(&(prev_busy, prev_total))) = (cpu_times.get(cpu), self.cpu_times.get(cpu)) {
+                    busy += now_busy.saturating_sub(prev_busy);
+                    total += now_total.saturating_sub(prev_total);
+                }
+            }
+            node.cpu_percent = if total > 0 { busy as f32 / total as f32 * 100.0 } else { 0.0 };
+
+            // numa_foreign counts pages; there is no rate before the second refresh
+            if let Some(foreign) = read_numastat(&dir, "numa_foreign") {
+                node.foreign_rate = self.foreign.get(&node.id).map_or(0.0, |&previous| foreign.saturating_sub(previous) as f32 / seconds);
+                self.foreign.insert(node.id, foreign);
+            }
+        }
+        self.cpu_times = cpu_times;
+        self.error = None;
+    }
+}
+
+/// Nodes with their CPUs; usage is filled in by the first refresh
+fn list_nodes() -> Vec<NumaNode> {
+    let Ok(entries) = fs::read_dir("/sys/devices/system/node") else {
+        return Vec::new();
+    };
+    let mut nodes: Vec<NumaNode> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let id = entry.file_name().to_str()?.strip_prefix("node")?.parse().ok()?;
+            let cpus = fs::read_to_string(entry.path().join("cpulist")).unwrap_or_default().trim().to_string();
+            Some(NumaNode {
+                id,
+                cpu_ids: parse_cpu_list(&cpus),
+                cpus,
+                memory_total: 0,
+                memory_free: 0,
+                cpu_percent: 0.0,
+                foreign_rate: 0.0,
+            })
+        })
+        .collect();
+    nodes.sort_by_key(|node| node.id);
+    nodes
+}
+
+/// CPU numbers from a kernel CPU list like "0-3,8,10-11"; empty for memory-only nodes
+fn parse_cpu_list(list: &str) -> Vec<usize> {
+    list.split(',')
+        .filter_map(|range| match range.split_once('-') {
+            Some((first, last)) => Some(first.trim().parse().ok()?..=last.trim().parse().ok()?),
+            None => range.trim().parse().ok().map(|cpu| cpu..=cpu),
+        })
+        .flatten()
+        .collect()
+}
+
+/// Busy and total jiffies of each CPU from the cpuN lines of /proc/stat; idle and
+/// iowait count as not busy
+fn read_cpu_times() -> std::io::Result<HashMap<usize, (u64, u64)>> {
+    let stat = fs::read_to_string("/proc/stat")?;
+    Ok(stat
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let cpu = fields.next()?.strip_prefix("cpu")?.parse().ok()?;
+            // Guest time is already included in user and nice
+            let times: Vec<u64> = fields.take(8).filter_map(|field| field.parse().ok()).collect();
+            let total: u64 = times.iter().sum();
+            let idle = times.get(3).copied().unwrap_or(0) + times.get(4).copied().unwrap_or(0);
+            Some((cpu, (total.saturating_sub(idle), total)))
+        })
+        .collect())
+}
+
+/// A counter of a node's numastat file
+fn read_numastat(dir: &Path, key: &str) -> Option<u64> {
+    let numastat = fs::read_to_string(dir.join("numastat")).ok()?;
+    numastat.lines().find_map(|line| {
+        let (name, value) = line.split_once(' ')?;
+        (name == key).then(|| value.trim().parse().ok())?
+    })
+}
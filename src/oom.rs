@@ -0,0 +1,141 @@
+use chrono::{DateTime, Local};
+use regex::Regex;
+use std::process::Command;
+
+/// A process the kernel's OOM killer picked
+pub struct OomKill {
+    pub time: DateTime<Local>,
+    pub pid: u32,
+    pub process: String,
+    pub rss: u64,               // Bytes: anonymous, file and shared memory
+    pub cgroup: Option<String>, // Memory cgroup of the task, e.g. /system.slice/foo.service
+    pub cgroup_limit: bool,     // Killed for hitting a cgroup's limit rather than system memory
+}
+
+impl OomKill {
+    /// Unit or scope the task ran in, the last part of its cgroup
+    pub fn unit(&self) -> Option<&str> {
+        self.cgroup.as_deref().and_then(|cgroup| cgroup.rsplit('/').next()).filter(|unit| !unit.is_empty())
+    }
+
+    /// "stress (PID 1234)", with the limit that was hit for cgroup kills
+    pub fn summary(&self) -> String {
+        match self.unit().filter(|_| self.cgroup_limit) {
+            Some(unit) => format!("{} (PID {}), memory limit of {}", self.process, self.pid, unit),
+            None => format!("{} (PID {})", self.process, self.pid),
+        }
+    }
+}
+
+/// OOM kills of this boot. The kernel's `oom_kill` counter in /proc/vmstat comes along
+/// with the memory metrics; the kernel log is only searched for the details once it moves.
+pub struct OomWatch {
+    pub kills: Vec<OomKill>, // Newest first
+    pub count: u64,          // Kills this boot, also those the log could not tell us about
+}
+
+impl OomWatch {
+    /// Starts from the kernel's count at startup. Kills from before we started are loaded
+    /// up front so only new ones raise a notice.
+    pub fn new(count: u64) -> Self {
+        let kills = if count > 0 { read_kills() } else { Vec::new() };
+        Self { kills, count }
+    }
+
+    /// Catch up with the kernel's current count. Returns how many kills are new since the
+    /// previous update.
+    pub fn update(&mut self, count: u64) -> usize {
+        if count == self.count {
+            return 0;
+        }
+        let known = self.kills.len();
+        self.count = count;
+        self.kills = read_kills();
+        self.kills.len().saturating_sub(known)
+    }
+}
+
+fn read_kills() -> Vec<OomKill> {
+    read_kernel_log().map(|log| parse_kills(&log)).unwrap_or_default()
+}
+
+/// Kernel messages of this boot as (time, message): from the journal, or from dmesg
+/// on systems without one. Either may need root or the adm group.
+fn read_kernel_log() -> Option<Vec<(DateTime<Local>, String)>> {
+    // 1697100000.123456 host kernel: Out of memory: Killed process ...
+    let journal = Command::new("timeout")
+        .args(["2s", "journalctl", "-k", "-b", "-q", "--no-pager", "-o", "short-unix"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success());
+    if let Some(output) = journal {
+        let log: Vec<_> = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| {
+                let (timestamp, rest) = line.split_once(' ')?;
+                let (_, message) = rest.split_once(" kernel: ")?;
+                Some((timestamp_time(timestamp.parse().ok()?)?, message.to_string()))
+            })
+            .collect();
+        // Empty where journald is installed but not running, as in containers
+        if !log.is_empty() {
+            return Some(log);
+        }
+    }
+
+    // [12345.678901] Out of memory: Killed process ..., seconds since boot
+    let output = Command::new("timeout").args(["2s", "dmesg"]).output().ok().filter(|output| output.status.success())?;
+    let boot = crate::boot_time()?.timestamp() as f64;
+    let log = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let (uptime, message) = line.strip_prefix('[')?.split_once(']')?;
+            Some((timestamp_time(boot + uptime.trim().parse::<f64>().ok()?)?, message.trim().to_string()))
+        })
+        .collect();
+    Some(log)
+}
+
+fn timestamp_time(seconds: f64) -> Option<DateTime<Local>> {
+    DateTime::from_timestamp(seconds as i64, 0).map(|time| time.with_timezone(&Local))
+}
+
+/// Kills from the kernel's report, newest first. Each kill logs the victim's cgroup
+/// first, then the kill itself:
+///
+/// ```text
+/// oom-kill:constraint=CONSTRAINT_MEMCG,...,task_memcg=/system.slice/foo.service,task=foo,pid=1234,uid=0
+/// Memory cgroup out of memory: Killed process 1234 (foo) total-vm:2048kB, anon-rss:1024kB, file-rss:0kB, shmem-rss:0kB, UID:0 ...
+/// ```
+fn parse_kills(log: &[(DateTime<Local>, String)]) -> Vec<OomKill> {
+    let killed = Regex::new(r"Killed process (\d+) \((.*?)\)").expect("valid kill regex");
+    let rss = Regex::new(r"(?:anon|file|shmem)-rss:(\d+)kB").expect("valid rss regex");
+    let memcg = Regex::new(r"task_memcg=([^,]*),.*pid=(\d+)").expect("valid memcg regex");
+
+    let mut cgroups: Vec<(u32, String)> = Vec::new();
+    let mut kills = Vec::new();
+    for (time, message) in log {
+        if let Some(captures) = memcg.captures(message) {
+            if let Ok(pid) = captures[2].parse() {
+                cgroups.push((pid, captures[1].to_string()));
+            }
+            continue;
+        }
+        let Some(captures) = killed.captures(message) else {
+            continue;
+        };
+        let Ok(pid) = captures[1].parse() else {
+            continue;
+        };
+        kills.push(OomKill {
+            time: *time,
+            pid,
+            process: captures[2].to_string(),
+            rss: rss.captures_iter(message).filter_map(|rss| rss[1].parse::<u64>().ok()).sum::<u64>() * 1024,
+            cgroup: cgroups.iter().rev().find(|(cgroup_pid, _)| *cgroup_pid == pid).map(|(_, cgroup)| cgroup.clone()),
+            cgroup_limit: message.starts_with("Memory cgroup out of memory"),
+        });
+    }
+    kills.reverse();
+    kills
+}
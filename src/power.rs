@@ -0,0 +1,234 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+const POWERCAP_DIR: &str = "/sys/class/powercap";
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// Cumulative energy counter of one RAPL package domain (`intel-rapl:N`)
+#[derive(Clone)]
+pub struct RaplCounter {
+    pub domain: String,
+    pub energy_uj: u64,
+    /// Value at which `energy_uj` wraps back to zero
+    pub max_energy_uj: u64,
+}
+
+/// Read package-level RAPL counters. Sub-domains (`intel-rapl:0:0`, core/uncore/dram)
+/// are already included in their package and are skipped. AMD CPUs expose the same
+/// interface. `energy_uj` is root-only on most kernels, so this is often empty.
+pub fn read_rapl_counters() -> Vec<RaplCounter> {
+    let Ok(entries) = fs::read_dir(POWERCAP_DIR) else {
+        return Vec::new();
+    };
+
+    let mut counters: Vec<RaplCounter> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let domain = entry.file_name().to_string_lossy().into_owned();
+            if !domain.starts_with("intel-rapl:") || domain.matches(':').count() != 1 {
+                return None;
+            }
+            let path = entry.path();
+            Some(RaplCounter {
+                energy_uj: read_u64(&path.join("energy_uj"))?,
+                max_energy_uj: read_u64(&path.join("max_energy_range_uj")).unwrap_or(u64::MAX),
+                domain,
+            })
+        })
+        .collect();
+    counters.sort_by(|a, b| a.domain.cmp(&b.domain));
+    counters
+}
+
+/// Average CPU package power in watts between two samples, summed over all packages
+pub fn rapl_power(prev: &[RaplCounter], current: &[RaplCounter], seconds: f32) -> Option<f32> {
+    if seconds <= 0.0 {
+        return None;
+    }
+
+    let mut total_uj = 0u64;
+    let mut matched = false;
+    for counter in current {
+        let Some(prev) = prev.iter().find(|prev| prev.domain == counter.domain) else {
+            continue;
+        };
+        // The counter wraps at max_energy_range_uj
+        total_uj += if counter.energy_uj >= prev.energy_uj {
+            counter.energy_uj - prev.energy_uj
+        } else {
+            counter.max_energy_uj.saturating_sub(prev.energy_uj) + counter.energy_uj
+        };
+        matched = true;
+    }
+
+    matched.then(|| total_uj as f32 / 1_000_000.0 / seconds)
+}
+
+/// Charging state from the `status` attribute of a battery
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BatteryStatus {
+    Charging,
+    Discharging,
+    Full,
+    /// Plugged in but held below full, e.g. by a charge threshold
+    NotCharging,
+    Unknown,
+}
+
+impl BatteryStatus {
+    fn parse(status: &str) -> Self {
+        match status {
+            "Charging" => Self::Charging,
+            "Discharging" => Self::Discharging,
+            "Full" => Self::Full,
+            "Not charging" => Self::NotCharging,
+            _ => Self::Unknown,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Charging => "Charging",
+            Self::Discharging => "Discharging",
+            Self::Full => "Full",
+            Self::NotCharging => "Not charging",
+            Self::Unknown => "Unknown",
+        }
+    }
+}
+
+/// One battery from /sys/class/power_supply
+#[derive(Clone)]
+pub struct Battery {
+    pub name: String,             // BAT0, BAT1, ...
+    pub percent: f32,
+    pub status: BatteryStatus,
+    pub watts: Option<f32>,       // Charge or discharge rate
+    pub energy_now: Option<f32>,  // Wh
+    pub energy_full: Option<f32>, // Wh, at the current (worn) capacity
+    /// Time until empty while discharging, or until full while charging
+    pub time_remaining: Option<Duration>,
+}
+
+/// Every battery, sorted by name. Empty on desktops and most VMs.
+pub fn read_batteries() -> Vec<Battery> {
+    let Ok(entries) = fs::read_dir(POWER_SUPPLY_DIR) else {
+        return Vec::new();
+    };
+
+    let mut batteries: Vec<Battery> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            // Peripherals (mice, keyboards) report scope "Device" and do not power the system
+            if read_string(&path.join("type")).as_deref() != Some("Battery")
+                || read_string(&path.join("scope")).as_deref() == Some("Device")
+            {
+                return None;
+            }
+            let status = BatteryStatus::parse(&read_string(&path.join("status")).unwrap_or_default());
+
+            // Drivers report either energy (µWh) and power (µW), or charge (µAh) and
+            // current (µA) to be multiplied by the voltage (µV). Some report the
+            // current as negative while discharging.
+            let voltage = read_f64(&path.join("voltage_now")).map(|microvolts| microvolts / 1_000_000.0);
+            let energy = |name: &str| {
+                read_f64(&path.join(format!("energy_{}", name)))
+                    .or_else(|| Some(read_f64(&path.join(format!("charge_{}", name)))? * voltage?))
+                    .map(|microwatt_hours| (microwatt_hours / 1_000_000.0) as f32)
+            };
+            let energy_now = energy("now");
+            let energy_full = energy("full");
+            let watts = read_f64(&path.join("power_now"))
+                .or_else(|| Some(read_f64(&path.join("current_now"))? * voltage?))
+                .map(|microwatts| (microwatts.abs() / 1_000_000.0) as f32);
+
+            let percent = read_f64(&path.join("capacity")).map(|percent| percent as f32).or_else(|| {
+                let (now, full) = (energy_now?, energy_full?);
+                (full > 0.0).then(|| now / full * 100.0)
+            })?;
+
+            // Prefer the driver's own estimate (ACPI batteries have none)
+            let hours_left = match status {
+                BatteryStatus::Discharging => energy_now,
+                BatteryStatus::Charging => energy_full.zip(energy_now).map(|(full, now)| (full - now).max(0.0)),
+                _ => None,
+            }
+            .zip(watts.filter(|watts| *watts > 0.0))
+            .map(|(watt_hours, watts)| watt_hours / watts);
+            let time_file = if status == BatteryStatus::Charging { "time_to_full_now" } else { "time_to_empty_now" };
+            let time_remaining = read_u64(&path.join(time_file))
+                .filter(|seconds| *seconds > 0)
+                .map(Duration::from_secs)
+                .or_else(|| hours_left.map(|hours| Duration::from_secs_f32(hours * 3600.0)));
+
+            Some(Battery {
+                name: entry.file_name().to_string_lossy().into_owned(),
+                percent: percent.min(100.0),
+                status,
+                watts,
+                energy_now,
+                energy_full,
+                time_remaining,
+            })
+        })
+        .collect();
+    batteries.sort_by(|a, b| a.name.cmp(&b.name));
+    batteries
+}
+
+/// Battery discharge rate in watts, summed over all batteries.
+/// `None` without a battery, `Some(0.0)` while charging or on AC.
+pub fn battery_discharge(batteries: &[Battery]) -> Option<f32> {
+    (!batteries.is_empty()).then(|| {
+        batteries
+            .iter()
+            .filter(|battery| battery.status == BatteryStatus::Discharging)
+            .filter_map(|battery| battery.watts)
+            .sum()
+    })
+}
+
+/// Charge of all batteries together in percent, weighted by capacity where known
+pub fn battery_charge(batteries: &[Battery]) -> Option<f32> {
+    if batteries.is_empty() {
+        return None;
+    }
+    let energies: Option<Vec<(f32, f32)>> = batteries
+        .iter()
+        .map(|battery| battery.energy_now.zip(battery.energy_full))
+        .collect();
+    let (now, full) = energies
+        .unwrap_or_default()
+        .iter()
+        .fold((0.0, 0.0), |(now, full), &(battery_now, battery_full)| (now + battery_now, full + battery_full));
+    if full > 0.0 {
+        Some((now / full * 100.0).min(100.0))
+    } else {
+        Some(batteries.iter().map(|battery| battery.percent).sum::<f32>() / batteries.len() as f32)
+    }
+}
+
+/// Best estimate of whole-system draw. On battery the discharge rate already covers
+/// everything, CPU and GPU included, so it is used alone; otherwise the measured
+/// components are summed.
+pub fn total_power(cpu: Option<f32>, gpu: Option<f32>, battery: Option<f32>) -> Option<f32> {
+    match (battery, cpu, gpu) {
+        (Some(battery), _, _) if battery > 0.0 => Some(battery),
+        (_, None, None) => None,
+        (_, cpu, gpu) => Some(cpu.unwrap_or(0.0) + gpu.unwrap_or(0.0)),
+    }
+}
+
+fn read_string(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+fn read_u64(path: &Path) -> Option<u64> {
+    read_string(path)?.parse().ok()
+}
+
+fn read_f64(path: &Path) -> Option<f64> {
+    read_string(path)?.parse().ok()
+}
@@ -0,0 +1,67 @@
+use std::fs;
+use std::time::Duration;
+
+use crate::history::History;
+
+/// Resources with a file in /proc/pressure
+pub const RESOURCES: [&str; 3] = ["cpu", "memory", "io"];
+
+/// Pressure stall information of one resource: the share of the last 10 seconds in
+/// which some / all non-idle tasks were stalled waiting for it
+pub struct Pressure {
+    pub resource: &'static str,
+    pub some: f32,         // Percent
+    pub full: Option<f32>, // Percent; None for CPU before Linux 5.13
+    some_history: History,
+    full_history: History,
+}
+
+impl Pressure {
+    pub fn new(resource: &'static str, max_history: usize) -> Self {
+        Self {
+            resource,
+            some: 0.0,
+            full: None,
+            some_history: History::new(max_history),
+            full_history: History::new(max_history),
+        }
+    }
+
+    pub fn update(&mut self, some: f32, full: Option<f32>, retention: Option<Duration>) {
+        self.some = some;
+        self.full = full;
+        for (history, value) in [(&mut self.some_history, some), (&mut self.full_history, full.unwrap_or(0.0))] {
+            history.push(value, retention);
+        }
+    }
+
+    pub fn some_history(&self) -> &History {
+        &self.some_history
+    }
+
+    pub fn full_history(&self) -> &History {
+        &self.full_history
+    }
+}
+
+/// avg10 of the `some` and `full` lines of /proc/pressure/<resource>:
+///
+/// ```text
+/// some avg10=3.14 avg60=4.19 avg300=5.63 total=180673760
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+/// ```
+///
+/// None without PSI support (kernels before 4.20, or booted with psi=0).
+pub fn read_pressure(resource: &str) -> Option<(f32, Option<f32>)> {
+    let contents = fs::read_to_string(format!("/proc/pressure/{}", resource)).ok()?;
+    let avg10 = |kind: &str| {
+        contents
+            .lines()
+            .find(|line| line.starts_with(kind))?
+            .split_whitespace()
+            .find_map(|field| field.strip_prefix("avg10="))?
+            .parse::<f32>()
+            .ok()
+    };
+    Some((avg10("some ")?, avg10("full ")))
+}
@@ -0,0 +1,210 @@
+use crate::config::QuotaConfig;
+use crate::network::{InterfaceFilter, NetworkInterface};
+use crate::state::{state_path, write_atomically};
+use chrono::{Datelike, Local, Months, NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Totals are written at most this often while running, and once more on exit
+const SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Quotas are sold in decimal gigabytes
+const BYTES_PER_GB: f64 = 1_000_000_000.0;
+
+/// Bytes received and sent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Transfer {
+    pub rx: u64,
+    pub tx: u64,
+}
+
+impl Transfer {
+    pub fn total(&self) -> u64 {
+        self.rx + self.tx
+    }
+}
+
+/// Contents of the state file
+#[derive(Serialize, Deserialize)]
+struct TrafficState {
+    /// First day of the billing period the totals belong to
+    period_start: NaiveDate,
+    interfaces: BTreeMap<String, Transfer>,
+}
+
+impl TrafficState {
+    fn new(period_start: NaiveDate) -> Self {
+        Self {
+            period_start,
+            interfaces: BTreeMap::new(),
+        }
+    }
+}
+
+/// Transfer per interface in the current billing period, carried over between sessions
+/// through the state file
+pub struct TrafficQuota {
+    pub config: QuotaConfig,
+    pub error: Option<String>, // Last failure reading or writing the state file
+    path: Option<PathBuf>,     // None without a home directory; totals then last one session
+    state: TrafficState,
+    accounted: HashMap<String, Transfer>, // Session bytes of each interface already counted
+    interface_filter: InterfaceFilter,    // The metrics' own, for quotas on all interfaces
+    last_save: Instant,
+}
+
+impl TrafficQuota {
+    /// Transfer of `interfaces` so far counts as accounted, so a tracker replaced on a
+    /// config reload does not add the session's bytes a second time
+    pub fn new(config: QuotaConfig, interfaces: &[NetworkInterface], interface_filter: &InterfaceFilter) -> Self {
+        let path = state_path("traffic.toml");
+        let period_start = period_start(Local::now().date_naive(), config.reset_day);
+        let (state, error) = match path.as_deref().map(load) {
+            Some(Ok(Some(state))) if state.period_start == period_start => (state, None),
+            Some(Err(err)) => (TrafficState::new(period_start), Some(err)),
+            _ => (TrafficState::new(period_start), None),
+        };
+        Self {
+            config,
+            error,
+            path,
+            state,
+            accounted: interfaces
+                .iter()
+                .map(|interface| {
+                    let (rx, tx) = interface.session_bytes();
+                    (interface.name.clone(), Transfer { rx, tx })
+                })
+                .collect(),
+            interface_filter: interface_filter.clone(),
+            last_save: Instant::now(),
+        }
+    }
+
+    /// Add what each interface transferred since the last update, starting over when a
+    /// new billing period begins
+    pub fn update(&mut self, interfaces: &[NetworkInterface], interface_filter: &InterfaceFilter) {
+        if self.interface_filter != *interface_filter {
+            self.interface_filter = interface_filter.clone();
+        }
+        let period_start = period_start(Local::now().date_naive(), self.config.reset_day);
+        if period_start != self.state.period_start {
+            self.state = TrafficState::new(period_start);
+        }
+        for interface in interfaces {
+            let (rx, tx) = interface.session_bytes();
+            let accounted = self.accounted.entry(interface.name.clone()).or_default();
+            // Saturating, as counters restart when an interface is recreated
+            let delta = Transfer {
+                rx: rx.saturating_sub(accounted.rx),
+                tx: tx.saturating_sub(accounted.tx),
+            };
+            *accounted = Transfer { rx, tx };
+            if delta.total() > 0 {
+                let total = self.state.interfaces.entry(interface.name.clone()).or_default();
+                total.rx += delta.rx;
+                total.tx += delta.tx;
+            }
+        }
+        if self.last_save.elapsed() >= SAVE_INTERVAL {
+            self.save();
+        }
+    }
+
+    /// Write the totals to the state file, replacing it atomically
+    pub fn save(&mut self) {
+        self.last_save = Instant::now();
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = toml::to_string(&self.state)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| write_atomically(path, &contents).map_err(|e| e.to_string()));
+        self.error = result.err().map(|err| format!("{}: {}", path.display(), err));
+    }
+
+    /// Transfer counted against the limit
+    pub fn used(&self) -> u64 {
+        match &self.config.interface {
+            Some(name) => self.state.interfaces.get(name).map_or(0, Transfer::total),
+            None => self.interfaces().map(|(_, transfer)| transfer.total()).sum(),
+        }
+    }
+
+    pub fn limit(&self) -> u64 {
+        (self.config.limit * BYTES_PER_GB) as u64
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.used() as f64 / self.limit() as f64 * 100.0
+    }
+
+    /// Interfaces counted against the limit with their totals this period
+    pub fn interfaces(&self) -> impl Iterator<Item = (&String, &Transfer)> {
+        self.state.interfaces.iter().filter(|(name, _)| match &self.config.interface {
+            Some(interface) => *name == interface,
+            None => !self.interface_filter.is_excluded(name),
+        })
+    }
+
+    /// "wlp2s0" or "all interfaces"
+    pub fn scope_label(&self) -> &str {
+        self.config.interface.as_deref().unwrap_or("all interfaces")
+    }
+
+    pub fn period_start(&self) -> NaiveDate {
+        self.state.period_start
+    }
+
+    /// First day of the next billing period
+    pub fn next_reset(&self) -> NaiveDate {
+        self.state.period_start.checked_add_months(Months::new(1)).unwrap_or(self.state.period_start)
+    }
+
+    /// Usage at the end of the period if transfer continues at this period's average
+    /// rate; None during the first day, when the average says little
+    pub fn projected(&self) -> Option<u64> {
+        let start = self.state.period_start.and_time(NaiveTime::MIN);
+        let elapsed = (Local::now().naive_local() - start).num_seconds();
+        let length = (self.next_reset().and_time(NaiveTime::MIN) - start).num_seconds();
+        (elapsed >= 86_400).then(|| (self.used() as f64 * length as f64 / elapsed as f64) as u64)
+    }
+}
+
+impl Drop for TrafficQuota {
+    /// Keep the transfer since the last periodic save, on exit and config reloads alike
+    fn drop(&mut self) {
+        self.save();
+    }
+}
+
+/// "12.34 GB", in the decimal gigabytes the limit is configured in
+pub fn format_gigabytes(bytes: u64) -> String {
+    format!("{:.2} GB", bytes as f64 / BYTES_PER_GB)
+}
+
+/// Billing period containing `today`, which starts on `reset_day` (at most 28, so
+/// every month has it)
+fn period_start(today: NaiveDate, reset_day: u32) -> NaiveDate {
+    let this_month = today.with_day(reset_day).unwrap_or(today);
+    if today.day() >= reset_day {
+        this_month
+    } else {
+        this_month.checked_sub_months(Months::new(1)).unwrap_or(this_month)
+    }
+}
+
+/// None when there is no state file yet
+fn load(path: &Path) -> Result<Option<TrafficState>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).map(Some).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    fs,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    path::Path,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5);
+
+// Route flags from include/uapi/linux/route.h and ipv6_route.h
+const RTF_UP: u32 = 0x0001;
+const RTF_REJECT: u32 = 0x0200;
+const RTF_LOCAL: u32 = 0x8000_0000;
+
+/// One entry of the kernel routing table
+pub struct Route {
+    pub destination: IpAddr,
+    pub prefix: u8,
+    pub gateway: Option<IpAddr>, // None for on-link routes
+    pub interface: String,
+    pub metric: u32,
+}
+
+impl Route {
+    pub fn is_default(&self) -> bool {
+        self.prefix == 0
+    }
+
+    /// "default" or the destination in CIDR notation, like `ip route`
+    pub fn destination_label(&self) -> String {
+        if self.is_default() {
+            "default".to_string()
+        } else {
+            format!("{}/{}", self.destination, self.prefix)
+        }
+    }
+}
+
+/// An ARP (IPv4) or NDP (IPv6) neighbor cache entry
+pub struct Neighbor {
+    pub address: IpAddr,
+    pub mac: Option<String>, // None while unresolved
+    pub interface: String,
+    pub state: String, // REACHABLE, STALE, DELAY, FAILED, ...
+    pub router: bool,  // Advertised itself as an IPv6 router
+}
+
+/// State of the Routes tab: routing table and neighbor cache
+pub struct RoutesView {
+    pub routes: Vec<Route>,
+    pub neighbors: Vec<Neighbor>,
+    pub scroll: usize, // Into the neighbor table, which is the one that grows
+    last_refresh: Option<Instant>,
+}
+
+impl RoutesView {
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            neighbors: Vec::new(),
+            scroll: 0,
+            last_refresh: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        self.routes = read_routes();
+        self.neighbors = read_neighbors();
+        self.scroll = self.scroll.min(self.neighbors.len().saturating_sub(1));
+        self.last_refresh = Some(Instant::now());
+    }
+}
+
+/// Whether this system has a routing table to show (Linux)
+pub fn available() -> bool {
+    Path::new("/proc/net/route").exists()
+}
+
+/// Main routing table from /proc/net/route and /proc/net/ipv6_route, IPv4 first and
+/// default routes first within each, then by metric. IPv4 addresses are little endian
+/// hex, IPv6 ones big endian:
+///
+/// ```text
+/// Iface   Destination  Gateway   Flags  RefCnt  Use  Metric  Mask      ...
+/// eth0    00000000     0101A8C0  0003   0       0    100     00000000  ...
+///
+/// <destination> <prefix> <source> <prefix> <next hop> <metric> <refcnt> <use> <flags> <iface>
+/// 00000000000000000000000000000000 00 ... 00 fe800000000000000000000000000001 00000400 ... 00000003 eth0
+/// ```
+///
+/// Local, broadcast and multicast routes are left out, as `ip route` does.
+pub fn read_routes() -> Vec<Route> {
+    let mut routes = Vec::new();
+    if let Ok(table) = fs::read_to_string("/proc/net/route") {
+        for line in table.lines().skip(1) {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [interface, destination, gateway, flags, _, _, metric, mask, ..] = fields[..] else {
+                continue;
+            };
+            let hex = |value: &str| u32::from_str_radix(value, 16).ok();
+            let (Some(destination), Some(gateway), Some(flags), Some(mask)) = (hex(destination), hex(gateway), hex(flags), hex(mask)) else {
+                continue;
+            };
+            if flags & RTF_UP == 0 || flags & RTF_REJECT != 0 {
+                continue;
+            }
+            routes.push(Route {
+                destination: IpAddr::V4(Ipv4Addr::from(destination.swap_bytes())),
+                prefix: mask.count_ones() as u8,
+                gateway: (gateway != 0).then(|| IpAddr::V4(Ipv4Addr::from(gateway.swap_bytes()))),
+                interface: interface.to_string(),
+                metric: metric.parse().unwrap_or(0),
+            });
+        }
+    }
+    if let Ok(table) = fs::read_to_string("/proc/net/ipv6_route") {
+        for line in table.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [destination, prefix, _, _, next_hop, metric, _, _, flags, interface] = fields[..] else {
+                continue;
+            };
+            let (Ok(destination), Ok(prefix), Ok(next_hop), Ok(metric), Ok(flags)) = (
+                u128::from_str_radix(destination, 16),
+                u8::from_str_radix(prefix, 16),
+                u128::from_str_radix(next_hop, 16),
+                u32::from_str_radix(metric, 16),
+                u32::from_str_radix(flags, 16),
+            ) else {
+                continue;
+            };
+            let destination = Ipv6Addr::from(destination);
+            if flags & RTF_UP == 0 || flags & (RTF_REJECT | RTF_LOCAL) != 0 || destination.is_multicast() {
+                continue;
+            }
+            routes.push(Route {
+                destination: IpAddr::V6(destination),
+                prefix,
+                gateway: (next_hop != 0).then(|| IpAddr::V6(Ipv6Addr::from(next_hop))),
+                interface: interface.to_string(),
+                metric,
+            });
+        }
+    }
+    routes.sort_by_key(|route| (route.destination.is_ipv6(), !route.is_default(), route.metric, route.destination));
+    routes
+}
+
+/// Neighbor cache from `ip neigh show`, which covers IPv6 too, e.g.
+/// `192.168.1.1 dev wlp2s0 lladdr 3c:7c:3f:1e:22:a1 REACHABLE` or
+/// `fe80::1 dev wlp2s0 lladdr 3c:7c:3f:1e:22:a1 router STALE`.
+/// Falls back to the IPv4-only /proc/net/arp without iproute2, or when `ip` does not
+/// answer within a second.
+fn read_neighbors() -> Vec<Neighbor> {
+    let output = match Command::new("timeout").args(["1s", "ip", "neigh", "show"]).output() {
+        Ok(output) if output.status.success() => output,
+        _ => return read_arp_cache(),
+    };
+    let mut neighbors: Vec<Neighbor> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| {
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            let value_of = |key: &str| tokens.iter().position(|token| *token == key).and_then(|i| tokens.get(i + 1));
+            Some(Neighbor {
+                address: tokens.first()?.parse().ok()?,
+                mac: value_of("lladdr").map(|mac| mac.to_string()),
+                interface: value_of("dev")?.to_string(),
+                state: tokens.last().filter(|state| state.chars().all(|c| c.is_ascii_uppercase())).map_or("NONE", |state| state).to_string(),
+                router: tokens.contains(&"router"),
+            })
+        })
+        .collect();
+    neighbors.sort_by_key(|neighbor| (neighbor.address.is_ipv6(), neighbor.interface.clone(), neighbor.address));
+    neighbors
+}
+
+/// `192.168.1.1  0x1  0x2  3c:7c:3f:1e:22:a1  *  wlp2s0`; flag 0x2 marks a resolved entry
+fn read_arp_cache() -> Vec<Neighbor> {
+    let Ok(table) = fs::read_to_string("/proc/net/arp") else {
+        return Vec::new();
+    };
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [address, _, flags, mac, _, interface] = fields[..] else {
+                return None;
+            };
+            let complete = u32::from_str_radix(flags.trim_start_matches("0x"), 16).is_ok_and(|flags| flags & 0x2 != 0);
+            Some(Neighbor {
+                address: address.parse().ok()?,
+                mac: complete.then(|| mac.to_string()),
+                interface: interface.to_string(),
+                state: if complete { "COMPLETE" } else { "INCOMPLETE" }.to_string(),
+                router: false,
+            })
+        })
+        .collect()
+}
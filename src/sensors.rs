@@ -0,0 +1,357 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::history::History;
+
+const HWMON_PATH: &str = "/sys/class/hwmon";
+const REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+/// Super I/O and firmware drivers for motherboard sensors (Nuvoton, ITE, Winbond, Fintek,
+/// ASUS/Gigabyte/Dell/ThinkPad firmware), ACPI thermal zones and the Intel chipset
+const BOARD_CHIPS: &[&str] = &[
+    "nct", "it87", "it86", "w83", "f71", "acpitz", "pch_", "asus", "gigabyte_wmi", "dell_smm", "thinkpad",
+];
+
+/// Kind of an hwmon channel, from the prefix of its `*_input` file
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SensorKind {
+    Temperature,
+    Fan,
+    Voltage,
+    Current,
+    Power,
+}
+
+impl SensorKind {
+    /// File prefix and the factor from sysfs units (m°C, RPM, mV, mA, µW) to
+    /// °C, RPM, V, A and W
+    fn from_prefix(prefix: &str) -> Option<(Self, f64)> {
+        match prefix {
+            "temp" => Some((Self::Temperature, 1000.0)),
+            "fan" => Some((Self::Fan, 1.0)),
+            "in" => Some((Self::Voltage, 1000.0)),
+            "curr" => Some((Self::Current, 1000.0)),
+            "power" => Some((Self::Power, 1_000_000.0)),
+            _ => None,
+        }
+    }
+
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Temperature => "°C",
+            Self::Fan => "RPM",
+            Self::Voltage => "V",
+            Self::Current => "A",
+            Self::Power => "W",
+        }
+    }
+}
+
+/// One channel of an hwmon chip, e.g. `coretemp/Package id 0` or `nct6775/fan2`
+pub struct Sensor {
+    pub chip: String,
+    pub label: String,
+    pub kind: SensorKind,
+    pub value: f64,
+    pub lowest: f64,  // Since the tab was opened or min/max were reset
+    pub highest: f64,
+    pub low_limit: Option<f64>,  // *_min from the driver
+    pub high_limit: Option<f64>, // *_crit, or *_max where there is no critical limit
+    key: PathBuf,                // *_input file, to carry min/max across refreshes
+}
+
+impl Sensor {
+    /// Beyond a limit the driver reports
+    pub fn out_of_range(&self) -> bool {
+        self.high_limit.is_some_and(|limit| self.value >= limit)
+            || self.low_limit.is_some_and(|limit| limit > 0.0 && self.value < limit)
+    }
+
+    /// Motherboard, VRM or chipset temperature rather than a CPU, GPU or drive one.
+    /// Unconnected Super I/O inputs read as nonsense like -62 or 127 °C and are left out.
+    pub fn is_board_temperature(&self) -> bool {
+        self.kind == SensorKind::Temperature
+            && BOARD_CHIPS.iter().any(|prefix| self.chip.starts_with(prefix))
+            && self.value > -40.0
+            && self.value < 125.0
+    }
+
+    /// Where a board sensor sits, for the cryptic Super I/O labels. AUXTIN wiring varies
+    /// by board, but it is usually the VRM or the chipset.
+    pub fn board_location(&self) -> Option<&'static str> {
+        let label = self.label.to_ascii_uppercase();
+        if self.chip.starts_with("pch_") || label.starts_with("PCH") {
+            Some("chipset")
+        } else if label.starts_with("SYSTIN") {
+            Some("motherboard")
+        } else if label.starts_with("CPUTIN") {
+            Some("CPU socket")
+        } else if label.starts_with("AUXTIN") {
+            Some("VRM/aux")
+        } else if label.starts_with("PECI") || label.starts_with("TSI") || label.starts_with("SMBUSMASTER") {
+            Some("CPU")
+        } else if self.chip == "acpitz" {
+            Some("ACPI zone")
+        } else {
+            None
+        }
+    }
+
+    /// Label with the board location where it adds something, e.g. "SYSTIN (motherboard)"
+    pub fn board_label(&self) -> String {
+        match self.board_location() {
+            Some(location) if !self.label.to_ascii_lowercase().contains(&location.to_ascii_lowercase()) => {
+                format!("{} ({})", self.label, location)
+            }
+            _ => self.label.clone(),
+        }
+    }
+}
+
+/// State of the Sensors tab: every hwmon channel with min/max tracking, like `sensors`
+pub struct SensorsView {
+    pub sensors: Vec<Sensor>,
+    pub scroll: usize,
+    last_refresh: Option<Instant>,
+}
+
+impl SensorsView {
+    pub fn new() -> Self {
+        let mut view = Self {
+            sensors: Vec::new(),
+            scroll: 0,
+            last_refresh: None,
+        };
+        view.refresh();
+        view
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        self.last_refresh = Some(Instant::now());
+        let previous: HashMap<PathBuf, (f64, f64)> = self
+            .sensors
+            .drain(..)
+            .map(|sensor| (sensor.key, (sensor.lowest, sensor.highest)))
+            .collect();
+        self.sensors = read_sensors();
+        for sensor in &mut self.sensors {
+            if let Some(&(lowest, highest)) = previous.get(&sensor.key) {
+                sensor.lowest = lowest.min(sensor.value);
+                sensor.highest = highest.max(sensor.value);
+            }
+        }
+        self.scroll = self.scroll.min(self.sensors.len().saturating_sub(1));
+    }
+
+    /// Start min/max tracking over from the current values
+    pub fn reset_min_max(&mut self) {
+        for sensor in &mut self.sensors {
+            sensor.lowest = sensor.value;
+            sensor.highest = sensor.value;
+        }
+    }
+}
+
+/// A chassis, CPU or PSU fan with RPM history
+pub struct Fan {
+    pub name: String,
+    pub rpm: f32,
+    pub percent: Option<f32>, // Of fanN_max, or the PWM duty cycle
+    history: History,         // RPM
+    key: PathBuf,             // fanN_input file
+}
+
+impl Fan {
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+}
+
+/// Update `fans` with a new reading of every fan, keeping the history of fans seen
+/// before. Fans are listed in hwmon order.
+pub fn update_fans(fans: &mut Vec<Fan>, max_history: usize, retention: Option<Duration>) {
+    let mut previous: HashMap<PathBuf, History> = fans.drain(..).map(|fan| (fan.key, fan.history)).collect();
+    fans.extend(read_fans(max_history).into_iter().map(|mut fan| {
+        if let Some(history) = previous.remove(&fan.key) {
+            fan.history = history;
+        }
+        fan.history.push(fan.rpm, retention);
+        fan
+    }));
+}
+
+/// fanN_input of every hwmon chip. Boards expose inputs for every header, so unlabeled
+/// fans standing still are taken to be unconnected headers and skipped.
+fn read_fans(max_history: usize) -> Vec<Fan> {
+    let Ok(entries) = fs::read_dir(HWMON_PATH) else {
+        return Vec::new();
+    };
+    let mut chips: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    chips.sort();
+
+    let mut fans = Vec::new();
+    for chip_path in chips {
+        let chip = fs::read_to_string(chip_path.join("name")).map(|name| name.trim().to_string()).unwrap_or_default();
+        let Ok(files) = fs::read_dir(&chip_path) else {
+            continue;
+        };
+        let mut numbers: Vec<u32> = files
+            .flatten()
+            .filter_map(|file| file.file_name().to_str()?.strip_prefix("fan")?.strip_suffix("_input")?.parse().ok())
+            .collect();
+        numbers.sort_unstable();
+        for number in numbers {
+            let key = chip_path.join(format!("fan{}_input", number));
+            let Some(rpm) = read_value(&key) else {
+                continue;
+            };
+            let label = fs::read_to_string(chip_path.join(format!("fan{}_label", number))).ok();
+            if rpm == 0.0 && label.is_none() {
+                continue;
+            }
+            // Duty cycle of the matching PWM output (0-255) where there is no rated maximum
+            let percent = read_value(&chip_path.join(format!("fan{}_max", number)))
+                .filter(|max| *max > 0.0)
+                .map(|max| rpm / max * 100.0)
+                .or_else(|| read_value(&chip_path.join(format!("pwm{}", number))).map(|pwm| pwm / 255.0 * 100.0));
+            let name = match label {
+                Some(label) => label.trim().to_string(),
+                None => format!("{} fan{}", chip, number),
+            };
+            fans.push(Fan {
+                name,
+                rpm: rpm as f32,
+                percent: percent.map(|percent| percent as f32),
+                history: History::new(max_history),
+                key,
+            });
+        }
+    }
+    fans
+}
+
+/// Temperature of an NVMe or SATA/SAS drive
+pub struct DriveTemperature {
+    pub drive: String,         // nvme0, sda, ...
+    pub celsius: f32,
+    pub warning: Option<f32>,  // Where the drive starts throttling or warns, if reported
+}
+
+impl DriveTemperature {
+    pub fn is_hot(&self) -> bool {
+        self.warning.is_some_and(|warning| self.celsius >= warning)
+    }
+}
+
+/// Drive temperatures from the `nvme` hwmon chips and the `drivetemp` driver (SATA),
+/// sorted by drive name. drivetemp has to be loaded (`modprobe drivetemp`).
+pub fn read_drive_temperatures() -> Vec<DriveTemperature> {
+    let Ok(entries) = fs::read_dir(HWMON_PATH) else {
+        return Vec::new();
+    };
+    let mut drives: Vec<DriveTemperature> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.path();
+            let chip = fs::read_to_string(path.join("name")).ok()?;
+            if !matches!(chip.trim(), "nvme" | "drivetemp") {
+                return None;
+            }
+            // temp1 is the composite temperature on NVMe and the only one on SATA
+            let celsius = read_value(&path.join("temp1_input"))? as f32 / 1000.0;
+            let warning = read_value(&path.join("temp1_max"))
+                .or_else(|| read_value(&path.join("temp1_crit")))
+                .map(|millidegrees| millidegrees as f32 / 1000.0);
+            Some(DriveTemperature { drive: drive_name(&path).unwrap_or_else(|| chip.trim().to_string()), celsius, warning })
+        })
+        .collect();
+    drives.sort_by(|a, b| a.drive.cmp(&b.drive));
+    drives
+}
+
+/// Block or controller name behind an hwmon chip. The `device` link points at the NVMe
+/// controller (nvme0) or its PCI device (with an `nvme/nvme0` child), or at a SCSI
+/// device with the disk under `block/sda`.
+fn drive_name(hwmon: &Path) -> Option<String> {
+    let device = fs::canonicalize(hwmon.join("device")).ok()?;
+    let name = device.file_name()?.to_string_lossy().to_string();
+    if name.starts_with("nvme") {
+        return Some(name);
+    }
+    ["nvme", "block"].iter().find_map(|child| {
+        let mut entries = fs::read_dir(device.join(child)).ok()?;
+        Some(entries.next()?.ok()?.file_name().to_string_lossy().to_string())
+    })
+}
+
+/// Motherboard, VRM and chipset temperatures of every board sensor chip
+pub fn read_board_temperatures() -> Vec<Sensor> {
+    read_sensors().into_iter().filter(Sensor::is_board_temperature).collect()
+}
+
+/// Whether there is any hwmon chip; VMs and containers usually have none
+pub fn hwmon_available() -> bool {
+    fs::read_dir(HWMON_PATH).is_ok_and(|mut entries| entries.next().is_some())
+}
+
+/// Every readable channel of every hwmon chip, grouped by chip
+fn read_sensors() -> Vec<Sensor> {
+    let Ok(entries) = fs::read_dir(HWMON_PATH) else {
+        return Vec::new();
+    };
+    let mut chips: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    chips.sort();
+
+    let mut sensors = Vec::new();
+    for chip_path in chips {
+        let chip = fs::read_to_string(chip_path.join("name"))
+            .map(|name| name.trim().to_string())
+            .unwrap_or_else(|_| chip_path.file_name().unwrap_or_default().to_string_lossy().to_string());
+        let Ok(files) = fs::read_dir(&chip_path) else {
+            continue;
+        };
+        let mut chip_sensors: Vec<(SensorKind, u32, Sensor)> = files
+            .flatten()
+            .filter_map(|file| {
+                // temp1_input, fan2_input, in0_input, power1_input, ...
+                let file_name = file.file_name().to_string_lossy().to_string();
+                let channel = file_name.strip_suffix("_input")?;
+                let split = channel.find(|c: char| c.is_ascii_digit())?;
+                let (prefix, number) = channel.split_at(split);
+                let (kind, scale) = SensorKind::from_prefix(prefix)?;
+                // Disconnected channels fail to read with ENODATA or EIO
+                let value = read_value(&file.path())? / scale;
+                let limit = |suffix: &str| read_value(&chip_path.join(format!("{}_{}", channel, suffix))).map(|value| value / scale);
+                let label = fs::read_to_string(chip_path.join(format!("{}_label", channel)))
+                    .map(|label| label.trim().to_string())
+                    .unwrap_or_else(|_| channel.to_string());
+                let sensor = Sensor {
+                    chip: chip.clone(),
+                    label,
+                    kind,
+                    value,
+                    lowest: value,
+                    highest: value,
+                    low_limit: limit("min"),
+                    high_limit: limit("crit").or_else(|| limit("max")),
+                    key: file.path(),
+                };
+                Some((kind, number.parse().ok()?, sensor))
+            })
+            .collect();
+        chip_sensors.sort_by_key(|(kind, number, _)| (*kind as u8, *number));
+        sensors.extend(chip_sensors.into_iter().map(|(_, _, sensor)| sensor));
+    }
+    sensors
+}
+
+fn read_value(path: &Path) -> Option<f64> {
+    fs::read_to_string(path).ok()?.trim().parse().ok()
+}
@@ -0,0 +1,212 @@
+use crate::worker::Job;
+use std::{
+    fs,
+    io::ErrorKind,
+    path::Path,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// Wear moves by a percent over months; SMART reads are not free either
+const REFRESH_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Share of rated endurance used from which a drive is flagged as aging
+pub const WEAR_WARNING_PERCENT: f32 = 80.0;
+
+/// Endurance of one SSD from its SMART data
+pub struct DriveWear {
+    pub drive: String,        // nvme0n1, sda, ...
+    pub percent_used: f32,    // Of rated endurance; NVMe drives count past 100
+    pub written: Option<u64>, // Bytes written by the host over the drive's life
+    pub power_on_hours: Option<u64>,
+    pub spare: Option<f32>, // NVMe available spare, percent
+}
+
+impl DriveWear {
+    pub fn is_worn(&self) -> bool {
+        self.percent_used >= WEAR_WARNING_PERCENT
+    }
+}
+
+/// Result of `read_wear` for each drive, in drive order
+type WearReadings = Vec<(String, Result<Option<DriveWear>, String>)>;
+
+/// SSD wear indicators from `smartctl -A`, for every non-rotational drive that has them.
+/// smartctl runs on a worker thread, since a drive that stops answering holds it up.
+pub struct SsdWear {
+    pub drives: Vec<DriveWear>,
+    pub error: Option<String>, // smartctl refusing to run, usually without root
+    last_refresh: Option<Instant>,
+    job: Option<Job<WearReadings>>,
+}
+
+impl SsdWear {
+    pub fn new() -> Self {
+        Self {
+            drives: Vec::new(),
+            error: None,
+            last_refresh: None,
+            job: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none() && self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        self.job = Some(Job::spawn(read_drives));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Take the readings once the worker is done with them
+    pub fn poll(&mut self) {
+        let Some(result) = self.job.as_ref().and_then(Job::try_result) else {
+            return;
+        };
+        self.job = None;
+        let readings = match result {
+            Ok(readings) => readings,
+            Err(err) => {
+                self.error = Some(err);
+                return;
+            }
+        };
+        self.error = None;
+        let mut drives = Vec::new();
+        for (drive, wear) in readings {
+            match wear {
+                Ok(Some(wear)) => drives.push(wear),
+                // Drives asleep keep their previous reading rather than being woken up
+                Ok(None) => drives.extend(self.drives.iter().position(|wear| wear.drive == drive).map(|i| self.drives.swap_remove(i))),
+                Err(err) => {
+                    self.error = Some(err);
+                    break;
+                }
+            }
+        }
+        self.drives = drives;
+    }
+}
+
+/// Wear of each SSD, up to the first drive smartctl fails on
+fn read_drives() -> WearReadings {
+    let mut readings = Vec::new();
+    for drive in solid_state_drives() {
+        let wear = read_wear(&drive);
+        let failed = wear.is_err();
+        readings.push((drive, wear));
+        if failed {
+            break;
+        }
+    }
+    readings
+}
+
+/// Physical drives in /sys/block that report being non-rotational, sorted by name
+fn solid_state_drives() -> Vec<String> {
+    let Ok(entries) = fs::read_dir("/sys/block") else {
+        return Vec::new();
+    };
+    let mut drives: Vec<String> = entries
+        .flatten()
+        .filter(|entry| entry.path().join("device").exists())
+        .filter(|entry| fs::read_to_string(entry.path().join("queue/rotational")).is_ok_and(|value| value.trim() == "0"))
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .collect();
+    drives.sort();
+    drives
+}
+
+/// Wear of one drive; Ok(None) for drives in standby and ones without wear
+/// indicators (eMMC, virtual disks, USB bridges hiding SMART). Err when smartctl may
+/// not open drives at all, which holds for every other drive too.
+fn read_wear(drive: &str) -> Result<Option<DriveWear>, String> {
+    let device = Path::new("/dev").join(drive);
+    let output = match Command::new("smartctl").args(["-A", "-n", "standby"]).arg(&device).env("LC_ALL", "C").output() {
+        Ok(output) => output,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("failed to run smartctl: {}", e)),
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Exit status bit 1: the device could not be opened, or is asleep
+    if output.status.code().is_some_and(|code| code & 0b10 != 0) {
+        if stdout.contains("Permission denied") || stdout.contains("Operation not permitted") {
+            return Err("smartctl: permission denied (run as root for SSD wear)".to_string());
+        }
+        return Ok(None);
+    }
+    Ok(parse_nvme(drive, &stdout).or_else(|| parse_ata(drive, &stdout)))
+}
+
+/// The NVMe SMART/Health log:
+///
+/// ```text
+/// Available Spare:                    100%
+/// Percentage Used:                    3%
+/// Data Units Written:                 12,345,678 [6.32 TB]
+/// Power On Hours:                     1,234
+/// ```
+fn parse_nvme(drive: &str, output: &str) -> Option<DriveWear> {
+    let field = |name: &str| {
+        output.lines().find_map(|line| {
+            let value = line.strip_prefix(name)?.trim();
+            // Up to the unit or bracketed summary, without thousands separators
+            let number: String = value.split_whitespace().next()?.chars().filter(|c| c.is_ascii_digit()).collect();
+            number.parse::<u64>().ok()
+        })
+    };
+    Some(DriveWear {
+        drive: drive.to_string(),
+        percent_used: field("Percentage Used:")? as f32,
+        // Data units are thousands of 512-byte sectors
+        written: field("Data Units Written:").map(|units| units * 512_000),
+        power_on_hours: field("Power On Hours:"),
+        spare: field("Available Spare:").map(|spare| spare as f32),
+    })
+}
+
+/// Vendor attributes of a SATA SSD:
+///
+/// ```text
+/// ID# ATTRIBUTE_NAME          FLAG     VALUE WORST THRESH TYPE      UPDATED  WHEN_FAILED RAW_VALUE
+///   9 Power_On_Hours          0x0032   096   096   000    Old_age   Always       -       17394
+/// 177 Wear_Leveling_Count     0x0013   095   095   000    Pre-fail  Always       -       53
+/// 241 Total_LBAs_Written      0x0032   099   099   000    Old_age   Always       -       41927883541
+/// ```
+///
+/// The wear attributes count remaining life down from 100 in their normalized value.
+fn parse_ata(drive: &str, output: &str) -> Option<DriveWear> {
+    // Attribute name, normalized value and raw value
+    let attributes: Vec<(&str, u64, u64)> = output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            fields.first()?.parse::<u32>().ok()?;
+            let raw: String = fields.get(9)?.chars().take_while(|c| c.is_ascii_digit()).collect();
+            Some((*fields.get(1)?, fields.get(3)?.parse().ok()?, raw.parse().unwrap_or(0)))
+        })
+        .collect();
+    let attribute = |names: &[&str]| attributes.iter().find(|(name, _, _)| names.contains(name));
+
+    let (_, remaining, _) = attribute(&[
+        "SSD_Life_Left",
+        "Media_Wearout_Indicator",
+        "Wear_Leveling_Count",
+        "Percent_Lifetime_Remain",
+        "Remaining_Lifetime_Perc",
+    ])?;
+    let written = attributes.iter().find_map(|&(name, _, raw)| match name {
+        "Total_LBAs_Written" => Some(raw * 512),
+        "Host_Writes_32MiB" => Some(raw * 32 * 1024 * 1024),
+        "Host_Writes_GiB" | "Lifetime_Writes_GiB" => Some(raw * 1024 * 1024 * 1024),
+        _ => None,
+    });
+    Some(DriveWear {
+        drive: drive.to_string(),
+        percent_used: 100u64.saturating_sub(*remaining) as f32,
+        written,
+        power_on_hours: attribute(&["Power_On_Hours"]).map(|&(_, _, raw)| raw),
+        spare: None,
+    })
+}
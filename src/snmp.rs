@@ -0,0 +1,638 @@
+use crate::config::SnmpConfig;
+use crate::network::{Link, NetworkInterface};
+use crate::worker::Job;
+use std::{
+    net::{IpAddr, SocketAddr, ToSocketAddrs, UdpSocket},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+/// How long a device may take to answer a request before the poll counts as failed.
+/// Polls run in the background, but a poll sends its requests one after another.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+const DEFAULT_PORT: u16 = 161;
+
+// SNMPv2-MIB and IF-MIB objects; interface columns are followed by the ifIndex
+const SYS_UPTIME: &str = "1.3.6.1.2.1.1.3.0";
+const SYS_NAME: &str = "1.3.6.1.2.1.1.5.0";
+const IF_OPER_STATUS: &str = "1.3.6.1.2.1.2.2.1.8";
+const IF_IN_OCTETS: &str = "1.3.6.1.2.1.2.2.1.10"; // 32-bit, for devices without the ifHC counters
+const IF_OUT_OCTETS: &str = "1.3.6.1.2.1.2.2.1.16";
+const IF_NAME: &str = "1.3.6.1.2.1.31.1.1.1.1";
+const IF_HC_IN_OCTETS: &str = "1.3.6.1.2.1.31.1.1.1.6";
+const IF_HC_OUT_OCTETS: &str = "1.3.6.1.2.1.31.1.1.1.10";
+const IF_HIGH_SPEED: &str = "1.3.6.1.2.1.31.1.1.1.15"; // Mbps
+
+// BER tags of SNMPv2c messages (RFC 3416)
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_NULL: u8 = 0x05;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_COUNTER32: u8 = 0x41;
+const TAG_GAUGE32: u8 = 0x42;
+const TAG_TIMETICKS: u8 = 0x43;
+const TAG_COUNTER64: u8 = 0x46;
+const TAG_GET_REQUEST: u8 = 0xA0;
+const TAG_RESPONSE: u8 = 0xA2;
+const SNMP_V2C: i64 = 1;
+
+/// A value from a GetResponse
+#[derive(Debug, PartialEq)]
+enum Value {
+    Number(u64),    // INTEGER, Gauge32, TimeTicks
+    Counter32(u64), // Wraps at 2³²
+    Counter64(u64),
+    Text(String),
+    Missing, // noSuchObject, noSuchInstance, or anything else we cannot use
+}
+
+impl Value {
+    fn number(&self) -> Option<u64> {
+        match self {
+            Value::Number(value) | Value::Counter32(value) | Value::Counter64(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// An interface of the device, or a pair of configured counters
+#[derive(Clone)]
+enum Source {
+    Interface(u32), // ifIndex
+    Counters { name: String, rx: Vec<u32>, tx: Vec<u32> },
+}
+
+/// Byte counters of one source, extended past 32-bit wraps
+struct Counters {
+    source: Source,
+    raw: Option<(u64, u64)>, // As last read
+    totals: (u64, u64),      // Since the first read
+    interface: Option<NetworkInterface>,
+}
+
+impl Counters {
+    /// Fold in a reading; `wraps` for Counter32 values, which restart at 2³²
+    fn add(&mut self, rx: u64, tx: u64, wraps: bool) {
+        if let Some((prev_rx, prev_tx)) = self.raw {
+            let delta = |current: u64, prev: u64| {
+                if wraps && current < prev {
+                    current + (1 << 32) - prev
+                } else {
+                    current.saturating_sub(prev)
+                }
+            };
+            self.totals.0 += delta(rx, prev_rx);
+            self.totals.1 += delta(tx, prev_tx);
+        }
+        self.raw = Some((rx, tx));
+    }
+}
+
+/// Name, link and byte counters of one source as read by a poll; no counters where the
+/// device does not have them
+type SourceReading = (String, Option<Link>, Option<(u64, u64, bool)>);
+
+/// What one poll read from the device
+struct Reading {
+    system_name: Option<String>,
+    uptime: Option<u64>,
+    sources: Vec<SourceReading>, // In the order of `SnmpDevice::sources`
+    read_at: Instant,
+}
+
+/// Interface counters of a switch, router or access point, polled over SNMPv2c in the
+/// background, so an unreachable device does not hold up the UI
+pub struct SnmpDevice {
+    pub config: SnmpConfig,
+    pub system_name: Option<String>, // sysName, as the device calls itself
+    pub uptime: Option<u64>,         // Seconds
+    pub error: Option<String>,       // Last failed poll, or sources the device does not have
+    sources: Vec<Counters>,
+    max_history: usize,
+    job: Option<Job<Result<Reading, String>>>,
+    last_refresh: Option<Instant>,
+    last_reading: Option<Instant>, // When the previous answer came in, for rates
+}
+
+impl SnmpDevice {
+    pub fn new(config: SnmpConfig, max_history: usize) -> Self {
+        let mut sources: Vec<Source> = config.interfaces.iter().copied().map(Source::Interface).collect();
+        // OIDs are validated when the config is loaded
+        sources.extend(config.counters.iter().filter_map(|counter| {
+            Some(Source::Counters {
+                name: counter.name.clone(),
+                rx: parse_oid(&counter.rx)?,
+                tx: parse_oid(&counter.tx)?,
+            })
+        }));
+        Self {
+            config,
+            system_name: None,
+            uptime: None,
+            error: None,
+            sources: sources
+                .into_iter()
+                .map(|source| Counters { source, raw: None, totals: (0, 0), interface: None })
+                .collect(),
+            max_history,
+            job: None,
+            last_refresh: None,
+            last_reading: None,
+        }
+    }
+
+    /// Due for a poll, and none still running
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none()
+            && self.last_refresh.is_none_or(|last| last.elapsed() >= Duration::from_secs(self.config.interval.max(1)))
+    }
+
+    /// Start a poll in the background; `poll` picks up its answer
+    pub fn refresh(&mut self) {
+        let (host, community) = (self.config.host.clone(), self.config.community.clone());
+        let sources: Vec<Source> = self.sources.iter().map(|counters| counters.source.clone()).collect();
+        self.job = Some(Job::spawn(move || fetch(&host, &community, &sources)));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// Fold in the answer of a finished poll
+    pub fn poll(&mut self, retention: Option<Duration>) {
+        let Some(result) = self.job.as_ref().and_then(Job::try_result) else {
+            return;
+        };
+        self.job = None;
+        self.error = result.and_then(|reading| reading).and_then(|reading| self.apply(reading, retention)).err();
+    }
+
+    /// Interfaces that answered at least once, in config order, with rates and history
+    pub fn interfaces(&self) -> impl Iterator<Item = &NetworkInterface> {
+        self.sources.iter().filter_map(|source| source.interface.as_ref())
+    }
+
+    /// Configured name and sysName, e.g. "switch · core-sw1", or only the configured name
+    /// before the first answer and when both are the same
+    pub fn label(&self) -> String {
+        match &self.system_name {
+            Some(system_name) if *system_name != self.config.name => format!("{} · {}", self.config.name, system_name),
+            _ => self.config.name.clone(),
+        }
+    }
+
+    /// Update names, totals and rates from a reading
+    fn apply(&mut self, reading: Reading, retention: Option<Duration>) -> Result<(), String> {
+        let seconds = self.last_reading.map_or(0.0, |last| reading.read_at.duration_since(last).as_secs_f32());
+        self.last_reading = Some(reading.read_at);
+        if reading.system_name.is_some() {
+            self.system_name = reading.system_name;
+        }
+        self.uptime = reading.uptime;
+
+        let mut missing = Vec::new();
+        for (source, (name, link, counters)) in self.sources.iter_mut().zip(reading.sources) {
+            let Some((rx, tx, wraps)) = counters else {
+                missing.push(match source.source {
+                    Source::Interface(index) => format!("ifIndex {}", index),
+                    Source::Counters { .. } => name,
+                });
+                continue;
+            };
+            source.add(rx, tx, wraps);
+            let (rx, tx) = source.totals;
+            // Rates from the second answer on
+            let interface = match &mut source.interface {
+                Some(interface) => {
+                    interface.update(rx, tx, seconds, retention);
+                    interface
+                }
+                None => source.interface.insert(NetworkInterface::new(name.clone(), rx, tx, self.max_history)),
+            };
+            interface.name = name;
+            interface.link = link;
+        }
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("no counters for {}", missing.join(", ")))
+        }
+    }
+}
+
+/// One request for the system objects, which also tells whether the device answers at
+/// all, then one per source to keep responses small. Runs on a worker thread.
+fn fetch(host: &str, community: &str, sources: &[Source]) -> Result<Reading, String> {
+    let address = resolve(host)?;
+    let socket = UdpSocket::bind(if address.is_ipv4() { "0.0.0.0:0" } else { "[::]:0" }).map_err(|e| format!("socket: {}", e))?;
+    socket.connect(address).map_err(|e| format!("connect: {}", e))?;
+
+    // Not security relevant, just enough to tell our answers from stray packets
+    let request_id = &mut SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |time| time.subsec_nanos() as i32 & 0x7FFF_FFFF);
+    let system = get(&socket, community, request_id, &[oid(SYS_NAME, None), oid(SYS_UPTIME, None)])?;
+    let system_name = match &system[0] {
+        Value::Text(name) => Some(name.clone()),
+        _ => None,
+    };
+    // TimeTicks are hundredths of a second
+    let uptime = system[1].number().map(|ticks| ticks / 100);
+
+    let mut readings = Vec::with_capacity(sources.len());
+    for source in sources {
+        readings.push(match source {
+            Source::Interface(index) => {
+                let index = *index;
+                let values = get(
+                    &socket,
+                    community,
+                    request_id,
+                    &[
+                        oid(IF_NAME, Some(index)),
+                        oid(IF_OPER_STATUS, Some(index)),
+                        oid(IF_HIGH_SPEED, Some(index)),
+                        oid(IF_HC_IN_OCTETS, Some(index)),
+                        oid(IF_HC_OUT_OCTETS, Some(index)),
+                        oid(IF_IN_OCTETS, Some(index)),
+                        oid(IF_OUT_OCTETS, Some(index)),
+                    ],
+                )?;
+                let name = match &values[0] {
+                    Value::Text(name) if !name.is_empty() => name.clone(),
+                    _ => format!("if{}", index),
+                };
+                // ifOperStatus from IF-MIB
+                let state = match values[1].number() {
+                    Some(1) => "up",
+                    Some(2) => "down",
+                    Some(3) => "testing",
+                    Some(5) => "dormant",
+                    Some(6) => "notPresent",
+                    Some(7) => "lowerLayerDown",
+                    _ => "unknown",
+                };
+                let link = Link {
+                    state: state.to_string(),
+                    speed: values[2].number().filter(|&speed| speed > 0).map(|speed| speed as u32),
+                    ..Default::default()
+                };
+                // The 64-bit counters where the device has them
+                let counters = match (&values[3], &values[4]) {
+                    (Value::Counter64(rx), Value::Counter64(tx)) => Some((*rx, *tx, false)),
+                    _ => match (&values[5], &values[6]) {
+                        (Value::Counter32(rx), Value::Counter32(tx)) => Some((*rx, *tx, true)),
+                        _ => None,
+                    },
+                };
+                (name, Some(link), counters)
+            }
+            Source::Counters { name, rx, tx } => {
+                let values = get(&socket, community, request_id, &[rx.clone(), tx.clone()])?;
+                let counters = match (&values[0], &values[1]) {
+                    (Value::Counter32(rx), Value::Counter32(tx)) => Some((*rx, *tx, true)),
+                    (rx, tx) => rx.number().zip(tx.number()).map(|(rx, tx)| (rx, tx, false)),
+                };
+                (name.clone(), None, counters)
+            }
+        });
+    }
+    Ok(Reading { system_name, uptime, sources: readings, read_at: Instant::now() })
+}
+
+/// Send a GetRequest for `oids` and wait for its response, returning the values in
+/// request order
+fn get(socket: &UdpSocket, community: &str, request_id: &mut i32, oids: &[Vec<u32>]) -> Result<Vec<Value>, String> {
+    *request_id = request_id.wrapping_add(1) & 0x7FFF_FFFF;
+    socket
+        .send(&build_request(community, *request_id, oids))
+        .map_err(|e| format!("send: {}", e))?;
+
+    let mut response = vec![0u8; 65_535];
+    // Stray answers do not extend the wait
+    let deadline = Instant::now() + REQUEST_TIMEOUT;
+    loop {
+        let timeout = || format!("no answer within {}s (wrong community?)", REQUEST_TIMEOUT.as_secs());
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(timeout());
+        }
+        socket.set_read_timeout(Some(remaining)).map_err(|e| format!("socket: {}", e))?;
+        let length = socket.recv(&mut response).map_err(|e| match e.kind() {
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => timeout(),
+            _ => format!("receive: {}", e),
+        })?;
+        // Answers to an earlier, timed out request are skipped
+        let Some(result) = parse_response(&response[..length], *request_id) else {
+            continue;
+        };
+        let values = result?;
+        if values.len() != oids.len() {
+            return Err(format!("expected {} values, got {}", oids.len(), values.len()));
+        }
+        return Ok(values);
+    }
+}
+
+/// `1.3.6.1.2.1.1.5.0`, with or without a leading dot
+pub fn parse_oid(oid: &str) -> Option<Vec<u32>> {
+    let arcs: Vec<u32> = oid.trim_start_matches('.').split('.').map(|arc| arc.parse().ok()).collect::<Option<_>>()?;
+    // The first two arcs share a byte on the wire
+    (arcs.len() >= 2 && arcs[0] <= 2 && (arcs[0] == 2 || arcs[1] < 40)).then_some(arcs)
+}
+
+/// A MIB object, or a column of a table at `index`
+fn oid(object: &str, index: Option<u32>) -> Vec<u32> {
+    let mut arcs = parse_oid(object).expect("valid MIB object");
+    arcs.extend(index);
+    arcs
+}
+
+/// `192.168.1.2`, `192.168.1.2:1161`, `fe80::1`, `[fe80::1]:161` or a hostname,
+/// optionally with a port
+fn resolve(host: &str) -> Result<SocketAddr, String> {
+    if let Ok(address) = host.parse::<SocketAddr>() {
+        return Ok(address);
+    }
+    if let Ok(address) = host.parse::<IpAddr>() {
+        return Ok(SocketAddr::new(address, DEFAULT_PORT));
+    }
+    let target = if host.contains(':') { host.to_string() } else { format!("{}:{}", host, DEFAULT_PORT) };
+    target
+        .to_socket_addrs()
+        .map_err(|e| format!("{}: {}", host, e))?
+        .next()
+        .ok_or_else(|| format!("{}: no address", host))
+}
+
+/// An SNMPv2c message: version, community and a GetRequest PDU binding each OID to NULL
+fn build_request(community: &str, request_id: i32, oids: &[Vec<u32>]) -> Vec<u8> {
+    let bindings: Vec<u8> = oids
+        .iter()
+        .flat_map(|oid| encode(TAG_SEQUENCE, &[encode(TAG_OID, &encode_oid(oid)), encode(TAG_NULL, &[])].concat()))
+        .collect();
+    let pdu = [
+        encode_integer(request_id as i64),
+        encode_integer(0), // error-status
+        encode_integer(0), // error-index
+        encode(TAG_SEQUENCE, &bindings),
+    ]
+    .concat();
+    let message = [
+        encode_integer(SNMP_V2C),
+        encode(TAG_OCTET_STRING, community.as_bytes()),
+        encode(TAG_GET_REQUEST, &pdu),
+    ]
+    .concat();
+    encode(TAG_SEQUENCE, &message)
+}
+
+fn encode(tag: u8, contents: &[u8]) -> Vec<u8> {
+    let mut encoded = vec![tag];
+    let length = contents.len();
+    if length < 0x80 {
+        encoded.push(length as u8);
+    } else {
+        // Long form: the number of length bytes, then the length big endian
+        let bytes: Vec<u8> = length.to_be_bytes().into_iter().skip_while(|&byte| byte == 0).collect();
+        encoded.push(0x80 | bytes.len() as u8);
+        encoded.extend(bytes);
+    }
+    encoded.extend_from_slice(contents);
+    encoded
+}
+
+/// Two's complement, without redundant leading bytes
+fn encode_integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    while start < 7
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0) || (bytes[start] == 0xFF && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    encode(TAG_INTEGER, &bytes[start..])
+}
+
+/// The first two arcs as 40 × first + second, then every arc in base 128 with the high
+/// bit set on all but the last byte
+fn encode_oid(arcs: &[u32]) -> Vec<u8> {
+    let mut encoded = Vec::new();
+    let first = arcs[0] * 40 + arcs[1];
+    for arc in std::iter::once(first).chain(arcs[2..].iter().copied()) {
+        let mut bytes = vec![(arc & 0x7F) as u8];
+        let mut rest = arc >> 7;
+        while rest > 0 {
+            bytes.push((rest & 0x7F) as u8 | 0x80);
+            rest >>= 7;
+        }
+        encoded.extend(bytes.iter().rev());
+    }
+    encoded
+}
+
+/// Reads one BER element at a time
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    /// The next element's tag and contents
+    fn next(&mut self) -> Option<(u8, &'a [u8])> {
+        let (&tag, rest) = self.data.split_first()?;
+        let (&first, mut rest) = rest.split_first()?;
+        let length = if first < 0x80 {
+            first as usize
+        } else {
+            let count = (first & 0x7F) as usize;
+            if count == 0 || count > 4 || rest.len() < count {
+                return None;
+            }
+            let length = rest[..count].iter().fold(0usize, |length, &byte| length << 8 | byte as usize);
+            rest = &rest[count..];
+            length
+        };
+        if rest.len() < length {
+            return None;
+        }
+        self.data = &rest[length..];
+        Some((tag, &rest[..length]))
+    }
+
+    fn expect(&mut self, expected: u8) -> Option<&'a [u8]> {
+        self.next().filter(|(tag, _)| *tag == expected).map(|(_, contents)| contents)
+    }
+}
+
+fn decode_integer(contents: &[u8]) -> i64 {
+    let sign = if contents.first().is_some_and(|byte| byte & 0x80 != 0) { -1 } else { 0 };
+    contents.iter().fold(sign, |value, &byte| value << 8 | byte as i64)
+}
+
+fn decode_unsigned(contents: &[u8]) -> u64 {
+    contents.iter().fold(0, |value, &byte| value << 8 | byte as u64)
+}
+
+/// Values of a GetResponse to request `request_id`; None for anything else on the
+/// socket, an error when the device refused the request
+fn parse_response(data: &[u8], request_id: i32) -> Option<Result<Vec<Value>, String>> {
+    let mut message = Reader { data: Reader { data }.expect(TAG_SEQUENCE)? };
+    message.expect(TAG_INTEGER)?;
+    message.expect(TAG_OCTET_STRING)?;
+    let mut pdu = Reader { data: message.expect(TAG_RESPONSE)? };
+    if decode_integer(pdu.expect(TAG_INTEGER)?) != request_id as i64 {
+        return None;
+    }
+    let status = decode_integer(pdu.expect(TAG_INTEGER)?);
+    pdu.expect(TAG_INTEGER)?;
+    // error-status values from RFC 3416
+    let error = match status {
+        0 => None,
+        1 => Some("response too big".to_string()),
+        2 => Some("no such name".to_string()),
+        5 => Some("general error".to_string()),
+        6 => Some("no access (check the community)".to_string()),
+        status => Some(format!("error status {}", status)),
+    };
+    if let Some(error) = error {
+        return Some(Err(error));
+    }
+
+    let mut bindings = Reader { data: pdu.expect(TAG_SEQUENCE)? };
+    let mut values = Vec::new();
+    while let Some(binding) = bindings.expect(TAG_SEQUENCE) {
+        let mut binding = Reader { data: binding };
+        binding.expect(TAG_OID)?;
+        let (tag, contents) = binding.next()?;
+        values.push(match tag {
+            TAG_INTEGER => u64::try_from(decode_integer(contents)).map_or(Value::Missing, Value::Number),
+            TAG_GAUGE32 | TAG_TIMETICKS => Value::Number(decode_unsigned(contents)),
+            TAG_COUNTER32 => Value::Counter32(decode_unsigned(contents)),
+            TAG_COUNTER64 => Value::Counter64(decode_unsigned(contents)),
+            TAG_OCTET_STRING => Value::Text(String::from_utf8_lossy(contents).trim_end_matches('\0').to_string()),
+            _ => Value::Missing,
+        });
+    }
+    Some(Ok(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A GetResponse to `request_id` carrying `values`, as a device would send it
+    fn response(request_id: i32, status: i64, values: &[(u8, &[u8])]) -> Vec<u8> {
+        let bindings: Vec<u8> = values
+            .iter()
+            .flat_map(|&(tag, contents)| encode(TAG_SEQUENCE, &[encode(TAG_OID, &encode_oid(&oid(SYS_NAME, None))), encode(tag, contents)].concat()))
+            .collect();
+        let pdu = [encode_integer(request_id as i64), encode_integer(status), encode_integer(0), encode(TAG_SEQUENCE, &bindings)].concat();
+        let message = [encode_integer(SNMP_V2C), encode(TAG_OCTET_STRING, b"public"), encode(TAG_RESPONSE, &pdu)].concat();
+        encode(TAG_SEQUENCE, &message)
+    }
+
+    #[test]
+    fn encodes_integers_in_the_fewest_bytes() {
+        assert_eq!(encode_integer(0), [TAG_INTEGER, 1, 0x00]);
+        assert_eq!(encode_integer(127), [TAG_INTEGER, 1, 0x7F]);
+        assert_eq!(encode_integer(128), [TAG_INTEGER, 2, 0x00, 0x80]);
+        assert_eq!(encode_integer(-1), [TAG_INTEGER, 1, 0xFF]);
+        assert_eq!(encode_integer(-128), [TAG_INTEGER, 1, 0x80]);
+        assert_eq!(encode_integer(-129), [TAG_INTEGER, 2, 0xFF, 0x7F]);
+    }
+
+    #[test]
+    fn integers_round_trip() {
+        for value in [0, 1, 255, 256, 0x7FFF_FFFF, -1, -256, i64::MAX, i64::MIN] {
+            let encoded = encode_integer(value);
+            let contents = Reader { data: &encoded }.expect(TAG_INTEGER).unwrap();
+            assert_eq!(decode_integer(contents), value);
+        }
+    }
+
+    #[test]
+    fn encodes_oids() {
+        // sysName.0
+        assert_eq!(encode_oid(&parse_oid(SYS_NAME).unwrap()), [0x2B, 6, 1, 2, 1, 1, 5, 0]);
+        // Arcs of 128 and up take several bytes
+        assert_eq!(encode_oid(&parse_oid("1.3.6.1.4.1.2021.255").unwrap()), [0x2B, 6, 1, 4, 1, 0x8F, 0x65, 0x81, 0x7F]);
+        assert_eq!(encode_oid(&[2, 100, 16384]), [0x81, 0x34, 0x81, 0x80, 0x00]);
+    }
+
+    #[test]
+    fn parses_oids() {
+        assert_eq!(parse_oid(".1.3.6.1.2.1.1.3.0"), parse_oid(SYS_UPTIME));
+        assert_eq!(parse_oid("1"), None);
+        assert_eq!(parse_oid("1.40"), None);
+        assert_eq!(parse_oid("3.1"), None);
+        assert_eq!(parse_oid("1.3.x"), None);
+    }
+
+    #[test]
+    fn reads_long_lengths() {
+        let contents = vec![0xAB; 300];
+        let encoded = encode(TAG_OCTET_STRING, &contents);
+        assert_eq!(encoded[..4], [TAG_OCTET_STRING, 0x82, 0x01, 0x2C]);
+        let mut reader = Reader { data: &encoded };
+        assert_eq!(reader.expect(TAG_OCTET_STRING), Some(&contents[..]));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn reader_rejects_truncated_elements() {
+        // Contents shorter than the length says
+        assert_eq!(Reader { data: &[TAG_OCTET_STRING, 5, 1, 2] }.next(), None);
+        // Long form without its length bytes
+        assert_eq!(Reader { data: &[TAG_OCTET_STRING, 0x82, 0x01] }.next(), None);
+        // Tag without a length
+        assert_eq!(Reader { data: &[TAG_OCTET_STRING] }.next(), None);
+        assert_eq!(Reader { data: &[] }.next(), None);
+        // Wrong tag
+        assert_eq!(Reader { data: &encode_integer(5) }.expect(TAG_OCTET_STRING), None);
+    }
+
+    #[test]
+    fn parses_responses() {
+        let packet = response(
+            42,
+            0,
+            &[
+                (TAG_OCTET_STRING, b"switch\0"),
+                (TAG_TIMETICKS, &[0x01, 0x00, 0x00]),
+                (TAG_COUNTER32, &[0x00, 0xFF, 0xFF, 0xFF, 0xFF]),
+                (TAG_COUNTER64, &[0x01, 0x00, 0x00, 0x00, 0x00]),
+                (TAG_INTEGER, &[0x02]),
+                (0x81, &[]), // noSuchInstance
+            ],
+        );
+        let values = parse_response(&packet, 42).unwrap().unwrap();
+        assert_eq!(
+            values,
+            [
+                Value::Text("switch".to_string()),
+                Value::Number(0x1_0000),
+                Value::Counter32(0xFFFF_FFFF),
+                Value::Counter64(0x1_0000_0000),
+                Value::Number(2),
+                Value::Missing,
+            ]
+        );
+    }
+
+    #[test]
+    fn ignores_answers_to_other_requests() {
+        assert_eq!(parse_response(&response(41, 0, &[(TAG_INTEGER, &[1])]), 42), None);
+        // Our own request is not an answer
+        assert_eq!(parse_response(&build_request("public", 42, &[oid(SYS_NAME, None)]), 42), None);
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let result = parse_response(&response(7, 6, &[]), 7).unwrap();
+        assert_eq!(result, Err("no access (check the community)".to_string()));
+    }
+
+    #[test]
+    fn rejects_truncated_responses() {
+        let packet = response(42, 0, &[(TAG_OCTET_STRING, b"switch")]);
+        for length in 0..packet.len() {
+            assert_eq!(parse_response(&packet[..length], 42), None, "cut at {}", length);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::worker::Job;
+
+/// `ss` walks every TCP socket and, to find their owners, every process's open files,
+/// so it runs less often than the metrics update
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Seconds `ss` gets before it is given up on
+const SS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A connected TCP socket with a remote host and its byte counters
+pub struct TcpSocket {
+    pub local: String,
+    pub peer: String,
+    pub address: IpAddr,  // Host part of the peer
+    pub pid: Option<u32>, // Owner, when its process is visible to us
+    pub sent: u64,
+    pub received: u64,
+}
+
+/// The sockets of one `ss` run and when it finished
+pub struct SocketSample {
+    pub sockets: Vec<TcpSocket>,
+    pub taken: Instant,
+}
+
+/// Runs `ss -tinpH` on a worker thread. One sample serves both the per-process rates
+/// and the top talkers, so the sockets are walked once for the two.
+#[derive(Default)]
+pub struct SocketSampler {
+    job: Option<Job<Result<SocketSample, String>>>,
+    last_refresh: Option<Instant>,
+}
+
+impl SocketSampler {
+    pub fn is_stale(&self) -> bool {
+        self.job.is_none() && self.last_refresh.is_none_or(|last| last.elapsed() >= SAMPLE_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        self.job = Some(Job::spawn(read_tcp_sockets));
+        self.last_refresh = Some(Instant::now());
+    }
+
+    /// The sample once the worker is done with it
+    pub fn poll(&mut self) -> Option<Result<SocketSample, String>> {
+        let result = self.job.as_ref()?.try_result()?;
+        self.job = None;
+        Some(result.and_then(|sample| sample))
+    }
+}
+
+/// Per-process network rates, nethogs style. `ss -tinp` maps sockets to PIDs (through
+/// the `socket:[inode]` links in /proc/<pid>/fd) and reports the kernel's tcp_info byte
+/// counters, so rates come from the change between two samples. Only TCP carries byte
+/// counters; sockets of other users' processes need root to be attributed.
+#[derive(Default)]
+pub struct SocketTraffic {
+    pub rates: HashMap<u32, (f32, f32)>, // (download, upload) in KB/s per PID
+    sockets: HashMap<(String, String), (u64, u64)>, // Bytes sent and received per socket at the previous sample
+    last_sample: Option<Instant>,
+}
+
+impl SocketTraffic {
+    /// Rates from the change since the previous sample. Processes without traffic
+    /// since then are left out.
+    pub fn update(&mut self, sample: &SocketSample) {
+        let elapsed = self.last_sample.map(|last| sample.taken.duration_since(last).as_secs_f32());
+        self.last_sample = Some(sample.taken);
+
+        let mut rates: HashMap<u32, (f32, f32)> = HashMap::new();
+        let mut counters = HashMap::with_capacity(sample.sockets.len());
+        for socket in &sample.sockets {
+            let Some(pid) = socket.pid else {
+                continue;
+            };
+            let key = (socket.local.clone(), socket.peer.clone());
+            if let Some(elapsed) = elapsed.filter(|elapsed| *elapsed > 0.0) {
+                // New sockets count from zero; their first bytes belong to this interval
+                let (prev_sent, prev_received) = self.sockets.get(&key).copied().unwrap_or((0, 0));
+                let sent = socket.sent.saturating_sub(prev_sent);
+                let received = socket.received.saturating_sub(prev_received);
+                if sent > 0 || received > 0 {
+                    let rate = rates.entry(pid).or_default();
+                    rate.0 += received as f32 / 1000.0 / elapsed;
+                    rate.1 += sent as f32 / 1000.0 / elapsed;
+                }
+            }
+            counters.insert(key, (socket.sent, socket.received));
+        }
+
+        self.sockets = counters;
+        self.rates = rates;
+    }
+}
+
+/// Parse `ss -tinpH`: a socket line followed by an indented tcp_info line
+///
+/// ```text
+/// ESTAB 0 0 10.0.0.2:41234 93.184.216.34:443 users:(("curl",pid=4242,fd=5))
+///      cubic ... bytes_sent:512 bytes_acked:513 bytes_received:104857 ...
+/// ```
+///
+/// Loopback connections are left out: they never leave the machine.
+fn read_tcp_sockets() -> Result<SocketSample, String> {
+    let output = Command::new("timeout")
+        .args([&format!("{}s", SS_TIMEOUT.as_secs()), "ss", "-tinpH"])
+        .output()
+        .map_err(|e| format!("failed to run ss: {}", e))?;
+    if output.status.code() == Some(124) {
+        return Err(format!("ss timed out after {}s", SS_TIMEOUT.as_secs()));
+    }
+    if !output.status.success() {
+        return Err(format!("ss: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    let taken = Instant::now();
+
+    let out_str = String::from_utf8_lossy(&output.stdout);
+    let mut sockets = Vec::new();
+    let mut current: Option<(String, String, Option<u32>)> = None;
+    for line in out_str.lines() {
+        if !line.starts_with(char::is_whitespace) {
+            current = parse_socket_line(line);
+            continue;
+        }
+        let Some((local, peer, pid)) = current.take() else {
+            continue;
+        };
+        let Some(address) = remote_address(&peer).filter(|address| !address.is_loopback()) else {
+            continue;
+        };
+
+        let counter = |name: &str| {
+            line.split_whitespace()
+                .find_map(|field| field.strip_prefix(name)?.strip_prefix(':')?.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        sockets.push(TcpSocket {
+            local,
+            peer,
+            address,
+            pid,
+            sent: counter("bytes_sent"),
+            received: counter("bytes_received"),
+        });
+    }
+    Ok(SocketSample { sockets, taken })
+}
+
+/// Local and peer address of a socket line, and the PID of the process holding it
+fn parse_socket_line(line: &str) -> Option<(String, String, Option<u32>)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let local = fields.get(3)?;
+    let peer = fields.get(4)?;
+
+    // users:(("name",pid=123,fd=4),...) - shared sockets go to the first process
+    let pid = fields.get(5).and_then(|users| {
+        users
+            .split("pid=")
+            .nth(1)?
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()
+    });
+    Some((local.to_string(), peer.to_string(), pid))
+}
+
+/// Host part of `93.184.216.34:443`, `[2606:2800::1]:443` or `[::ffff:10.0.0.5]:22`,
+/// with IPv4-mapped addresses as plain IPv4 and any `%scope` dropped
+fn remote_address(remote: &str) -> Option<IpAddr> {
+    let (host, _) = remote.rsplit_once(':')?;
+    let host = host.trim_start_matches('[').trim_end_matches(']');
+    let host = host.split('%').next()?;
+    host.parse::<IpAddr>().ok().map(|address| address.to_canonical())
+}
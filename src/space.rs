@@ -0,0 +1,253 @@
+use std::{
+    collections::HashSet,
+    fs,
+    os::unix::fs::MetadataExt,
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{self, Receiver, TryRecvError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// A directory with the space allocated below it
+pub struct Dir {
+    pub name: String,
+    pub size: u64,          // Bytes allocated, like du, itself included
+    pub files: u64,         // At any depth
+    pub children: Vec<Dir>, // Largest first
+}
+
+impl Dir {
+    /// Space of the files directly in this directory, rather than in a subdirectory
+    pub fn own_size(&self) -> u64 {
+        self.size.saturating_sub(self.children.iter().map(|child| child.size).sum())
+    }
+}
+
+/// Counters the scanning thread bumps as it goes, for the progress line
+#[derive(Default)]
+struct Progress {
+    bytes: AtomicU64,
+    files: AtomicU64,
+    unreadable: AtomicU64, // Directories we may not list, usually for lack of root
+}
+
+/// A scan running in the background
+struct Scan {
+    receiver: Receiver<Dir>,
+    progress: Arc<Progress>,
+    cancel: Arc<AtomicBool>,
+    started: Instant,
+}
+
+impl Drop for Scan {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+/// "Where did my space go": the largest directories of a mount, from a scan in a
+/// background thread, browsable like ncdu. The scan stays on the chosen filesystem.
+pub struct SpaceExplorer {
+    pub mounts: Vec<String>,
+    pub mount_point: Option<String>, // None while choosing a mount
+    pub selected: usize,             // In `mounts` while choosing, otherwise in the shown directory
+    pub elapsed: Duration,           // Of the finished scan
+    pub unreadable: u64,
+    root: Option<Dir>,
+    path: Vec<usize>, // Child indices from the root down to the shown directory
+    scan: Option<Scan>,
+}
+
+impl SpaceExplorer {
+    /// Starts on the mount list, or right away with a single mount
+    pub fn new(mounts: Vec<String>) -> Self {
+        let mut explorer = Self {
+            mounts,
+            mount_point: None,
+            selected: 0,
+            elapsed: Duration::ZERO,
+            unreadable: 0,
+            root: None,
+            path: Vec::new(),
+            scan: None,
+        };
+        if explorer.mounts.len() == 1 {
+            explorer.start();
+        }
+        explorer
+    }
+
+    /// Scan the selected mount, or the current one again once a scan finished
+    pub fn start(&mut self) {
+        let mount_point = match &self.mount_point {
+            Some(mount_point) => mount_point.clone(),
+            None => match self.mounts.get(self.selected) {
+                Some(mount_point) => mount_point.clone(),
+                None => return,
+            },
+        };
+        let (sender, receiver) = mpsc::channel();
+        let progress = Arc::new(Progress::default());
+        let cancel = Arc::new(AtomicBool::new(false));
+        let (thread_progress, thread_cancel) = (Arc::clone(&progress), Arc::clone(&cancel));
+        let root = mount_point.clone();
+        thread::spawn(move || {
+            let Ok(metadata) = fs::symlink_metadata(&root) else {
+                thread_progress.unreadable.fetch_add(1, Ordering::Relaxed);
+                let _ = sender.send(Dir { name: root, size: 0, files: 0, children: Vec::new() });
+                return;
+            };
+            let mut seen = HashSet::new();
+            let dir = scan(Path::new(&root), root.clone(), &metadata, &mut seen, &thread_progress, &thread_cancel);
+            // The explorer may have been closed meanwhile
+            let _ = sender.send(dir);
+        });
+        self.mount_point = Some(mount_point);
+        self.root = None;
+        self.path.clear();
+        self.selected = 0;
+        self.scan = Some(Scan { receiver, progress, cancel, started: Instant::now() });
+    }
+
+    /// Pick up the result of a finished scan
+    pub fn poll(&mut self) {
+        let Some(scan) = &self.scan else {
+            return;
+        };
+        match scan.receiver.try_recv() {
+            Ok(root) => {
+                self.elapsed = scan.started.elapsed();
+                self.unreadable = scan.progress.unreadable.load(Ordering::Relaxed);
+                self.root = Some(root);
+                self.scan = None;
+            }
+            Err(TryRecvError::Empty) => {}
+            Err(TryRecvError::Disconnected) => self.scan = None,
+        }
+    }
+
+    pub fn is_scanning(&self) -> bool {
+        self.scan.is_some()
+    }
+
+    /// Bytes and files counted so far, and how long the scan has been running
+    pub fn progress(&self) -> Option<(u64, u64, Duration)> {
+        self.scan.as_ref().map(|scan| {
+            (
+                scan.progress.bytes.load(Ordering::Relaxed),
+                scan.progress.files.load(Ordering::Relaxed),
+                scan.started.elapsed(),
+            )
+        })
+    }
+
+    /// The directory being shown, once the scan finished
+    pub fn current(&self) -> Option<&Dir> {
+        let mut dir = self.root.as_ref()?;
+        for &index in &self.path {
+            dir = &dir.children[index];
+        }
+        Some(dir)
+    }
+
+    /// Full path of the shown directory
+    pub fn current_path(&self) -> String {
+        let Some(mut dir) = self.root.as_ref() else {
+            return self.mount_point.clone().unwrap_or_default();
+        };
+        let mut path = dir.name.clone();
+        for &index in &self.path {
+            dir = &dir.children[index];
+            if !path.ends_with('/') {
+                path.push('/');
+            }
+            path.push_str(&dir.name);
+        }
+        path
+    }
+
+    /// Entries of the list: mounts while choosing, otherwise subdirectories
+    fn len(&self) -> usize {
+        match (&self.mount_point, self.current()) {
+            (None, _) => self.mounts.len(),
+            (Some(_), Some(dir)) => dir.children.len(),
+            (Some(_), None) => 0,
+        }
+    }
+
+    pub fn select(&mut self, delta: isize) {
+        let last = self.len().saturating_sub(1);
+        self.selected = self.selected.saturating_add_signed(delta).min(last);
+    }
+
+    /// Scan the selected mount, or open the selected subdirectory
+    pub fn enter(&mut self) {
+        if self.mount_point.is_none() {
+            self.start();
+        } else if self.current().is_some_and(|dir| self.selected < dir.children.len()) {
+            self.path.push(self.selected);
+            self.selected = 0;
+        }
+    }
+
+    /// Up one directory, keeping the one we came from selected; from the top of a
+    /// mount back to the mount list when there is more than one
+    pub fn back(&mut self) {
+        if let Some(index) = self.path.pop() {
+            self.selected = index;
+        } else if self.mount_point.is_some() && self.mounts.len() > 1 {
+            self.selected = self.mount_point.as_ref().and_then(|mount| self.mounts.iter().position(|m| m == mount)).unwrap_or(0);
+            self.mount_point = None;
+            self.root = None;
+            self.scan = None;
+        }
+    }
+}
+
+/// Walk `path` depth first, skipping other filesystems mounted below it and counting
+/// hard-linked files once
+fn scan(
+    path: &Path,
+    name: String,
+    metadata: &fs::Metadata,
+    seen: &mut HashSet<(u64, u64)>,
+    progress: &Progress,
+    cancel: &AtomicBool,
+) -> Dir {
+    let mut dir = Dir { name, size: metadata.blocks() * 512, files: 0, children: Vec::new() };
+    if cancel.load(Ordering::Relaxed) {
+        return dir;
+    }
+    let Ok(entries) = fs::read_dir(path) else {
+        progress.unreadable.fetch_add(1, Ordering::Relaxed);
+        return dir;
+    };
+    for entry in entries.flatten() {
+        // Not following symlinks
+        let Ok(child) = entry.metadata() else {
+            continue;
+        };
+        if child.dev() != metadata.dev() {
+            continue;
+        }
+        if child.is_dir() {
+            let child = scan(&entry.path(), entry.file_name().to_string_lossy().into_owned(), &child, seen, progress, cancel);
+            dir.size += child.size;
+            dir.files += child.files;
+            dir.children.push(child);
+        } else if child.nlink() <= 1 || seen.insert((child.dev(), child.ino())) {
+            // st_blocks is in 512-byte units whatever the block size
+            let size = child.blocks() * 512;
+            dir.size += size;
+            dir.files += 1;
+            progress.bytes.fetch_add(size, Ordering::Relaxed);
+            progress.files.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    dir.children.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.name.cmp(&b.name)));
+    dir
+}
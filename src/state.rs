@@ -0,0 +1,96 @@
+use crate::history::SavedHistory;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Histories are written at most this often while running, and once more on exit, so
+/// a crash loses little
+const SAVE_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Contents of the history state file
+#[derive(Serialize, Deserialize)]
+struct HistoryFile {
+    saved_at: DateTime<Utc>,
+    histories: BTreeMap<String, SavedHistory>,
+}
+
+/// Chart history carried over between sessions through a state file, with
+/// `persist_history`
+pub struct HistoryStore {
+    pub error: Option<String>, // Last failure reading or writing the state file
+    path: Option<PathBuf>,     // None without a home directory
+    last_save: Instant,
+}
+
+impl HistoryStore {
+    pub fn new() -> Self {
+        Self {
+            error: None,
+            path: state_path("history.toml"),
+            last_save: Instant::now(),
+        }
+    }
+
+    /// The saved histories and how long ago they were saved; None before the first save
+    pub fn load(&mut self) -> Option<(BTreeMap<String, SavedHistory>, Duration)> {
+        let path = self.path.as_ref()?;
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return None,
+            Err(e) => {
+                self.error = Some(format!("{}: {}", path.display(), e));
+                return None;
+            }
+        };
+        match toml::from_str::<HistoryFile>(&contents) {
+            // A clock set back counts as just saved
+            Ok(file) => Some((file.histories, (Utc::now() - file.saved_at).to_std().unwrap_or_default())),
+            Err(e) => {
+                self.error = Some(format!("{}: {}", path.display(), e));
+                None
+            }
+        }
+    }
+
+    pub fn is_due(&self) -> bool {
+        self.last_save.elapsed() >= SAVE_INTERVAL
+    }
+
+    /// Write the histories to the state file, replacing it atomically
+    pub fn save(&mut self, histories: BTreeMap<String, SavedHistory>) {
+        self.last_save = Instant::now();
+        let Some(path) = &self.path else {
+            return;
+        };
+        let file = HistoryFile { saved_at: Utc::now(), histories };
+        let result = toml::to_string(&file)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| write_atomically(path, &contents).map_err(|e| e.to_string()));
+        self.error = result.err().map(|err| format!("{}: {}", path.display(), err));
+    }
+}
+
+/// `$XDG_STATE_HOME/rmon/<file>`, falling back to `~/.local/state/rmon/<file>`
+pub fn state_path(file: &str) -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("state")))?;
+    Some(base.join("rmon").join(file))
+}
+
+/// Write through a temporary file, so an interrupted write cannot lose the contents
+pub fn write_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let temporary = path.with_extension("toml.tmp");
+    fs::write(&temporary, contents)?;
+    fs::rename(&temporary, path)
+}
@@ -0,0 +1,460 @@
+use std::{
+    fs,
+    path::Path,
+    process::Command,
+    time::{Duration, Instant},
+};
+
+/// Pool state changes rarely, but a running scrub's progress is worth following
+const REFRESH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Time given to zpool and the LVM tools before they are killed: they block on a
+/// suspended pool or device
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(2);
+
+const BTRFS_SYSFS: &str = "/sys/fs/btrfs";
+
+/// Thin pool data or metadata usage from which the pool is flagged as filling up. A full
+/// thin pool stops every volume in it, however empty their filesystems look.
+pub const THIN_POOL_WARNING_PERCENT: f32 = 80.0;
+
+#[derive(Clone, PartialEq, Eq)]
+pub enum PoolKind {
+    Zfs,
+    Btrfs,
+    Md { level: String }, // raid1, raid5, linear...
+}
+
+impl PoolKind {
+    pub fn label(&self) -> &str {
+        match self {
+            PoolKind::Zfs => "zfs",
+            PoolKind::Btrfs => "btrfs",
+            PoolKind::Md { level } => level,
+        }
+    }
+}
+
+/// A ZFS pool, btrfs filesystem or md RAID array with the health of its devices
+pub struct Pool {
+    pub kind: PoolKind,
+    pub name: String,                 // ZFS pool name; btrfs label, or mount point without one; md0
+    pub health: Option<String>,       // ZFS: ONLINE, DEGRADED, FAULTED...; md: active, degraded or inactive; btrfs has none
+    pub used: Option<u64>,            // Bytes; None for md arrays, which hold no filesystem of their own
+    pub size: u64,
+    pub errors: u64,                  // Read, write and checksum errors of the devices
+    pub problems: Vec<String>,        // Devices not online, e.g. "sdb UNAVAIL", and data errors
+    pub scrub: Option<String>,        // ZFS: the last or running scrub; md: a running resync, check or rebuild
+    pub metadata: Option<(u64, u64)>, // btrfs: metadata used and allocated, which can fill up before data does
+}
+
+impl Pool {
+    /// Degraded, faulted, missing a device or seeing I/O errors
+    pub fn is_degraded(&self) -> bool {
+        self.health.as_deref().is_some_and(|health| health != "ONLINE" && health != "active")
+            || self.errors > 0
+            || !self.problems.is_empty()
+    }
+
+    pub fn usage_percent(&self) -> Option<f32> {
+        let used = self.used?;
+        Some(if self.size == 0 { 0.0 } else { used as f32 / self.size as f32 * 100.0 })
+    }
+}
+
+/// An LVM volume group and its thin pools
+pub struct VolumeGroup {
+    pub name: String,
+    pub size: u64, // Bytes
+    pub free: u64, // Not allocated to any logical volume
+    pub thin_pools: Vec<ThinPool>,
+}
+
+pub struct ThinPool {
+    pub name: String,
+    pub size: u64,
+    pub data_percent: f32,
+    pub metadata_percent: f32,
+}
+
+impl ThinPool {
+    pub fn is_filling(&self) -> bool {
+        self.data_percent.max(self.metadata_percent) >= THIN_POOL_WARNING_PERCENT
+    }
+}
+
+/// ZFS pools from `zpool`, btrfs filesystems from sysfs, md arrays from /proc/mdstat
+/// and LVM volume groups from `vgs` and `lvs`
+pub struct StorageHealth {
+    pub pools: Vec<Pool>,
+    pub volume_groups: Vec<VolumeGroup>,
+    pub errors: Vec<String>, // zpool or the LVM tools failing, e.g. without root
+    last_refresh: Option<Instant>,
+}
+
+impl StorageHealth {
+    pub fn new() -> Self {
+        Self {
+            pools: Vec::new(),
+            volume_groups: Vec::new(),
+            errors: Vec::new(),
+            last_refresh: None,
+        }
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        self.errors.clear();
+        let mut pools = match read_zfs_pools() {
+            Ok(pools) => pools,
+            Err(err) => {
+                self.errors.extend(err);
+                Vec::new()
+            }
+        };
+        pools.extend(read_btrfs_filesystems());
+        pools.extend(read_md_arrays());
+        self.pools = pools;
+        // The LVM tools complain without root even on systems not using LVM
+        self.volume_groups = if lvm_in_use() {
+            read_volume_groups().unwrap_or_else(|err| {
+                self.errors.push(err);
+                Vec::new()
+            })
+        } else {
+            Vec::new()
+        };
+        self.last_refresh = Some(Instant::now());
+    }
+}
+
+/// Pools from `zpool list -Hp`, one tab-separated line each (`tank ONLINE 3985729650688
+/// 1327144923136`), each completed from its `zpool status`. Err(None) when ZFS is not installed.
+fn read_zfs_pools() -> Result<Vec<Pool>, Option<String>> {
+    let output = Command::new("timeout")
+        .arg(format!("{}s", COMMAND_TIMEOUT.as_secs()))
+        .args(["zpool", "list", "-Hp", "-o", "name,health,size,allocated"])
+        .output()
+        .map_err(|e| Some(format!("failed to run zpool: {}", e)))?;
+    // timeout exits with 127 when it cannot find the command
+    match output.status.code() {
+        Some(127) => return Err(None),
+        Some(124) => return Err(Some(format!("zpool: timed out after {}s", COMMAND_TIMEOUT.as_secs()))),
+        _ => {}
+    }
+    if !output.status.success() {
+        return Err(Some(format!("zpool: {}", String::from_utf8_lossy(&output.stderr).trim())));
+    }
+    let mut pools = Vec::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let [name, health, size, allocated] = line.split('\t').collect::<Vec<_>>()[..] else {
+            continue;
+        };
+        let mut pool = Pool {
+            kind: PoolKind::Zfs,
+            name: name.to_string(),
+            health: Some(health.to_string()),
+            used: allocated.parse().ok(),
+            size: size.parse().unwrap_or(0),
+            errors: 0,
+            problems: Vec::new(),
+            scrub: None,
+            metadata: None,
+        };
+        if let Ok(output) = Command::new("timeout")
+            .arg(format!("{}s", COMMAND_TIMEOUT.as_secs()))
+            .args(["zpool", "status", "-p", name])
+            .output()
+        {
+            parse_zpool_status(&String::from_utf8_lossy(&output.stdout), &mut pool);
+        }
+        pools.push(pool);
+    }
+    Ok(pools)
+}
+
+/// Scrub state, device errors and devices that are not online from `zpool status -p`:
+///
+/// ```text
+///   scan: scrub repaired 0B in 00:00:01 with 0 errors on Sun Oct 12 00:24:02 2025
+/// config:
+///
+///         NAME        STATE     READ WRITE CKSUM
+///         tank        DEGRADED     0     0     0
+///           mirror-0  DEGRADED     0     0     0
+///             sda     ONLINE       0     0     0
+///             sdb     UNAVAIL      0     0     0  cannot open
+///
+/// errors: No known data errors
+/// ```
+///
+/// A running scrub continues on the next lines with its progress ("30.52% done").
+/// Only devices without children count towards the errors, as vdevs and the pool
+/// itself repeat them.
+fn parse_zpool_status(status: &str, pool: &mut Pool) {
+    let lines: Vec<&str> = status.lines().collect();
+    let mut devices = Vec::new();
+    let mut in_config = false;
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if let Some(scan) = trimmed.strip_prefix("scan:") {
+            let mut scrub = scan.trim().to_string();
+            if scrub.contains("in progress") {
+                let done = lines[i + 1..]
+                    .iter()
+                    .take(3)
+                    .flat_map(|line| line.split(','))
+                    .find(|part| part.contains("% done"));
+                if let Some(done) = done {
+                    scrub.push_str(&format!(", {}", done.trim()));
+                }
+            }
+            pool.scrub = Some(scrub);
+        } else if trimmed.starts_with("NAME") && trimmed.contains("STATE") {
+            in_config = true;
+        } else if let Some(errors) = trimmed.strip_prefix("errors:") {
+            in_config = false;
+            if errors.trim() != "No known data errors" {
+                pool.problems.push(errors.trim().to_string());
+            }
+        } else if in_config && !trimmed.is_empty() {
+            let indent = line.len() - line.trim_start().len();
+            devices.push((indent, trimmed.split_whitespace().collect::<Vec<_>>()));
+        }
+    }
+    for (i, (indent, fields)) in devices.iter().enumerate() {
+        // The pool's own row, and section headings such as "spares" or "logs"
+        if i == 0 || fields.len() < 5 {
+            continue;
+        }
+        let is_leaf = devices.get(i + 1).is_none_or(|(next, _)| next <= indent);
+        if !is_leaf {
+            continue;
+        }
+        let state = fields[1];
+        // Hot spares waiting to be used are AVAIL
+        if state != "ONLINE" && state != "AVAIL" {
+            pool.problems.push(format!("{} {}", fields[0], state));
+        }
+        pool.errors += fields[2..5].iter().filter_map(|count| count.parse::<u64>().ok()).sum::<u64>();
+    }
+}
+
+/// Mounted btrfs filesystems from /sys/fs/btrfs/<uuid>: space from the allocation
+/// directory, device errors and missing devices from devinfo. Error counters are there
+/// since Linux 5.14; before that only `btrfs device stats` has them, which needs root.
+fn read_btrfs_filesystems() -> Vec<Pool> {
+    let Ok(entries) = fs::read_dir(BTRFS_SYSFS) else {
+        return Vec::new();
+    };
+    let mounts = fs::read_to_string("/proc/self/mounts").unwrap_or_default();
+    let read = |path: &Path| fs::read_to_string(path).ok().map(|value| value.trim().to_string());
+    let number = |path: &Path| read(path).and_then(|value| value.parse::<u64>().ok()).unwrap_or(0);
+
+    let mut pools = Vec::new();
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        // Besides the filesystems, the directory holds a "features" directory
+        let uuid = entry.file_name().to_string_lossy().into_owned();
+        if !dir.join("allocation").is_dir() {
+            continue;
+        }
+
+        // Devices by name (sda2, nvme0n1p3), with their size in 512-byte sectors
+        let mut devices = Vec::new();
+        let mut size = 0;
+        for device in fs::read_dir(dir.join("devices")).into_iter().flatten().flatten() {
+            size += number(&device.path().join("size")) * 512;
+            devices.push(device.file_name().to_string_lossy().into_owned());
+        }
+        let mount_point = mounts.lines().find_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let device = fields.first()?.trim_start_matches("/dev/");
+            (fields.get(2) == Some(&"btrfs") && devices.iter().any(|name| name == device)).then(|| fields[1].to_string())
+        });
+        let name = read(&dir.join("label"))
+            .filter(|label| !label.is_empty())
+            .or(mount_point)
+            .unwrap_or_else(|| uuid.chars().take(8).collect());
+
+        // Raw bytes, so RAID profiles compare against the raw device size
+        let allocation = dir.join("allocation");
+        let used = ["data", "metadata", "system"]
+            .iter()
+            .map(|kind| number(&allocation.join(kind).join("disk_used")))
+            .sum();
+        let metadata = (number(&allocation.join("metadata/bytes_used")), number(&allocation.join("metadata/total_bytes")));
+
+        let mut errors = 0;
+        let mut problems = Vec::new();
+        for device in fs::read_dir(dir.join("devinfo")).into_iter().flatten().flatten() {
+            // write_errs, read_errs, flush_errs, corruption_errs and generation_errs
+            errors += read(&device.path().join("error_stats"))
+                .unwrap_or_default()
+                .lines()
+                .filter_map(|line| line.split_whitespace().nth(1)?.parse::<u64>().ok())
+                .sum::<u64>();
+            if number(&device.path().join("missing")) == 1 {
+                problems.push(format!("devid {} missing", device.file_name().to_string_lossy()));
+            }
+        }
+
+        pools.push(Pool {
+            kind: PoolKind::Btrfs,
+            name,
+            health: None,
+            used: Some(used),
+            size,
+            errors,
+            problems,
+            scrub: None,
+            metadata: (metadata.1 > 0).then_some(metadata),
+        });
+    }
+    pools.sort_by(|a, b| a.name.cmp(&b.name));
+    pools
+}
+
+/// Software RAID arrays from /proc/mdstat:
+///
+/// ```text
+/// md1 : active raid5 sdc1[3] sdb2[1] sda2[0] sdd1[4](F)
+///       2093056 blocks super 1.2 level 5, 512k chunk, algorithm 2 [3/2] [UU_]
+///       [=>...................]  recovery =  8.6% (90432/1046528) finish=0.5min speed=30144K/sec
+/// ```
+///
+/// `[3/2]` is devices wanted and working. Devices are marked (F) when failed and (S)
+/// when spare.
+fn read_md_arrays() -> Vec<Pool> {
+    let Ok(mdstat) = fs::read_to_string("/proc/mdstat") else {
+        return Vec::new();
+    };
+    let mut arrays: Vec<Pool> = Vec::new();
+    for line in mdstat.lines() {
+        if let Some((name, description)) = line.split_once(" : ").filter(|(name, _)| name.starts_with("md")) {
+            let mut fields = description.split_whitespace().filter(|field| !field.starts_with('('));
+            let state = fields.next().unwrap_or("unknown");
+            let members: Vec<&str> = description.split_whitespace().filter(|field| field.contains('[')).collect();
+            // Inactive arrays list no level
+            let level = fields.next().filter(|level| !level.contains('[')).unwrap_or("md");
+            let problems = members
+                .iter()
+                .filter(|member| member.ends_with("(F)"))
+                .map(|member| format!("{} failed", member.split('[').next().unwrap_or(member)))
+                .collect();
+            arrays.push(Pool {
+                kind: PoolKind::Md { level: level.to_string() },
+                name: name.trim().to_string(),
+                health: Some(state.to_string()),
+                used: None,
+                size: 0,
+                errors: 0,
+                problems,
+                scrub: None,
+                metadata: None,
+            });
+            continue;
+        }
+        let Some(array) = arrays.last_mut() else {
+            continue;
+        };
+        let line = line.trim();
+        if let Some(blocks) = line.split_once(" blocks").and_then(|(blocks, _)| blocks.parse::<u64>().ok()) {
+            array.size = blocks * 1024;
+            // "[3/2]": a device short
+            let counts = line.split_whitespace().find_map(|field| {
+                let (wanted, working) = field.strip_prefix('[')?.strip_suffix(']')?.split_once('/')?;
+                Some((wanted.parse::<u32>().ok()?, working.parse::<u32>().ok()?))
+            });
+            if let Some((wanted, working)) = counts.filter(|(wanted, working)| working < wanted) {
+                array.health = Some("degraded".to_string());
+                array.problems.push(format!("{} of {} devices missing", wanted - working, wanted));
+            }
+        } else if let Some((operation, progress)) = line.trim_start_matches(|c| "[=>.] ".contains(c)).split_once('=') {
+            // "recovery =  8.6% (90432/1046528) finish=0.5min speed=30144K/sec" or "resync=DELAYED"
+            let operation = operation.trim();
+            if ["recovery", "resync", "reshape", "check", "repair"].contains(&operation) {
+                let mut fields = progress.split_whitespace();
+                let mut scrub = format!("{} {}", operation, fields.next().unwrap_or_default());
+                if let Some(finish) = fields.find_map(|field| field.strip_prefix("finish=")) {
+                    scrub.push_str(&format!(", {} to go", finish));
+                }
+                array.scrub = Some(scrub);
+            }
+        }
+    }
+    arrays
+}
+
+/// Whether any device-mapper device belongs to LVM, whose UUIDs start with "LVM-"
+fn lvm_in_use() -> bool {
+    let Ok(devices) = fs::read_dir("/sys/class/block") else {
+        return false;
+    };
+    devices
+        .flatten()
+        .any(|device| fs::read_to_string(device.path().join("dm/uuid")).is_ok_and(|uuid| uuid.starts_with("LVM-")))
+}
+
+/// Run an LVM reporting command with exact byte sizes and `|` between fields
+fn lvm_report(program: &str, args: &[&str]) -> Result<Vec<Vec<String>>, String> {
+    let output = Command::new("timeout")
+        .arg(format!("{}s", COMMAND_TIMEOUT.as_secs()))
+        .arg(program)
+        .args(["--noheadings", "--nosuffix", "--units", "b", "--separator", "|"])
+        .args(args)
+        .env("LC_ALL", "C")
+        .output()
+        .map_err(|e| format!("failed to run {}: {}", program, e))?;
+    if output.status.code() == Some(124) {
+        return Err(format!("{}: timed out after {}s", program, COMMAND_TIMEOUT.as_secs()));
+    }
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        // The last line names the actual failure, after warnings about running without root
+        let reason = stderr.lines().map(str::trim).rfind(|line| !line.is_empty()).unwrap_or_default();
+        return Err(format!("{}: {}", program, reason));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.trim().split('|').map(str::to_string).collect())
+        .collect())
+}
+
+/// Volume groups from `vgs`, each with its thin pools from `lvs` (logical volumes whose
+/// attributes start with "t")
+fn read_volume_groups() -> Result<Vec<VolumeGroup>, String> {
+    let mut groups: Vec<VolumeGroup> = lvm_report("vgs", &["-o", "vg_name,vg_size,vg_free"])?
+        .into_iter()
+        .filter_map(|fields| match &fields[..] {
+            [name, size, free] => Some(VolumeGroup {
+                name: name.clone(),
+                size: size.parse().ok()?,
+                free: free.parse().ok()?,
+                thin_pools: Vec::new(),
+            }),
+            _ => None,
+        })
+        .collect();
+    let volumes = lvm_report("lvs", &["-o", "vg_name,lv_name,lv_attr,lv_size,data_percent,metadata_percent"])?;
+    for fields in volumes {
+        let [group, name, attributes, size, data, metadata] = &fields[..] else {
+            continue;
+        };
+        if !attributes.starts_with('t') {
+            continue;
+        }
+        if let Some(group) = groups.iter_mut().find(|vg| vg.name == *group) {
+            group.thin_pools.push(ThinPool {
+                name: name.clone(),
+                size: size.parse().unwrap_or(0),
+                data_percent: data.parse().unwrap_or(0.0),
+                metadata_percent: metadata.parse().unwrap_or(0.0),
+            });
+        }
+    }
+    Ok(groups)
+}
@@ -0,0 +1,99 @@
+use std::{collections::HashMap, net::IpAddr, time::Instant};
+
+use crate::sockets::SocketSample;
+
+/// Hosts listed by the panel and report
+pub const TOP_TALKERS: usize = 10;
+
+/// Hosts whose session totals are kept once they have no connection left; beyond
+/// this, disconnected hosts are forgotten
+const MAX_REMEMBERED_HOSTS: usize = 1000;
+
+/// Traffic with one remote host, summed over its TCP connections
+pub struct Talker {
+    pub address: IpAddr,
+    pub connections: usize,
+    pub rx_rate: f32,  // Kbps
+    pub tx_rate: f32,  // Kbps
+    pub rx_bytes: u64, // Since rmon started watching
+    pub tx_bytes: u64,
+}
+
+/// Remote hosts by TCP traffic, from the per-socket byte counters of `ss -ti`
+pub struct TopTalkers {
+    pub talkers: Vec<Talker>, // Connected hosts, busiest first
+    pub error: Option<String>,
+    totals: HashMap<IpAddr, (u64, u64)>,             // Bytes received and sent per host
+    sockets: HashMap<(String, String), (u64, u64)>, // Counters of each socket at the previous sample
+    last_sample: Option<Instant>,
+}
+
+impl TopTalkers {
+    pub fn new() -> Self {
+        Self {
+            talkers: Vec::new(),
+            error: None,
+            totals: HashMap::new(),
+            sockets: HashMap::new(),
+            last_sample: None,
+        }
+    }
+
+    /// Rates and totals from a sample of the TCP sockets, taken by the
+    /// [`SocketSampler`](crate::sockets::SocketSampler)
+    pub fn update(&mut self, sample: Result<&SocketSample, String>) {
+        let sample = match sample {
+            Ok(sample) => sample,
+            Err(err) => {
+                self.error = Some(err);
+                return;
+            }
+        };
+        let seconds = self.last_sample.map_or(0.0, |last| sample.taken.duration_since(last).as_secs_f32()).max(0.001);
+        let mut talkers: HashMap<IpAddr, Talker> = HashMap::new();
+        let mut counters = HashMap::with_capacity(sample.sockets.len());
+        for socket in &sample.sockets {
+            // Sockets opened since the previous sample moved all their bytes since then.
+            // On the first sample there is no previous one to measure from.
+            let (received, sent) = match self.sockets.get(&(socket.local.clone(), socket.peer.clone())) {
+                Some(&(received, sent)) => (socket.received.saturating_sub(received), socket.sent.saturating_sub(sent)),
+                None if self.last_sample.is_some() => (socket.received, socket.sent),
+                None => (0, 0),
+            };
+            let total = self.totals.entry(socket.address).or_default();
+            total.0 += received;
+            total.1 += sent;
+            let talker = talkers.entry(socket.address).or_insert_with(|| Talker {
+                address: socket.address,
+                connections: 0,
+                rx_rate: 0.0,
+                tx_rate: 0.0,
+                rx_bytes: 0,
+                tx_bytes: 0,
+            });
+            talker.connections += 1;
+            // Bytes per second, converted to Kbps like the interface rates
+            talker.rx_rate += received as f32 / seconds * 8.0 / 1000.0;
+            talker.tx_rate += sent as f32 / seconds * 8.0 / 1000.0;
+            counters.insert((socket.local.clone(), socket.peer.clone()), (socket.received, socket.sent));
+        }
+        if self.totals.len() > MAX_REMEMBERED_HOSTS {
+            self.totals.retain(|address, _| talkers.contains_key(address));
+        }
+
+        let mut talkers: Vec<Talker> = talkers.into_values().collect();
+        for talker in &mut talkers {
+            (talker.rx_bytes, talker.tx_bytes) = self.totals[&talker.address];
+        }
+        talkers.sort_by(|a, b| {
+            (b.rx_rate + b.tx_rate)
+                .total_cmp(&(a.rx_rate + a.tx_rate))
+                .then((b.rx_bytes + b.tx_bytes).cmp(&(a.rx_bytes + a.tx_bytes)))
+                .then(a.address.cmp(&b.address))
+        });
+        self.talkers = talkers;
+        self.sockets = counters;
+        self.error = None;
+        self.last_sample = Some(sample.taken);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    collections::HashMap,
+    fs,
+    time::{Duration, Instant},
+};
+
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+/// USER_HZ, the unit of the utime/stime fields in /proc; 100 on all common Linux builds
+const CLOCK_TICKS_PER_SEC: f32 = 100.0;
+
+/// One thread of the inspected process, from /proc/<pid>/task/<tid>
+pub struct ThreadInfo {
+    pub tid: u32,
+    pub name: String,
+    pub state: char,     // R running, S sleeping, D uninterruptible, ...
+    pub cpu_usage: f32,  // Percent of one core since the previous refresh
+}
+
+/// State of the threads sub-view of the Processes tab
+pub struct ThreadView {
+    pub pid: u32,
+    pub name: String,
+    pub threads: Vec<ThreadInfo>,
+    pub error: Option<String>,
+    cpu_ticks: HashMap<u32, u64>, // tid -> utime + stime at the previous refresh
+    last_refresh: Option<Instant>,
+}
+
+impl ThreadView {
+    pub fn new(pid: u32, name: String) -> Self {
+        let mut view = Self {
+            pid,
+            name,
+            threads: Vec::new(),
+            error: None,
+            cpu_ticks: HashMap::new(),
+            last_refresh: None,
+        };
+        view.refresh();
+        view
+    }
+
+    pub fn is_stale(&self) -> bool {
+        self.last_refresh.is_none_or(|last| last.elapsed() >= REFRESH_INTERVAL)
+    }
+
+    pub fn refresh(&mut self) {
+        let now = Instant::now();
+        let elapsed = self.last_refresh.map(|last| now.duration_since(last).as_secs_f32());
+        self.last_refresh = Some(now);
+
+        let entries = match fs::read_dir(format!("/proc/{}/task", self.pid)) {
+            Ok(entries) => entries,
+            Err(_) => {
+                self.threads.clear();
+                self.error = Some(format!("Process {} is gone", self.pid));
+                return;
+            }
+        };
+
+        let mut threads = Vec::new();
+        let mut cpu_ticks = HashMap::new();
+        for tid in entries.flatten().filter_map(|entry| entry.file_name().to_str()?.parse::<u32>().ok()) {
+            // A thread can exit between listing the directory and reading its files
+            let Some((state, ticks)) = read_thread_stat(self.pid, tid) else {
+                continue;
+            };
+            let name = fs::read_to_string(format!("/proc/{}/task/{}/comm", self.pid, tid))
+                .map(|comm| comm.trim_end().to_string())
+                .unwrap_or_default();
+
+            // Threads first seen in this refresh show 0% until the next one
+            let cpu_usage = match (elapsed, self.cpu_ticks.get(&tid)) {
+                (Some(elapsed), Some(&prev)) if elapsed > 0.0 => {
+                    ticks.saturating_sub(prev) as f32 / CLOCK_TICKS_PER_SEC / elapsed * 100.0
+                }
+                _ => 0.0,
+            };
+            cpu_ticks.insert(tid, ticks);
+            threads.push(ThreadInfo { tid, name, state, cpu_usage });
+        }
+
+        threads.sort_by(|a, b| {
+            b.cpu_usage.partial_cmp(&a.cpu_usage)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.tid.cmp(&b.tid))
+        });
+        self.threads = threads;
+        self.cpu_ticks = cpu_ticks;
+        self.error = None;
+    }
+}
+
+/// State and utime + stime of a thread. The name field in parentheses may contain
+/// spaces, so fields are counted from the closing parenthesis: state is field 3,
+/// utime and stime are fields 14 and 15.
+fn read_thread_stat(pid: u32, tid: u32) -> Option<(char, u64)> {
+    let stat = fs::read_to_string(format!("/proc/{}/task/{}/stat", pid, tid)).ok()?;
+    let fields: Vec<&str> = stat.rsplit_once(')')?.1.split_whitespace().collect();
+    let state = fields.first()?.chars().next()?;
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some((state, utime + stime))
+}
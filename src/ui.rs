@@ -1,15 +1,100 @@
-use crate::App;
+use crate::audit::AuditView;
+use crate::config::{CustomTabFormat, Panel, TemperatureUnit};
+use crate::connections::{ConnectionsView, StateCount};
+use crate::custom::CustomTab;
+use crate::detail::ProcessDetail;
+use crate::filesystems;
+use crate::firewall::FirewallView;
+use crate::gpu::{GpuDevice, NvLink, VramTrend};
+use crate::history::History;
+use crate::meminfo;
+use crate::network::{Link, NetworkInterface};
+use crate::power::BatteryStatus;
+use crate::pressure::Pressure;
+use crate::quota;
+use crate::routes::RoutesView;
+use crate::talkers;
+use crate::sensors::{Fan, Sensor, SensorKind, SensorsView};
+use crate::smart;
+use crate::space::SpaceExplorer;
+use crate::storage;
+use crate::threads::ThreadView;
+use crate::{App, KillDialog, Signal, Tab};
 use chrono::Local;
+use std::time::Instant;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols,
     text::{Line, Span},
-    widgets::{Axis, Block, Borders, BorderType, Chart, Dataset, Gauge, List, ListItem, ListState, Paragraph, Tabs, Table, Row, Cell, TableState},
+    widgets::{Axis, Block, Borders, BorderType, Chart, Clear, Dataset, Gauge, GraphType, List, ListItem, ListState, Paragraph, Sparkline, Tabs, Table, Row, Cell, TableState, Wrap},
     Frame,
 };
+use sysinfo::System;
+
+/// Minimum width of a single dashboard panel
+const MIN_PANEL_WIDTH: u16 = 26;
+/// Minimum height of one row of dashboard panels
+const MIN_PANEL_HEIGHT: u16 = 9;
+/// Title bar + tab bar
+const HEADER_HEIGHT: u16 = 6;
+/// VRAM samples shown per process in the GPU process table
+const VRAM_TREND_WIDTH: usize = 10;
+/// Samples in the usage trend of each extra tracked mount in the disk panel
+const DISK_TREND_WIDTH: usize = 15;
+/// Cells of the size bars in the disk space explorer
+const SPACE_BAR_WIDTH: usize = 20;
+/// Samples in the per-disk trend of the I/O panel
+const IO_TREND_WIDTH: usize = 10;
+/// Line colors of the per-disk utilization chart, repeated past six disks
+const DISK_COLORS: [Color; 6] = [
+    Color::Rgb(136, 192, 208), // Nord frost
+    Color::Rgb(163, 190, 140), // Nord green
+    Color::Rgb(235, 203, 139), // Nord yellow
+    Color::Rgb(180, 142, 173), // Nord purple
+    Color::Rgb(208, 135, 112), // Nord orange
+    Color::Rgb(143, 188, 187), // Nord teal
+];
+/// Samples in the 1-minute load trend of the CPU info panel
+const LOAD_TREND_WIDTH: usize = 10;
+/// Samples in the frequency trends of the CPU info panel
+const FREQUENCY_TREND_WIDTH: usize = 10;
+/// Samples in the context switch and interrupt trends of the CPU info panel
+const SCHEDULER_TREND_WIDTH: usize = 10;
+/// Samples in the major fault sparkline of the memory panel
+const PAGING_TREND_WIDTH: usize = 15;
+/// Samples in the dirty page sparkline of the memory panel
+const DIRTY_TREND_WIDTH: usize = 15;
+/// Samples in the some/full trends of the pressure panel
+const PRESSURE_TREND_WIDTH: usize = 15;
+/// Samples in the RPM and CPU temperature trends of the fan panel
+const FAN_TREND_WIDTH: usize = 15;
+/// Samples in the error/drop rate trend of the network panel
+const NETWORK_ERROR_TREND_WIDTH: usize = 15;
+/// Samples in the Wi-Fi signal trend of the network panel
+const WIFI_SIGNAL_TREND_WIDTH: usize = 15;
+/// Samples in the latency trend of the DNS panel
+const DNS_TREND_WIDTH: usize = 15;
+/// Samples in the per-state trends of the TCP panel
+const TCP_STATE_TREND_WIDTH: usize = 15;
+/// Samples in the per-interface traffic trends of the SNMP panel
+const SNMP_TREND_WIDTH: usize = 15;
+/// Width of the per-partition usage bars of the disk panel
+const PARTITION_BAR_WIDTH: usize = 10;
+/// Board temperatures per line above the Sensors tab table
+const BOARD_TEMPERATURES_PER_LINE: usize = 4;
+/// tmpfs mounts listed in the memory panel, fullest first
+const TMPFS_LINES: usize = 3;
 
 pub fn draw(f: &mut Frame, app: &App) {
+    // Refuse to draw overlapping widgets; the next frame after a resize picks up again
+    let (min_width, min_height) = min_terminal_size(app);
+    let area = f.area();
+    if area.width < min_width || area.height < min_height {
+        draw_too_small(f, min_width, min_height);
+        return;
+    }
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -21,7 +106,10 @@ pub fn draw(f: &mut Frame, app: &App) {
 
     // Clock with Btop-inspired styling
     let now = Local::now();
-    let clock_text = format!("⏰ {}", now.format("%H:%M:%S"));
+    let clock_text = match app.status_message() {
+        Some(message) => format!("⏰ {} │ ⬆️ up {} │ {}", now.format("%H:%M:%S"), crate::format_uptime(System::uptime()), message),
+        None => format!("⏰ {} │ ⬆️ up {}", now.format("%H:%M:%S"), crate::format_uptime(System::uptime())),
+    };
     let clock = Paragraph::new(clock_text)
         .style(Style::default().fg(Color::Rgb(139, 233, 253))) // Bright cyan
         .alignment(Alignment::Center)
@@ -32,14 +120,26 @@ pub fn draw(f: &mut Frame, app: &App) {
     f.render_widget(clock, chunks[0]);
 
     // Tabs with enhanced Btop-inspired styling
-    let tab_titles = vec![
-        "🖥️ System Monitor", 
-        "⚙️ Processes", 
-        "📋 Journal Logs"
-    ];
+    let tab_titles: Vec<String> = app.tabs
+        .iter()
+        .map(|tab| match tab {
+            Tab::System => "🖥️ System Monitor".to_string(),
+            Tab::Processes => "⚙️ Processes".to_string(),
+            Tab::Journal => "📋 Journal Logs".to_string(),
+            Tab::Gpu => "🎮 GPU".to_string(),
+            Tab::Coredumps => "💥 Crashes".to_string(),
+            Tab::Filesystems => "💾 Filesystems".to_string(),
+            Tab::Connections => "🔌 Connections".to_string(),
+            Tab::Routes => "🔀 Routes".to_string(),
+            Tab::Firewall => "🧱 Firewall".to_string(),
+            Tab::Sensors => "🌡️ Sensors".to_string(),
+            Tab::Audit => "🛡️ Audit".to_string(),
+            Tab::Custom(i) => format!("🧩 {}", app.custom_tabs[*i].config.title),
+        })
+        .collect();
     let tabs = Tabs::new(tab_titles)
         .block(Block::default()
-            .title("Navigation - [Tab] switch │ [Q] quit")
+            .title("Navigation - [Tab/Shift+Tab/1-9] switch │ [Ctrl+R] reload config │ [Shift+F] °C/°F │ [[/]] history │ [Q] quit")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::default().fg(Color::Rgb(98, 114, 164))))
@@ -52,49 +152,287 @@ pub fn draw(f: &mut Frame, app: &App) {
     f.render_widget(tabs, chunks[1]);
 
     // Main content based on selected tab
-    match app.current_tab {
-        0 => draw_system_monitor(f, app, chunks[2]),
-        1 => draw_processes(f, app, chunks[2]),
-        2 => draw_journal_logs(f, app, chunks[2]),
-        _ => {}
+    match app.tab() {
+        Tab::System => draw_system_monitor(f, app, chunks[2]),
+        Tab::Processes => draw_processes(f, app, chunks[2]),
+        Tab::Journal => draw_journal_logs(f, app, chunks[2]),
+        Tab::Gpu => draw_gpu_tab(f, app, chunks[2]),
+        Tab::Coredumps => draw_coredumps(f, app, chunks[2]),
+        Tab::Filesystems => draw_filesystems(f, app, chunks[2]),
+        Tab::Sensors => {
+            if let Some(sensors) = &app.sensors {
+                draw_sensors(f, sensors, app.temperature_unit, chunks[2]);
+            }
+        }
+        Tab::Connections => {
+            if let Some(connections) = &app.connections {
+                draw_connections(f, connections, chunks[2]);
+            }
+        }
+        Tab::Routes => {
+            if let Some(routes) = &app.routes {
+                draw_routes(f, routes, chunks[2]);
+            }
+        }
+        Tab::Firewall => {
+            if let Some(firewall) = &app.firewall {
+                draw_firewall(f, firewall, chunks[2]);
+            }
+        }
+        Tab::Audit => {
+            if let Some(audit) = &app.audit {
+                draw_audit(f, audit, chunks[2]);
+            }
+        }
+        Tab::Custom(i) => draw_custom_tab(f, &app.custom_tabs[i], chunks[2]),
+    }
+
+    if let Some(explorer) = &app.space_explorer {
+        draw_space_explorer(f, explorer);
+    }
+    if let Some(dialog) = &app.kill_dialog {
+        draw_kill_dialog(f, dialog);
+    }
+}
+
+/// Overlay listing the largest directories of a mount, or the mounts to choose from
+fn draw_space_explorer(f: &mut Frame, explorer: &SpaceExplorer) {
+    let screen = f.area();
+    let width = (screen.width * 4 / 5).max(60.min(screen.width));
+    let height = (screen.height * 4 / 5).max(12.min(screen.height));
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+    // Rows left for the list inside the border, below the header and above the key hints
+    let rows = height.saturating_sub(6) as usize;
+    let selected_style = Style::default()
+        .fg(Color::Rgb(46, 52, 64))
+        .bg(Color::Rgb(136, 192, 208)) // Nord frost
+        .add_modifier(Modifier::BOLD);
+    let hint_style = Style::default().fg(Color::Gray);
+
+    let mut lines = Vec::new();
+    let hints;
+    if explorer.mount_point.is_none() {
+        lines.push(Line::from("Scan which mount?"));
+        lines.push(Line::from(""));
+        for (i, mount) in explorer.mounts.iter().enumerate().skip(explorer.selected.saturating_sub(rows.saturating_sub(1))).take(rows) {
+            let style = if i == explorer.selected { selected_style } else { Style::default() };
+            lines.push(Line::styled(format!(" {} ", mount), style));
+        }
+        hints = "[↑/↓] choose  [Enter] scan  [Esc] close";
+    } else if let Some((bytes, files, elapsed)) = explorer.progress() {
+        lines.push(Line::from(format!(
+            "Scanning {}: {} in {} files so far ({}s)",
+            explorer.current_path(),
+            crate::format_bytes(bytes),
+            files,
+            elapsed.as_secs(),
+        )));
+        hints = "[Esc] cancel";
+    } else if let Some(dir) = explorer.current() {
+        lines.push(Line::styled(
+            format!(
+                "{}  {} in {} files · scanned in {:.1}s",
+                explorer.current_path(),
+                crate::format_bytes(dir.size),
+                dir.files,
+                explorer.elapsed.as_secs_f32(),
+            ),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        if explorer.unreadable > 0 {
+            lines.push(Line::styled(
+                format!("{} directories could not be read; sizes are lower bounds", explorer.unreadable),
+                Style::default().fg(Color::Rgb(235, 203, 139)), // Nord aurora yellow
+            ));
+        } else {
+            lines.push(Line::from(""));
+        }
+        // Share of the shown directory, so the biggest culprit stands out
+        let bar = |size: u64| {
+            let filled = if dir.size == 0 { 0 } else { (size as f64 / dir.size as f64 * SPACE_BAR_WIDTH as f64).round() as usize };
+            format!("{}{}", "█".repeat(filled), "░".repeat(SPACE_BAR_WIDTH - filled))
+        };
+        let offset = explorer.selected.saturating_sub(rows.saturating_sub(2));
+        for (i, child) in dir.children.iter().enumerate().skip(offset).take(rows.saturating_sub(1)) {
+            let text = format!(" {:>10} {} {}/ ", crate::format_bytes(child.size), bar(child.size), child.name);
+            let style = if i == explorer.selected { selected_style } else { Style::default() };
+            lines.push(Line::styled(text, style));
+        }
+        lines.push(Line::styled(
+            format!(" {:>10} {} (files here)", crate::format_bytes(dir.own_size()), bar(dir.own_size())),
+            hint_style,
+        ));
+        hints = "[↑/↓] select  [Enter] open  [Backspace] up  [R] rescan  [Esc] close";
+    } else {
+        lines.push(Line::from(format!("Could not scan {}", explorer.current_path())));
+        hints = "[R] retry  [Esc] close";
     }
+    // Key hints on the last row
+    while lines.len() < height.saturating_sub(3) as usize {
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::styled(hints, hint_style));
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title("🔍 Disk space")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(136, 192, 208)))); // Nord frost
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Modal confirmation before a process is signalled
+fn draw_kill_dialog(f: &mut Frame, dialog: &KillDialog) {
+    let screen = f.area();
+    let width = 66.min(screen.width);
+    let height = 8.min(screen.height);
+    let area = Rect {
+        x: screen.x + (screen.width - width) / 2,
+        y: screen.y + (screen.height - height) / 2,
+        width,
+        height,
+    };
+
+    let mut choices = Vec::new();
+    for (i, signal) in Signal::KILL_CHOICES.iter().enumerate() {
+        let style = if i == dialog.choice {
+            Style::default()
+                .fg(Color::Rgb(46, 52, 64))
+                .bg(Color::Rgb(191, 97, 106)) // Nord aurora red
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Rgb(216, 222, 233))
+        };
+        choices.push(Span::raw(" "));
+        choices.push(Span::styled(format!(" {} SIG{} ", i + 1, signal.name()), style));
+    }
+
+    let text = vec![
+        Line::from(format!("Send a signal to PID {} ({})?", dialog.pid, dialog.name)),
+        Line::from(""),
+        Line::from(choices),
+        Line::from(""),
+        Line::from("[←/→] choose  [Enter] send  [Esc] cancel")
+            .style(Style::default().fg(Color::Gray)),
+    ];
+
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(Block::default()
+            .title("⚠️ Kill process")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(191, 97, 106))));
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
+
+/// Smallest terminal that fits the layout of the current tab
+fn min_terminal_size(app: &App) -> (u16, u16) {
+    match app.tab() {
+        Tab::System => {
+            let panels = app.dashboard_panels().len() as u16;
+            let top = panels / 2;
+            let bottom = panels - top;
+            let rows = if top > 0 { 2 } else { 1 };
+            (MIN_PANEL_WIDTH * top.max(bottom).max(1), HEADER_HEIGHT + MIN_PANEL_HEIGHT * rows)
+        }
+        _ => (60, HEADER_HEIGHT + 8),
+    }
+}
+
+fn draw_too_small(f: &mut Frame, min_width: u16, min_height: u16) {
+    let area = f.area();
+    let message = vec![
+        Line::from("Terminal too small"),
+        Line::from(format!("need {}×{}, have {}×{}", min_width, min_height, area.width, area.height)),
+        Line::from("Resize to continue • [Q] quit"),
+    ];
+
+    // Vertically center the message when there is room for it
+    let top = area.height.saturating_sub(message.len() as u16) / 2;
+    let message_area = Rect {
+        y: area.y + top,
+        height: area.height - top,
+        ..area
+    };
+
+    let paragraph = Paragraph::new(message)
+        .style(Style::default().fg(Color::Rgb(235, 203, 139)).add_modifier(Modifier::BOLD))
+        .alignment(Alignment::Center);
+    f.render_widget(paragraph, message_area);
 }
 
 fn draw_system_monitor(f: &mut Frame, app: &App, area: Rect) {
-    // Main content in 5 panels layout - CPU and GPU on top, everything else on bottom
+    // Panels come from the config - by default CPU, GPU and the top processes on top, everything else on bottom
+    let panels = app.dashboard_panels();
+    if panels.is_empty() {
+        return;
+    }
+
+    let top_count = panels.len() / 2;
+    let (top_panels, bottom_panels) = panels.split_at(top_count);
+
+    let row_constraints = if top_panels.is_empty() {
+        vec![Constraint::Percentage(100)]
+    } else {
+        vec![Constraint::Percentage(50), Constraint::Percentage(50)]
+    };
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .constraints(row_constraints)
         .split(area);
 
-    let top_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(main_chunks[0]);
-
-    let bottom_chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage(33),
-            Constraint::Percentage(33),
-            Constraint::Percentage(34),
-        ])
-        .split(main_chunks[1]);
+    let rows = if top_panels.is_empty() {
+        vec![(bottom_panels, main_chunks[0])]
+    } else {
+        vec![(top_panels, main_chunks[0]), (bottom_panels, main_chunks[1])]
+    };
 
-    // CPU usage (top-left)
-    draw_cpu_widget(f, app, top_chunks[0]);
-    
-    // GPU usage (top-right)
-    draw_gpu_widget(f, app, top_chunks[1]);
-    
-    // Memory usage (bottom-left)
-    draw_memory_widget(f, app, bottom_chunks[0]);
+    for (row_panels, row_area) in rows {
+        let row_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, row_panels.len() as u32); row_panels.len()])
+            .split(row_area);
 
-    // Disk usage (bottom-middle)
-    draw_disk_widget(f, app, bottom_chunks[1]);
+        for (panel, &panel_area) in row_panels.iter().zip(row_chunks.iter()) {
+            draw_panel(f, app, *panel, panel_area);
+        }
+    }
+}
 
-    // Network usage (bottom-right)
-    draw_network_widget(f, app, bottom_chunks[2]);
+fn draw_panel(f: &mut Frame, app: &App, panel: Panel, area: Rect) {
+    match panel {
+        Panel::Cpu => draw_cpu_widget(f, app, area),
+        Panel::Gpu => draw_gpu_widget(f, app, area),
+        Panel::Top => draw_top_widget(f, app, area),
+        Panel::Memory => draw_memory_widget(f, app, area),
+        Panel::Disk => draw_disk_widget(f, app, area),
+        Panel::Io => draw_io_widget(f, app, area),
+        Panel::Pressure => draw_pressure_widget(f, app, area),
+        Panel::Cgroups => draw_cgroups_widget(f, app, area),
+        Panel::Numa => draw_numa_widget(f, app, area),
+        Panel::Network => draw_network_widget(f, app, area),
+        Panel::Power => draw_power_widget(f, app, area),
+        Panel::Battery => draw_battery_widget(f, app, area),
+        Panel::Gauges => draw_custom_gauges_widget(f, app, area),
+        Panel::Ports => draw_ports_widget(f, app, area),
+        Panel::Tcp => draw_tcp_widget(f, app, area),
+        Panel::Talkers => draw_talkers_widget(f, app, area),
+        Panel::Dns => draw_dns_widget(f, app, area),
+        Panel::Quota => draw_quota_widget(f, app, area),
+        Panel::Snmp => draw_snmp_widget(f, app, area),
+        Panel::Fans => draw_fans_widget(f, app, area),
+        // Temperatures are shown inside the CPU panel
+        Panel::Temperature => {}
+    }
 }
 
 fn draw_journal_logs(f: &mut Frame, app: &App, area: Rect) {
@@ -107,12 +445,22 @@ fn draw_journal_logs(f: &mut Frame, app: &App, area: Rect) {
         .split(area);
 
     // Instructions
-    let instructions = Paragraph::new("⬆️⬇️ scroll, PgUp/PgDn for fast scroll, Tab to switch tabs")
+    let instructions_text = if app.journal_context.is_some() {
+        "⬆️⬇️ scroll, PgUp/PgDn for fast scroll, Tab to switch tabs • [Backspace] back to latest logs"
+    } else {
+        "⬆️⬇️ scroll, PgUp/PgDn for fast scroll, Tab to switch tabs"
+    };
+    let instructions = Paragraph::new(instructions_text)
         .style(Style::default().fg(Color::Gray))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(instructions, chunks[0]);
 
+    let title = match &app.journal_context {
+        Some(context) => format!("📋 Journal around {} - Newest First", context.title),
+        None => "📋 System Journal Logs (Latest 100 - Newest First)".to_string(),
+    };
+
     // Log content
     let log_items: Vec<ListItem> = app.journal_logs
         .iter()
@@ -121,7 +469,7 @@ fn draw_journal_logs(f: &mut Frame, app: &App, area: Rect) {
 
     let logs_list = List::new(log_items)
         .block(Block::default()
-            .title("📋 System Journal Logs (Latest 100 - Newest First)")
+            .title(title)
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Green)))
         .style(Style::default().fg(Color::White))
@@ -132,442 +480,3286 @@ fn draw_journal_logs(f: &mut Frame, app: &App, area: Rect) {
     f.render_stateful_widget(logs_list, chunks[1], &mut list_state);
 }
 
-fn draw_processes(f: &mut Frame, app: &App, area: Rect) {
+fn draw_coredumps(f: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(3),  // Instructions
-            Constraint::Min(0),     // Process table
+            Constraint::Min(0),     // Crash table
         ])
         .split(area);
 
-    // Instructions with sort and kill controls
-    let instructions = Paragraph::new("⬆️⬇️ scroll, PgUp/PgDn fast scroll, Tab switch • [C] CPU sort • [M] Memory sort • [K] kill process")
+    let instructions = Paragraph::new("⬆️⬇️ scroll, PgUp/PgDn fast scroll, Tab switch • [Enter] journal around the crash")
         .style(Style::default().fg(Color::Gray))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL));
     f.render_widget(instructions, chunks[0]);
 
-    // Process table
+    let block = Block::default()
+        .title(format!("💥 Crashes ({} recorded by systemd-coredump - Newest First)", app.coredumps.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(191, 97, 106)));
+
+    if app.coredumps.is_empty() {
+        let empty = Paragraph::new("No coredumps recorded (or coredumpctl unavailable)")
+            .style(Style::default().fg(Color::Gray))
+            .block(block);
+        f.render_widget(empty, chunks[1]);
+        return;
+    }
+
     let header = Row::new(vec![
+        Cell::from("Time"),
         Cell::from("PID"),
-        Cell::from("Name"),
-        Cell::from("CPU%"),
-        Cell::from("Memory"),
-        Cell::from("User"),
+        Cell::from("UID"),
+        Cell::from("Signal"),
+        Cell::from("Core"),
+        Cell::from("Program"),
     ])
     .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
 
-    let rows: Vec<Row> = app.processes
+    let rows: Vec<Row> = app.coredumps
         .iter()
-        .map(|process| {
-            let memory_mb = process.memory_usage as f64 / 1024.0 / 1024.0;
-            
+        .map(|dump| {
             Row::new(vec![
-                Cell::from(process.pid.to_string()),
-                Cell::from(process.name.clone()),
-                Cell::from(format!("{:.1}", process.cpu_usage)),
-                Cell::from(format!("{:.1}MB", memory_mb)),
-                Cell::from(process.user.clone()),
+                Cell::from(dump.time.format("%Y-%m-%d %H:%M:%S").to_string()),
+                Cell::from(dump.pid.to_string()),
+                Cell::from(dump.uid.to_string()),
+                Cell::from(dump.signal.clone()).style(Style::default().fg(Color::Rgb(191, 97, 106))),
+                Cell::from(dump.corefile.clone()),
+                Cell::from(dump.exe.clone()),
             ])
         })
         .collect();
 
     let widths = [
+        Constraint::Length(20),  // Time
         Constraint::Length(8),   // PID
-        Constraint::Min(20),     // Name
-        Constraint::Length(8),   // CPU%
-        Constraint::Length(12),  // Memory
-        Constraint::Length(15),  // User
+        Constraint::Length(6),   // UID
+        Constraint::Length(8),   // Signal
+        Constraint::Length(9),   // Core
+        Constraint::Min(20),     // Program
     ];
 
-    let sort_indicator = match app.process_sort_mode {
-        crate::ProcessSortMode::Cpu => "CPU",
-        crate::ProcessSortMode::Memory => "Memory",
-    };
-    
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White).add_modifier(Modifier::BOLD))
+        .column_spacing(1);
+
+    let mut table_state = TableState::default();
+    table_state.select(Some(app.coredump_scroll));
+    f.render_stateful_widget(table, chunks[1], &mut table_state);
+}
+
+fn draw_filesystems(f: &mut Frame, app: &App, area: Rect) {
+    const USAGE_BAR_WIDTH: usize = 20;
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Instructions
+            Constraint::Min(0),     // Filesystem table
+        ])
+        .split(area);
+
+    let instructions = Paragraph::new("⬆️⬇️ scroll, PgUp/PgDn fast scroll, Tab switch")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
+
+    let gigabytes = |bytes: u64| format!("{:.1} GB", bytes as f64 / 1024.0 / 1024.0 / 1024.0);
+    let filesystems = app.metrics.filesystems();
+    let header = Row::new(vec!["Mount", "Device", "Type", "Size", "Used", "Avail", "Use%", "Inodes"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = filesystems
+        .iter()
+        .map(|fs| {
+            let percent = fs.usage_percent();
+            let color = if percent < 50.0 {
+                Color::Rgb(163, 190, 140) // Nord aurora green
+            } else if percent < 70.0 {
+                Color::Rgb(235, 203, 139) // Nord aurora yellow
+            } else if percent < 90.0 {
+                Color::Rgb(208, 135, 112) // Nord aurora orange
+            } else {
+                Color::Rgb(191, 97, 106) // Nord aurora red
+            };
+            let filled = ((percent / 100.0) * USAGE_BAR_WIDTH as f32).round() as usize;
+            let bar = Line::from(vec![
+                Span::styled("█".repeat(filled.min(USAGE_BAR_WIDTH)), Style::default().fg(color)),
+                Span::styled("░".repeat(USAGE_BAR_WIDTH.saturating_sub(filled)), Style::default().fg(Color::DarkGray)),
+                Span::raw(format!(" {:5.1}%", percent)),
+            ]);
+            // Filesystems that allocate inodes on demand have no fixed count
+            let inodes = match fs.inode_percent() {
+                Some(inode_percent) if fs.inodes_low() => Cell::from(format!("{:5.1}% ⚠️", inode_percent))
+                    .style(Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD)), // Nord aurora red
+                Some(inode_percent) => Cell::from(format!("{:5.1}%", inode_percent)),
+                None => Cell::from("    -").style(Style::default().fg(Color::DarkGray)),
+            };
+            Row::new(vec![
+                Cell::from(fs.mount_point.clone()),
+                Cell::from(fs.device.clone()),
+                Cell::from(fs.fs_type.clone()),
+                Cell::from(gigabytes(fs.total)),
+                Cell::from(gigabytes(fs.used())),
+                Cell::from(gigabytes(fs.available)),
+                Cell::from(bar),
+                inodes,
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Min(20),     // Mount
+        Constraint::Length(20),  // Device
+        Constraint::Length(8),   // Type
+        Constraint::Length(10),  // Size
+        Constraint::Length(10),  // Used
+        Constraint::Length(10),  // Avail
+        Constraint::Length(USAGE_BAR_WIDTH as u16 + 7), // Use%
+        Constraint::Length(10),  // Inodes
+    ];
+
+    let low_inodes = filesystems.iter().filter(|fs| fs.inodes_low()).count();
+    let mut title = format!("💾 Filesystems ({} mounted)", filesystems.len());
+    if low_inodes > 0 {
+        title.push_str(&format!(" • ⚠️ {} low on inodes", low_inodes));
+    }
+
     let table = Table::new(rows, widths)
         .header(header)
         .block(Block::default()
-            .title(format!("⚙️ Running Processes ({} total, sorted by {}) • Selected: [K] to kill", app.processes.len(), sort_indicator))
+            .title(title)
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)))
-        .row_highlight_style(Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD))
+            .border_style(Style::default().fg(Color::Rgb(235, 203, 139))))
+        .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White).add_modifier(Modifier::BOLD))
         .column_spacing(1);
 
     let mut table_state = TableState::default();
-    if !app.processes.is_empty() {
-        // Ensure scroll position is always valid
-        let scroll_pos = app.process_scroll.min(app.processes.len().saturating_sub(1));
-        table_state.select(Some(scroll_pos));
+    if !filesystems.is_empty() {
+        table_state.select(Some(app.filesystem_scroll.min(filesystems.len() - 1)));
     }
     f.render_stateful_widget(table, chunks[1], &mut table_state);
 }
 
-fn draw_cpu_widget(f: &mut Frame, app: &App, area: Rect) {
-    let cpu_usage = app.metrics.cpu_usage();
-    
-    // Split into gauge and info areas (no chart)
+fn draw_sensors(f: &mut Frame, view: &SensorsView, unit: TemperatureUnit, area: Rect) {
+    // Board temperatures on top: VRM and chipset heat often explains throttling
+    let board: Vec<&Sensor> = view.sensors.iter().filter(|sensor| sensor.is_board_temperature()).collect();
+    let board_lines = board.len().div_ceil(BOARD_TEMPERATURES_PER_LINE) as u16;
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),  // Gauge
-            Constraint::Min(0),     // Info (expanded to fill space)
+            Constraint::Length(3),                                          // Instructions
+            Constraint::Length(if board.is_empty() { 0 } else { board_lines + 2 }),  // Board temperatures
+            Constraint::Min(0),                                             // Sensor table
         ])
         .split(area);
 
-    // Enhanced CPU Gauge with Btop-inspired colors
-    let cpu_color = if cpu_usage < 30.0 {
-        Color::Rgb(163, 190, 140) // Nord green
-    } else if cpu_usage < 50.0 {
-        Color::Rgb(235, 203, 139) // Nord yellow
-    } else if cpu_usage < 80.0 {
-        Color::Rgb(208, 135, 112) // Nord orange
-    } else {
-        Color::Rgb(191, 97, 106) // Nord red
+    let instructions = Paragraph::new("⬆️⬇️ scroll, PgUp/PgDn fast scroll • [R] reset min/max • Tab switch")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
+
+    if !board.is_empty() {
+        let lines: Vec<Line> = board
+            .chunks(BOARD_TEMPERATURES_PER_LINE)
+            .map(|sensors| {
+                let mut spans = Vec::new();
+                for sensor in sensors {
+                    let color = if sensor.out_of_range() || sensor.value >= 90.0 {
+                        Color::Rgb(191, 97, 106) // Nord aurora red
+                    } else if sensor.value >= 70.0 {
+                        Color::Rgb(235, 203, 139) // Nord aurora yellow
+                    } else {
+                        Color::Rgb(163, 190, 140) // Nord aurora green
+                    };
+                    spans.push(Span::raw(format!("{}: ", sensor.board_label())));
+                    spans.push(Span::styled(unit.format(sensor.value as f32, 1), Style::default().fg(color)));
+                    spans.push(Span::styled(format!(" (max {:.0})   ", unit.convert(sensor.highest as f32)), Style::default().fg(Color::DarkGray)));
+                }
+                Line::from(spans)
+            })
+            .collect();
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default()
+                .title("🧩 Board Temperatures")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Rgb(208, 135, 112)))) // Nord aurora orange
+            .style(Style::default().fg(Color::White));
+        f.render_widget(paragraph, chunks[1]);
+    }
+
+    // Voltages and currents need more precision than temperatures and fan speeds
+    let format_value = |kind: SensorKind, value: f64| match kind {
+        SensorKind::Temperature => format!("{:.1} {}", unit.convert(value as f32), unit.symbol()),
+        SensorKind::Fan => format!("{:.0} {}", value, kind.unit()),
+        SensorKind::Voltage | SensorKind::Current => format!("{:.3} {}", value, kind.unit()),
+        SensorKind::Power => format!("{:.2} {}", value, kind.unit()),
     };
-    
-    let gauge = Gauge::default()
+    let header = Row::new(vec!["Chip", "Sensor", "Value", "Min", "Max", "Limits"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let mut previous_chip = None;
+    let rows: Vec<Row> = view.sensors
+        .iter()
+        .map(|sensor| {
+            // The chip name is only shown on the first of its rows
+            let chip = if previous_chip == Some(&sensor.chip) { String::new() } else { sensor.chip.clone() };
+            previous_chip = Some(&sensor.chip);
+            let limits = match (sensor.low_limit, sensor.high_limit) {
+                (Some(low), Some(high)) => format!("{} – {}", format_value(sensor.kind, low), format_value(sensor.kind, high)),
+                (Some(low), None) => format!("≥ {}", format_value(sensor.kind, low)),
+                (None, Some(high)) => format!("≤ {}", format_value(sensor.kind, high)),
+                (None, None) => String::new(),
+            };
+            let value_style = if sensor.out_of_range() {
+                Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD) // Nord aurora red
+            } else {
+                Style::default().fg(Color::Rgb(163, 190, 140)) // Nord aurora green
+            };
+            Row::new(vec![
+                Cell::from(chip).style(Style::default().fg(Color::Rgb(136, 192, 208))), // Nord frost
+                Cell::from(sensor.label.clone()),
+                Cell::from(format_value(sensor.kind, sensor.value)).style(value_style),
+                Cell::from(format_value(sensor.kind, sensor.lowest)),
+                Cell::from(format_value(sensor.kind, sensor.highest)),
+                Cell::from(limits).style(Style::default().fg(Color::DarkGray)),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(16),  // Chip
+        Constraint::Min(20),     // Sensor
+        Constraint::Length(12),  // Value
+        Constraint::Length(12),  // Min
+        Constraint::Length(12),  // Max
+        Constraint::Length(26),  // Limits
+    ];
+
+    let alerts = view.sensors.iter().filter(|sensor| sensor.out_of_range()).count();
+    let mut title = format!("🌡️ Sensors ({})", view.sensors.len());
+    if alerts > 0 {
+        title.push_str(&format!(" • ⚠️ {} beyond limits", alerts));
+    }
+    let table = Table::new(rows, widths)
+        .header(header)
         .block(Block::default()
-            .title("🧠 CPU Usage")
+            .title(title)
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(163, 190, 140))))
-        .gauge_style(Style::default().fg(cpu_color))
-        .percent(cpu_usage as u16)
-        .label(format!("{:.1}%", cpu_usage));
-    f.render_widget(gauge, chunks[0]);
+            .border_style(Style::default().fg(Color::Rgb(208, 135, 112)))) // Nord aurora orange
+        .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White).add_modifier(Modifier::BOLD))
+        .column_spacing(1);
 
-    // Enhanced CPU Info
-    let mut cpu_info = if let Some(cpu) = app.system.cpus().first() {
-        vec![
-            Line::from("╭─ CPU Info ─────────────────╮"),
-            Line::from(format!("│ Brand: {}", cpu.brand())),
-            Line::from(format!("│ ⚡ Cores: {}  Freq: {:.0} MHz", app.system.cpus().len(), cpu.frequency())),
-            Line::from("╰───────────────────────────╯"),
-            Line::from(""),  // Empty line for spacing
-        ]
-    } else {
-        vec![Line::from("⚠️ CPU info unavailable")]
+    let mut table_state = TableState::default();
+    if !view.sensors.is_empty() {
+        table_state.select(Some(view.scroll));
+    }
+    f.render_stateful_widget(table, chunks[2], &mut table_state);
+}
+
+fn draw_audit(f: &mut Frame, audit: &AuditView, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Instructions / filters
+            Constraint::Min(0),     // Event table
+        ])
+        .split(area);
+
+    let filters = format!(
+        "⬆️⬇️ scroll, PgUp/PgDn fast scroll • [T] type: {} • [K] key: {}",
+        audit.type_filter.as_deref().unwrap_or("all"),
+        audit.key_filter.as_deref().unwrap_or("all"),
+    );
+    let (status, status_color) = match &audit.error {
+        Some(err) => (format!("⚠️ {}", err), Color::Rgb(191, 97, 106)),
+        None => (filters, Color::Gray),
     };
+    let instructions = Paragraph::new(status)
+        .style(Style::default().fg(status_color))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
 
-    // Add per-core usage and temperature info side by side
-    let per_core = app.metrics.per_core_usage();
-    let per_core_temps = app.metrics.per_core_temperatures();
-    
-    if !per_core.is_empty() {
-        if per_core.len() <= 8 {
-            // For systems with 8 cores or fewer, show detailed per-core info
-            cpu_info.push(Line::from("╭─ Core Usage & Temperature ─╮"));
-            
-            for (i, &usage) in per_core.iter().enumerate() {
-                // Get temperature for this core if available
-                let temp_str = if i < per_core_temps.len() {
-                    format!("{:5.1}°C", per_core_temps[i])
-                } else {
-                    "  N/A ".to_string()
-                };
-                
-                // Enhanced visual usage bars with better Unicode
-                let usage_bar = if usage < 12.5 {
-                    "▁"
-                } else if usage < 25.0 {
-                    "▂"
-                } else if usage < 37.5 {
-                    "▃"
-                } else if usage < 50.0 {
-                    "▄"
-                } else if usage < 62.5 {
-                    "▅"
-                } else if usage < 75.0 {
-                    "▆"
-                } else if usage < 87.5 {
-                    "▇"
-                } else {
-                    "█"
-                };
-                
-                cpu_info.push(Line::from(format!("│ Core {:2}: {:5.1}% {} │ 🌡️ {}", i, usage, usage_bar, temp_str)));
+    let events = audit.filtered();
+    let header = Row::new(vec![
+        Cell::from("Time"),
+        Cell::from("Serial"),
+        Cell::from("Type"),
+        Cell::from("Key"),
+        Cell::from("Details"),
+    ])
+    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = events
+        .iter()
+        .map(|event| {
+            // Denials and anomalies stand out from routine records
+            let type_style = if event.record_type.contains("AVC") || event.record_type.starts_with("ANOM") {
+                Style::default().fg(Color::Rgb(191, 97, 106))
+            } else {
+                Style::default().fg(Color::Rgb(136, 192, 208))
+            };
+            Row::new(vec![
+                Cell::from(event.time.map(|t| t.format("%m-%d %H:%M:%S").to_string()).unwrap_or_default()),
+                Cell::from(event.serial.to_string()),
+                Cell::from(event.record_type.clone()).style(type_style),
+                Cell::from(event.key.clone().unwrap_or_default()),
+                Cell::from(event.message.clone()),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(15),  // Time
+        Constraint::Length(8),   // Serial
+        Constraint::Length(16),  // Type
+        Constraint::Length(14),  // Key
+        Constraint::Min(20),     // Details
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default()
+            .title(format!("🛡️ Audit Events ({} of {} - Newest First)", events.len(), audit.events.len()))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
+        .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+        .column_spacing(1);
+
+    let mut table_state = TableState::default();
+    if !events.is_empty() {
+        table_state.select(Some(audit.scroll));
+    }
+    f.render_stateful_widget(table, chunks[1], &mut table_state);
+}
+
+fn draw_connections(f: &mut Frame, view: &ConnectionsView, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Instructions / filters
+            Constraint::Min(0),     // Socket table
+        ])
+        .split(area);
+
+    let filters = format!(
+        "⬆️⬇️ scroll, PgUp/PgDn fast scroll • [Enter] show process • [S] sort: {} • [P] protocol: {} • [T] state: {}",
+        view.sort.label(),
+        view.protocol_filter.unwrap_or("all"),
+        view.state_filter.as_deref().unwrap_or("all"),
+    );
+    let instructions = Paragraph::new(filters)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
+
+    let sockets = view.filtered();
+    let header = Row::new(vec![
+        Cell::from("Proto"),
+        Cell::from("State"),
+        Cell::from("Local Address"),
+        Cell::from("Remote Address"),
+        Cell::from("PID"),
+        Cell::from("Process"),
+    ])
+    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = sockets
+        .iter()
+        .map(|socket| {
+            let connection = &socket.connection;
+            // Listening sockets are what the box offers, established ones what it talks to
+            let state_style = match connection.state {
+                "LISTEN" | "UNCONN" => Style::default().fg(Color::Rgb(136, 192, 208)),      // Nord frost
+                "ESTABLISHED" | "CONNECTED" => Style::default().fg(Color::Rgb(163, 190, 140)), // Nord green
+                "SYN_SENT" | "SYN_RECV" | "CLOSE_WAIT" => Style::default().fg(Color::Rgb(235, 203, 139)), // Nord yellow
+                _ => Style::default().fg(Color::Rgb(76, 86, 106)),                          // Nord gray
+            };
+            let (pid, name) = match &socket.owner {
+                Some((pid, name)) => (pid.to_string(), name.clone()),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            Row::new(vec![
+                Cell::from(connection.protocol),
+                Cell::from(connection.state).style(state_style),
+                Cell::from(connection.local.clone()),
+                Cell::from(connection.remote.clone().unwrap_or_else(|| "*".to_string())),
+                Cell::from(pid),
+                Cell::from(name),
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(5),   // Proto
+        Constraint::Length(11),  // State
+        Constraint::Min(22),     // Local
+        Constraint::Min(22),     // Remote
+        Constraint::Length(8),   // PID
+        Constraint::Min(15),     // Process
+    ];
+
+    // Other users' sockets have no visible owner without root; TIME_WAIT sockets (inode 0)
+    // belong to no process at all
+    let unowned = sockets.iter().filter(|socket| socket.owner.is_none() && socket.connection.inode != 0).count();
+    let mut title = format!("🔌 Connections ({} of {})", sockets.len(), view.sockets.len());
+    if unowned > 0 {
+        title.push_str(&format!(" - {} without a visible process, run as root to see all", unowned));
+    }
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
+        .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+        .column_spacing(1);
+
+    let mut table_state = TableState::default();
+    if !sockets.is_empty() {
+        table_state.select(Some(view.scroll));
+    }
+    f.render_stateful_widget(table, chunks[1], &mut table_state);
+}
+
+fn draw_routes(f: &mut Frame, view: &RoutesView, area: Rect) {
+    // The routing table is short; the neighbor cache gets the rest and scrolls
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),                                                  // Instructions
+            Constraint::Length((view.routes.len() as u16 + 3).min(area.height / 2)), // Routes
+            Constraint::Min(0),                                                     // Neighbors
+        ])
+        .split(area);
+
+    let instructions = Paragraph::new("⬆️⬇️ scroll neighbors, PgUp/PgDn fast scroll • refreshed every 5s")
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
+
+    let header_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let route_rows: Vec<Row> = view.routes
+        .iter()
+        .map(|route| {
+            // Default routes decide where most traffic goes
+            let style = if route.is_default() {
+                Style::default().fg(Color::Rgb(136, 192, 208)).add_modifier(Modifier::BOLD) // Nord frost
+            } else {
+                Style::default()
+            };
+            Row::new(vec![
+                Cell::from(route.destination_label()),
+                Cell::from(route.gateway.map_or("on-link".to_string(), |gateway| gateway.to_string())),
+                Cell::from(route.interface.clone()),
+                Cell::from(route.metric.to_string()),
+            ])
+            .style(style)
+        })
+        .collect();
+    let route_table = Table::new(route_rows, [
+        Constraint::Min(24),     // Destination
+        Constraint::Min(24),     // Gateway
+        Constraint::Length(12),  // Interface
+        Constraint::Length(8),   // Metric
+    ])
+    .header(Row::new(vec!["Destination", "Gateway", "Interface", "Metric"]).style(header_style))
+    .block(Block::default()
+        .title(format!("🔀 Routes ({})", view.routes.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(143, 188, 187)))) // Nord teal
+    .column_spacing(1);
+    f.render_widget(route_table, chunks[1]);
+
+    let neighbor_rows: Vec<Row> = view.neighbors
+        .iter()
+        .map(|neighbor| {
+            let state_style = match neighbor.state.as_str() {
+                "REACHABLE" | "PERMANENT" | "COMPLETE" => Style::default().fg(Color::Rgb(163, 190, 140)), // Nord green
+                "FAILED" | "INCOMPLETE" => Style::default().fg(Color::Rgb(191, 97, 106)),                  // Nord red
+                "DELAY" | "PROBE" => Style::default().fg(Color::Rgb(235, 203, 139)),                      // Nord yellow
+                _ => Style::default().fg(Color::Rgb(76, 86, 106)),                                        // Nord gray
+            };
+            Row::new(vec![
+                Cell::from(neighbor.address.to_string()),
+                Cell::from(neighbor.mac.clone().unwrap_or_else(|| "-".to_string())),
+                Cell::from(neighbor.interface.clone()),
+                Cell::from(neighbor.state.clone()).style(state_style),
+                Cell::from(if neighbor.router { "router" } else { "" }),
+            ])
+        })
+        .collect();
+    let neighbor_table = Table::new(neighbor_rows, [
+        Constraint::Min(24),     // Address
+        Constraint::Length(18),  // MAC
+        Constraint::Length(12),  // Interface
+        Constraint::Length(11),  // State
+        Constraint::Length(6),   // Router
+    ])
+    .header(Row::new(vec!["Address", "MAC", "Interface", "State", ""]).style(header_style))
+    .block(Block::default()
+        .title(format!("🔗 Neighbors, ARP/NDP ({})", view.neighbors.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
+    .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+    .column_spacing(1);
+
+    let mut table_state = TableState::default();
+    if !view.neighbors.is_empty() {
+        table_state.select(Some(view.scroll));
+    }
+    f.render_stateful_widget(neighbor_table, chunks[2], &mut table_state);
+}
+
+fn draw_firewall(f: &mut Frame, view: &FirewallView, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Instructions / error
+            Constraint::Min(0),     // Rules
+        ])
+        .split(area);
+
+    let instructions = format!(
+        "⬆️⬇️ scroll, PgUp/PgDn fast scroll • [M] {} • refreshed every {}s",
+        if view.matching_only { "all rules" } else { "matching rules only" },
+        view.config.interval.max(1),
+    );
+    let (status, status_color) = match &view.error {
+        Some(err) => (format!("⚠️ {}", err), Color::Rgb(191, 97, 106)), // Nord red
+        None => (instructions, Color::Gray),
+    };
+    let status = Paragraph::new(status)
+        .style(Style::default().fg(status_color))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(status, chunks[0]);
+
+    let rules = view.filtered();
+    let rows: Vec<Row> = rules
+        .iter()
+        .map(|rule| {
+            // Rules matching right now are what firewall debugging is looking for
+            let style = if rule.rate > 0.0 {
+                Style::default().fg(Color::Rgb(235, 203, 139)).add_modifier(Modifier::BOLD) // Nord yellow
+            } else if rule.counter.is_some_and(|(packets, _)| packets > 0) {
+                Style::default()
+            } else {
+                Style::default().fg(Color::Rgb(76, 86, 106)) // Nord gray
+            };
+            let (packets, bytes) = match rule.counter {
+                Some((packets, bytes)) => (packets.to_string(), crate::format_bytes(bytes)),
+                None => ("-".to_string(), "-".to_string()),
+            };
+            Row::new(vec![
+                Cell::from(rule.table.clone()),
+                Cell::from(rule.chain.clone()),
+                Cell::from(packets),
+                Cell::from(bytes),
+                Cell::from(if rule.rate > 0.0 { format!("{:.1}/s", rule.rate) } else { String::new() }),
+                Cell::from(rule.rule.clone()),
+            ])
+            .style(style)
+        })
+        .collect();
+    let title = match view.backend {
+        Some(backend) => format!("🧱 Firewall Rules · {} ({})", backend.label(), rules.len()),
+        None => "🧱 Firewall Rules".to_string(),
+    };
+    let table = Table::new(rows, [
+        Constraint::Length(14),  // Table
+        Constraint::Length(14),  // Chain
+        Constraint::Length(10),  // Packets
+        Constraint::Length(10),  // Bytes
+        Constraint::Length(10),  // Rate
+        Constraint::Min(20),     // Rule
+    ])
+    .header(Row::new(vec!["Table", "Chain", "Packets", "Bytes", "Rate", "Rule"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+    .block(Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(208, 135, 112)))) // Nord orange
+    .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+    .column_spacing(1);
+
+    let mut table_state = TableState::default();
+    if !rules.is_empty() {
+        table_state.select(Some(view.scroll));
+    }
+    f.render_stateful_widget(table, chunks[1], &mut table_state);
+}
+
+fn draw_custom_tab(f: &mut Frame, tab: &CustomTab, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Command / status
+            Constraint::Min(0),     // Output
+        ])
+        .split(area);
+
+    // Show the command being run, or the last error
+    let (status, status_color) = match &tab.error {
+        Some(err) => (format!("⚠️ {}: {}", tab.config.command, err), Color::Rgb(191, 97, 106)),
+        None => (format!("$ {} (every {}s)", tab.config.command, tab.config.interval), Color::Gray),
+    };
+    let instructions = Paragraph::new(status)
+        .style(Style::default().fg(status_color))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
+
+    let block = Block::default()
+        .title(format!("🧩 {}", tab.config.title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(180, 142, 173))); // Nord purple
+
+    match tab.config.format {
+        CustomTabFormat::Text => {
+            let items: Vec<ListItem> = tab.lines
+                .iter()
+                .map(|line| ListItem::new(line.as_str()))
+                .collect();
+            let list = List::new(items)
+                .block(block)
+                .style(Style::default().fg(Color::White))
+                .highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White));
+
+            let mut list_state = ListState::default();
+            if !tab.lines.is_empty() {
+                list_state.select(Some(tab.scroll));
             }
-            cpu_info.push(Line::from("╰─────────────────────────────╯"));
-        } else {
-            // For systems with many cores, show summary stats first
-            let avg_usage = per_core.iter().sum::<f32>() / per_core.len() as f32;
-            let max_usage = per_core.iter().fold(0.0f32, |a, &b| a.max(b));
-            let min_usage = per_core.iter().fold(100.0f32, |a, &b| a.min(b));
-            
-            cpu_info.push(Line::from("┌─ Usage Summary ─────────────"));
-            cpu_info.push(Line::from(format!("│ Avg: {:5.1}%  Max: {:5.1}%", avg_usage, max_usage)));
-            cpu_info.push(Line::from(format!("│ Min: {:5.1}%  Cores: {:3}", min_usage, per_core.len())));
-            
-            // Show temperature stats if available
-            if !per_core_temps.is_empty() {
-                let avg_temp = per_core_temps.iter().sum::<f32>() / per_core_temps.len() as f32;
-                let max_temp = per_core_temps.iter().fold(0.0f32, |a, &b| a.max(b));
-                let _min_temp = per_core_temps.iter().fold(200.0f32, |a, &b| a.min(b));
-                cpu_info.push(Line::from(format!("│ Temp: {:.1}°C  Max: {:.1}°C", avg_temp, max_temp)));
+            f.render_stateful_widget(list, chunks[1], &mut list_state);
+        }
+        CustomTabFormat::Table => {
+            let (header, rows) = tab.table();
+            let columns = header.len().max(1);
+            let header = Row::new(header.into_iter().map(Cell::from).collect::<Vec<_>>())
+                .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+            let rows: Vec<Row> = rows
+                .into_iter()
+                .map(|row| Row::new(row.into_iter().map(Cell::from).collect::<Vec<_>>()))
+                .collect();
+            let has_rows = !rows.is_empty();
+
+            let table = Table::new(rows, vec![Constraint::Ratio(1, columns as u32); columns])
+                .header(header)
+                .block(block)
+                .row_highlight_style(Style::default().bg(Color::DarkGray).fg(Color::White))
+                .column_spacing(1);
+
+            let mut table_state = TableState::default();
+            if has_rows {
+                table_state.select(Some(tab.scroll));
             }
-            cpu_info.push(Line::from("└─────────────────────────────"));
-            cpu_info.push(Line::from(""));  // Empty line for spacing
-            
-            // Show all cores in a more compact but readable format
-            cpu_info.push(Line::from("┌─ Individual Cores ─────────"));
-            
-            let cores_per_line = 4;
-            for chunk in per_core.chunks(cores_per_line) {
-                let mut line = String::from("│ ");
-                for (local_i, &usage) in chunk.iter().enumerate() {
-                    let core_idx = (chunk.as_ptr() as usize - per_core.as_ptr() as usize) / std::mem::size_of::<f32>() + local_i;
-                    
-                    // Get temperature for this core if available
-                    let temp_str = if core_idx < per_core_temps.len() {
-                        format!("{:.0}°", per_core_temps[core_idx])
-                    } else {
-                        "N/A".to_string()
-                    };
-                    
-                    line += &format!("C{:2}:{:4.0}%/{:>3} ", core_idx, usage, temp_str);
+            f.render_stateful_widget(table, chunks[1], &mut table_state);
+        }
+    }
+}
+
+fn draw_processes(f: &mut Frame, app: &App, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(4),  // Instructions
+            Constraint::Min(0),     // Process table
+        ])
+        .split(area);
+
+    // Instructions with sort and kill controls, one line each for viewing and acting
+    let instructions = Paragraph::new(vec![
+        Line::from("⬆️⬇️ PgUp/PgDn scroll • [C/M/B/P/N/O/T/S] sort cpu/mem/net/pid/name/owner/time/swap • [R] reverse • [W] swap/shared • [U] user • [F] follow"),
+        Line::from("[Shift+T] threads • [Enter] details • [+/-] nice • [K] kill • [Z/G/H] stop/cont/hup"),
+    ])
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL));
+    f.render_widget(instructions, chunks[0]);
+
+    // Process table, with the sort direction on the sorted column
+    let (sort_column, sort_name, descending) = match app.process_sort_mode {
+        crate::ProcessSortMode::Cpu => ("CPU%", "CPU", true),
+        crate::ProcessSortMode::Memory => ("Memory", "Memory", true),
+        crate::ProcessSortMode::Network => ("Net ↓", "Network", true),
+        crate::ProcessSortMode::Pid => ("PID", "PID", false),
+        crate::ProcessSortMode::Name => ("Name", "Name", false),
+        crate::ProcessSortMode::User => ("User", "User", false),
+        crate::ProcessSortMode::Runtime => ("Time", "Runtime", true),
+        crate::ProcessSortMode::Swap => ("Swap", "Swap", true),
+    };
+    // Optional swap and shared memory columns go right after Memory
+    const MEMORY_DETAILS_AT: usize = 8;
+    let mut labels = vec!["PID", "Name", "S", "CPU%", "CPU History", "PRI", "NI", "Memory", "Net ↓", "Net ↑", "Time", "User"];
+    if app.show_memory_columns {
+        labels.splice(MEMORY_DETAILS_AT..MEMORY_DETAILS_AT, ["Swap", "Shared"]);
+    }
+    let arrow = if descending != app.process_sort_reversed { "▼" } else { "▲" };
+    let header = Row::new(
+        labels
+            .into_iter()
+            .map(|label| {
+                if label == sort_column {
+                    Cell::from(format!("{}{}", label, arrow))
+                } else {
+                    Cell::from(label)
                 }
-                cpu_info.push(Line::from(line));
+            }),
+    )
+    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    // Compact runtime: 45s, 12m05s, 3h20m, 2d04h
+    let format_runtime = |seconds: u64| match seconds {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m{:02}s", s / 60, s % 60),
+        s if s < 86400 => format!("{}h{:02}m", s / 3600, s % 3600 / 60),
+        s => format!("{}d{:02}h", s / 86400, s % 86400 / 3600),
+    };
+
+    // Process rates are measured in KB/s
+    let format_rate = |kbs: f32| {
+        if kbs <= 0.0 {
+            "-".to_string()
+        } else {
+            app.rate_unit.format(kbs * 8.0)
+        }
+    };
+
+    // Scaled to one core, or higher for processes that kept several cores busy
+    let cpu_sparkline = |pid: u32| {
+        app.process_cpu_history.get(&pid).map_or(String::new(), |samples| {
+            let samples: Vec<f32> = samples.iter().copied().collect();
+            let peak = samples.iter().copied().fold(100.0, f32::max);
+            text_sparkline(&samples, 0.0, peak)
+        })
+    };
+
+    // Newly started processes in green, exited ones struck through until they drop out
+    let is_new = |process: &crate::ProcessInfo| {
+        !process.exited && process.run_time < crate::NEW_PROCESS_AGE.as_secs()
+    };
+    let rows: Vec<Row> = app.processes
+        .iter()
+        .map(|process| {
+            let megabytes = |bytes: u64| format!("{:.1}MB", bytes as f64 / 1024.0 / 1024.0);
+            let style = if process.exited {
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::CROSSED_OUT)
+            } else if process.state == 'Z' {
+                Style::default().fg(Color::Rgb(191, 97, 106)) // Nord red
+            } else if process.state == 'D' {
+                Style::default().fg(Color::Rgb(208, 135, 112)) // Nord orange
+            } else if is_new(process) {
+                Style::default().fg(Color::Rgb(163, 190, 140)) // Nord green
+            } else {
+                Style::default()
+            };
+            
+            let mut cells = vec![
+                Cell::from(process.pid.to_string()),
+                if app.followed_pid == Some(process.pid) {
+                    Cell::from(format!("📌 {}", process.name))
+                } else {
+                    Cell::from(process.name.clone())
+                },
+                Cell::from(process.state.to_string()),
+                Cell::from(format!("{:.1}", process.cpu_usage)),
+                Cell::from(cpu_sparkline(process.pid)),
+                Cell::from(process.priority.map_or("-".to_string(), |(priority, _)| priority.to_string())),
+                Cell::from(process.priority.map_or("-".to_string(), |(_, nice)| nice.to_string())),
+                Cell::from(megabytes(process.memory_usage)),
+                Cell::from(format_rate(process.net_rx)),
+                Cell::from(format_rate(process.net_tx)),
+                Cell::from(format_runtime(process.run_time)),
+                Cell::from(process.user.clone()),
+            ];
+            if app.show_memory_columns {
+                cells.splice(MEMORY_DETAILS_AT..MEMORY_DETAILS_AT, [
+                    Cell::from(megabytes(process.swap_usage)),
+                    Cell::from(megabytes(process.shared_memory)),
+                ]);
             }
-            cpu_info.push(Line::from("└─────────────────────────────"));
+            Row::new(cells).style(style)
+        })
+        .collect();
+
+    let mut widths = vec![
+        Constraint::Length(8),   // PID
+        Constraint::Min(20),     // Name
+        Constraint::Length(1),   // State
+        Constraint::Length(8),   // CPU%
+        Constraint::Length(crate::PROCESS_CPU_SAMPLES as u16), // CPU History
+        Constraint::Length(4),   // PRI
+        Constraint::Length(4),   // NI
+        Constraint::Length(12),  // Memory
+        Constraint::Length(11),  // Net ↓
+        Constraint::Length(11),  // Net ↑
+        Constraint::Length(7),   // Time
+        Constraint::Length(15),  // User
+    ];
+    if app.show_memory_columns {
+        widths.splice(MEMORY_DETAILS_AT..MEMORY_DETAILS_AT, [
+            Constraint::Length(10),  // Swap
+            Constraint::Length(10),  // Shared
+        ]);
+    }
+
+    let sort_indicator = format!("{} {}", sort_name, arrow);
+    let exited = app.processes.iter().filter(|p| p.exited).count();
+    let running = app.processes.len() - exited;
+    let started = app.processes.iter().filter(|p| is_new(p)).count();
+    let mut notices = if started + exited > 0 {
+        format!(" • {} new, {} exited", started, exited)
+    } else {
+        String::new()
+    };
+    let zombies = app.processes.iter().filter(|p| !p.exited && p.state == 'Z').count();
+    if zombies > 0 {
+        notices.push_str(&format!(" • ⚠️ {} zombie{}", zombies, if zombies == 1 { "" } else { "s" }));
+    }
+    if let Some(pid) = app.followed_pid {
+        notices.push_str(&format!(" • 📌 following PID {}", pid));
+    }
+    
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default()
+            .title(if app.filter_by_user {
+                format!("⚙️ Running Processes ({} of user {}, sorted by {}){} • Selected: [K] to kill", running, app.process_user, sort_indicator, notices)
+            } else {
+                format!("⚙️ Running Processes ({} total, sorted by {}){} • Selected: [K] to kill", running, sort_indicator, notices)
+            })
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)))
+        .row_highlight_style(Style::default().bg(Color::Red).fg(Color::White).add_modifier(Modifier::BOLD))
+        .column_spacing(1);
+
+    let mut table_state = TableState::default();
+    if !app.processes.is_empty() {
+        // Ensure scroll position is always valid
+        let scroll_pos = app.process_scroll.min(app.processes.len().saturating_sub(1));
+        table_state.select(Some(scroll_pos));
+    }
+    // The threads or detail pane takes the lower part of the table area while open
+    if app.thread_view.is_some() || app.process_detail.is_some() {
+        let table_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(chunks[1]);
+        f.render_stateful_widget(table, table_chunks[0], &mut table_state);
+        if let Some(view) = &app.thread_view {
+            draw_threads(f, view, table_chunks[1]);
+        } else if let Some(detail) = &app.process_detail {
+            draw_process_detail(f, detail, table_chunks[1]);
         }
+    } else {
+        f.render_stateful_widget(table, chunks[1], &mut table_state);
     }
+}
+
+/// Command line, memory, open file descriptors and sockets of the process opened with [Enter]
+fn draw_process_detail(f: &mut Frame, detail: &ProcessDetail, area: Rect) {
+    let block = Block::default()
+        .title(format!("🔍 {} (PID {}) • [Enter] close", detail.name, detail.pid))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)])
+        .split(inner);
+
+    let fds = &detail.fds;
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Command: ", Style::default().fg(Color::Gray)),
+        Span::raw(detail.command.clone()),
+    ])];
+    if let Some(error) = &detail.error {
+        lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(Color::Rgb(191, 97, 106))))); // Nord red
+    } else {
+        let (usage, usage_color) = match (fds.limit, fds.percent_used()) {
+            (Some(limit), Some(percent)) => (
+                format!("{} of {} ({:.1}%)", fds.count, limit, percent),
+                if percent >= 80.0 {
+                    Color::Rgb(191, 97, 106) // Nord red
+                } else if percent >= 50.0 {
+                    Color::Rgb(235, 203, 139) // Nord yellow
+                } else {
+                    Color::Rgb(163, 190, 140) // Nord green
+                },
+            ),
+            _ => (fds.count.to_string(), Color::White),
+        };
+        lines.push(Line::from(vec![
+            Span::styled("Open files: ", Style::default().fg(Color::Gray)),
+            Span::styled(usage, Style::default().fg(usage_color).add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!(" • {} files, {} sockets, {} pipes, {} other", fds.files, fds.sockets, fds.pipes, fds.other),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+    }
+    match &detail.memory {
+        Some(memory) => lines.push(Line::from(vec![
+            Span::styled("Memory: ", Style::default().fg(Color::Gray)),
+            Span::styled(
+                format!("{} PSS", crate::format_bytes(memory.pss)),
+                Style::default().fg(Color::Rgb(136, 192, 208)).add_modifier(Modifier::BOLD), // Nord frost
+            ),
+            Span::styled(
+                format!(
+                    " • {} RSS: {} anon, {} file, {} shared • {} swap",
+                    crate::format_bytes(memory.rss),
+                    crate::format_bytes(memory.anonymous),
+                    crate::format_bytes(memory.file),
+                    crate::format_bytes(memory.shared),
+                    crate::format_bytes(memory.swap),
+                ),
+                Style::default().fg(Color::Gray),
+            ),
+        ])),
+        None if detail.error.is_none() => {
+            lines.push(Line::from(Span::styled("Memory: smaps_rollup not readable", Style::default().fg(Color::Gray))));
+        }
+        None => {}
+    }
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    if detail.error.is_some() {
+        return;
+    }
+    let header = Row::new(vec!["FDs", "Target"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = fds.top
+        .iter()
+        .map(|(target, count)| Row::new(vec![Cell::from(count.to_string()), Cell::from(target.clone())]))
+        .collect();
+    let table = Table::new(rows, [Constraint::Length(6), Constraint::Min(20)])
+        .header(header)
+        .column_spacing(1);
+
+    // Open files on the left, sockets on the right
+    let lists = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(chunks[1]);
+    f.render_widget(table, lists[0]);
+
+    let header = Row::new(vec!["Proto", "Local", "Remote", "State"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = detail.connections
+        .iter()
+        .map(|connection| {
+            let state_color = match connection.state {
+                "LISTEN" | "UNCONN" => Color::Rgb(136, 192, 208),    // Nord frost
+                "ESTABLISHED" | "CONNECTED" => Color::Rgb(163, 190, 140), // Nord green
+                _ => Color::Gray,
+            };
+            Row::new(vec![
+                Cell::from(connection.protocol),
+                Cell::from(connection.local.clone()),
+                Cell::from(connection.remote.clone().unwrap_or_else(|| "*".to_string())),
+                Cell::from(Span::styled(connection.state, Style::default().fg(state_color))),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(5),   // Proto
+        Constraint::Min(21),     // Local
+        Constraint::Min(21),     // Remote
+        Constraint::Length(11),  // State
+    ];
+    let connections = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default()
+            .title(format!("🔌 Connections ({})", detail.connections.len()))
+            .borders(Borders::LEFT))
+        .column_spacing(1);
+    f.render_widget(connections, lists[1]);
+}
+
+/// Threads of the process opened with [T], busiest first
+fn draw_threads(f: &mut Frame, view: &ThreadView, area: Rect) {
+    let block = Block::default()
+        .title(format!("🧵 Threads of {} (PID {}, {} threads) • [T] close", view.name, view.pid, view.threads.len()))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    if let Some(error) = &view.error {
+        let message = Paragraph::new(error.as_str())
+            .style(Style::default().fg(Color::Rgb(191, 97, 106))) // Nord red
+            .block(block);
+        f.render_widget(message, area);
+        return;
+    }
+
+    let header = Row::new(vec!["TID", "Name", "State", "CPU%"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+    let rows: Vec<Row> = view.threads
+        .iter()
+        .map(|thread| {
+            Row::new(vec![
+                Cell::from(thread.tid.to_string()),
+                Cell::from(thread.name.clone()),
+                Cell::from(thread.state.to_string()),
+                Cell::from(format!("{:.1}", thread.cpu_usage)),
+            ])
+        })
+        .collect();
+    let widths = [
+        Constraint::Length(8),   // TID
+        Constraint::Min(20),     // Name
+        Constraint::Length(6),   // State
+        Constraint::Length(8),   // CPU%
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+fn draw_cpu_widget(f: &mut Frame, app: &App, area: Rect) {
+    let cpu_usage = app.metrics.cpu_usage();
+    
+    // Split into gauge and info areas (no chart)
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Gauge
+            Constraint::Min(0),     // Info (expanded to fill space)
+        ])
+        .split(area);
+
+    // Enhanced CPU Gauge with Btop-inspired colors
+    let cpu_color = if cpu_usage < 30.0 {
+        Color::Rgb(163, 190, 140) // Nord green
+    } else if cpu_usage < 50.0 {
+        Color::Rgb(235, 203, 139) // Nord yellow
+    } else if cpu_usage < 80.0 {
+        Color::Rgb(208, 135, 112) // Nord orange
+    } else {
+        Color::Rgb(191, 97, 106) // Nord red
+    };
+    
+    let gauge = Gauge::default()
+        .block(Block::default()
+            .title("🧠 CPU Usage")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(163, 190, 140))))
+        .gauge_style(Style::default().fg(cpu_color))
+        .percent(cpu_usage as u16)
+        .label(format!("{:.1}%", cpu_usage));
+    f.render_widget(gauge, chunks[0]);
+
+    // Frequency trends share one scale across cores, from the slowest to the fastest
+    // recent sample, so boosting and throttled cores stand out against each other
+    let recent_frequencies = |history: &History| -> Vec<f32> {
+        history.iter().skip(history.len().saturating_sub(FREQUENCY_TREND_WIDTH)).copied().collect()
+    };
+    let frequency_histories = app.metrics.per_core_frequency_history();
+    let (frequency_low, frequency_high) = frequency_histories
+        .iter()
+        .flat_map(&recent_frequencies)
+        .fold((f32::MAX, 0.0f32), |(low, high), mhz| (low.min(mhz), high.max(mhz)));
+    let frequency_trend = |history: &History| text_sparkline(&recent_frequencies(history), frequency_low, frequency_high);
+    let current_frequencies: Vec<f32> = frequency_histories.iter().filter_map(|history| history.back().copied()).collect();
+
+    // Enhanced CPU Info
+    let mut cpu_info = if let Some(cpu) = app.system.cpus().first() {
+        let average_frequency = app.metrics.frequency_history().back().copied().unwrap_or(cpu.frequency() as f32);
+        vec![
+            Line::from("╭─ CPU Info ─────────────────╮"),
+            Line::from(format!("│ Brand: {}", cpu.brand())),
+            Line::from(format!(
+                "│ ⚡ Cores: {}  Freq: {:.0} MHz {}",
+                app.system.cpus().len(),
+                average_frequency,
+                frequency_trend(app.metrics.frequency_history()),
+            )),
+            load_line(app),
+            scheduler_line(app),
+            Line::from(match crate::boot_time() {
+                Some(boot) => format!("│ ⏱️ Up: {}  Booted: {}", crate::format_uptime(System::uptime()), boot.format("%Y-%m-%d %H:%M")),
+                None => format!("│ ⏱️ Up: {}", crate::format_uptime(System::uptime())),
+            }),
+            Line::from("╰───────────────────────────╯"),
+            Line::from(""),  // Empty line for spacing
+        ]
+    } else {
+        vec![Line::from("⚠️ CPU info unavailable")]
+    };
+
+    // Add per-core usage and temperature info side by side
+    let per_core = app.metrics.per_core_usage();
+    let per_core_temps = app.metrics.per_core_temperatures();
+    
+    if !per_core.is_empty() {
+        if per_core.len() <= 8 {
+            // For systems with 8 cores or fewer, show detailed per-core info
+            cpu_info.push(Line::from("╭─ Core Usage, Temp & Freq ──╮"));
+            
+            for (i, &usage) in per_core.iter().enumerate() {
+                // Get temperature for this core if available
+                let temp_str = if i < per_core_temps.len() {
+                    format!("{:5.1}{}", app.temperature_unit.convert(per_core_temps[i]), app.temperature_unit.symbol())
+                } else {
+                    "  N/A ".to_string()
+                };
+                
+                // Enhanced visual usage bars with better Unicode
+                let usage_bar = if usage < 12.5 {
+                    "▁"
+                } else if usage < 25.0 {
+                    "▂"
+                } else if usage < 37.5 {
+                    "▃"
+                } else if usage < 50.0 {
+                    "▄"
+                } else if usage < 62.5 {
+                    "▅"
+                } else if usage < 75.0 {
+                    "▆"
+                } else if usage < 87.5 {
+                    "▇"
+                } else {
+                    "█"
+                };
+                
+                let frequency = match frequency_histories.get(i) {
+                    Some(history) => format!("{:5.0} MHz {}", history.back().copied().unwrap_or(0.0), frequency_trend(history)),
+                    None => "  N/A".to_string(),
+                };
+                cpu_info.push(Line::from(format!("│ Core {:2}: {:5.1}% {} │ 🌡️ {} │ {}", i, usage, usage_bar, temp_str, frequency)));
+            }
+            cpu_info.push(Line::from("╰─────────────────────────────╯"));
+        } else {
+            // For systems with many cores, show summary stats first
+            let avg_usage = per_core.iter().sum::<f32>() / per_core.len() as f32;
+            let max_usage = per_core.iter().fold(0.0f32, |a, &b| a.max(b));
+            let min_usage = per_core.iter().fold(100.0f32, |a, &b| a.min(b));
+            
+            cpu_info.push(Line::from("┌─ Usage Summary ─────────────"));
+            cpu_info.push(Line::from(format!("│ Avg: {:5.1}%  Max: {:5.1}%", avg_usage, max_usage)));
+            cpu_info.push(Line::from(format!("│ Min: {:5.1}%  Cores: {:3}", min_usage, per_core.len())));
+            
+            // Show temperature stats if available
+            if !per_core_temps.is_empty() {
+                let avg_temp = per_core_temps.iter().sum::<f32>() / per_core_temps.len() as f32;
+                let max_temp = per_core_temps.iter().fold(0.0f32, |a, &b| a.max(b));
+                let _min_temp = per_core_temps.iter().fold(200.0f32, |a, &b| a.min(b));
+                cpu_info.push(Line::from(format!("│ Temp: {}  Max: {}", app.temperature_unit.format(avg_temp, 1), app.temperature_unit.format(max_temp, 1))));
+            }
+            if !current_frequencies.is_empty() {
+                let min_frequency = current_frequencies.iter().copied().fold(f32::MAX, f32::min);
+                let max_frequency = current_frequencies.iter().copied().fold(0.0f32, f32::max);
+                cpu_info.push(Line::from(format!("│ Freq: {:.0}-{:.0} MHz", min_frequency, max_frequency)));
+            }
+            cpu_info.push(Line::from("└─────────────────────────────"));
+            cpu_info.push(Line::from(""));  // Empty line for spacing
+            
+            // Show all cores in a more compact but readable format
+            cpu_info.push(Line::from("┌─ Individual Cores ─────────"));
+            
+            let cores_per_line = 4;
+            for chunk in per_core.chunks(cores_per_line) {
+                let mut line = String::from("│ ");
+                for (local_i, &usage) in chunk.iter().enumerate() {
+                    let core_idx = (chunk.as_ptr() as usize - per_core.as_ptr() as usize) / std::mem::size_of::<f32>() + local_i;
+                    
+                    // Get temperature for this core if available
+                    let temp_str = if core_idx < per_core_temps.len() {
+                        format!("{:.0}°", app.temperature_unit.convert(per_core_temps[core_idx]))
+                    } else {
+                        "N/A".to_string()
+                    };
+                    
+                    line += &format!("C{:2}:{:4.0}%/{:>3} ", core_idx, usage, temp_str);
+                }
+                cpu_info.push(Line::from(line));
+            }
+            cpu_info.push(Line::from("└─────────────────────────────"));
+        }
+    }
+
+
+    let info_paragraph = Paragraph::new(cpu_info)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::White));
+    f.render_widget(info_paragraph, chunks[1]);
+}
+
+/// 1/5/15-minute load with a trend of the 1-minute value and the run queue. Load is
+/// colored relative to the core count, since a load of 4 saturates only a 4-core box.
+fn load_line(app: &App) -> Line<'static> {
+    let load = app.metrics.load_average();
+    let cores = app.system.cpus().len().max(1) as f64;
+    let color = if load.one < cores * 0.7 {
+        Color::Rgb(163, 190, 140) // Nord green
+    } else if load.one < cores {
+        Color::Rgb(235, 203, 139) // Nord yellow
+    } else if load.one < cores * 2.0 {
+        Color::Rgb(208, 135, 112) // Nord orange
+    } else {
+        Color::Rgb(191, 97, 106) // Nord red
+    };
+
+    let history = app.metrics.load_history();
+    let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(LOAD_TREND_WIDTH)).copied().collect();
+    let peak = recent.iter().copied().fold(cores as f32, f32::max);
+
+    let mut spans = vec![
+        Span::raw("│ Load: "),
+        Span::styled(format!("{:.2} {:.2} {:.2}", load.one, load.five, load.fifteen), Style::default().fg(color)),
+        Span::raw(" "),
+        Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(color)),
+    ];
+    if let Some((running, blocked)) = app.metrics.run_queue() {
+        spans.push(Span::raw(format!("  Tasks: {} running,", running)));
+        // Blocked tasks wait on I/O and push the load up without using CPU
+        let blocked_style = if blocked > 0 {
+            Style::default().fg(Color::Rgb(208, 135, 112)) // Nord orange
+        } else {
+            Style::default()
+        };
+        spans.push(Span::styled(format!(" {} blocked", blocked), blocked_style));
+    }
+    Line::from(spans)
+}
+
+/// Context switch and interrupt rates with their recent trends, each scaled to its
+/// own peak so a runaway rate shows as a climbing line
+fn scheduler_line(app: &App) -> Line<'static> {
+    let (Some(switches), Some(interrupts)) = (app.metrics.context_switch_rate(), app.metrics.interrupt_rate()) else {
+        return Line::from("│ 🔀 Ctx switches: N/A");
+    };
+    let trend = |history: &History| {
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(SCHEDULER_TREND_WIDTH)).copied().collect();
+        let peak = recent.iter().copied().fold(1.0, f32::max);
+        text_sparkline(&recent, 0.0, peak)
+    };
+    Line::from(vec![
+        Span::raw(format!("│ 🔀 Ctx: {} ", per_second(switches))),
+        Span::styled(trend(app.metrics.context_switch_history()), Style::default().fg(Color::Rgb(136, 192, 208))), // Nord frost
+        Span::raw(format!("  Intr: {} ", per_second(interrupts))),
+        Span::styled(trend(app.metrics.interrupt_history()), Style::default().fg(Color::Rgb(180, 142, 173))), // Nord purple
+    ])
+}
+
+/// Page fault and major fault rates, with the major fault trend: sustained major faults
+/// mean the system is thrashing even when the usage graphs look calm
+fn paging_line(app: &App) -> Option<Line<'static>> {
+    let (faults, major) = (app.metrics.page_fault_rate()?, app.metrics.major_fault_rate()?);
+    let history = app.metrics.major_fault_history();
+    let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(PAGING_TREND_WIDTH)).copied().collect();
+    // Scaled to at least the warning rate, so a few stray faults stay flat
+    let peak = recent.iter().copied().fold(meminfo::MAJOR_FAULT_WARNING_RATE, f32::max);
+    let major_style = if major >= meminfo::MAJOR_FAULT_WARNING_RATE {
+        Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD) // Nord red
+    } else {
+        Style::default().fg(Color::Rgb(216, 222, 233)) // Nord snow storm
+    };
+    Some(Line::from(vec![
+        Span::styled(format!("Faults: {} • ", per_second(faults)), Style::default().fg(Color::Rgb(216, 222, 233))), // Nord snow storm
+        Span::styled(format!("Major: {} ", per_second(major)), major_style),
+        Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(Color::Rgb(208, 135, 112))), // Nord orange
+    ]))
+}
+
+/// Dirty and writeback pages, with their trend and share of the dirty limit: a large
+/// backlog means the next fsync, or any writer once the limit is hit, waits for the disk
+fn dirty_line(app: &App) -> Option<Line<'static>> {
+    let memory = app.metrics.memory_breakdown()?;
+    let history = app.metrics.dirty_history();
+    let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(DIRTY_TREND_WIDTH)).copied().collect();
+    // Scaled to the limit where known, so the height tells how close writers are to stalling
+    let ceiling = memory.dirty_limit.map_or(0.0, |limit| limit as f32 / 1024.0 / 1024.0);
+    let peak = recent.iter().copied().fold(ceiling, f32::max);
+    let percent = memory.dirty_percent();
+    let style = if percent.is_some_and(|percent| percent >= meminfo::DIRTY_WARNING_PERCENT) {
+        Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD) // Nord red
+    } else {
+        Style::default().fg(Color::Rgb(216, 222, 233)) // Nord snow storm
+    };
+    let limit = percent.map(|percent| format!(" ({:.0}% of limit)", percent)).unwrap_or_default();
+    Some(Line::from(vec![
+        Span::styled(
+            format!("Dirty: {} • Writeback: {}{} ", crate::format_bytes(memory.dirty), crate::format_bytes(memory.writeback), limit),
+            style,
+        ),
+        Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(Color::Rgb(235, 203, 139))), // Nord yellow
+    ]))
+}
+
+/// ECC status for the memory widget: the totals since boot, plus the module with the
+/// most errors. Uncorrected errors mean corrupted data and are shown in red.
+/// The fullest tmpfs mounts, since their contents take RAM without showing up in any
+/// process and a full /dev/shm breaks applications in confusing ways
+fn tmpfs_lines(app: &App) -> Vec<Line<'static>> {
+    app.metrics
+        .tmpfs_mounts()
+        .iter()
+        .filter(|fs| fs.used() > 0 || fs.mount_point == "/dev/shm")
+        .take(TMPFS_LINES)
+        .map(|fs| {
+            let style = if fs.usage_percent() >= filesystems::TMPFS_WARNING_PERCENT {
+                Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD) // Nord red
+            } else {
+                Style::default().fg(Color::Rgb(143, 188, 187)) // Nord teal
+            };
+            Line::styled(
+                format!("tmpfs {}: {} of {} ({:.0}%)", fs.mount_point, crate::format_bytes(fs.used()), crate::format_bytes(fs.total), fs.usage_percent()),
+                style,
+            )
+        })
+        .collect()
+}
+
+fn ecc_lines(app: &App) -> Vec<Line<'static>> {
+    let Some(errors) = app.metrics.ecc_errors() else {
+        return Vec::new();
+    };
+    let (new_corrected, new_uncorrected) = app.metrics.new_ecc_errors();
+    let mut lines = vec![if errors.uncorrected > 0 {
+        Line::styled(
+            format!("⚠️ ECC: {} uncorrected, {} corrected (+{}/+{})", errors.uncorrected, errors.corrected, new_uncorrected, new_corrected),
+            Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD), // Nord red
+        )
+    } else if errors.corrected > 0 {
+        Line::styled(
+            format!("ECC: {} corrected (+{} since start)", errors.corrected, new_corrected),
+            Style::default().fg(Color::Rgb(235, 203, 139)), // Nord yellow
+        )
+    } else {
+        Line::styled(
+            format!("ECC: no errors ({} controller{})", errors.controllers, if errors.controllers == 1 { "" } else { "s" }),
+            Style::default().fg(Color::Rgb(163, 190, 140)), // Nord green
+        )
+    }];
+    if let Some(worst) = errors.dimms.first() {
+        lines.push(Line::styled(
+            format!("  {}: {} CE, {} UE", worst.label, worst.corrected, worst.uncorrected),
+            Style::default().fg(Color::Rgb(208, 135, 112)), // Nord orange
+        ));
+    }
+    lines
+}
+
+/// The latest OOM kill of this boot, so a process that vanished is explained at a glance
+fn oom_lines(app: &App) -> Vec<Line<'static>> {
+    let Some(watch) = app.oom_watch.as_ref().filter(|watch| watch.count > 0) else {
+        return Vec::new();
+    };
+    let mut lines = Vec::new();
+    match watch.kills.first() {
+        Some(kill) => {
+            let time = if kill.time.date_naive() == Local::now().date_naive() {
+                kill.time.format("%H:%M:%S").to_string()
+            } else {
+                kill.time.format("%Y-%m-%d %H:%M").to_string()
+            };
+            lines.push(Line::styled(
+                format!("💀 OOM killed {} at {}", kill.summary(), time),
+                Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD), // Nord red
+            ));
+            if watch.count > 1 {
+                lines.push(Line::styled(
+                    format!("  {} OOM kills this boot", watch.count),
+                    Style::default().fg(Color::Rgb(208, 135, 112)), // Nord orange
+                ));
+            }
+        }
+        // The kernel log needs root or the adm group
+        None => lines.push(Line::styled(
+            format!("💀 {} OOM kill{} this boot (kernel log not readable)", watch.count, if watch.count == 1 { "" } else { "s" }),
+            Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD), // Nord red
+        )),
+    }
+    lines
+}
+
+fn draw_memory_widget(f: &mut Frame, app: &App, area: Rect) {
+    let memory_usage = app.metrics.memory_usage();
+    let breakdown = app.metrics.memory_breakdown();
+    let special_lines = breakdown.map(|memory| memory.special_memory_lines()).unwrap_or_default();
+    // Paging, dirty pages, tmpfs usage, ECC errors and OOM kills below the breakdown
+    let mut alert_lines: Vec<Line> = paging_line(app).into_iter().chain(dirty_line(app)).collect();
+    alert_lines.extend(tmpfs_lines(app));
+    alert_lines.extend(ecc_lines(app));
+    alert_lines.extend(oom_lines(app));
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Gauge
+            Constraint::Length(if breakdown.is_some() { 7 + special_lines.len() as u16 } else { 4 } + alert_lines.len() as u16),  // Info
+            Constraint::Min(0),     // Chart
+        ])
+        .split(area);
+
+    // Enhanced Memory Gauge with Btop-inspired colors
+    let memory_color = if memory_usage < 40.0 {
+        Color::Rgb(136, 192, 208) // Nord frost
+    } else if memory_usage < 60.0 {
+        Color::Rgb(163, 190, 140) // Nord aurora green
+    } else if memory_usage < 80.0 {
+        Color::Rgb(235, 203, 139) // Nord aurora yellow
+    } else {
+        Color::Rgb(191, 97, 106) // Nord aurora red
+    };
+    
+    let gauge = Gauge::default()
+        .block(Block::default()
+            .title("💾 Memory Usage")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(136, 192, 208))))
+        .gauge_style(Style::default().fg(memory_color))
+        .percent(memory_usage as u16)
+        .label(match breakdown {
+            // Cache is reclaimable, so it is shown beside the usage rather than in it
+            Some(memory) => format!("{:.1}% (+{:.1}% cache)", memory_usage, memory.cache() as f64 / memory.total as f64 * 100.0),
+            None => format!("{:.1}%", memory_usage),
+        });
+    f.render_widget(gauge, chunks[0]);
+
+    // Enhanced Memory Info with visual indicators
+    let total_mem = app.system.total_memory() as f64 / 1024.0 / 1024.0;
+    let used_mem = app.system.used_memory() as f64 / 1024.0 / 1024.0;
+    let free_mem = total_mem - used_mem;
+    let usage_ratio = used_mem / total_mem;
+    
+    let mem_bar = if usage_ratio < 0.4 {
+        "▁▂▃▂▁"
+    } else if usage_ratio < 0.6 {
+        "▂▃▅▃▂"
+    } else if usage_ratio < 0.8 {
+        "▃▅▆▅▃"
+    } else {
+        "▅▇▇▇▅"
+    };
+
+    let memory_info = match breakdown {
+        Some(memory) => {
+            let megabytes = |bytes: u64| bytes as f64 / 1024.0 / 1024.0;
+            let muted = Style::default().fg(Color::Rgb(216, 222, 233)); // Nord snow storm
+            vec![
+                Line::from(format!("Total: {:.1} MB", megabytes(memory.total))),
+                Line::from(format!("Used: {:.1} MB {}", megabytes(memory.used()), mem_bar)),
+                Line::from(Span::styled(format!("Cache/Buffers: {:.1} MB", megabytes(memory.cache())), muted)),
+                Line::from(Span::styled(
+                    format!("Available: {:.1} MB", megabytes(memory.available)),
+                    Style::default().fg(Color::Rgb(163, 190, 140)), // Nord aurora green
+                )),
+                Line::from(Span::styled(
+                    format!("Free: {:.1} MB • Shared: {:.1} MB • Slab: {:.1} MB", megabytes(memory.free), megabytes(memory.shared), megabytes(memory.slab)),
+                    muted,
+                )),
+            ]
+            .into_iter()
+            .chain(special_lines.into_iter().map(|line| Line::styled(line, Style::default().fg(Color::Rgb(180, 142, 173))))) // Nord purple
+            .collect()
+        }
+        None => vec![
+            Line::from(format!("Total: {:.1} MB", total_mem)),
+            Line::from(format!("Used: {:.1} MB {}", used_mem, mem_bar)),
+            Line::from(format!("Free: {:.1} MB", free_mem)),
+        ],
+    };
+    let memory_info: Vec<Line> = memory_info.into_iter().chain(alert_lines).collect();
+
+    let info_paragraph = Paragraph::new(memory_info)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded))
+        .style(Style::default().fg(Color::White));
+    f.render_widget(info_paragraph, chunks[1]);
+
+    // Enhanced Memory Chart with Btop-inspired styling
+    let now = Instant::now();
+    let memory_data: Vec<(f64, f64)> = app.metrics.memory_history().points(now);
+
+    if !memory_data.is_empty() {
+        let datasets = vec![Dataset::default()
+            .name("◈ Memory Usage")
+            .marker(symbols::Marker::Braille)
+            .style(Style::default().fg(Color::Rgb(136, 192, 208)))
+            .data(&memory_data)];
+
+        let chart = Chart::new(datasets)
+            .block(Block::default()
+                .title("📊 Memory Usage History")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Rgb(136, 192, 208))))
+            .x_axis(
+                time_axis(&[app.metrics.memory_history()], now)
+                    .title("◀ Time ▶")
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("% Usage")
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                    .bounds([0.0, 100.0])
+                    .labels(vec!["0", "25", "50", "75", "100"]),
+            );
+        f.render_widget(chart, chunks[2]);
+    }
+}
+
+fn draw_disk_widget(f: &mut Frame, app: &App, area: Rect) {
+    // The first tracked mount gets the gauge and details, the others a line each
+    let tracked = app.metrics.tracked_mounts();
+    let primary = tracked.first().map_or("/", |mount| mount.mount_point.as_str());
+    let disk_usage = tracked.first().map_or(0.0, |mount| mount.usage());
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),  // Gauge
+            Constraint::Min(0),     // Info (expanded to fill space)
+        ])
+        .split(area);
+
+    // Enhanced Disk Gauge with Btop-inspired colors
+    let disk_color = if disk_usage < 50.0 {
+        Color::Rgb(163, 190, 140) // Nord aurora green
+    } else if disk_usage < 70.0 {
+        Color::Rgb(235, 203, 139) // Nord aurora yellow
+    } else if disk_usage < 90.0 {
+        Color::Rgb(208, 135, 112) // Nord aurora orange
+    } else {
+        Color::Rgb(191, 97, 106) // Nord aurora red
+    };
+    
+    let gauge = Gauge::default()
+        .block(Block::default()
+            .title("💽 Disk Usage [U] explore")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(235, 203, 139))))
+        .gauge_style(Style::default().fg(disk_color))
+        .percent(disk_usage as u16)
+        .label(if primary == "/" { format!("{:.1}%", disk_usage) } else { format!("{} {:.1}%", primary, disk_usage) });
+    f.render_widget(gauge, chunks[0]);
+
+    // Enhanced Disk Info
+    let mut disk_info = vec![Line::from(if primary == "/" { "Root filesystem:".to_string() } else { format!("{}:", primary) })];
+    if let Some(root) = app.metrics.filesystem(primary) {
+        let total = root.total as f64 / 1024.0 / 1024.0 / 1024.0;
+        let available = root.available as f64 / 1024.0 / 1024.0 / 1024.0;
+        let used = total - available;
+
+        disk_info.push(Line::from(format!("Total: {:.1} GB", total)));
+        disk_info.push(Line::from(format!("Used: {:.1} GB", used)));
+        disk_info.push(Line::from(format!("Free: {:.1} GB", available)));
+        if let (Some(used), Some((total, _))) = (root.inodes_used(), root.inodes) {
+            disk_info.push(Line::from(format!(
+                "Inodes: {} / {} ({:.1}%)",
+                used,
+                total,
+                root.inode_percent().unwrap_or(0.0)
+            )));
+        }
+    } else {
+        disk_info.push(Line::styled("Not mounted", Style::default().fg(Color::Rgb(191, 97, 106)))); // Nord aurora red
+    }
+
+    // Other tracked mounts with their usage trend, on a fixed 0-100% scale
+    for mount in tracked.iter().skip(1) {
+        let Some(fs) = app.metrics.filesystem(&mount.mount_point) else {
+            disk_info.push(Line::styled(
+                format!("{} not mounted", mount.mount_point),
+                Style::default().fg(Color::Rgb(191, 97, 106)), // Nord aurora red
+            ));
+            continue;
+        };
+        let percent = fs.usage_percent();
+        let color = if percent < 50.0 {
+            Color::Rgb(163, 190, 140) // Nord aurora green
+        } else if percent < 70.0 {
+            Color::Rgb(235, 203, 139) // Nord aurora yellow
+        } else if percent < 90.0 {
+            Color::Rgb(208, 135, 112) // Nord aurora orange
+        } else {
+            Color::Rgb(191, 97, 106) // Nord aurora red
+        };
+        let history = mount.history();
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(DISK_TREND_WIDTH)).copied().collect();
+        disk_info.push(Line::from(vec![
+            Span::raw(format!("{}: {} free of {} ", mount.mount_point, crate::format_bytes(fs.available), crate::format_bytes(fs.total))),
+            Span::styled(format!("{:.1}% ", percent), Style::default().fg(color)),
+            Span::styled(text_sparkline(&recent, 0.0, 100.0), Style::default().fg(color)),
+        ]));
+    }
+
+    // Every drive and partition, so /home, data and backup disks are covered too
+    let partitions = app.metrics.partitions();
+    if partitions.len() > 1 {
+        let device_width = partitions.iter().map(|fs| fs.device.trim_start_matches("/dev/").chars().count()).max().unwrap_or(0);
+        for fs in partitions {
+            let percent = fs.usage_percent();
+            let color = if percent < 50.0 {
+                Color::Rgb(163, 190, 140) // Nord aurora green
+            } else if percent < 70.0 {
+                Color::Rgb(235, 203, 139) // Nord aurora yellow
+            } else if percent < 90.0 {
+                Color::Rgb(208, 135, 112) // Nord aurora orange
+            } else {
+                Color::Rgb(191, 97, 106) // Nord aurora red
+            };
+            let filled = ((percent / 100.0) * PARTITION_BAR_WIDTH as f32).round() as usize;
+            disk_info.push(Line::from(vec![
+                Span::raw(format!("{:<width$} ", fs.device.trim_start_matches("/dev/"), width = device_width)),
+                Span::styled("█".repeat(filled.min(PARTITION_BAR_WIDTH)), Style::default().fg(color)),
+                Span::styled("░".repeat(PARTITION_BAR_WIDTH.saturating_sub(filled)), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!(" {:5.1}% ", percent), Style::default().fg(color)),
+                Span::styled(
+                    format!("{} of {}", fs.mount_point, crate::format_bytes(fs.total)),
+                    Style::default().fg(Color::Rgb(76, 86, 106)), // Nord gray
+                ),
+            ]));
+        }
+    }
+
+    // Lifetime of each SSD: the share of its rated endurance written so far
+    let drive_width = app.ssd_wear.drives.iter().map(|wear| wear.drive.chars().count()).max().unwrap_or(0);
+    for wear in &app.ssd_wear.drives {
+        let color = if wear.percent_used < 50.0 {
+            Color::Rgb(163, 190, 140) // Nord aurora green
+        } else if wear.percent_used < smart::WEAR_WARNING_PERCENT {
+            Color::Rgb(235, 203, 139) // Nord aurora yellow
+        } else if wear.percent_used < 90.0 {
+            Color::Rgb(208, 135, 112) // Nord aurora orange
+        } else {
+            Color::Rgb(191, 97, 106) // Nord aurora red
+        };
+        let filled = ((wear.percent_used / 100.0) * PARTITION_BAR_WIDTH as f32).round() as usize;
+        let written = wear.written.map_or(String::new(), |bytes| format!(" · {} written", crate::format_bytes(bytes)));
+        disk_info.push(Line::from(vec![
+            Span::raw(format!("{:<width$} ", wear.drive, width = drive_width)),
+            Span::styled("█".repeat(filled.min(PARTITION_BAR_WIDTH)), Style::default().fg(color)),
+            Span::styled("░".repeat(PARTITION_BAR_WIDTH.saturating_sub(filled)), Style::default().fg(Color::DarkGray)),
+            Span::styled(
+                format!(" {:3.0}% worn{}", wear.percent_used, if wear.is_worn() { " ⚠️" } else { "" }),
+                Style::default().fg(color),
+            ),
+            Span::styled(written, Style::default().fg(Color::Rgb(76, 86, 106))), // Nord gray
+        ]));
+    }
+    if let Some(err) = &app.ssd_wear.error {
+        disk_info.push(Line::styled(err.clone(), Style::default().fg(Color::Rgb(76, 86, 106)))); // Nord gray
+    }
+
+    // ZFS pools, btrfs filesystems and md arrays, red as soon as a device fails or reports errors
+    for pool in &app.storage_health.pools {
+        let color = if pool.is_degraded() {
+            Color::Rgb(191, 97, 106) // Nord aurora red
+        } else {
+            Color::Rgb(163, 190, 140) // Nord aurora green
+        };
+        let health = pool.health.clone().unwrap_or_else(|| if pool.is_degraded() { "ERRORS" } else { "OK" }.to_string());
+        let mut spans = vec![
+            Span::styled(format!("{} ", pool.name), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{} {} ", pool.kind.label(), health), Style::default().fg(color)),
+            Span::raw(match pool.usage_percent() {
+                Some(percent) => format!("{:.1}% of {}", percent, crate::format_bytes(pool.size)),
+                None => crate::format_bytes(pool.size),
+            }),
+        ];
+        if let Some((used, total)) = pool.metadata {
+            spans.push(Span::styled(
+                format!(" · metadata {:.0}%", used as f64 / total as f64 * 100.0),
+                Style::default().fg(Color::Rgb(76, 86, 106)), // Nord gray
+            ));
+        }
+        disk_info.push(Line::from(spans));
+        let mut problems = pool.problems.clone();
+        if pool.errors > 0 {
+            problems.push(format!("{} device errors", pool.errors));
+        }
+        if !problems.is_empty() {
+            disk_info.push(Line::styled(
+                format!("⚠️ {}", problems.join(" · ")),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        }
+        if let Some(scrub) = &pool.scrub {
+            disk_info.push(Line::styled(scrub.clone(), Style::default().fg(Color::Rgb(76, 86, 106)))); // Nord gray
+        }
+    }
+    // Volume group space left for new or growing volumes, and thin pools, which take
+    // every volume in them down when full
+    for group in &app.storage_health.volume_groups {
+        disk_info.push(Line::from(vec![
+            Span::styled(format!("{} ", group.name), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("lvm {} free of {}", crate::format_bytes(group.free), crate::format_bytes(group.size))),
+        ]));
+        for pool in &group.thin_pools {
+            let percent = pool.data_percent.max(pool.metadata_percent);
+            let color = if percent < 70.0 {
+                Color::Rgb(163, 190, 140) // Nord aurora green
+            } else if percent < storage::THIN_POOL_WARNING_PERCENT {
+                Color::Rgb(235, 203, 139) // Nord aurora yellow
+            } else if percent < 90.0 {
+                Color::Rgb(208, 135, 112) // Nord aurora orange
+            } else {
+                Color::Rgb(191, 97, 106) // Nord aurora red
+            };
+            disk_info.push(Line::styled(
+                format!(
+                    "  {}{} thin pool: data {:.1}% · metadata {:.1}%",
+                    if pool.is_filling() { "⚠️ " } else { "" },
+                    pool.name,
+                    pool.data_percent,
+                    pool.metadata_percent,
+                ),
+                Style::default().fg(color),
+            ));
+        }
+    }
+    for err in &app.storage_health.errors {
+        disk_info.push(Line::styled(err.clone(), Style::default().fg(Color::Rgb(191, 97, 106)))); // Nord aurora red
+    }
+
+    // Hot NVMe drives throttle long before they fail
+    let drives = app.metrics.drive_temperatures();
+    if !drives.is_empty() {
+        let mut spans = vec![Span::raw("🌡️ Drives:")];
+        for drive in drives {
+            let color = if drive.is_hot() {
+                Color::Rgb(191, 97, 106) // Nord aurora red
+            } else if drive.celsius >= 50.0 {
+                Color::Rgb(235, 203, 139) // Nord aurora yellow
+            } else {
+                Color::Rgb(163, 190, 140) // Nord aurora green
+            };
+            spans.push(Span::raw(format!(" {} ", drive.drive)));
+            spans.push(Span::styled(app.temperature_unit.format(drive.celsius, 0), Style::default().fg(color)));
+        }
+        disk_info.push(Line::from(spans));
+    }
+
+    // Out of inodes means "No space left on device" even with free bytes
+    for fs in app.metrics.filesystems().iter().filter(|fs| fs.inodes_low()) {
+        disk_info.push(Line::from(Span::styled(
+            format!("⚠️ inodes {:.0}% on {}", fs.inode_percent().unwrap_or(0.0), fs.mount_point),
+            Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD), // Nord aurora red
+        )));
+    }
+
+    // Network filesystems: op rate and latency say more than fullness
+    let nfs_mounts = app.metrics.nfs_mounts();
+    if !nfs_mounts.is_empty() {
+        disk_info.push(Line::from(""));
+        disk_info.push(Line::from("Network mounts:"));
+        for mount in nfs_mounts {
+            // A hung mount stalls everything touching it, so it is named outright
+            if mount.is_not_responding() {
+                disk_info.push(Line::styled(
+                    format!("⚠️ {} not responding for {:.0}s", mount.mount_point, mount.unanswered_secs),
+                    Style::default().fg(Color::Rgb(191, 97, 106)).add_modifier(Modifier::BOLD), // Nord aurora red
+                ));
+                continue;
+            }
+            if mount.is_smb() {
+                disk_info.push(Line::from(format!("{} SMB {:.0} op/s", mount.mount_point, mount.ops_per_sec)));
+                continue;
+            }
+            let rtt = mount.avg_rtt_ms.map(|rtt| format!("{:.1}ms", rtt)).unwrap_or_else(|| "-".to_string());
+            let style = if mount.retrans_per_sec > 0.0 {
+                Style::default().fg(Color::Rgb(208, 135, 112)) // Nord aurora orange
+            } else {
+                Style::default()
+            };
+            disk_info.push(Line::styled(
+                format!("{} {:.0} op/s RTT {} rtx {:.1}/s", mount.mount_point, mount.ops_per_sec, rtt, mount.retrans_per_sec),
+                style,
+            ));
+        }
+    }
+
+    let info_paragraph = Paragraph::new(disk_info)
+        .block(Block::default().borders(Borders::ALL))
+        .style(Style::default().fg(Color::White));
+    f.render_widget(info_paragraph, chunks[1]);
+}
+
+fn draw_network_widget(f: &mut Frame, app: &App, area: Rect) {
+    let selected = app.selected_network();
+    let download_rate = selected.rx_rate;
+    let upload_rate = selected.tx_rate;
+    let interfaces = app.metrics.network_interfaces();
+
+    // Where traffic leaves the machine, to tell which uplink or VPN is in use
+    let mut routing = Vec::new();
+    if !app.metrics.gateways().is_empty() {
+        let gateways: Vec<String> = app.metrics.gateways()
+            .iter()
+            .map(|gateway| format!("{} via {}", gateway.address, gateway.interface))
+            .collect();
+        routing.push(format!("Gateway {}", gateways.join(" · ")));
+    }
+    if let Some(public_ip) = &app.public_ip {
+        routing.push(format!("Public IP {}", public_ip.address.as_deref().unwrap_or("unknown")));
+    }
+    
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),                               // Download gauge
+            Constraint::Length(3),                               // Upload gauge
+            // Total, VPN, interfaces, routing, details, members, wireless, errors and scale
+            Constraint::Length(
+                interfaces.len().min(8) as u16 + 6
+                    + app.metrics.vpn_total().is_some() as u16
+                    + !routing.is_empty() as u16
+                    + selected.link.as_ref().is_some_and(|link| link.aggregate.is_some()) as u16
+                    + selected.wireless.is_some() as u16,
+            ),
+            Constraint::Min(8),                                  // Download/upload history
+        ])
+        .split(area);
+
+    // Gauges are scaled to the link speed, or to the session peak when it is unknown
+    let (scale_kbps, scale_source) = selected.gauge_scale();
+    let percent = |rate: f32| (rate / scale_kbps * 100.0).min(100.0);
+    let gauge_color = |percent: f32| {
+        if percent < 50.0 {
+            Color::Green
+        } else if percent < 80.0 {
+            Color::Yellow
+        } else {
+            Color::Red
+        }
+    };
+    let download_percent = percent(download_rate);
+    let upload_percent = percent(upload_rate);
+
+    // Download Gauge
+    let download_gauge = Gauge::default()
+        .block(Block::default()
+            .title(format!("📥 Download · {}", selected.name))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Green)))
+        .gauge_style(Style::default().fg(gauge_color(download_percent)))
+        .percent(download_percent as u16)
+        .label(format!("{} of {}", app.rate_unit.format(download_rate), app.rate_unit.format(scale_kbps)));
+    f.render_widget(download_gauge, chunks[0]);
+
+    // Upload Gauge
+    let upload_gauge = Gauge::default()
+        .block(Block::default()
+            .title(format!("📤 Upload · {}", selected.name))
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Red)))
+        .gauge_style(Style::default().fg(gauge_color(upload_percent)))
+        .percent(upload_percent as u16)
+        .label(format!("{} of {}", app.rate_unit.format(upload_rate), app.rate_unit.format(scale_kbps)));
+    f.render_widget(upload_gauge, chunks[1]);
+
+    // One line per interface plus the total, the selected one highlighted
+    let interface_line = |interface: &NetworkInterface| {
+        let (rx, tx) = interface.session_bytes();
+        let is_selected = std::ptr::eq(interface, selected);
+        let state_color = match interface.link.as_ref().map(|link| link.state.as_str()) {
+            Some("up") => Color::Rgb(163, 190, 140),   // Nord green
+            Some("down") => Color::Rgb(191, 97, 106),  // Nord red
+            _ => Color::Rgb(76, 86, 106),              // Nord gray
+        };
+        let mut spans = vec![Span::styled(
+            format!("{} {:<10}", if is_selected { "▶" } else { " " }, interface.name),
+            if is_selected { Style::default().add_modifier(Modifier::BOLD) } else { Style::default() },
+        )];
+        // The total has no link of its own
+        let link = interface.link.as_ref().map(Link::summary).unwrap_or_default();
+        spans.push(Span::styled(format!("{:<18}", link), Style::default().fg(state_color)));
+        spans.push(Span::raw(format!(
+            "↓ {:<11} ↑ {:<11} Σ {:.1} / {:.1} MB",
+            app.rate_unit.format(interface.rx_rate),
+            app.rate_unit.format(interface.tx_rate),
+            rx as f64 / 1024.0 / 1024.0,
+            tx as f64 / 1024.0 / 1024.0,
+        )));
+        // Packets lost this session, red while they are still being lost
+        if let Some(errors) = interface.session_errors().filter(|errors| errors.total() > 0) {
+            let color = if interface.error_rate > 0.0 { Color::Rgb(191, 97, 106) } else { Color::Rgb(235, 203, 139) }; // Nord red / yellow
+            spans.push(Span::styled(format!("  ⚠ {} lost", errors.total()), Style::default().fg(color)));
+        }
+        // A failed bond leg or bridge port, which the bond's own state hides
+        if let Some(aggregate) = interface.link.as_ref().and_then(|link| link.aggregate.as_ref()) {
+            let down = aggregate.down_members();
+            if down > 0 {
+                spans.push(Span::styled(
+                    format!("  ⚠ {} of {} members down", down, aggregate.members.len()),
+                    Style::default().fg(Color::Rgb(191, 97, 106)), // Nord red
+                ));
+            }
+        }
+        Line::from(spans)
+    };
+    let mut network_info = vec![interface_line(app.metrics.network_total())];
+    // Tunneled traffic, with its share of the total in place of the link state
+    if let Some(vpn) = app.metrics.vpn_total() {
+        let mut line = interface_line(vpn);
+        let share = match vpn.share_of(app.metrics.network_total()).0 {
+            Some(percent) => format!("{:.0}% tunneled", percent),
+            None => "idle".to_string(),
+        };
+        line.spans[1] = Span::styled(format!("{:<18}", share), Style::default().fg(Color::Rgb(180, 142, 173))); // Nord purple
+        network_info.push(line);
+    }
+    network_info.extend(interfaces.iter().map(interface_line));
+    if !routing.is_empty() {
+        network_info.push(Line::styled(
+            routing.join("   "),
+            Style::default().fg(Color::Rgb(143, 188, 187)), // Nord teal
+        ));
+    }
+    // MAC, MTU and addresses of the selected interface, to check what it negotiated
+    if let Some(link) = &selected.link {
+        network_info.push(Line::styled(
+            format!("{}: {}", selected.name, link.details()),
+            Style::default().fg(Color::Rgb(136, 192, 208)), // Nord frost
+        ));
+    }
+    // Members of the selected bond or bridge with their link state
+    if let Some(aggregate) = selected.link.as_ref().and_then(|link| link.aggregate.as_ref()) {
+        let color = if aggregate.down_members() > 0 { Color::Rgb(191, 97, 106) } else { Color::Rgb(163, 190, 140) }; // Nord red / green
+        network_info.push(Line::styled(
+            format!("{}: {}", selected.name, aggregate.summary()),
+            Style::default().fg(color),
+        ));
+    }
+    // Signal of the selected wireless interface, -90 dBm (unusable) to -30 dBm (excellent)
+    if let Some(wireless) = &selected.wireless {
+        let history = selected.signal_history();
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(WIFI_SIGNAL_TREND_WIDTH)).copied().collect();
+        let color = match wireless.signal {
+            Some(signal) if signal >= -67.0 => Color::Rgb(163, 190, 140), // Nord green
+            Some(signal) if signal >= -75.0 => Color::Rgb(235, 203, 139), // Nord yellow
+            Some(_) => Color::Rgb(191, 97, 106),                          // Nord red
+            None => Color::Rgb(136, 192, 208),                            // Nord frost
+        };
+        network_info.push(Line::from(vec![
+            Span::styled(format!("{}: {} ", selected.name, wireless.summary()), Style::default().fg(color)),
+            Span::styled(text_sparkline(&recent, -90.0, -30.0), Style::default().fg(color)),
+        ]));
+    }
+    // Error and drop counts of the selected interface, with the recent rate
+    if let Some(errors) = selected.session_errors() {
+        let history = selected.error_history();
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(NETWORK_ERROR_TREND_WIDTH)).copied().collect();
+        let peak = recent.iter().copied().fold(1.0, f32::max);
+        let color = if errors.total() > 0 { Color::Rgb(235, 203, 139) } else { Color::Rgb(76, 86, 106) }; // Nord yellow / gray
+        network_info.push(Line::from(vec![
+            Span::styled(format!("{}: {} ", selected.name, errors.summary()), Style::default().fg(color)),
+            Span::raw(format!("{:.1}/s ", selected.error_rate)),
+            Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(Color::Rgb(191, 97, 106))), // Nord red
+        ]));
+    }
+    network_info.push(Line::styled(
+        format!("Gauge scale: {} ({})", app.rate_unit.format(scale_kbps), scale_source),
+        Style::default().fg(Color::Rgb(76, 86, 106)), // Nord gray
+    ));
+
+    let info_paragraph = Paragraph::new(network_info)
+        .block(Block::default()
+            .title("🌐 Network Stats [i] interface")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Magenta)))
+        .style(Style::default().fg(Color::White));
+    f.render_widget(info_paragraph, chunks[2]);
+
+    let now = Instant::now();
+    let mut rx_data = selected.rx_history().points(now);
+    let mut tx_data = selected.tx_history().points(now);
+    if chunks[3].height < 5 || rx_data.is_empty() {
+        return;
+    }
+
+    // In the unit that suits the peak, so both idle and busy links get readable labels
+    let peak = rx_data.iter().chain(&tx_data).map(|&(_, kbps)| kbps).fold(0.0, f64::max);
+    let (factor, unit) = app.rate_unit.scale(peak);
+    for point in rx_data.iter_mut().chain(tx_data.iter_mut()) {
+        point.1 *= factor;
+    }
+    let y_max = (peak * factor * 1.25).max(10.0);
+    let datasets = vec![
+        Dataset::default()
+            .name("◈ Download")
+            .marker(symbols::Marker::Braille)
+            .style(Style::default().fg(Color::Rgb(163, 190, 140))) // Nord green
+            .data(&rx_data),
+        Dataset::default()
+            .name("◈ Upload")
+            .marker(symbols::Marker::Braille)
+            .style(Style::default().fg(Color::Rgb(191, 97, 106))) // Nord red
+            .data(&tx_data),
+    ];
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title(format!("📊 Network History · {}", selected.name))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173)))) // Nord purple
+        .x_axis(
+            time_axis(&[selected.rx_history(), selected.tx_history()], now)
+                .title("◀ Time ▶")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+        )
+        .y_axis(
+            Axis::default()
+                .title(unit)
+                .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                .bounds([0.0, y_max])
+                .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+        );
+    f.render_widget(chart, chunks[3]);
+}
+
+fn draw_io_widget(f: &mut Frame, app: &App, area: Rect) {
+    let disks = app.metrics.disk_io();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(disks.len().min(8) as u16 * 2 + 3),  // Per-disk rates, latency and total
+            Constraint::Min(0),                                     // Charts
+        ])
+        .split(area);
+
+    // Recent per-disk trend next to each rate, scaled to the disk's own peak
+    let trend = |history: &History| {
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(IO_TREND_WIDTH)).copied().collect();
+        let peak = recent.iter().copied().fold(0.1, f32::max);
+        text_sparkline(&recent, 0.0, peak)
+    };
+    // Utilization on a fixed 0-100% scale, so a flat line means an idle disk
+    let utilization_trend = |history: &History| {
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(IO_TREND_WIDTH)).copied().collect();
+        text_sparkline(&recent, 0.0, 100.0)
+    };
+    let utilization_color = |percent: f32| {
+        if percent < 50.0 {
+            Color::Rgb(163, 190, 140) // Nord green
+        } else if percent < 80.0 {
+            Color::Rgb(235, 203, 139) // Nord yellow
+        } else if percent < 95.0 {
+            Color::Rgb(208, 135, 112) // Nord orange
+        } else {
+            Color::Rgb(191, 97, 106) // Nord red
+        }
+    };
+    let mut io_info: Vec<Line> = disks
+        .iter()
+        .flat_map(|disk| {
+            [
+                Line::from(vec![
+                    Span::raw(format!("{}: R {:.1} MB/s ", disk.name, disk.read_rate)),
+                    Span::styled(trend(disk.read_history()), Style::default().fg(Color::Rgb(163, 190, 140))), // Nord green
+                    Span::raw(format!("  W {:.1} MB/s ", disk.write_rate)),
+                    Span::styled(trend(disk.write_history()), Style::default().fg(Color::Rgb(208, 135, 112))), // Nord orange
+                ]),
+                Line::from(vec![
+                    Span::raw(format!("  await {:.1} ms ", disk.await_time)),
+                    Span::styled(trend(disk.await_history()), Style::default().fg(Color::Rgb(136, 192, 208))), // Nord frost
+                    Span::styled(format!("  util {:.0}% ", disk.utilization), Style::default().fg(utilization_color(disk.utilization))),
+                    Span::styled(utilization_trend(disk.utilization_history()), Style::default().fg(utilization_color(disk.utilization))),
+                    Span::raw(format!("  queue {:.2} ", disk.queue_depth)),
+                    Span::styled(trend(disk.queue_history()), Style::default().fg(Color::Rgb(180, 142, 173))), // Nord purple
+                ]),
+            ]
+        })
+        .collect();
+    io_info.push(Line::styled(
+        format!("Total: R {:.1} MB/s  W {:.1} MB/s", app.metrics.disk_read_rate(), app.metrics.disk_write_rate()),
+        Style::default().add_modifier(Modifier::BOLD),
+    ));
+
+    let info_paragraph = Paragraph::new(io_info)
+        .block(Block::default()
+            .title("💽 Disk I/O")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187)))) // Nord teal
+        .style(Style::default().fg(Color::White));
+    f.render_widget(info_paragraph, chunks[0]);
+
+    let charts = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[1]);
+
+    let now = Instant::now();
+    let read_data = app.metrics.disk_read_history().points(now);
+    let write_data = app.metrics.disk_write_history().points(now);
+
+    if !read_data.is_empty() {
+        // Scale to the peak so idle and busy disks both get a readable chart
+        let peak = read_data.iter().chain(&write_data).map(|&(_, mbs)| mbs).fold(0.0, f64::max);
+        let y_max = (peak * 1.25).max(1.0);
+
+        let datasets = vec![
+            Dataset::default()
+                .name("◈ Read")
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(Color::Rgb(163, 190, 140))) // Nord green
+                .data(&read_data),
+            Dataset::default()
+                .name("◈ Write")
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(Color::Rgb(208, 135, 112))) // Nord orange
+                .data(&write_data),
+        ];
+
+        let chart = Chart::new(datasets)
+            .block(Block::default()
+                .title("📊 I/O History")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
+            .x_axis(
+                time_axis(&[app.metrics.disk_read_history(), app.metrics.disk_write_history()], now)
+                    .title("◀ Time ▶")
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("MB/s")
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                    .bounds([0.0, y_max])
+                    .labels(vec!["0".to_string(), format!("{:.1}", y_max / 2.0), format!("{:.1}", y_max)]),
+            );
+        f.render_widget(chart, charts[0]);
+    }
+
+    // Utilization of each disk; one pinned near 100% is saturated
+    let utilization_data: Vec<Vec<(f64, f64)>> = disks.iter().map(|disk| disk.utilization_history().points(now)).collect();
+    if utilization_data.iter().any(|points| !points.is_empty()) {
+        let datasets = disks
+            .iter()
+            .zip(&utilization_data)
+            .zip(DISK_COLORS.iter().cycle())
+            .map(|((disk, points), &color)| {
+                Dataset::default()
+                    .name(format!("◈ {}", disk.name))
+                    .marker(symbols::Marker::Braille)
+                    .style(Style::default().fg(color))
+                    .data(points)
+            })
+            .collect();
+        let histories: Vec<&History> = disks.iter().map(|disk| disk.utilization_history()).collect();
+        let chart = Chart::new(datasets)
+            .block(Block::default()
+                .title("⏱ Utilization History")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Rgb(143, 188, 187)))) // Nord teal
+            .x_axis(
+                time_axis(&histories, now)
+                    .title("◀ Time ▶")
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("%")
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                    .bounds([0.0, 100.0])
+                    .labels(vec!["0".to_string(), "50".to_string(), "100".to_string()]),
+            );
+        f.render_widget(chart, charts[1]);
+    }
+}
+
+fn draw_pressure_widget(f: &mut Frame, app: &App, area: Rect) {
+    let pressures = app.metrics.pressure();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(pressures.len().max(1) as u16 + 2),  // Current values
+            Constraint::Min(0),                                     // Chart
+        ])
+        .split(area);
+
+    let color = |percent: f32| {
+        if percent < 5.0 {
+            Color::Rgb(163, 190, 140) // Nord green
+        } else if percent < 20.0 {
+            Color::Rgb(235, 203, 139) // Nord yellow
+        } else if percent < 50.0 {
+            Color::Rgb(208, 135, 112) // Nord orange
+        } else {
+            Color::Rgb(191, 97, 106) // Nord red
+        }
+    };
+    // Trends on a fixed 0-100% scale, so a flat line means little stall time
+    let trend = |history: &History| {
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(PRESSURE_TREND_WIDTH)).copied().collect();
+        text_sparkline(&recent, 0.0, 100.0)
+    };
+
+    let mut lines = Vec::new();
+    if pressures.is_empty() {
+        lines.push(Line::styled("PSI unavailable (needs Linux 4.20+ with CONFIG_PSI)", Style::default().fg(Color::DarkGray)));
+    }
+    for pressure in pressures {
+        let mut spans = vec![
+            Span::styled(format!("{:<7}", pressure.resource), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw("some "),
+            Span::styled(format!("{:5.1}% ", pressure.some), Style::default().fg(color(pressure.some))),
+            Span::styled(trend(pressure.some_history()), Style::default().fg(color(pressure.some))),
+        ];
+        // All tasks stalled at once: time in which nothing productive ran
+        if let Some(full) = pressure.full {
+            spans.push(Span::raw("  full "));
+            spans.push(Span::styled(format!("{:5.1}% ", full), Style::default().fg(color(full))));
+            spans.push(Span::styled(trend(pressure.full_history()), Style::default().fg(color(full))));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title("⏳ Pressure (avg10)")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173)))) // Nord purple
+        .style(Style::default().fg(Color::White));
+    f.render_widget(paragraph, chunks[0]);
+
+    // "some" of every resource over the whole history
+    if pressures.is_empty() {
+        return;
+    }
+    let now = Instant::now();
+    let histories: Vec<&History> = pressures.iter().map(Pressure::some_history).collect();
+    let points: Vec<Vec<(f64, f64)>> = histories.iter().map(|history| history.points(now)).collect();
+    // Low pressure is the common case, so small stalls stay visible
+    let peak = points.iter().flatten().map(|&(_, value)| value).fold(0.0, f64::max);
+    let y_max = (peak * 1.25).clamp(10.0, 100.0);
+    let colors = [
+        Color::Rgb(163, 190, 140), // Nord green
+        Color::Rgb(136, 192, 208), // Nord frost
+        Color::Rgb(208, 135, 112), // Nord orange
+    ];
+    let datasets = pressures
+        .iter()
+        .zip(&points)
+        .zip(colors)
+        .map(|((pressure, points), color)| {
+            Dataset::default()
+                .name(format!("◈ {}", pressure.resource))
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(color))
+                .data(points)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title("📊 Pressure History (some)")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173))))
+        .x_axis(
+            time_axis(&histories, now)
+                .title("◀ Time ▶")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+        )
+        .y_axis(
+            Axis::default()
+                .title("% Stalled")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                .bounds([0.0, y_max])
+                .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+        );
+    f.render_widget(chart, chunks[1]);
+}
+
+fn draw_tcp_widget(f: &mut Frame, app: &App, area: Rect) {
+    let states = app.metrics.tcp_states();
+    let health = app.metrics.tcp_health();
+    let health_lines = if health.is_some() { 3 } else { 0 };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(states.len().max(1) as u16 + health_lines + 2),  // Current counts
+            Constraint::Min(0),                                  // Chart
+        ])
+        .split(area);
+
+    let color = |state: &str| match state {
+        "ESTABLISHED" => Color::Rgb(163, 190, 140), // Nord green
+        "TIME_WAIT" => Color::Rgb(235, 203, 139),   // Nord yellow
+        "CLOSE_WAIT" => Color::Rgb(208, 135, 112),  // Nord orange
+        _ => Color::Rgb(191, 97, 106),              // Nord red
+    };
+
+    let mut lines = Vec::new();
+    if states.is_empty() {
+        lines.push(Line::styled("Socket tables unavailable (/proc/net/tcp)", Style::default().fg(Color::DarkGray)));
+    }
+    for state in states {
+        // Each trend on its own scale from zero, so a steady climb shows as a ramp
+        let recent: Vec<f32> =
+            state.history().iter().skip(state.history().len().saturating_sub(TCP_STATE_TREND_WIDTH)).copied().collect();
+        let peak = recent.iter().copied().fold(1.0, f32::max);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<12}", state.state), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:>6} ", state.count), Style::default().fg(color(state.state))),
+            Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(color(state.state))),
+        ]));
+    }
+    if let Some(health) = health {
+        let recent = |history: &History| -> Vec<f32> {
+            history.iter().skip(history.len().saturating_sub(TCP_STATE_TREND_WIDTH)).copied().collect()
+        };
+        // A percent or two of segments resent already costs throughput
+        let retransmit_color = if health.retransmit_percent < 1.0 {
+            Color::Rgb(163, 190, 140) // Nord green
+        } else if health.retransmit_percent < 5.0 {
+            Color::Rgb(235, 203, 139) // Nord yellow
+        } else {
+            Color::Rgb(191, 97, 106) // Nord red
+        };
+        let retransmits = recent(health.retransmit_history());
+        let memory = recent(health.memory_history());
+        let memory_color = Color::Rgb(136, 192, 208); // Nord frost
+        // Compact, to fit the count column and keep the trends aligned
+        let memory_label = if health.socket_memory < 1024 * 1024 {
+            format!("{}K", health.socket_memory / 1024)
+        } else {
+            format!("{:.1}M", health.socket_memory as f64 / (1024.0 * 1024.0))
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<12}", "Retransmits"), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(
+                format!("{:>6} ", format!("{:.0}/s", health.retransmit_rate)),
+                Style::default().fg(retransmit_color),
+            ),
+            Span::styled(
+                text_sparkline(&retransmits, 0.0, retransmits.iter().copied().fold(1.0, f32::max)),
+                Style::default().fg(retransmit_color),
+            ),
+            Span::styled(format!(" {:.1}% of sent", health.retransmit_percent), Style::default().fg(retransmit_color)),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<12}", "Socket mem"), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:>6} ", memory_label), Style::default().fg(memory_color)),
+            Span::styled(
+                text_sparkline(&memory, 0.0, memory.iter().copied().fold(1.0, f32::max)),
+                Style::default().fg(memory_color),
+            ),
+            Span::styled(
+                format!(" {} orphan{}", health.orphans, if health.orphans == 1 { "" } else { "s" }),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title("🔗 TCP States")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173)))) // Nord purple
+        .style(Style::default().fg(Color::White));
+    f.render_widget(paragraph, chunks[0]);
+
+    if states.is_empty() {
+        return;
+    }
+    let now = Instant::now();
+    let histories: Vec<&History> = states.iter().map(StateCount::history).collect();
+    let points: Vec<Vec<(f64, f64)>> = histories.iter().map(|history| history.points(now)).collect();
+    let peak = points.iter().flatten().map(|&(_, value)| value).fold(0.0, f64::max);
+    let y_max = (peak * 1.25).max(10.0).ceil();
+    let datasets = states
+        .iter()
+        .zip(&points)
+        .map(|(state, points)| {
+            Dataset::default()
+                .name(format!("◈ {}", state.state))
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(color(state.state)))
+                .data(points)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title("📊 TCP State History")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173))))
+        .x_axis(
+            time_axis(&histories, now)
+                .title("◀ Time ▶")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+        )
+        .y_axis(
+            Axis::default()
+                .title("Sockets")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                .bounds([0.0, y_max])
+                .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+        );
+    f.render_widget(chart, chunks[1]);
+}
+
+fn draw_fans_widget(f: &mut Frame, app: &App, area: Rect) {
+    let fans = app.metrics.fans();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(fans.len().max(1) as u16 + 3),  // CPU temperature + fans
+            Constraint::Min(0),                                // Chart
+        ])
+        .split(area);
+
+    let recent = |history: &History| -> Vec<f32> {
+        history.iter().skip(history.len().saturating_sub(FAN_TREND_WIDTH)).copied().collect()
+    };
+    let name_width = fans.iter().map(|fan| fan.name.chars().count()).max().unwrap_or(0).max(8);
+
+    // CPU temperature on top with a trend of the same width, so spikes line up with the fans below
+    let mut lines = Vec::new();
+    let temperature_history = recent(app.metrics.cpu_temperature_history());
+    match app.metrics.cpu_temperature() {
+        Some(temperature) => {
+            let color = if temperature < 60.0 {
+                Color::Rgb(163, 190, 140) // Nord green
+            } else if temperature < 80.0 {
+                Color::Rgb(235, 203, 139) // Nord yellow
+            } else {
+                Color::Rgb(191, 97, 106) // Nord red
+            };
+            lines.push(Line::from(vec![
+                Span::styled(format!("{:<w$} ", "CPU", w = name_width), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{:>5.0}{}        ", app.temperature_unit.convert(temperature), app.temperature_unit.symbol()), Style::default().fg(color)),
+                Span::styled(text_sparkline(&temperature_history, 30.0, 100.0), Style::default().fg(color)),
+            ]));
+        }
+        None => lines.push(Line::styled("CPU temperature N/A", Style::default().fg(Color::DarkGray))),
+    }
+
+    if fans.is_empty() {
+        lines.push(Line::styled("No fan sensors (hwmon fan*_input)", Style::default().fg(Color::DarkGray)));
+    }
+    for fan in fans {
+        // A fan that stopped while others spin is worth noticing
+        let color = if fan.rpm == 0.0 {
+            Color::Rgb(191, 97, 106) // Nord red
+        } else {
+            Color::Rgb(136, 192, 208) // Nord frost
+        };
+        let percent = fan.percent.map_or(String::new(), |percent| format!("{:3.0}%", percent));
+        let history = recent(fan.history());
+        let peak = history.iter().copied().fold(0.0, f32::max);
+        lines.push(Line::from(vec![
+            Span::styled(format!("{:<w$} ", fan.name, w = name_width), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:>5.0} RPM ", fan.rpm), Style::default().fg(color)),
+            Span::styled(format!("{:<5}", percent), Style::default().fg(Color::Rgb(216, 222, 233))),
+            Span::styled(text_sparkline(&history, 0.0, peak.max(1.0)), Style::default().fg(color)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title("🌀 Fans")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(136, 192, 208)))) // Nord frost
+        .style(Style::default().fg(Color::White));
+    f.render_widget(paragraph, chunks[0]);
 
+    if fans.is_empty() {
+        return;
+    }
+    let now = Instant::now();
+    let histories: Vec<&History> = fans.iter().map(Fan::history).collect();
+    let points: Vec<Vec<(f64, f64)>> = histories.iter().map(|history| history.points(now)).collect();
+    let peak = points.iter().flatten().map(|&(_, rpm)| rpm).fold(0.0, f64::max);
+    let y_max = (peak * 1.25).max(1000.0);
+    let colors = [
+        Color::Rgb(136, 192, 208), // Nord frost
+        Color::Rgb(163, 190, 140), // Nord green
+        Color::Rgb(235, 203, 139), // Nord yellow
+        Color::Rgb(180, 142, 173), // Nord purple
+        Color::Rgb(208, 135, 112), // Nord orange
+        Color::Rgb(143, 188, 187), // Nord teal
+    ];
+    let datasets = fans
+        .iter()
+        .zip(&points)
+        .zip(colors.iter().cycle())
+        .map(|((fan, points), &color)| {
+            Dataset::default()
+                .name(format!("◈ {}", fan.name))
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(color))
+                .data(points)
+        })
+        .collect();
 
-    let info_paragraph = Paragraph::new(cpu_info)
-        .block(Block::default().borders(Borders::ALL))
-        .style(Style::default().fg(Color::White));
-    f.render_widget(info_paragraph, chunks[1]);
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title("📊 Fan History")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(136, 192, 208))))
+        .x_axis(
+            time_axis(&histories, now)
+                .title("◀ Time ▶")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+        )
+        .y_axis(
+            Axis::default()
+                .title("RPM")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                .bounds([0.0, y_max])
+                .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+        );
+    f.render_widget(chart, chunks[1]);
 }
 
-fn draw_memory_widget(f: &mut Frame, app: &App, area: Rect) {
-    let memory_usage = app.metrics.memory_usage();
-    
+fn draw_power_widget(f: &mut Frame, app: &App, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),  // Gauge
-            Constraint::Length(4),  // Info
+            Constraint::Length(7),  // Info
             Constraint::Min(0),     // Chart
         ])
         .split(area);
 
-    // Enhanced Memory Gauge with Btop-inspired colors
-    let memory_color = if memory_usage < 40.0 {
-        Color::Rgb(136, 192, 208) // Nord frost
-    } else if memory_usage < 60.0 {
-        Color::Rgb(163, 190, 140) // Nord aurora green
-    } else if memory_usage < 80.0 {
-        Color::Rgb(235, 203, 139) // Nord aurora yellow
-    } else {
-        Color::Rgb(191, 97, 106) // Nord aurora red
+    let watts = |value: Option<f32>| value.map(|w| format!("{:.1} W", w)).unwrap_or_else(|| "N/A".to_string());
+    let battery = match app.metrics.battery_power() {
+        Some(battery) if app.metrics.on_battery() => format!("{:.1} W discharging", battery),
+        Some(_) => "not discharging".to_string(),
+        None => "N/A".to_string(),
     };
-    
-    let gauge = Gauge::default()
-        .block(Block::default()
-            .title("💾 Memory Usage")
-            .borders(Borders::ALL)
-            .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(136, 192, 208))))
-        .gauge_style(Style::default().fg(memory_color))
-        .percent(memory_usage as u16)
-        .label(format!("{:.1}%", memory_usage));
-    f.render_widget(gauge, chunks[0]);
-
-    // Enhanced Memory Info with visual indicators
-    let total_mem = app.system.total_memory() as f64 / 1024.0 / 1024.0;
-    let used_mem = app.system.used_memory() as f64 / 1024.0 / 1024.0;
-    let free_mem = total_mem - used_mem;
-    let usage_ratio = used_mem / total_mem;
-    
-    let mem_bar = if usage_ratio < 0.4 {
-        "▁▂▃▂▁"
-    } else if usage_ratio < 0.6 {
-        "▂▃▅▃▂"
-    } else if usage_ratio < 0.8 {
-        "▃▅▆▅▃"
-    } else {
-        "▅▇▇▇▅"
+    let total = match app.metrics.system_power() {
+        Some(total) if app.metrics.on_battery() => format!("{:.1} W (battery)", total),
+        Some(total) => format!("{:.1} W (CPU+GPU)", total),
+        None => "N/A".to_string(),
     };
 
-    let memory_info = vec![
-        Line::from(format!("Total: {:.1} MB", total_mem)),
-        Line::from(format!("Used: {:.1} MB {}", used_mem, mem_bar)),
-        Line::from(format!("Free: {:.1} MB", free_mem)),
+    let power_info = vec![
+        Line::from(format!("CPU (RAPL): {}", watts(app.metrics.cpu_power()))),
+        Line::from(format!("GPU: {}", watts(app.metrics.gpu_power_draw()))),
+        Line::from(format!("Battery: {}", battery)),
+        Line::styled(format!("Total: {}", total), Style::default().add_modifier(Modifier::BOLD)),
+        Line::from(format!("Session: {:.2} Wh", app.metrics.session_energy_wh())),
     ];
 
-    let info_paragraph = Paragraph::new(memory_info)
+    let info_paragraph = Paragraph::new(power_info)
         .block(Block::default()
+            .title("⚡ System Power")
             .borders(Borders::ALL)
-            .border_type(BorderType::Rounded))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(235, 203, 139)))) // Nord aurora yellow
         .style(Style::default().fg(Color::White));
-    f.render_widget(info_paragraph, chunks[1]);
+    f.render_widget(info_paragraph, chunks[0]);
 
-    // Enhanced Memory Chart with Btop-inspired styling
-    let memory_data: Vec<(f64, f64)> = app.metrics.memory_history()
-        .iter()
-        .enumerate()
-        .map(|(i, &value)| (i as f64, value as f64))
-        .collect();
+    let now = Instant::now();
+    let power_data: Vec<(f64, f64)> = app.metrics.power_history().points(now);
+
+    if !power_data.is_empty() {
+        // Scale to the peak so idle and loaded machines both get a readable chart
+        let peak = power_data.iter().map(|&(_, w)| w).fold(0.0, f64::max);
+        let y_max = (peak * 1.25).max(10.0);
 
-    if !memory_data.is_empty() {
         let datasets = vec![Dataset::default()
-            .name("◈ Memory Usage")
+            .name("◈ Total Power")
             .marker(symbols::Marker::Braille)
-            .style(Style::default().fg(Color::Rgb(136, 192, 208)))
-            .data(&memory_data)];
+            .style(Style::default().fg(Color::Rgb(235, 203, 139)))
+            .data(&power_data)];
 
         let chart = Chart::new(datasets)
             .block(Block::default()
-                .title("📊 Memory Usage History")
+                .title("📊 Power History")
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::default().fg(Color::Rgb(136, 192, 208))))
+                .border_style(Style::default().fg(Color::Rgb(235, 203, 139))))
             .x_axis(
-                Axis::default()
+                time_axis(&[app.metrics.power_history()], now)
                     .title("◀ Time ▶")
-                    .style(Style::default().fg(Color::Rgb(216, 222, 233)))
-                    .bounds([0.0, app.metrics.memory_history().len() as f64])
-                    .labels(vec!["Past", "Now"]),
+                    .style(Style::default().fg(Color::Rgb(216, 222, 233))),
             )
             .y_axis(
                 Axis::default()
-                    .title("% Usage")
+                    .title("Watts")
                     .style(Style::default().fg(Color::Rgb(216, 222, 233)))
-                    .bounds([0.0, 100.0])
-                    .labels(vec!["0", "25", "50", "75", "100"]),
+                    .bounds([0.0, y_max])
+                    .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
             );
-        f.render_widget(chart, chunks[2]);
+        f.render_widget(chart, chunks[1]);
     }
 }
 
-fn draw_disk_widget(f: &mut Frame, app: &App, area: Rect) {
-    let disk_usage = app.metrics.disk_usage();
-    
+fn draw_battery_widget(f: &mut Frame, app: &App, area: Rect) {
+    let batteries = app.metrics.batteries();
+    let mut constraints = vec![Constraint::Length(3); batteries.len()]; // One gauge per battery
+    constraints.push(Constraint::Length(batteries.len() as u16 * 2 + 2)); // Info
+    constraints.push(Constraint::Min(0)); // Chart
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Length(3),  // Gauge
-            Constraint::Min(0),     // Info (expanded to fill space)
-        ])
+        .constraints(constraints)
         .split(area);
 
-    // Enhanced Disk Gauge with Btop-inspired colors
-    let disk_color = if disk_usage < 50.0 {
-        Color::Rgb(163, 190, 140) // Nord aurora green
-    } else if disk_usage < 70.0 {
-        Color::Rgb(235, 203, 139) // Nord aurora yellow
-    } else if disk_usage < 90.0 {
-        Color::Rgb(208, 135, 112) // Nord aurora orange
-    } else {
-        Color::Rgb(191, 97, 106) // Nord aurora red
-    };
-    
-    let gauge = Gauge::default()
+    let mut info = Vec::new();
+    for (i, battery) in batteries.iter().enumerate() {
+        let color = if battery.percent < 15.0 {
+            Color::Rgb(191, 97, 106) // Nord aurora red
+        } else if battery.percent < 40.0 {
+            Color::Rgb(235, 203, 139) // Nord aurora yellow
+        } else {
+            Color::Rgb(163, 190, 140) // Nord aurora green
+        };
+        let icon = if battery.status == BatteryStatus::Charging { "🔌" } else { "🔋" };
+        let gauge = Gauge::default()
+            .block(Block::default()
+                .title(format!("{} {}", icon, battery.name))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Rgb(163, 190, 140))))
+            .gauge_style(Style::default().fg(color))
+            .percent(battery.percent as u16)
+            .label(format!("{:.0}% • {}", battery.percent, battery.status.label()));
+        f.render_widget(gauge, chunks[i]);
+
+        let rate = match (battery.status, battery.watts.filter(|watts| *watts > 0.0)) {
+            (BatteryStatus::Discharging, Some(watts)) => format!("drawing {:.1} W", watts),
+            (BatteryStatus::Charging, Some(watts)) => format!("charging at {:.1} W", watts),
+            _ => battery.status.label().to_string(),
+        };
+        let remaining = match (battery.status, battery.time_remaining) {
+            (BatteryStatus::Charging, Some(time)) => format!(" • {} until full", crate::format_uptime(time.as_secs())),
+            (_, Some(time)) => format!(" • {} left", crate::format_uptime(time.as_secs())),
+            (_, None) => String::new(),
+        };
+        info.push(Line::from(vec![
+            Span::styled(format!("{}: ", battery.name), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!("{}{}", rate, remaining)),
+        ]));
+        let energy = match (battery.energy_now, battery.energy_full) {
+            (Some(now), Some(full)) => format!("Energy: {:.1} / {:.1} Wh", now, full),
+            _ => "Energy: N/A".to_string(),
+        };
+        info.push(Line::styled(energy, Style::default().fg(Color::Rgb(216, 222, 233)))); // Nord snow storm
+    }
+    if batteries.is_empty() {
+        info.push(Line::styled("No battery", Style::default().fg(Color::DarkGray)));
+    }
+
+    let info_paragraph = Paragraph::new(info)
         .block(Block::default()
-            .title("💽 Disk Usage")
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
-            .border_style(Style::default().fg(Color::Rgb(235, 203, 139))))
-        .gauge_style(Style::default().fg(disk_color))
-        .percent(disk_usage as u16)
-        .label(format!("{:.1}%", disk_usage));
-    f.render_widget(gauge, chunks[0]);
+            .border_style(Style::default().fg(Color::Rgb(163, 190, 140)))) // Nord aurora green
+        .style(Style::default().fg(Color::White));
+    f.render_widget(info_paragraph, chunks[batteries.len()]);
 
-    // Enhanced Disk Info
-    let mut disk_info = vec![Line::from("Root filesystem:")];
-    let disks = sysinfo::Disks::new_with_refreshed_list();
-    for disk in &disks {
-        if disk.mount_point().to_str() == Some("/") {
-            let total = disk.total_space() as f64 / 1024.0 / 1024.0 / 1024.0;
-            let available = disk.available_space() as f64 / 1024.0 / 1024.0 / 1024.0;
-            let used = total - available;
-            
-            disk_info.push(Line::from(format!("Total: {:.1} GB", total)));
-            disk_info.push(Line::from(format!("Used: {:.1} GB", used)));
-            disk_info.push(Line::from(format!("Free: {:.1} GB", available)));
-            break;
+    if app.metrics.battery_history().is_empty() {
+        return;
+    }
+    let now = Instant::now();
+    let charge_data: Vec<(f64, f64)> = app.metrics.battery_history().points(now);
+
+    let datasets = vec![Dataset::default()
+        .name("◈ Charge")
+        .marker(symbols::Marker::Braille)
+        .style(Style::default().fg(Color::Rgb(163, 190, 140)))
+        .data(&charge_data)];
+
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title("📊 Charge History")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(163, 190, 140))))
+        .x_axis(
+            time_axis(&[app.metrics.battery_history()], now)
+                .title("◀ Time ▶")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+        )
+        .y_axis(
+            Axis::default()
+                .title("%")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                .bounds([0.0, 100.0])
+                .labels(vec!["0", "50", "100"]),
+        );
+    f.render_widget(chart, chunks[batteries.len() + 1]);
+}
+
+fn draw_custom_gauges_widget(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title("📏 Custom Gauges")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Rgb(180, 142, 173))); // Nord purple
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.custom_gauges.is_empty() {
+        let hint = Paragraph::new("No [[custom_gauge]] entries in config")
+            .style(Style::default().fg(Color::Gray));
+        f.render_widget(hint, inner);
+        return;
+    }
+
+    // One gauge + sparkline row per configured gauge, as many as fit
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(3); app.custom_gauges.len()])
+        .split(inner);
+
+    for (gauge, &row) in app.custom_gauges.iter().zip(rows.iter()) {
+        let row_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(row);
+
+        let percent = gauge.percent();
+        let color = if percent < 50.0 {
+            Color::Rgb(163, 190, 140) // Nord aurora green
+        } else if percent < 80.0 {
+            Color::Rgb(235, 203, 139) // Nord aurora yellow
+        } else {
+            Color::Rgb(191, 97, 106) // Nord aurora red
+        };
+        let label = match (gauge.value, &gauge.error) {
+            (Some(value), _) => format!("{} / {}", value, gauge.config.max),
+            (None, Some(err)) => format!("N/A ({})", err),
+            (None, None) => "N/A".to_string(),
+        };
+
+        let gauge_widget = Gauge::default()
+            .block(Block::default().title(gauge.config.name.as_str()).borders(Borders::ALL))
+            .gauge_style(Style::default().fg(color))
+            .percent(percent as u16)
+            .label(label);
+        f.render_widget(gauge_widget, row_chunks[0]);
+
+        // Sparkline scaled so the configured max is the top of the chart
+        let data: Vec<u64> = gauge.history()
+            .iter()
+            .map(|&value| (value as f64 / gauge.config.max * 100.0).clamp(0.0, 100.0) as u64)
+            .collect();
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL))
+            .data(&data)
+            .max(100)
+            .style(Style::default().fg(color));
+        f.render_widget(sparkline, row_chunks[1]);
+    }
+}
+
+fn draw_ports_widget(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title("🔓 Listening Ports")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Rgb(180, 142, 173))); // Nord purple
+
+    let processes = app.listening_ports.as_ref().map_or(&[][..], |ports| &ports.processes);
+    let mut lines = Vec::new();
+    if processes.is_empty() {
+        lines.push(Line::styled("No listening sockets", Style::default().fg(Color::DarkGray)));
+    }
+    // One line per process, its sockets wrapping below. Binds on every address stand
+    // out, loopback-only ones fade: those are the ports other machines cannot reach.
+    for process in processes {
+        let mut spans = match &process.owner {
+            Some((pid, name)) => vec![
+                Span::styled(name.clone(), Style::default().add_modifier(Modifier::BOLD)),
+                Span::styled(format!(" ({})", pid), Style::default().fg(Color::DarkGray)),
+            ],
+            None => vec![Span::styled("unknown process", Style::default().fg(Color::DarkGray))],
+        };
+        spans.push(Span::raw(":"));
+        for socket in &process.sockets {
+            let color = if socket.is_exposed() {
+                Color::Rgb(235, 203, 139) // Nord yellow
+            } else if socket.is_loopback() {
+                Color::Rgb(76, 86, 106) // Nord gray
+            } else {
+                Color::Rgb(136, 192, 208) // Nord frost
+            };
+            spans.push(Span::styled(format!(" {}/{}", socket.local, socket.protocol), Style::default().fg(color)));
         }
+        lines.push(Line::from(spans));
     }
 
-    let info_paragraph = Paragraph::new(disk_info)
-        .block(Block::default().borders(Borders::ALL))
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+fn draw_talkers_widget(f: &mut Frame, app: &App, area: Rect) {
+    let Some(view) = &app.top_talkers else {
+        return;
+    };
+    let block = Block::default()
+        .title("📡 Top Talkers")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Rgb(136, 192, 208))); // Nord frost
+    let message = match &view.error {
+        Some(err) => Some(Line::styled(err.clone(), Style::default().fg(Color::Rgb(191, 97, 106)))), // Nord red
+        None if view.talkers.is_empty() => Some(Line::styled("No remote TCP connections", Style::default().fg(Color::DarkGray))),
+        None => None,
+    };
+    if let Some(message) = message {
+        f.render_widget(Paragraph::new(message).block(block).wrap(Wrap { trim: true }), area);
+        return;
+    }
+
+    // The busiest host sets the scale of the bars
+    let peak = view.talkers.first().map_or(0.0, |talker| talker.rx_rate + talker.tx_rate);
+    let rows: Vec<Row> = view.talkers
+        .iter()
+        .take(talkers::TOP_TALKERS)
+        .map(|talker| {
+            let rate = talker.rx_rate + talker.tx_rate;
+            let style = if rate > 0.0 { Style::default() } else { Style::default().fg(Color::Rgb(76, 86, 106)) }; // Nord gray
+            let bar = if peak > 0.0 { "█".repeat((rate / peak * 8.0).ceil() as usize) } else { String::new() };
+            Row::new(vec![
+                Cell::from(talker.address.to_string()),
+                Cell::from(talker.connections.to_string()),
+                Cell::from(format!("↓ {}", app.rate_unit.format(talker.rx_rate))),
+                Cell::from(format!("↑ {}", app.rate_unit.format(talker.tx_rate))),
+                Cell::from(format!("Σ {}", crate::format_bytes(talker.rx_bytes + talker.tx_bytes))),
+                Cell::from(bar).style(Style::default().fg(Color::Rgb(136, 192, 208))), // Nord frost
+            ])
+            .style(style)
+        })
+        .collect();
+    let table = Table::new(rows, [
+        Constraint::Min(16),     // Address
+        Constraint::Length(5),   // Connections
+        Constraint::Length(14),  // Download
+        Constraint::Length(14),  // Upload
+        Constraint::Length(11),  // Session total
+        Constraint::Length(8),   // Bar
+    ])
+    .header(Row::new(vec!["Remote host", "Conns", "Download", "Upload", "Session", ""])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+    .block(block)
+    .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+/// The worst offenders by CPU and by memory side by side, to spot them without
+/// switching to the Processes tab
+fn draw_top_widget(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .title("🔥 Top Processes")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Rgb(208, 135, 112))); // Nord orange
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+    if app.processes.is_empty() {
+        f.render_widget(Paragraph::new(Line::styled("Loading processes...", Style::default().fg(Color::DarkGray))), inner);
+        return;
+    }
+
+    let (by_cpu, by_memory) = crate::top_consumers(&app.processes);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner);
+    let header_style = Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let cpu_rows: Vec<Row> = by_cpu
+        .iter()
+        .map(|process| {
+            let color = if process.cpu_usage > 80.0 {
+                Color::Rgb(191, 97, 106) // Nord red
+            } else if process.cpu_usage > 50.0 {
+                Color::Rgb(235, 203, 139) // Nord yellow
+            } else {
+                Color::Rgb(163, 190, 140) // Nord green
+            };
+            Row::new(vec![
+                Cell::from(process.name.clone()),
+                Cell::from(format!("{:.1}%", process.cpu_usage)).style(Style::default().fg(color)),
+            ])
+        })
+        .collect();
+    let cpu_table = Table::new(cpu_rows, [Constraint::Min(8), Constraint::Length(7)])
+        .header(Row::new(vec!["CPU", ""]).style(header_style))
+        .column_spacing(1);
+    f.render_widget(cpu_table, columns[0]);
+
+    let memory_rows: Vec<Row> = by_memory
+        .iter()
+        .map(|process| {
+            Row::new(vec![
+                Cell::from(process.name.clone()),
+                Cell::from(crate::format_bytes(process.memory_usage)).style(Style::default().fg(Color::Rgb(136, 192, 208))), // Nord frost
+            ])
+        })
+        .collect();
+    let memory_table = Table::new(memory_rows, [Constraint::Min(8), Constraint::Length(10)])
+        .header(Row::new(vec!["Memory", ""]).style(header_style))
+        .column_spacing(1);
+    f.render_widget(memory_table, columns[1]);
+}
+
+fn draw_cgroups_widget(f: &mut Frame, app: &App, area: Rect) {
+    let Some(view) = &app.cgroups else {
+        return;
+    };
+    let block = Block::default()
+        .title("🧩 Cgroups")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Rgb(180, 142, 173))); // Nord purple
+    let message = match &view.error {
+        Some(err) => Some(Line::styled(err.clone(), Style::default().fg(Color::Rgb(191, 97, 106)))), // Nord red
+        None if view.groups.is_empty() => Some(Line::styled("No cgroups", Style::default().fg(Color::DarkGray))),
+        None => None,
+    };
+    if let Some(message) = message {
+        f.render_widget(Paragraph::new(message).block(block).wrap(Wrap { trim: true }), area);
+        return;
+    }
+
+    // Bars against all RAM, so the groups can be compared with the memory panel
+    let total = app.metrics.memory_breakdown().map_or(0, |memory| memory.total);
+    let rows: Vec<Row> = view.groups
+        .iter()
+        .map(|group| {
+            let share = if total > 0 { group.memory as f32 / total as f32 } else { 0.0 };
+            let style = if group.processes > 0 { Style::default() } else { Style::default().fg(Color::Rgb(76, 86, 106)) }; // Nord gray
+            Row::new(vec![
+                Cell::from(group.name.clone()),
+                Cell::from(crate::format_bytes(group.memory)),
+                Cell::from(group.anon.map(crate::format_bytes).unwrap_or_else(|| "-".to_string())),
+                Cell::from(format!("{:.1}%", group.cpu_percent)),
+                Cell::from(group.processes.to_string()),
+                Cell::from("█".repeat((share * 10.0).ceil() as usize)).style(Style::default().fg(Color::Rgb(180, 142, 173))), // Nord purple
+            ])
+            .style(style)
+        })
+        .collect();
+    let table = Table::new(rows, [
+        Constraint::Min(14),     // Name
+        Constraint::Length(10),  // Memory
+        Constraint::Length(10),  // Anonymous
+        Constraint::Length(7),   // CPU
+        Constraint::Length(5),   // Processes
+        Constraint::Length(10),  // Bar
+    ])
+    .header(Row::new(vec!["Cgroup", "Memory", "Anon", "CPU", "Procs", ""])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+    .block(block)
+    .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+/// One row per node, so a node running out of memory while another has plenty free
+/// stands out; allocations spilling to other nodes are flagged in yellow
+fn draw_numa_widget(f: &mut Frame, app: &App, area: Rect) {
+    let Some(view) = &app.numa else {
+        return;
+    };
+    let block = Block::default()
+        .title("🧬 NUMA Nodes")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Rgb(143, 188, 187))); // Nord teal
+    if let Some(err) = &view.error {
+        let message = Line::styled(err.clone(), Style::default().fg(Color::Rgb(191, 97, 106))); // Nord red
+        f.render_widget(Paragraph::new(message).block(block).wrap(Wrap { trim: true }), area);
+        return;
+    }
+
+    let rows: Vec<Row> = view.nodes
+        .iter()
+        .map(|node| {
+            let memory_percent = node.memory_percent();
+            let memory_color = if memory_percent >= 90.0 {
+                Color::Rgb(191, 97, 106) // Nord red
+            } else if memory_percent >= 75.0 {
+                Color::Rgb(235, 203, 139) // Nord yellow
+            } else {
+                Color::Rgb(163, 190, 140) // Nord green
+            };
+            let (foreign, foreign_style) = if node.foreign_rate > 0.0 {
+                (per_second(node.foreign_rate), Style::default().fg(Color::Rgb(235, 203, 139))) // Nord yellow
+            } else {
+                ("-".to_string(), Style::default().fg(Color::Rgb(76, 86, 106))) // Nord gray
+            };
+            Row::new(vec![
+                Cell::from(node.id.to_string()),
+                Cell::from(if node.cpus.is_empty() { "-".to_string() } else { node.cpus.clone() }),
+                Cell::from(format!("{:.1}%", node.cpu_percent)),
+                Cell::from(format!("{} / {}", crate::format_bytes(node.memory_used()), crate::format_bytes(node.memory_total))),
+                Cell::from(Span::styled(
+                    format!("{:5.1}% {}", memory_percent, "█".repeat((memory_percent / 10.0).ceil() as usize)),
+                    Style::default().fg(memory_color),
+                )),
+                Cell::from(Span::styled(foreign, foreign_style)),
+            ])
+        })
+        .collect();
+    let table = Table::new(rows, [
+        Constraint::Length(4),   // Node
+        Constraint::Min(10),     // CPUs
+        Constraint::Length(6),   // CPU
+        Constraint::Length(21),  // Memory
+        Constraint::Length(17),  // Percent and bar
+        Constraint::Length(9),   // Foreign allocations
+    ])
+    .header(Row::new(vec!["Node", "CPUs", "CPU", "Memory", "Used", "Foreign"])
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)))
+    .block(block)
+    .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+fn draw_snmp_widget(f: &mut Frame, app: &App, area: Rect) {
+    let mut lines = Vec::new();
+    for device in &app.snmp_devices {
+        let mut header = vec![Span::styled(device.label(), Style::default().add_modifier(Modifier::BOLD))];
+        if let Some(uptime) = device.uptime {
+            header.push(Span::styled(
+                format!(" · up {}", crate::format_uptime(uptime)),
+                Style::default().fg(Color::Rgb(76, 86, 106)), // Nord gray
+            ));
+        }
+        lines.push(Line::from(header));
+        if let Some(err) = &device.error {
+            lines.push(Line::styled(format!("  {}", err), Style::default().fg(Color::Rgb(191, 97, 106)))); // Nord red
+        }
+        for interface in device.interfaces() {
+            let state_color = match interface.link.as_ref().map(|link| link.state.as_str()) {
+                Some("up") => Color::Rgb(163, 190, 140),  // Nord green
+                Some("down") => Color::Rgb(191, 97, 106), // Nord red
+                _ => Color::Rgb(76, 86, 106),             // Nord gray
+            };
+            // Download and upload together, on the scale of the recent peak
+            let (rx, tx) = (interface.rx_history(), interface.tx_history());
+            let recent: Vec<f32> = rx
+                .iter()
+                .zip(tx.iter())
+                .map(|(rx, tx)| rx + tx)
+                .skip(rx.len().min(tx.len()).saturating_sub(SNMP_TREND_WIDTH))
+                .collect();
+            let peak = recent.iter().copied().fold(1.0, f32::max);
+            lines.push(Line::from(vec![
+                Span::raw(format!("  {:<10} ", interface.name)),
+                Span::styled(
+                    format!("{:<18}", interface.link.as_ref().map(Link::summary).unwrap_or_default()),
+                    Style::default().fg(state_color),
+                ),
+                Span::raw(format!(
+                    "↓ {:<11} ↑ {:<11} ",
+                    app.rate_unit.format(interface.rx_rate),
+                    app.rate_unit.format(interface.tx_rate),
+                )),
+                Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(Color::Rgb(136, 192, 208))), // Nord frost
+            ]));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .title("🔀 SNMP")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187)))) // Nord teal
         .style(Style::default().fg(Color::White));
-    f.render_widget(info_paragraph, chunks[1]);
+    f.render_widget(paragraph, area);
 }
 
-fn draw_network_widget(f: &mut Frame, app: &App, area: Rect) {
-    let download_rate = app.metrics.network_download_rate();
-    let upload_rate = app.metrics.network_upload_rate();
-    let (total_rx, total_tx) = app.metrics.total_network_bytes();
-    
+fn draw_quota_widget(f: &mut Frame, app: &App, area: Rect) {
+    let Some(quota) = &app.traffic_quota else {
+        return;
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(3),  // Download gauge
-            Constraint::Length(3),  // Upload gauge
-            Constraint::Min(0),     // Info section
+            Constraint::Length(3), // Usage gauge
+            Constraint::Min(0),    // Period and per-interface totals
         ])
         .split(area);
 
-    // Calculate percentage for gauges (scale to a reasonable max speed)
-    let max_speed_kbps = 10000.0; // 10 Mbps max for gauge scale
-    let download_percent = ((download_rate / max_speed_kbps) * 100.0).min(100.0) as u16;
-    let upload_percent = ((upload_rate / max_speed_kbps) * 100.0).min(100.0) as u16;
-
-    // Download Gauge
-    let download_color = if download_rate < 1000.0 {
-        Color::Green
-    } else if download_rate < 5000.0 {
-        Color::Yellow
+    let percent = quota.percent();
+    let color = if percent < 75.0 {
+        Color::Rgb(163, 190, 140) // Nord green
+    } else if percent < 90.0 {
+        Color::Rgb(235, 203, 139) // Nord yellow
     } else {
-        Color::Red
+        Color::Rgb(191, 97, 106) // Nord red
     };
-    
-    let download_gauge = Gauge::default()
+    let gauge = Gauge::default()
         .block(Block::default()
-            .title("📥 Download")
+            .title(format!("📶 Quota · {}", quota.scope_label()))
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Green)))
-        .gauge_style(Style::default().fg(download_color))
-        .percent(download_percent)
-        .label(format!("{:.1} Kbps", download_rate));
-    f.render_widget(download_gauge, chunks[0]);
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187)))) // Nord teal
+        .gauge_style(Style::default().fg(color))
+        .percent(percent.min(100.0) as u16)
+        .label(format!(
+            "{} of {} • {:.1}%",
+            quota::format_gigabytes(quota.used()),
+            quota::format_gigabytes(quota.limit()),
+            percent,
+        ));
+    f.render_widget(gauge, chunks[0]);
 
-    // Upload Gauge
-    let upload_color = if upload_rate < 1000.0 {
-        Color::Green
-    } else if upload_rate < 5000.0 {
-        Color::Yellow
-    } else {
-        Color::Red
+    let days_left = (quota.next_reset() - Local::now().date_naive()).num_days();
+    let mut lines = vec![Line::from(vec![
+        Span::styled("Period     ", Style::default().add_modifier(Modifier::BOLD)),
+        Span::raw(format!(
+            "{} – {} • resets in {} day{}",
+            quota.period_start().format("%b %-d"),
+            quota.next_reset().pred_opt().unwrap_or(quota.next_reset()).format("%b %-d"),
+            days_left,
+            if days_left == 1 { "" } else { "s" },
+        )),
+    ])];
+    let projected = match quota.projected() {
+        Some(projected) if projected > quota.limit() => {
+            Span::styled(quota::format_gigabytes(projected), Style::default().fg(Color::Rgb(191, 97, 106))) // Nord red
+        }
+        Some(projected) => Span::raw(quota::format_gigabytes(projected)),
+        None => Span::styled("after the first day", Style::default().fg(Color::DarkGray)),
+    };
+    lines.push(Line::from(vec![Span::styled("Projected  ", Style::default().add_modifier(Modifier::BOLD)), projected]));
+    for (name, transfer) in quota.interfaces() {
+        lines.push(Line::styled(
+            format!("{:<10} ↓ {}  ↑ {}", name, quota::format_gigabytes(transfer.rx), quota::format_gigabytes(transfer.tx)),
+            Style::default().fg(Color::Rgb(136, 192, 208)), // Nord frost
+        ));
+    }
+    if let Some(err) = &quota.error {
+        lines.push(Line::styled(err.clone(), Style::default().fg(Color::Rgb(191, 97, 106)))); // Nord red
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187)))) // Nord teal
+        .style(Style::default().fg(Color::White))
+        .wrap(Wrap { trim: true });
+    f.render_widget(paragraph, chunks[1]);
+}
+
+fn draw_dns_widget(f: &mut Frame, app: &App, area: Rect) {
+    let Some(probe) = &app.dns_probe else {
+        return;
+    };
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(5),  // Latency, failures, last failure
+            Constraint::Min(0),     // Chart
+        ])
+        .split(area);
+
+    let color = |latency: f32| {
+        if latency < 50.0 {
+            Color::Rgb(163, 190, 140) // Nord green
+        } else if latency < 200.0 {
+            Color::Rgb(235, 203, 139) // Nord yellow
+        } else {
+            Color::Rgb(191, 97, 106) // Nord red
+        }
     };
-    
-    let upload_gauge = Gauge::default()
+    let history = probe.latency_history();
+    let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(DNS_TREND_WIDTH)).copied().collect();
+    let peak = recent.iter().copied().fold(1.0, f32::max);
+    let latency = match (probe.latency, &probe.error) {
+        (Some(latency), _) => vec![
+            Span::styled(format!("{:.1} ms ", latency), Style::default().fg(color(latency))),
+            Span::styled(text_sparkline(&recent, 0.0, peak), Style::default().fg(color(latency))),
+        ],
+        (None, Some(err)) => vec![Span::styled(format!("failed: {}", err), Style::default().fg(Color::Rgb(191, 97, 106)))], // Nord red
+        (None, None) => vec![Span::styled("waiting for the first lookup", Style::default().fg(Color::DarkGray))],
+    };
+    let failure_color = if probe.failures > 0 { Color::Rgb(235, 203, 139) } else { Color::Rgb(76, 86, 106) }; // Nord yellow / gray
+    let mut lines = vec![
+        Line::from([vec![Span::styled("Latency  ", Style::default().add_modifier(Modifier::BOLD))], latency].concat()),
+        Line::styled(format!("Failed   {} of {} lookups", probe.failures, probe.lookups), Style::default().fg(failure_color)),
+    ];
+    if let Some((time, err)) = &probe.last_failure {
+        lines.push(Line::styled(
+            format!("Last     {} ({})", time.format("%H:%M:%S"), err),
+            Style::default().fg(Color::Rgb(76, 86, 106)), // Nord gray
+        ));
+    }
+
+    let paragraph = Paragraph::new(lines)
         .block(Block::default()
-            .title("📤 Upload")
+            .title(format!("🧭 DNS · {} via {}", probe.config.hostname, probe.resolver_label()))
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Red)))
-        .gauge_style(Style::default().fg(upload_color))
-        .percent(upload_percent)
-        .label(format!("{:.1} Kbps", upload_rate));
-    f.render_widget(upload_gauge, chunks[1]);
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173)))) // Nord purple
+        .style(Style::default().fg(Color::White));
+    f.render_widget(paragraph, chunks[0]);
 
-    // Enhanced Network Info
-    let network_info = vec![
-        Line::from(format!("Total Down: {:.1} MB", total_rx as f64 / 1024.0 / 1024.0)),
-        Line::from(format!("Total Up: {:.1} MB", total_tx as f64 / 1024.0 / 1024.0)),
-        Line::from(format!("Max Scale: {:.0} Mbps", max_speed_kbps / 1000.0)),
+    let now = Instant::now();
+    let latency_points = history.points(now);
+    let peak = latency_points.iter().map(|&(_, latency)| latency).fold(0.0, f64::max);
+    let y_max = (peak * 1.25).max(10.0).ceil();
+    // Failed lookups as marks along the top edge, where they cannot be mistaken for latency
+    let failure_points: Vec<(f64, f64)> = probe
+        .failure_history()
+        .points(now)
+        .into_iter()
+        .filter(|&(_, failed)| failed > 0.0)
+        .map(|(age, _)| (age, y_max))
+        .collect();
+    let datasets = vec![
+        Dataset::default()
+            .name("◈ Latency")
+            .marker(symbols::Marker::Braille)
+            .style(Style::default().fg(Color::Rgb(136, 192, 208))) // Nord frost
+            .data(&latency_points),
+        Dataset::default()
+            .name("✖ Failed")
+            .marker(symbols::Marker::Dot)
+            .graph_type(GraphType::Scatter)
+            .style(Style::default().fg(Color::Rgb(191, 97, 106))) // Nord red
+            .data(&failure_points),
     ];
 
-    let info_paragraph = Paragraph::new(network_info)
+    let chart = Chart::new(datasets)
         .block(Block::default()
-            .title("🌐 Network Stats")
+            .title("📊 DNS Latency History")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Magenta)))
-        .style(Style::default().fg(Color::White));
-    f.render_widget(info_paragraph, chunks[2]);
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173))))
+        .x_axis(
+            time_axis(&[history, probe.failure_history()], now)
+                .title("◀ Time ▶")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233))),
+        )
+        .y_axis(
+            Axis::default()
+                .title("ms")
+                .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+                .bounds([0.0, y_max])
+                .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+        );
+    f.render_widget(chart, chunks[1]);
 }
 
 fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
@@ -586,6 +3778,7 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
         .constraints([
             Constraint::Length(3),  // GPU Usage gauge
             Constraint::Length(3),  // VRAM Usage gauge
+            Constraint::Length(3),  // PCIe throughput
             Constraint::Percentage(40), // Charts section
             Constraint::Min(0),     // Detailed info section
         ])
@@ -615,11 +3808,17 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
         "💤"
     };
 
-    let gpu_title = if let Some(name) = gpu_name {
+    let mut gpu_title = if let Some(name) = gpu_name {
         format!("🎮 GPU {} - {}", performance_status, name)
     } else {
         format!("🎮 GPU {} - NVIDIA", performance_status)
     };
+    if let Some(driver) = app.metrics.gpu_driver_info() {
+        gpu_title.push_str(&format!(" (driver {})", driver.driver_version));
+    }
+    if let Some(age) = app.metrics.stale_gpu_data_age() {
+        gpu_title.push_str(&format!(" • {}s ago", age.as_secs()));
+    }
 
     let usage_gauge = Gauge::default()
         .block(Block::default()
@@ -674,18 +3873,18 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
         f.render_widget(memory_gauge, chunks[1]);
     }
 
+    draw_gpu_pcie(f, app, chunks[2]);
+
     // GPU Charts section
     let chart_chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(chunks[2]);
+        .constraints([Constraint::Ratio(1, 4); 4])
+        .split(chunks[3]);
+
+    let now = Instant::now();
 
     // GPU Usage Chart
-    let gpu_usage_data: Vec<(f64, f64)> = app.metrics.gpu_usage_history()
-        .iter()
-        .enumerate()
-        .map(|(i, &value)| (i as f64, value as f64))
-        .collect();
+    let gpu_usage_data: Vec<(f64, f64)> = app.metrics.gpu_usage_history().points(now);
 
     if !gpu_usage_data.is_empty() {
         let datasets = vec![Dataset::default()
@@ -700,11 +3899,9 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Magenta)))
             .x_axis(
-                Axis::default()
+                time_axis(&[app.metrics.gpu_usage_history()], now)
                     .title("Time")
-                    .style(Style::default().fg(Color::Gray))
-                    .bounds([0.0, app.metrics.gpu_usage_history().len() as f64])
-                    .labels(vec!["Past", "Now"]),
+                    .style(Style::default().fg(Color::Gray)),
             )
             .y_axis(
                 Axis::default()
@@ -716,12 +3913,46 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
         f.render_widget(chart, chart_chunks[0]);
     }
 
+    // GPU Power Chart
+    let gpu_power_data: Vec<(f64, f64)> = app.metrics.gpu_power_history().points(now);
+
+    if !gpu_power_data.is_empty() {
+        let peak = gpu_power_data.iter().map(|&(_, watts)| watts).fold(0.0, f64::max);
+        let y_max = (peak * 1.25).max(10.0);
+
+        let datasets = vec![Dataset::default()
+            .name("Power")
+            .marker(symbols::Marker::Braille)
+            .style(Style::default().fg(Color::Rgb(208, 135, 112))) // Nord aurora orange
+            .data(&gpu_power_data)];
+
+        let title = match power_draw {
+            Some(watts) => format!("⚡ {:.0}W {:.2}Wh", watts, app.metrics.gpu_energy_wh()),
+            None => "⚡ Power W".to_string(),
+        };
+
+        let chart = Chart::new(datasets)
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Rgb(208, 135, 112))))
+            .x_axis(
+                time_axis(&[app.metrics.gpu_power_history()], now)
+                    .title("Time")
+                    .style(Style::default().fg(Color::Gray)),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("Watts")
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, y_max])
+                    .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+            );
+        f.render_widget(chart, chart_chunks[1]);
+    }
+
     // GPU Memory Chart
-    let gpu_memory_data: Vec<(f64, f64)> = app.metrics.gpu_memory_percent_history()
-        .iter()
-        .enumerate()
-        .map(|(i, &value)| (i as f64, value as f64))
-        .collect();
+    let gpu_memory_data: Vec<(f64, f64)> = app.metrics.gpu_memory_percent_history().points(now);
 
     if !gpu_memory_data.is_empty() {
         let datasets = vec![Dataset::default()
@@ -736,11 +3967,9 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Cyan)))
             .x_axis(
-                Axis::default()
+                time_axis(&[app.metrics.gpu_memory_percent_history()], now)
                     .title("Time")
-                    .style(Style::default().fg(Color::Gray))
-                    .bounds([0.0, app.metrics.gpu_memory_percent_history().len() as f64])
-                    .labels(vec!["Past", "Now"]),
+                    .style(Style::default().fg(Color::Gray)),
             )
             .y_axis(
                 Axis::default()
@@ -749,14 +3978,64 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
                     .bounds([0.0, 100.0])
                     .labels(vec!["0%", "25%", "50%", "75%", "100%"]),
             );
-        f.render_widget(chart, chart_chunks[1]);
+        f.render_widget(chart, chart_chunks[2]);
+    }
+
+    // GPU Clock Chart - core and memory clocks share one MHz axis
+    let core_clock_data: Vec<(f64, f64)> = app.metrics.gpu_core_clock_history().points(now);
+    let memory_clock_data: Vec<(f64, f64)> = app.metrics.gpu_memory_clock_history().points(now);
+
+    if !core_clock_data.is_empty() {
+        let peak = core_clock_data.iter()
+            .chain(memory_clock_data.iter())
+            .map(|&(_, mhz)| mhz)
+            .fold(0.0, f64::max);
+        let y_max = (peak * 1.1).max(1000.0);
+
+        let datasets = vec![
+            Dataset::default()
+                .name("Core")
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(Color::Rgb(235, 203, 139))) // Nord aurora yellow
+                .data(&core_clock_data),
+            Dataset::default()
+                .name("Mem")
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(Color::Rgb(180, 142, 173))) // Nord purple
+                .data(&memory_clock_data),
+        ];
+
+        let title = match (app.metrics.gpu_core_clock(), app.metrics.gpu_memory_clock()) {
+            (Some(core), Some(mem)) => format!("⏱️ Clocks {:.0}/{:.0} MHz", core, mem),
+            (Some(core), None) => format!("⏱️ Clocks {:.0} MHz", core),
+            _ => "⏱️ Clocks MHz".to_string(),
+        };
+
+        let chart = Chart::new(datasets)
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Yellow)))
+            .x_axis(
+                time_axis(&[app.metrics.gpu_core_clock_history(), app.metrics.gpu_memory_clock_history()], now)
+                    .title("Time")
+                    .style(Style::default().fg(Color::Gray)),
+            )
+            .y_axis(
+                Axis::default()
+                    .title("MHz")
+                    .style(Style::default().fg(Color::Gray))
+                    .bounds([0.0, y_max])
+                    .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+            );
+        f.render_widget(chart, chart_chunks[3]);
     }
 
     // Split info section into analytics and processes
     let info_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(chunks[3]);
+        .split(chunks[4]);
 
     // Enhanced GPU Analytics panel
     let mut gpu_info = vec![
@@ -776,13 +4055,25 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
         } else {
             ("🚨", "▅▇▇▇▅")
         };
-        gpu_info.push(Line::from(format!("│ {} Temp: {:.1}°C {}", temp_icon, t, temp_bar)));
+        gpu_info.push(Line::from(format!("│ {} Temp: {} {}", temp_icon, app.temperature_unit.format(t, 1), temp_bar)));
+    } else if let Some(pressure) = app.metrics.gpu_thermal_pressure() {
+        gpu_info.push(Line::from(format!("│ 🌡️ Thermal pressure: {}", pressure)));
     } else {
         gpu_info.push(Line::from("│ 🌡️ Temperature: N/A"));
     }
 
     // Enhanced fan speed with visual RPM indicator
-    if let Some(fan) = fan_speed {
+    let fans = app.metrics.gpu_fans();
+    if !fans.is_empty() {
+        for fan in fans {
+            let rpm = fan.rpm.map(|rpm| format!("{} RPM", rpm)).unwrap_or_else(|| "- RPM".to_string());
+            let line = match fan.percent {
+                Some(percent) => format!("│ 🌪️ Fan {}: {} ({:.0}%)", fan.index, rpm, percent),
+                None => format!("│ 🌪️ Fan {}: {}", fan.index, rpm),
+            };
+            gpu_info.push(Line::from(line));
+        }
+    } else if let Some(fan) = fan_speed {
         let (fan_icon, fan_bar) = if fan < 20.0 {
             ("💨", "▁▁▁▁▁")
         } else if fan < 40.0 {
@@ -811,6 +4102,7 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
             ("🔋", "▆▇▇▇▆")
         };
         gpu_info.push(Line::from(format!("│ {} Power: {:.1}W {}", power_icon, power, power_bar)));
+        gpu_info.push(Line::from(format!("│ 🔋 Session: {:.2} Wh", app.metrics.gpu_energy_wh())));
     } else {
         gpu_info.push(Line::from("│ ⚡ Power Draw: N/A"));
     }
@@ -835,6 +4127,17 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
         gpu_info.push(Line::from(format!("│ Total: {:.0} MB", total)));
     }
 
+    if let Some(driver) = app.metrics.gpu_driver_info() {
+        gpu_info.push(Line::from("├─ 🧰 Driver ─────────────────┤"));
+        gpu_info.push(Line::from(format!("│ Driver: {}", driver.driver_version)));
+        if let Some(cuda) = &driver.cuda_version {
+            gpu_info.push(Line::from(format!("│ CUDA: {}", cuda)));
+        }
+        if let Some(vbios) = &driver.vbios_version {
+            gpu_info.push(Line::from(format!("│ VBIOS: {}", vbios)));
+        }
+    }
+
     gpu_info.push(Line::from("╰─────────────────────────────╯"));
 
     // Enhanced status indicators
@@ -879,219 +4182,513 @@ fn draw_gpu_widget(f: &mut Frame, app: &App, area: Rect) {
     draw_gpu_processes(f, app, info_chunks[1]);
 }
 
-fn draw_gpu_processes(f: &mut Frame, _app: &App, area: Rect) {
-    // Get GPU processes using nvidia-smi
-    let gpu_processes = get_gpu_processes();
-    
-    let mut process_lines = vec![
-        Line::from("╭─ 🎮 GPU Processes ──────────╮"),
-    ];
+/// PCIe link utilization gauge plus a throughput sparkline, to spot PCIe-bound workloads
+fn draw_gpu_pcie(f: &mut Frame, app: &App, area: Rect) {
+    let row_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(area);
 
-    if gpu_processes.is_empty() {
-        process_lines.push(Line::from("│ No GPU processes detected"));
-        process_lines.push(Line::from("│ or nvidia-smi unavailable"));
+    let format_rate = |kbs: f32| {
+        if kbs >= 1000.0 {
+            format!("{:.1} MB/s", kbs / 1000.0)
+        } else {
+            format!("{:.0} KB/s", kbs)
+        }
+    };
+
+    let link = app.metrics.gpu_pcie_link();
+    let title = match link {
+        Some(link) if link.generation < link.max_generation || link.width < link.max_width => format!(
+            "🔌 PCIe Gen{} x{} (max Gen{} x{})",
+            link.generation, link.width, link.max_generation, link.max_width
+        ),
+        Some(link) => format!("🔌 PCIe Gen{} x{}", link.generation, link.width),
+        None => "🔌 PCIe".to_string(),
+    };
+
+    // The busier direction decides how close the link is to saturation
+    let (percent, label) = match (app.metrics.gpu_pcie_throughput(), link) {
+        (Some((rx, tx)), Some(link)) => (
+            (rx.max(tx) / link.bandwidth_kbs() * 100.0).clamp(0.0, 100.0),
+            format!("RX {} TX {}", format_rate(rx), format_rate(tx)),
+        ),
+        (Some((rx, tx)), None) => (0.0, format!("RX {} TX {}", format_rate(rx), format_rate(tx))),
+        (None, _) => (0.0, "N/A".to_string()),
+    };
+    let color = if percent < 50.0 {
+        Color::Rgb(163, 190, 140) // Nord aurora green
+    } else if percent < 80.0 {
+        Color::Rgb(235, 203, 139) // Nord aurora yellow
     } else {
-        // Add header with better spacing for longer process names
-        process_lines.push(Line::from("│ PID   GPU%  MEM%   VRAM  Process"));
-        process_lines.push(Line::from("├───────────────────────────────────"));
-        
-        // Add each process (show all processes, not just limited number)
-        for process in gpu_processes.iter() {
-            let gpu_util_str = process.gpu_util
-                .map(|u| format!("{:3}%", u))
-                .unwrap_or_else(|| "  0%".to_string());
-                
-            // Calculate memory percentage based on actual VRAM usage
-            let mem_util_str = if process.memory_mb > 0 {
-                // Try to get GPU memory percentage from metrics if available
-                if let (Some(total_vram), _) = (_app.metrics.gpu_memory_total(), _app.metrics.gpu_memory_used()) {
-                    let mem_percent = (process.memory_mb as f32 / total_vram) * 100.0;
-                    format!("{:3.1}%", mem_percent)
-                } else {
-                    // Fallback: show memory in MB if total VRAM unknown
-                    format!("{:3}MB", process.memory_mb)
-                }
-            } else {
-                // Show 0% instead of N/A for processes with no memory usage or utilization data
-                process.mem_util
-                    .map(|u| format!("{:3}%", u))
-                    .unwrap_or_else(|| "  0%".to_string())
-            };
-            
-            // Show more of the process name - truncate at 20 characters instead of 9
-            let truncated_name = if process.name.len() > 20 {
-                format!("{}...", &process.name[..17])
-            } else {
-                process.name.clone()
-            };
-            
-            let line = format!("│ {:5} {:>4} {:>6} {:4}MB {}", 
-                process.pid,
-                gpu_util_str,
-                mem_util_str,
-                process.memory_mb,
-                truncated_name
-            );
-            process_lines.push(Line::from(line));
+        Color::Rgb(191, 97, 106) // Nord aurora red
+    };
+
+    let gauge = Gauge::default()
+        .block(Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
+        .gauge_style(Style::default().fg(color))
+        .percent(percent as u16)
+        .label(label);
+    f.render_widget(gauge, row_chunks[0]);
+
+    let data: Vec<u64> = app.metrics.gpu_pcie_history().iter().map(|&kbs| kbs as u64).collect();
+    let sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded))
+        .data(&data)
+        .style(Style::default().fg(Color::Rgb(143, 188, 187))); // Nord frost teal
+    f.render_widget(sparkline, row_chunks[1]);
+}
+
+fn draw_gpu_tab(f: &mut Frame, app: &App, area: Rect) {
+    let devices = app.metrics.gpu_devices();
+    if devices.is_empty() {
+        let message = Paragraph::new("No supported GPU detected (waiting for nvidia-smi)")
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL));
+        f.render_widget(message, area);
+        return;
+    }
+    let selected = app.selected_gpu.min(devices.len() - 1);
+    let device = &devices[selected];
+    let nvlinks: Vec<&NvLink> = app.metrics.nvlinks().iter().filter(|link| link.gpu == device.index).collect();
+    // Borders plus header; hidden without NVLink
+    let nvlink_height = if nvlinks.is_empty() { 0 } else { nvlinks.len() as u16 + 3 };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),             // GPU selector
+            Constraint::Length(3),             // Gauges
+            Constraint::Length(nvlink_height), // NVLink
+            Constraint::Percentage(55),        // Charts
+            Constraint::Min(0),                // Processes
+        ])
+        .split(area);
+
+    // One sub-tab per GPU
+    let titles: Vec<String> = devices
+        .iter()
+        .map(|device| format!("GPU {}: {}", device.index, device.name))
+        .collect();
+    let mut selector_title = "[←/→] select GPU • [↑↓] select process • [V/G] sort • [K] kill".to_string();
+    if let Some(age) = app.metrics.stale_gpu_data_age() {
+        selector_title.push_str(&format!(" • data {}s old", age.as_secs()));
+    }
+    let selector = Tabs::new(titles)
+        .block(Block::default()
+            .title(selector_title)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(98, 114, 164))))
+        .select(selected)
+        .style(Style::default().fg(Color::Rgb(216, 222, 233)))
+        .highlight_style(Style::default()
+            .add_modifier(Modifier::BOLD)
+            .fg(Color::Rgb(136, 192, 208)) // Nord frost
+            .bg(Color::Rgb(46, 52, 64)));
+    f.render_widget(selector, chunks[0]);
+
+    // Usage, VRAM, temperature gauges plus power/clock readout
+    let gauge_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 4); 4])
+        .split(chunks[1]);
+
+    let level_color = |percent: f32| {
+        if percent < 50.0 {
+            Color::Rgb(163, 190, 140) // Nord aurora green
+        } else if percent < 80.0 {
+            Color::Rgb(235, 203, 139) // Nord aurora yellow
+        } else {
+            Color::Rgb(191, 97, 106) // Nord aurora red
         }
+    };
+    let gauges = [
+        ("🎮 Usage", device.usage, device.usage.map(|u| format!("{:.0}%", u))),
+        (
+            "💾 VRAM",
+            device.memory_percent(),
+            device.memory_used.zip(device.memory_total).map(|(used, total)| format!("{:.0} / {:.0} MB", used, total)),
+        ),
+        ("🌡️ Temperature", device.temperature, device.temperature.map(|t| app.temperature_unit.format(t, 0))),
+    ];
+    for ((title, percent, label), &gauge_area) in gauges.into_iter().zip(gauge_chunks.iter()) {
+        let percent = percent.unwrap_or(0.0).clamp(0.0, 100.0);
+        let gauge = Gauge::default()
+            .block(Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
+            .gauge_style(Style::default().fg(level_color(percent)))
+            .percent(percent as u16)
+            .label(label.unwrap_or_else(|| "N/A".to_string()));
+        f.render_widget(gauge, gauge_area);
     }
-    
-    process_lines.push(Line::from("╰────────────────────────────────────╯"));
 
-    let processes_paragraph = Paragraph::new(process_lines)
+    let power = device.power_draw.map(|w| format!("{:.1} W", w)).unwrap_or_else(|| "N/A".to_string());
+    let clocks = match (device.core_clock, device.memory_clock) {
+        (Some(core), Some(mem)) => format!("{:.0}/{:.0} MHz", core, mem),
+        _ => "N/A".to_string(),
+    };
+    let readout = Paragraph::new(format!("⚡ {}  ⏱️ {}", power, clocks))
         .block(Block::default()
-            .title("🎮 GPU Processes")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Yellow)))
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(143, 188, 187))))
         .style(Style::default().fg(Color::White));
-    f.render_widget(processes_paragraph, area);
+    f.render_widget(readout, gauge_chunks[3]);
+
+    if !nvlinks.is_empty() {
+        draw_nvlinks(f, &nvlinks, chunks[2]);
+    }
+
+    // 2x2 history charts
+    let chart_rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[3]);
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chart_rows[0]);
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chart_rows[1]);
+
+    draw_gpu_device_chart(f, device, "🎮 Usage %", &[("Usage", device.usage_history(), Color::Green)], 100.0, "%", top[0]);
+    draw_gpu_device_chart(f, device, "💾 VRAM %", &[("VRAM", device.memory_percent_history(), Color::Cyan)], 100.0, "%", top[1]);
+    let unit = app.temperature_unit;
+    let temperatures = device.temperature_history().map(|t| unit.convert(t));
+    let temperature_max = temperatures.iter().copied().fold(0.0, f32::max).max(unit.convert(90.0)) as f64;
+    draw_gpu_device_chart(
+        f,
+        device,
+        &format!("🌡️ Temperature {}", unit.symbol()),
+        &[("Temp", &temperatures, Color::Rgb(208, 135, 112))],
+        temperature_max,
+        unit.symbol(),
+        bottom[0],
+    );
+    let clock_max = device.core_clock_history()
+        .iter()
+        .chain(device.memory_clock_history().iter())
+        .copied()
+        .fold(0.0, f32::max) as f64 * 1.1;
+    draw_gpu_device_chart(
+        f,
+        device,
+        "⏱️ Clocks MHz",
+        &[
+            ("Core", device.core_clock_history(), Color::Rgb(235, 203, 139)),
+            ("Mem", device.memory_clock_history(), Color::Rgb(180, 142, 173)),
+        ],
+        clock_max.max(1000.0),
+        "MHz",
+        bottom[1],
+    );
+
+    draw_gpu_processes(f, app, chunks[4]);
 }
 
-#[derive(Debug)]
-struct GpuProcess {
-    pid: u32,
-    name: String,
-    memory_mb: u32,
-    gpu_util: Option<u32>,     // GPU utilization percentage
-    mem_util: Option<u32>,     // Memory utilization percentage
+/// One block character per value, scaled between `min` and `max`
+/// X axis of a history chart in seconds before now, back to the oldest sample shown.
+/// Samples sit at their real age, so irregular updates do not stretch the chart.
+fn time_axis<'a>(histories: &[&History], now: Instant) -> Axis<'a> {
+    let window = histories.iter().map(|history| history.span(now)).fold(0.0, f64::max).max(1.0);
+    Axis::default()
+        .bounds([-window, 0.0])
+        .labels(vec![format!("-{}", format_age(window)), "Now".to_string()])
 }
 
-fn get_gpu_processes() -> Vec<GpuProcess> {
-    use std::process::Command;
-    
-    let mut processes = Vec::new();
-    
-    // Try to get all GPU processes using the comprehensive query method
-    let comprehensive_output = Command::new("nvidia-smi")
-        .args([
-            "--query-compute-apps=pid,name,used_memory",
-            "--format=csv,noheader,nounits",
-        ])
-        .output();
-
-    if let Ok(output) = comprehensive_output {
-        if output.status.success() {
-            if let Ok(out_str) = String::from_utf8(output.stdout) {
-                for line in out_str.lines() {
-                    if line.trim().is_empty() {
-                        continue;
-                    }
-                    let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-                    if parts.len() >= 3 {
-                        if let (Ok(pid), Ok(memory)) = (parts[0].parse::<u32>(), parts[2].parse::<u32>()) {
-                            let name = parts[1].to_string();
-                            processes.push(GpuProcess {
-                                pid,
-                                name,
-                                memory_mb: memory,
-                                gpu_util: None,
-                                mem_util: None,
-                            });
-                        }
-                    }
-                }
-            }
-        }
+/// Short age for chart axes: 45s, 5m, 2m 30s, 1h 5m
+fn format_age(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 if seconds.is_multiple_of(60) => format!("{}m", seconds / 60),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        _ => crate::format_uptime(seconds),
     }
-    
-    // Get per-process GPU utilization using pmon
-    let pmon_output = Command::new("nvidia-smi")
-        .args(["pmon", "-c", "1", "-s", "u"])
-        .output();
-
-    if let Ok(output) = pmon_output {
-        if output.status.success() {
-            if let Ok(out_str) = String::from_utf8(output.stdout) {
-                for line in out_str.lines() {
-                    // Skip header and separator lines
-                    if line.starts_with('#') || line.trim().is_empty() || line.contains("gpu") {
-                        continue;
-                    }
-                    
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    // Expected format: gpu pid type sm mem enc dec command
-                    if parts.len() >= 7 {
-                        if let Ok(pid) = parts[1].parse::<u32>() {
-                            // Parse utilization percentages - handle both % and - cases
-                            let gpu_util = if parts[3] == "-" { 
-                                None 
-                            } else { 
-                                parts[3].replace("%", "").parse::<u32>().ok() 
-                            };
-                            let mem_util = if parts[4] == "-" { 
-                                None 
-                            } else { 
-                                parts[4].replace("%", "").parse::<u32>().ok() 
-                            };
-                            
-                            // Check if we already have this process from compute query
-                            if let Some(process) = processes.iter_mut().find(|p| p.pid == pid) {
-                                // Update existing process with utilization info
-                                process.gpu_util = gpu_util;
-                                process.mem_util = mem_util;
-                            } else {
-                                // Add new process found in pmon but not in compute apps
-                                let name = parts[6..].join(" ");
-                                processes.push(GpuProcess {
-                                    pid,
-                                    name,
-                                    memory_mb: 0, // Will be updated from graphics query
-                                    gpu_util,
-                                    mem_util,
-                                });
-                            }
-                        }
-                    }
-                }
-            }
-        }
+}
+
+/// An event rate, e.g. "1.2k/s"
+fn per_second(rate: f32) -> String {
+    if rate >= 1_000_000.0 {
+        format!("{:.1}M/s", rate / 1_000_000.0)
+    } else if rate >= 1000.0 {
+        format!("{:.1}k/s", rate / 1000.0)
+    } else {
+        format!("{:.0}/s", rate)
     }
-    
-    // Get additional graphics processes if available
-    let graphics_output = Command::new("nvidia-smi")
-        .args([
-            "--query-apps=pid,name,used_memory",
-            "--format=csv,noheader,nounits",
+}
+
+fn text_sparkline(values: &[f32], min: f32, max: f32) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let range = (max - min).max(f32::EPSILON);
+    values
+        .iter()
+        .map(|&v| BLOCKS[(((v - min) / range).clamp(0.0, 1.0) * 7.0).round() as usize])
+        .collect()
+}
+
+/// Per-link NVLink bandwidth and error counters of one GPU
+fn draw_nvlinks(f: &mut Frame, links: &[&NvLink], area: Rect) {
+    const TREND_SAMPLES: usize = 20;
+
+    let format_rate = |kbs: Option<f32>| match kbs {
+        Some(kbs) if kbs >= 1_000_000.0 => format!("{:.1} GB/s", kbs / 1_000_000.0),
+        Some(kbs) if kbs >= 1000.0 => format!("{:.1} MB/s", kbs / 1000.0),
+        Some(kbs) => format!("{:.0} KB/s", kbs),
+        None => "-".to_string(),
+    };
+
+    let header = Row::new(vec![
+        Cell::from("Link"),
+        Cell::from("Speed"),
+        Cell::from("TX"),
+        Cell::from("RX"),
+        Cell::from("Utilization"),
+        Cell::from("Trend"),
+        Cell::from("Replay"),
+        Cell::from("Recovery"),
+        Cell::from("CRC"),
+    ])
+    .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows = links.iter().map(|link| {
+        let (utilization, color) = match link.utilization() {
+            Some(percent) => {
+                let filled = (percent / 10.0).round() as usize;
+                let color = if percent < 50.0 {
+                    Color::Rgb(163, 190, 140) // Nord aurora green
+                } else if percent < 80.0 {
+                    Color::Rgb(235, 203, 139) // Nord aurora yellow
+                } else {
+                    Color::Rgb(191, 97, 106) // Nord aurora red
+                };
+                (format!("{}{} {:>3.0}%", "█".repeat(filled), "░".repeat(10 - filled), percent), color)
+            }
+            None => ("N/A".to_string(), Color::Gray),
+        };
+
+        // Recent combined traffic, scaled to the link's bidirectional capacity
+        let capacity = link.speed_gbs * 2_000_000.0;
+        let history = link.history();
+        let recent: Vec<f32> = history.iter().skip(history.len().saturating_sub(TREND_SAMPLES)).copied().collect();
+        let trend = text_sparkline(&recent, 0.0, capacity);
+
+        let errors = link.replay_errors + link.recovery_errors + link.crc_errors;
+        let error_style = if errors > 0 {
+            Style::default().fg(Color::Rgb(191, 97, 106)) // Nord aurora red
+        } else {
+            Style::default()
+        };
+
+        Row::new(vec![
+            Cell::from(link.link.to_string()),
+            Cell::from(format!("{:.0} GB/s", link.speed_gbs)),
+            Cell::from(format_rate(link.tx_rate)),
+            Cell::from(format_rate(link.rx_rate)),
+            Cell::from(utilization).style(Style::default().fg(color)),
+            Cell::from(trend).style(Style::default().fg(Color::Rgb(136, 192, 208))),
+            Cell::from(link.replay_errors.to_string()).style(error_style),
+            Cell::from(link.recovery_errors.to_string()).style(error_style),
+            Cell::from(link.crc_errors.to_string()).style(error_style),
         ])
-        .output();
-
-    if let Ok(output) = graphics_output {
-        if output.status.success() {
-            if let Ok(out_str) = String::from_utf8(output.stdout) {
-                for line in out_str.lines() {
-                    if line.trim().is_empty() {
-                        continue;
-                    }
-                    let parts: Vec<&str> = line.split(',').map(|s| s.trim()).collect();
-                    if parts.len() >= 3 {
-                        if let (Ok(pid), Ok(memory)) = (parts[0].parse::<u32>(), parts[2].parse::<u32>()) {
-                            let name = parts[1].to_string();
-                            
-                            // Check if we already have this process
-                            if let Some(process) = processes.iter_mut().find(|p| p.pid == pid) {
-                                // Update memory if it's higher (more accurate)
-                                if memory > process.memory_mb {
-                                    process.memory_mb = memory;
-                                }
-                            } else {
-                                // Add new graphics process
-                                processes.push(GpuProcess {
-                                    pid,
-                                    name,
-                                    memory_mb: memory,
-                                    gpu_util: None,
-                                    mem_util: None,
-                                });
-                            }
-                        }
-                    }
-                }
+    });
+
+    let widths = [
+        Constraint::Length(4),   // Link
+        Constraint::Length(8),   // Speed
+        Constraint::Length(10),  // TX
+        Constraint::Length(10),  // RX
+        Constraint::Length(15),  // Utilization
+        Constraint::Length(TREND_SAMPLES as u16), // Trend
+        Constraint::Length(7),   // Replay
+        Constraint::Length(9),   // Recovery
+        Constraint::Min(5),      // CRC
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default()
+            .title(format!("🔗 NVLink ({} links)", links.len()))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(180, 142, 173))))
+        .column_spacing(1);
+    f.render_widget(table, area);
+}
+
+/// A history chart for one GPU in the GPU tab
+fn draw_gpu_device_chart(
+    f: &mut Frame,
+    device: &GpuDevice,
+    title: &str,
+    series: &[(&str, &History, Color)],
+    y_max: f64,
+    unit: &str,
+    area: Rect,
+) {
+    let now = Instant::now();
+    let points: Vec<Vec<(f64, f64)>> = series
+        .iter()
+        .map(|(_, history, _)| history.points(now))
+        .collect();
+    let datasets = series
+        .iter()
+        .zip(points.iter())
+        .map(|((name, _, color), data)| {
+            Dataset::default()
+                .name(*name)
+                .marker(symbols::Marker::Braille)
+                .style(Style::default().fg(*color))
+                .data(data)
+        })
+        .collect();
+
+    let chart = Chart::new(datasets)
+        .block(Block::default()
+            .title(format!("{} (GPU {})", title, device.index))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(Color::Rgb(136, 192, 208))))
+        .x_axis(
+            time_axis(&series.iter().map(|&(_, history, _)| history).collect::<Vec<_>>(), now)
+                .style(Style::default().fg(Color::Gray)),
+        )
+        .y_axis(
+            Axis::default()
+                .title(unit)
+                .style(Style::default().fg(Color::Gray))
+                .bounds([0.0, y_max])
+                .labels(vec!["0".to_string(), format!("{:.0}", y_max / 2.0), format!("{:.0}", y_max)]),
+        );
+    f.render_widget(chart, area);
+}
+
+fn draw_gpu_processes(f: &mut Frame, app: &App, area: Rect) {
+    let gpu_processes = &app.gpu_processes;
+
+    let sort_indicator = match app.gpu_process_sort_mode {
+        crate::GpuProcessSortMode::Vram => "VRAM",
+        crate::GpuProcessSortMode::Gpu => "GPU%",
+    };
+    let block = Block::default()
+        .title(format!("🎮 GPU Processes ({}, by {}) • [V/G] sort [K] kill", gpu_processes.len(), sort_indicator))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    if gpu_processes.is_empty() {
+        let hint = Paragraph::new(vec![
+            Line::from("No GPU processes detected"),
+            Line::from("or nvidia-smi unavailable"),
+        ])
+        .style(Style::default().fg(Color::White))
+        .block(block);
+        f.render_widget(hint, area);
+        return;
+    }
+
+    // Which GPU a process runs on only matters with more than one
+    let multi_gpu = app.metrics.gpu_devices().len() > 1;
+
+    let mut header_cells = vec![Cell::from("PID")];
+    if multi_gpu {
+        header_cells.push(Cell::from("GPU"));
+    }
+    header_cells.extend([
+        Cell::from("User"),
+        Cell::from("CPU%"),
+        Cell::from("GPU%"),
+        Cell::from("MEM%"),
+        Cell::from("VRAM"),
+        Cell::from("Trend"),
+        Cell::from("Process"),
+    ]);
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD));
+
+    let rows: Vec<Row> = gpu_processes
+        .iter()
+        .map(|process| {
+            let gpu_util = process.gpu_util
+                .map(|u| format!("{}%", u))
+                .unwrap_or_else(|| "-".to_string());
+
+            // Memory percentage of total VRAM, falling back to pmon's memory utilization
+            let mem_util = match (process.memory_mb, app.metrics.gpu_memory_total()) {
+                (used, Some(total_vram)) if used > 0 => format!("{:.1}%", used as f32 / total_vram * 100.0),
+                _ => process.mem_util
+                    .map(|u| format!("{}%", u))
+                    .unwrap_or_else(|| "-".to_string()),
+            };
+
+            // VRAM over recent refreshes, scaled to the process's own range so growth
+            // stands out; a steady climb is the usual sign of a leak
+            let (arrow, arrow_color) = match app.gpu_vram_history.trend(process.pid) {
+                VramTrend::Rising => ("↑", Color::Rgb(191, 97, 106)), // Nord aurora red
+                VramTrend::Falling => ("↓", Color::Rgb(163, 190, 140)), // Nord aurora green
+                VramTrend::Steady => ("→", Color::Gray),
+            };
+            let samples: Vec<f32> = app.gpu_vram_history
+                .samples(process.pid)
+                .map(|samples| samples.iter().map(|&mb| mb as f32).collect())
+                .unwrap_or_default();
+            let low = samples.iter().copied().fold(f32::MAX, f32::min);
+            let high = samples.iter().copied().fold(0.0, f32::max);
+            let recent = &samples[samples.len().saturating_sub(VRAM_TREND_WIDTH)..];
+            let trend = Line::from(vec![
+                Span::styled(arrow, Style::default().fg(arrow_color)),
+                Span::styled(text_sparkline(recent, low, high), Style::default().fg(Color::Rgb(136, 192, 208))),
+            ]);
+
+            let mut cells = vec![Cell::from(process.pid.to_string())];
+            if multi_gpu {
+                cells.push(Cell::from(process.gpu_index.map(|i| i.to_string()).unwrap_or_else(|| "-".to_string())));
             }
-        }
+            cells.extend([
+                Cell::from(process.user.clone().unwrap_or_else(|| "-".to_string())),
+                Cell::from(process.cpu_usage.map(|cpu| format!("{:.1}", cpu)).unwrap_or_else(|| "-".to_string())),
+                Cell::from(gpu_util),
+                Cell::from(mem_util),
+                Cell::from(format!("{}MB", process.memory_mb)),
+                Cell::from(trend),
+                Cell::from(process.name.clone()),
+            ]);
+            Row::new(cells)
+        })
+        .collect();
+
+    let mut widths = vec![Constraint::Length(7)];  // PID
+    if multi_gpu {
+        widths.push(Constraint::Length(3));          // GPU
     }
+    widths.extend([
+        Constraint::Length(8),   // User
+        Constraint::Length(5),   // CPU%
+        Constraint::Length(5),   // GPU%
+        Constraint::Length(6),   // MEM%
+        Constraint::Length(8),   // VRAM
+        Constraint::Length(VRAM_TREND_WIDTH as u16 + 1), // Trend
+        Constraint::Min(10),     // Process
+    ]);
 
-    // Sort by memory usage (highest first)
-    processes.sort_by(|a, b| b.memory_mb.cmp(&a.memory_mb));
-    processes
-}
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(block)
+        .style(Style::default().fg(Color::White))
+        .row_highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .column_spacing(1);
 
+    let mut table_state = TableState::default();
+    table_state.select(Some(app.gpu_process_scroll.min(gpu_processes.len() - 1)));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
@@ -0,0 +1,30 @@
+use std::{
+    sync::mpsc::{self, Receiver, TryRecvError},
+    thread,
+};
+
+/// Work running on a thread of its own, so network requests and commands that may take
+/// seconds do not stall input and redraws. The owner checks back with `try_result`.
+pub struct Job<T> {
+    receiver: Receiver<T>,
+}
+
+impl<T: Send + 'static> Job<T> {
+    pub fn spawn(work: impl FnOnce() -> T + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            // The owner may be gone meanwhile, e.g. replaced by a config reload
+            let _ = sender.send(work());
+        });
+        Self { receiver }
+    }
+
+    /// The result once the work is done; None while it still runs
+    pub fn try_result(&self) -> Option<Result<T, String>> {
+        match self.receiver.try_recv() {
+            Ok(result) => Some(Ok(result)),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Err("worker thread panicked".to_string())),
+        }
+    }
+}